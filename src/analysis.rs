@@ -0,0 +1,253 @@
+//!
+//! 离线分析: 对一张客户区截图运行全部检测, 给出每个区域的分数和阈值
+//!
+//! * regions: 每个区域单帧打分, 不经过投票/迟滞/变化检测, 用于看分数离阈值有多远
+//! * detectors: 与主循环相同的判定 (check_game_shot / check_qte_appear)
+//!
+use crate::detector::{Detection, Detector, Detectors, ShotSource};
+use crate::error::Result;
+use crate::vision::Image;
+use crate::vision::draw::{BLACK, Canvas, GREEN, RED, WHITE, text_size};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// 一次检测的结果, 出错时 error 为错误信息
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub passed: bool,
+    pub score: f64,
+    pub details: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CheckResult {
+    fn new(name: &str, result: Result<Detection>) -> Self {
+        match result {
+            Ok(detection) => Self {
+                name: name.to_string(),
+                passed: detection.passed,
+                score: detection.score,
+                details: detection.details,
+                error: None,
+            },
+            Err(e) => Self {
+                name: name.to_string(),
+                passed: false,
+                score: 0.0,
+                details: String::new(),
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// 单个区域的位置, 阈值和单帧结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegionScore {
+    pub rect: (i32, i32, i32, i32),
+    pub threshold: f64,
+    #[serde(flatten)]
+    pub check: CheckResult,
+}
+
+impl RegionScore {
+    /// 每个区域单帧打分, 不经过投票/迟滞/变化检测
+    pub fn score_all(source: &dyn ShotSource, detectors: &Detectors) -> Vec<Self> {
+        detectors
+            .pipelines()
+            .into_iter()
+            .map(|pipeline| RegionScore {
+                rect: pipeline.region().rect,
+                threshold: pipeline.threshold(),
+                check: CheckResult::new(pipeline.name(), pipeline.score_once(source)),
+            })
+            .collect()
+    }
+
+    /// 标注图上的文字, 如 "TARGET 0.8123/0.7"
+    fn label(&self) -> String {
+        match self.check.error {
+            Some(_) => format!("{} error", self.check.name),
+            None => format!(
+                "{} {:.4}/{}",
+                self.check.name, self.check.score, self.threshold
+            ),
+        }
+    }
+}
+
+/// 标注文字的放大倍数
+static LABEL_SCALE: i32 = 2;
+
+///
+/// 在截图上画出各区域, 单帧通过为绿色, 否则为红色
+///
+/// * 区域上方 (放不下时在下方) 写区域名, 分数和阈值
+/// * caption 写在左上角, 如主循环的状态
+///
+pub fn annotate_regions(
+    img: &Image,
+    regions: &[RegionScore],
+    caption: Option<&str>,
+) -> Result<Image> {
+    let mut canvas = Canvas::from_image(img);
+    let (_, label_height) = text_size("", LABEL_SCALE);
+    let box_height = label_height + 2 * LABEL_SCALE;
+    // 已画的文字框, 位置重叠 (如 COIN_ONE/COIN_FIVE 共用区域) 时往上叠放
+    let mut placed: Vec<(i32, i32, i32, i32)> = Vec::new();
+    for region in regions {
+        let color = if region.check.passed { GREEN } else { RED };
+        canvas.draw_rect(region.rect, color, 2);
+        let (left, top, _, height) = region.rect;
+        let label = region.label();
+        let (label_width, _) = text_size(&label, LABEL_SCALE);
+        let mut rect = (
+            left - 2,
+            top - 2 - box_height,
+            label_width + 2 * LABEL_SCALE,
+            box_height,
+        );
+        if rect.1 < 0 {
+            rect.1 = top + height + 2;
+        }
+        while placed.iter().any(|other| overlaps(&rect, other)) {
+            rect.1 -= box_height;
+        }
+        placed.push(rect);
+        canvas.draw_label(rect.0, rect.1, &label, WHITE, color, LABEL_SCALE);
+    }
+    if let Some(caption) = caption {
+        canvas.draw_label(0, 0, caption, WHITE, BLACK, LABEL_SCALE);
+    }
+    Ok(canvas.into_image()?)
+}
+
+fn overlaps(a: &(i32, i32, i32, i32), b: &(i32, i32, i32, i32)) -> bool {
+    a.0 < b.0 + b.2 && b.0 < a.0 + a.2 && a.1 < b.1 + b.3 && b.1 < a.1 + a.3
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Analysis {
+    pub regions: Vec<RegionScore>,
+    pub detectors: Vec<CheckResult>,
+}
+
+impl Analysis {
+    pub fn run(source: &dyn ShotSource, detectors: &Detectors) -> Self {
+        let regions = RegionScore::score_all(source, detectors);
+        let detectors = detectors
+            .all()
+            .into_iter()
+            .map(|detector| CheckResult::new(detector.name(), detector.detect(source)))
+            .collect();
+        Self { regions, detectors }
+    }
+
+    /// 文本表格, 区域和检测器各一段
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<12} {:<22} {:>9} {:>9}  PASS",
+            "REGION", "RECT", "THRESHOLD", "SCORE"
+        );
+        for region in &self.regions {
+            let (left, top, width, height) = region.rect;
+            let _ = write!(
+                out,
+                "{:<12} {:<22} {:>9.4} {:>9.4}  {}",
+                region.check.name,
+                format!("({}, {}, {}, {})", left, top, width, height),
+                region.threshold,
+                region.check.score,
+                region.check.passed
+            );
+            if let Some(e) = &region.check.error {
+                let _ = write!(out, " error: {}", e);
+            }
+            out.push('\n');
+        }
+        out.push('\n');
+        let _ = writeln!(out, "{:<12} {:<5}  DETAILS", "DETECTOR", "PASS");
+        for detector in &self.detectors {
+            let details = detector.error.as_deref().unwrap_or(&detector.details);
+            let _ = writeln!(
+                out,
+                "{:<12} {:<5}  {}",
+                detector.name, detector.passed, details
+            );
+        }
+        out
+    }
+
+    /// 见 [`annotate_regions`]
+    pub fn annotate(&self, img: &Image) -> Result<Image> {
+        annotate_regions(img, &self.regions, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::Screenshot;
+    use crate::vision::ReadMode;
+
+    #[test]
+    fn test_analyze_target_screenshot() {
+        let img = Image::load("test_data/target.jpg", ReadMode::Unchanged).unwrap();
+        let source = Screenshot { img: img.clone() };
+        let analysis = Analysis::run(&source, &Detectors::new(0, 0));
+
+        let region = |name: &str| {
+            analysis
+                .regions
+                .iter()
+                .find(|r| r.check.name == name)
+                .unwrap()
+        };
+        assert_eq!(analysis.regions.len(), 7);
+        assert!(region("TARGET").check.passed);
+        assert_eq!(region("TARGET").threshold, 0.7);
+        assert!(!region("QTE").check.passed);
+        assert_eq!(analysis.detectors.len(), 8);
+        assert!(analysis.table().contains("COIN_KNOWN"));
+
+        let annotated = analysis.annotate(&img).unwrap();
+        assert_eq!(
+            (annotated.width(), annotated.height(), annotated.channels()),
+            (img.width(), img.height(), 3)
+        );
+        assert_eq!(
+            region("TARGET").label(),
+            format!("TARGET {:.4}/0.7", region("TARGET").check.score)
+        );
+
+        // caption 在左上角, 黑底白字
+        let captioned = annotate_regions(&img, &analysis.regions, Some("PRESS ENTER")).unwrap();
+        let px = |x, y| {
+            [
+                captioned.at(x, y, 0),
+                captioned.at(x, y, 1),
+                captioned.at(x, y, 2),
+            ]
+        };
+        assert_eq!(px(0, 0), BLACK);
+        // 'P' 第一行的第一个点
+        assert_eq!(px(2, 2), WHITE);
+    }
+
+    #[test]
+    fn test_errors_are_reported_per_region() {
+        // 截图比区域小, 每个区域都截图失败
+        let source = Screenshot {
+            img: Image::from_vec(10, 10, 3, vec![0; 300]).unwrap(),
+        };
+        let analysis = Analysis::run(&source, &Detectors::new(0, 0));
+        assert!(analysis.regions.iter().all(|r| r.check.error.is_some()));
+        let json = serde_json::to_value(&analysis).unwrap();
+        assert!(json["regions"][0]["error"].is_string());
+        assert!(json["regions"][0]["threshold"].is_number());
+    }
+}
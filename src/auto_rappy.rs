@@ -1,48 +1,36 @@
-use crate::capture_settings::CapturePos;
-use crate::dxgi_capture::DxgiCapture;
+use crate::cancel::CancellationToken;
+use crate::config::RunConfig;
+use crate::dataset::{DATASET_DIR, Dataset, DatasetLimits};
+use crate::detector::{Detector, Detectors, ShotSource};
+use crate::dxgi_capture::{DxgiCapture, Frame};
+use crate::error::{RappyError, Result};
 use crate::keyboard_utils::WindowsKeyboard;
-use crate::rappy_checker;
-use crate::rappy_checker::get_threshold_mat;
+use crate::latency::{FrameStamps, QteTiming};
+use crate::scheduler::{Phase, Scheduler};
+use crate::snapshot::{SNAPSHOT_DIR, Snapshots};
+use crate::stats::{Event, STATS_DIR, SessionStats};
+use crate::supervisor::Worker;
 use crate::template_img::TemplateImg;
-use crate::windows_utils::{get_window_client_offset, search_window_by_title, update_window};
-use egui::Context;
+use crate::windows_utils::{
+    get_window_client_offset, get_window_client_size, search_window_by_title, update_window,
+};
 use log::{error, info};
-use opencv::core::{Mat, MatTraitConst, Size, Vector, min_max_loc, no_array};
-use opencv::imgcodecs::imwrite;
-use opencv::imgproc::{INTER_LINEAR, TM_CCORR_NORMED, match_template, resize};
+use std::sync::Arc;
 use std::sync::mpsc::Sender;
-use std::thread::sleep;
-use std::time::Duration;
-use windows::core::Error;
-
-pub(crate) static QTE_DIR: &str = "QTE_";
-pub(crate) static TARGET_DIR: &str = "TARGET_";
-
-static IMG_THRESH: u8 = 190;
-
-pub fn check_or_create_dir(path: &str) {
-    use std::fs;
-    use std::path::Path;
-    let dir_path = Path::new(path);
-    if !dir_path.exists() || dir_path.is_file() {
-        if let Err(e) = fs::create_dir_all(dir_path) {
-            error!("Failed to create directory {}: {}", path, e);
-        }
-    } else {
-        info!("Directory {} already exists.", path);
-    }
-}
+use std::time::{Duration, Instant};
 
 ///
-/// 检查游戏中的截图(shot)部分是否和模板(TemplateImg)中的图片相似
+/// 检查游戏中的截图(shot)部分是否满足检测器, 检测结果写入日志
 ///
 /// # Examples
 ///
 /// ```
 ///    match search_window_by_title("PHANTASY STAR ONLINE 2"){
 ///         Some(hwnd)=>{
-///             let capture = DxgiCapture::new("libs/dxgi.dll", hwnd)?;
-///             let is_similar = check_game_shot(&capture, &CapturePos::key_ready, TemplateImg::key_ready.deref(), 0.9);
+///             let capture = DxgiCapture::new(hwnd)?;
+///             let detectors = Detectors::new(offset_x, offset_y);
+///             let frame = capture.frame(client_rect);
+///             let is_similar = check_game_shot(&frame, detectors.key_ready.as_ref());
 ///             info!("Similar key_ready_shot: {}", is_similar);
 ///             Ok(())
 ///         },
@@ -52,172 +40,245 @@ pub fn check_or_create_dir(path: &str) {
 ///         }
 ///     }
 /// ```
-/// ## Overloaded Parameters
 ///
 /// ## Parameters
 ///
-/// * capture: the dxgi capture.
-/// * capture_pos: the position of game shot, this is a relative position for (game) window.
-/// * template_img: the image decode from png code(created by opencv im_encode)
-/// * sim_threshold: the minimum value of SSIM similarity.
+/// * source: where the shots come from, usually a frame of the dxgi capture.
+/// * detector: region, preprocessing, scoring and threshold of the check (see detector::Detectors).
 ///
 /// ## Return
 ///
-/// * return true if the SSIM similarity > sim_threshold.
-fn check_game_shot(
-    capture: &DxgiCapture,
-    capture_pos: &CapturePos,
-    template_img: &TemplateImg,
-    sim_threshold: f64,
-    threshed: bool,
-) -> bool {
-    if threshed {
-        let game_shot = capture.grab(capture_pos);
-        let game_shot = get_threshold_mat(&game_shot, IMG_THRESH);
-        let img = get_threshold_mat(&template_img.img, IMG_THRESH);
-
-        let is_gray = game_shot.channels() == 1;
-        let sim = rappy_checker::get_ssim(&game_shot, &img, is_gray).unwrap_or_default();
-        #[cfg(test)]
-        {
-            info!("Sim: {}", sim);
+/// * return true if the detection passed, false if it failed or an error occurred.
+fn check_game_shot(source: &dyn ShotSource, detector: &dyn Detector) -> bool {
+    match detector.detect(source) {
+        Ok(detection) => {
+            info!(
+                "{}: {}, {}",
+                detector.name(),
+                detection.passed,
+                detection.details
+            );
+            detection.passed
+        }
+        Err(e) => {
+            error!("Failed to detect {}: {}", detector.name(), e);
+            false
         }
-        sim > sim_threshold
-    } else {
-        let game_shot = capture.grab_gray(capture_pos);
-        let img = &template_img.img;
-        let is_gray = game_shot.channels() == 1;
-        let sim = rappy_checker::get_ssim(&game_shot, img, is_gray).unwrap_or_default();
-        info!("Sim: {}", sim);
-        sim > sim_threshold
     }
 }
 
+/// 按键之后再执行的保存截图操作
+type SaveImage<'a> = Box<dyn Fn() + 'a>;
+
 struct AutoRappy {
-    offset_x: i32,
-    offset_y: i32,
+    detectors: Detectors,
+    client_rect: (i32, i32, i32, i32),
+    scheduler: Scheduler,
+    /// 保存各区域的截图, 打开失败时为 None, 不影响运行
+    dataset: Option<Arc<Dataset>>,
+    /// 执行操作时保存决策快照, 未开启时为 None
+    snapshots: Option<Snapshots>,
+    /// 会话统计, 由界面或命令行持有, 自动重启时继续累计
+    stats: Arc<SessionStats>,
 }
 
-impl AutoRappy {
-    fn check_qte_appear<'a>(
-        &self,
-        capture: &DxgiCapture,
-        tx: &'a Sender<String>,
-    ) -> (bool, Option<Box<dyn Fn() + 'a>>) {
-        let rappy_qte_shot = capture.grab_gray(&CapturePos::qte(self.offset_x, self.offset_y));
-        let mut resized_rappy_qte_shot = Mat::default();
+/// 快照标题中的主循环状态
+fn loop_state(bet_coin_is_one: bool, burst: bool) -> String {
+    format!("bet_coin_is_one: {}, burst: {}", bet_coin_is_one, burst)
+}
 
-        if resize(
-            &rappy_qte_shot,
-            &mut resized_rappy_qte_shot,
-            Size::new(0, 0),
-            0.5,
-            0.5,
-            INTER_LINEAR,
-        )
-        .is_err()
-        {
-            error!("Failed to resize QTE image");
-            return (false, None);
+impl AutoRappy {
+    fn new(
+        offset_x: i32,
+        offset_y: i32,
+        client_size: (i32, i32),
+        token: CancellationToken,
+        dataset: Option<Arc<Dataset>>,
+        stats: Arc<SessionStats>,
+        config: &RunConfig,
+    ) -> Self {
+        Self {
+            detectors: Detectors::with_dataset(
+                offset_x,
+                offset_y,
+                dataset.clone(),
+                config.qte.matcher(),
+                &config.regions.settings(),
+                &token,
+            ),
+            client_rect: (offset_x, offset_y, client_size.0, client_size.1),
+            scheduler: Scheduler::new(token),
+            dataset,
+            snapshots: None,
+            stats,
         }
+    }
+
+    fn with_snapshots(mut self, enabled: bool) -> Self {
+        self.snapshots =
+            enabled.then(|| Snapshots::new(SNAPSHOT_DIR, &self.stats.session(), self.client_rect));
+        self
+    }
+
+    /// 保存当前帧的决策快照, 返回附在日志后面的路径, 未开启时为空
+    fn snapshot(&self, frame: &Frame, action: &str, state: &str) -> String {
+        let Some(snapshots) = &self.snapshots else {
+            return String::new();
+        };
+        Snapshots::link(snapshots.take(frame, action, state))
+    }
 
-        let mut res_mat = Mat::default();
-        if match_template(
-            &resized_rappy_qte_shot,
-            &TemplateImg::QTE.img,
-            &mut res_mat,
-            TM_CCORR_NORMED,
-            &no_array(),
-        )
-        .is_err()
-        {
-            error!("Failed to match template for QTE");
-            return (false, None);
+    /// 主循环每轮一帧, 同一拍内的所有检测共用, 只抓一次整个客户区
+    /// * 按键, 等待和节拍之后调用 next_frame, 之后的检测取新的一帧
+    fn frame<'a>(&self, capture: &'a DxgiCapture) -> Frame<'a> {
+        capture.frame(self.client_rect)
+    }
+
+    fn check(&self, frame: &Frame, detector: &dyn Detector) -> bool {
+        check_game_shot(frame, detector)
+    }
+
+    /// 按键或等待之后画面已变化, 丢弃当前帧
+    fn next_frame(&self, frame: &Frame) {
+        if let Err(e) = frame.next_frame() {
+            error!("Failed to advance frame: {}", e);
         }
+    }
 
-        let mut max_val = 0f64;
-        if min_max_loc(&res_mat, None, Some(&mut max_val), None, None, &no_array()).is_err() {
-            error!("Failed to find max value in match result");
-            return (false, None);
+    /// 任务结束时输出本次的统计信息
+    fn report_stats(&self, tx: &Sender<String>) {
+        for (name, stats) in self.detectors.change_stats() {
+            info!("{} change stats: {}", name, stats);
+            let _ = tx.send(format!("{} change stats: {}", name, stats));
+        }
+        for (phase, stats) in self.scheduler.stats() {
+            info!("{} tick stats: {}", phase, stats);
+            let _ = tx.send(format!("{} tick stats: {}", phase, stats));
         }
+        if let Some(dataset) = &self.dataset {
+            // 等剩余的截图写完, 计数才是最终的
+            dataset.flush();
+            info!("Dataset stats: {}", dataset.stats());
+            let _ = tx.send(format!("Dataset stats: {}", dataset.stats()));
+        }
+        for line in self.stats.snapshot().to_string().lines() {
+            info!("Session stats: {}", line);
+            let _ = tx.send(format!("Session stats: {}", line));
+        }
+        match self.stats.persist(STATS_DIR) {
+            Ok(path) => info!("Session stats saved to {}", path.display()),
+            Err(e) => error!("Failed to save session stats: {}", e),
+        }
+    }
 
-        if max_val > 0.99 {
+    fn check_qte_appear<'a>(
+        &'a self,
+        capture: &DxgiCapture,
+        tx: &'a Sender<String>,
+    ) -> (bool, Option<SaveImage<'a>>, FrameStamps) {
+        let qte = &self.detectors.qte;
+        let mut stamps = FrameStamps::start();
+        // QTE 轮询频率最高且只看一个区域, 直接截取该区域, 不抓整个客户区
+        let rappy_qte_shot = match capture.grab_shot(qte.region()) {
+            Ok(shot) => shot,
+            Err(e) => {
+                error!("Failed to grab {}: {}", qte.name(), e);
+                return (false, None, stamps);
+            }
+        };
+        stamps.acquired();
+        let detection = match qte.detect_shot(&rappy_qte_shot) {
+            Ok(detection) => detection,
+            Err(e) => {
+                error!("Failed to detect {}: {}", qte.name(), e);
+                return (false, None, stamps);
+            }
+        };
+        stamps.detected();
+
+        let Some(dataset) = self.dataset.clone() else {
+            return (detection.passed, None, stamps);
+        };
+        if detection.passed {
+            // 只在按键之后入队, 日志在写入线程中写完图片后输出
             let save_img_function = move || {
-                // 生成时间戳文件名
-                let png_name = chrono::Local::now().format("%Y%m%d%H%M%S%.6f").to_string();
-                info!("qte name:{}, sim: {:.6}", png_name, max_val);
-                let _ = tx.send(format!("qte name:{}, sim: {:.6}", png_name, max_val));
-                // 确保目录存在 (Rust 不会自动创建目录，需使用 std::fs::create_dir_all)
-                let file_path = format!("{}/{}.png", QTE_DIR, png_name);
-                // 保存图片 (params 传空 Vector)
-                info!("qte image name: {}, sim: {}.", file_path, max_val);
-                let _ = tx.send(format!(
-                    "Save qte image, image path: {}, sim: {}",
-                    file_path, max_val
-                ));
-                if imwrite(&file_path, &resized_rappy_qte_shot, &Vector::new()).is_err() {
-                    error!("Failed to save QTE image to {}", file_path);
-                }
+                let tx = tx.clone();
+                let (details, score) = (detection.details.clone(), detection.score);
+                dataset.record_then(qte, &rappy_qte_shot, &detection, move |path| {
+                    info!("qte appear, {}", details);
+                    let _ = tx.send(format!("qte appear, {}", details));
+                    info!("qte image name: {}, sim: {}.", path.display(), score);
+                    let _ = tx.send(format!(
+                        "Save qte image, image path: {}, sim: {}",
+                        path.display(),
+                        score
+                    ));
+                });
             };
-            return (true, Some(Box::new(save_img_function)));
+            return (true, Some(Box::new(save_img_function)), stamps);
         }
-        (false, None)
+        // 没有按键在等待, 险些通过的截图直接入队
+        dataset.record(qte, &rappy_qte_shot, &detection);
+        (false, None, stamps)
     }
 
+    /// 任务取消时返回 false, 不再更新硬币状态
     fn wait_for_key_ready(
         &self,
-        capture: &DxgiCapture,
+        frame: &Frame,
         bet_coin_is_one: &mut bool,
         tx: &Sender<String>,
-    ) {
+    ) -> bool {
         info!("Waiting for key ready...");
         tx.send("Waiting for Key ready.".to_string())
             .unwrap_or_default();
+        // 进入等待阶段, 上一阶段的迟滞状态不再适用
+        self.detectors.reset();
 
         // 添加超时机制，最多等待60秒
         let start_time = std::time::Instant::now();
         let timeout = Duration::from_secs(60);
 
         // scroll灯亮起但游戏中开始(回车键)不可用，等待
-        while !check_game_shot(
-            capture,
-            &CapturePos::key_ready(self.offset_x, self.offset_y),
-            &TemplateImg::KEY_READY,
-            0.9,
-            true,
-        ) && WindowsKeyboard::state()
-        {
+        let mut ticker = self.scheduler.ticker(Phase::Idle);
+        while ticker.tick() {
+            if self.check(frame, &*self.detectors.key_ready) {
+                break;
+            }
             if start_time.elapsed() > timeout {
                 error!("Key ready detection timeout after 60 seconds");
                 let _ = tx.send("Key ready detection timeout after 60 seconds".to_string());
+                self.stats.record(Event::Timeout(Phase::Idle));
                 break;
             }
-            sleep(Duration::from_millis(2000));
+            // 下一拍取新的一帧
+            self.next_frame(frame);
         }
-        // 每次更新下状态
-        *bet_coin_is_one = check_game_shot(
-            capture,
-            &CapturePos::coin_count(self.offset_x, self.offset_y),
-            &TemplateImg::COIN_ONE,
-            0.85,
-            true,
-        );
+        if !self.scheduler.is_running() {
+            return false;
+        }
+        // 每次更新下状态, 与开始键可用的判定来自同一帧
+        *bet_coin_is_one = self.check(frame, &*self.detectors.coin_one);
+        self.stats.record(Event::BetObserved {
+            one: *bet_coin_is_one,
+        });
         info!("Key ready, [(bet coin nums == 1) : {}].", *bet_coin_is_one);
         tx.send(format!(
             "Key ready, [(bet coin nums == 1) : {}]",
             *bet_coin_is_one
         ))
         .unwrap_or_default();
+        true
     }
 
     fn try_increase_coin_while_energy_is_four(
         &self,
-        capture: &DxgiCapture,
+        frame: &Frame,
         keyboard: &WindowsKeyboard,
         bet_coin_is_one: &mut bool,
         burst: &mut bool,
         tx: &Sender<String>,
-    ) {
+    ) -> Result<()> {
         info!(
             "Trying to increase coin for the keyboard, bet coin is one: {}.",
             bet_coin_is_one
@@ -227,32 +288,29 @@ impl AutoRappy {
             bet_coin_is_one
         ))
         .unwrap_or_default();
-        if *bet_coin_is_one
-            && check_game_shot(
-                capture,
-                &CapturePos::energy_four(self.offset_x, self.offset_y),
-                &TemplateImg::ENERGY_FOUR,
-                0.9,
-                false,
-            )
-        {
-            info!("Bet coin = 1,  energy = 4, increase bet coin.");
-            tx.send("Bet coin = 1,  energy = 4, increase bet coin.".to_string())
-                .unwrap_or_default();
+        if *bet_coin_is_one && self.check(frame, &*self.detectors.energy_four) {
+            self.stats.record(Event::EnergyFour);
+            let link = self.snapshot(frame, "increase bet", &loop_state(*bet_coin_is_one, *burst));
+            info!("Bet coin = 1,  energy = 4, increase bet coin.{}", link);
+            tx.send(format!(
+                "Bet coin = 1,  energy = 4, increase bet coin.{}",
+                link
+            ))
+            .unwrap_or_default();
             // 没到5枚硬币时连续按上键(最大20次)
             for i in 0..=20 {
-                if !check_game_shot(
-                    &capture,
-                    &CapturePos::coin_count(self.offset_x, self.offset_y),
-                    &TemplateImg::COIN_FIVE,
-                    0.85,
-                    true,
-                ) {
-                    keyboard.increase_rappy_coin(1);
+                // 任务取消时硬币数未知, 不更新状态
+                if !self.scheduler.is_running() {
+                    return Ok(());
+                }
+                if !self.check(frame, &*self.detectors.coin_five) {
+                    keyboard.increase_rappy_coin(1)?;
+                    self.next_frame(frame);
                 } else {
                     // 按键20次依然没有增加到5枚硬币,说明卡在pse页面
                     if i == 20 {
                         *burst = true;
+                        self.stats.record(Event::BurstStarted);
                     }
                     break;
                 }
@@ -262,20 +320,23 @@ impl AutoRappy {
                 .unwrap_or_default();
             // 已经执行增加coin操作, bet_coin_one设置为false
             *bet_coin_is_one = false;
+            self.stats.record(Event::BetChanged { one: false });
         } else {
             info!("No need to increase coin.");
             tx.send("No need to increase coin.".to_string())
                 .unwrap_or_default();
         }
+        Ok(())
     }
 
     fn try_decrease_coin_while_energy_is_zero(
         &self,
-        capture: &DxgiCapture,
+        frame: &Frame,
         keyboard: &WindowsKeyboard,
         bet_coin_is_one: &mut bool,
+        burst: bool,
         tx: &Sender<String>,
-    ) {
+    ) -> Result<()> {
         info!(
             "Trying to decrease coin for the keyboard, bet coin is one: {}.",
             bet_coin_is_one
@@ -285,44 +346,43 @@ impl AutoRappy {
             bet_coin_is_one
         ))
         .unwrap_or_default();
-        if !*bet_coin_is_one
-            && check_game_shot(
-                &capture,
-                &CapturePos::energy_zero(self.offset_x, self.offset_y),
-                &TemplateImg::ENERGY_ZERO,
-                0.9,
-                false,
-            )
-        {
-            info!("Bet coin > 1,  energy = 0, decrease bet coin.");
-            tx.send("Bet coin > 1,  energy = 0, decrease bet coin.".to_string())
-                .unwrap_or_default();
+        if !*bet_coin_is_one && self.check(frame, &*self.detectors.energy_zero) {
+            self.stats.record(Event::EnergyZero);
+            let link = self.snapshot(frame, "decrease bet", &loop_state(*bet_coin_is_one, burst));
+            info!("Bet coin > 1,  energy = 0, decrease bet coin.{}", link);
+            tx.send(format!(
+                "Bet coin > 1,  energy = 0, decrease bet coin.{}",
+                link
+            ))
+            .unwrap_or_default();
             for _i in 0..=20 {
-                if !check_game_shot(
-                    &capture,
-                    &CapturePos::coin_count(self.offset_x, self.offset_y),
-                    &TemplateImg::COIN_ONE,
-                    0.85,
-                    true,
-                ) {
-                    keyboard.decrease_rappy_coin(1);
+                // 任务取消时硬币数未知, 不更新状态
+                if !self.scheduler.is_running() {
+                    return Ok(());
+                }
+                if !self.check(frame, &*self.detectors.coin_one_step) {
+                    keyboard.decrease_rappy_coin(1)?;
+                    self.next_frame(frame);
                 }
             }
             *bet_coin_is_one = true;
+            self.stats.record(Event::BetChanged { one: true });
         } else {
             info!("No need to decrease coin.");
             tx.send("No need to decrease coin.".to_string())
                 .unwrap_or_default();
         }
+        Ok(())
     }
 
     fn process_rappy_qte<'a>(
         &self,
         capture: &DxgiCapture,
+        frame: &Frame,
         burst: &mut bool,
         tx: &Sender<String>,
-        action: Box<dyn Fn() + 'a>,
-    ) {
+        action: Box<dyn Fn() -> Result<Instant> + 'a>,
+    ) -> Result<()> {
         info!(
             "Check if  processing rappy qte needed, rappy burst status: {}.",
             *burst
@@ -332,37 +392,56 @@ impl AutoRappy {
             *burst
         ))
         .unwrap_or_default();
-        if check_game_shot(
-            capture,
-            &CapturePos::target(self.offset_x, self.offset_y),
-            &TemplateImg::TARGET,
-            0.7,
-            false,
-        ) || *burst
-        {
+        let target = self.check(frame, &*self.detectors.target);
+        if target {
+            self.stats.record(Event::TargetSeen);
+        }
+        if target || *burst {
             info!("Rappy target appear, wait for qte.");
             tx.send("Rappy target appear, wait for qte.".to_string())
                 .unwrap_or_default();
             // 等qte完全开始
-            sleep(Duration::from_millis(3000));
+            let waited = self.scheduler.wait(Duration::from_millis(3000));
+            self.next_frame(frame);
+            if !waited {
+                return Ok(());
+            }
 
             // 添加超时机制，最多等待30秒
             let start_time = std::time::Instant::now();
             let timeout = Duration::from_secs(30);
+            let mut ticker = self.scheduler.ticker(Phase::QteWait);
+            let mut last_miss = None;
             loop {
-                let (appear, save_function) = self.check_qte_appear(capture, tx);
+                if !ticker.tick() {
+                    return Ok(());
+                }
+                let (appear, save_function, stamps) = self.check_qte_appear(capture, tx);
                 if !appear {
+                    last_miss = Some(stamps.frame_acquired);
                     if start_time.elapsed() > timeout {
                         error!("QTE detection timeout after 30 seconds");
                         let _ = tx.send("QTE detection timeout after 30 seconds".to_string());
+                        self.stats.record(Event::Timeout(Phase::QteWait));
                         *burst = false;
-                        return;
+                        return Ok(());
                     }
-                    sleep(Duration::from_millis(100));
                 } else {
                     // 先按键
-                    action();
+                    let key_sent = action()?;
                     info!("Qte appear, enter key pressed");
+                    let timing = QteTiming {
+                        last_miss,
+                        frame: stamps,
+                        key_sent,
+                    };
+                    self.stats.record(Event::QteHit {
+                        latency: timing.total(),
+                    });
+                    info!("QTE latency: {}", timing);
+                    let _ = tx.send(format!("QTE latency: {}", timing));
+                    self.stats.record_qte_timing(timing);
+                    self.next_frame(frame);
                     // 再保存图片
                     save_function.unwrap_or(Box::new(|| {}))();
                     break;
@@ -378,147 +457,176 @@ impl AutoRappy {
             tx.send("No rappy qte appear.".to_string())
                 .unwrap_or_default();
         }
+        Ok(())
     }
 }
 
-pub fn auto_rappy(ctx: &Context, tx: &Sender<String>) -> Result<String, Error> {
-    let window_name = "PHANTASY STAR ONLINE 2";
+/// 工作线程主循环, GUI 和命令行共用; 日志消息通过 tx 发送给界面或标准输出
+/// * stats: 本次会话的统计, 由调用方在会话开始时 reset
+pub fn auto_rappy(
+    config: &RunConfig,
+    tx: &Sender<String>,
+    worker: &Worker,
+    stats: &Arc<SessionStats>,
+) -> Result<String> {
+    let window_name = config.window_title.as_str();
+    let token = worker.token();
+    TemplateImg::check_all()?;
 
-    match search_window_by_title(window_name) {
-        Some(hwnd) => {
-            if let Some((offset_x, offset_y)) = get_window_client_offset(hwnd) {
-                let auto_rappy = AutoRappy { offset_x, offset_y };
-                let mut capture = DxgiCapture::new(hwnd)?;
-                // 检查赌场币是否为1
-                let mut bet_coin_is_one = check_game_shot(
-                    &capture,
-                    &CapturePos::coin_count(offset_x, offset_y),
-                    &TemplateImg::COIN_ONE,
-                    0.85,
-                    true,
+    let Some(hwnd) = search_window_by_title(window_name) else {
+        error!("Search window: {} failed", window_name);
+        return Err(RappyError::WindowNotFound(window_name.to_string()));
+    };
+    let (Some((offset_x, offset_y)), Some(client_size)) =
+        (get_window_client_offset(hwnd), get_window_client_size(hwnd))
+    else {
+        return Err(RappyError::WindowNotFound(format!(
+            "client area of {}",
+            window_name
+        )));
+    };
+    let dataset = match Dataset::open(DATASET_DIR, DatasetLimits::DEFAULT, stats.session()) {
+        Ok(dataset) => Some(Arc::new(dataset)),
+        Err(e) => {
+            error!("Failed to open dataset, captures will not be saved: {}", e);
+            let _ = tx.send(format!(
+                "Failed to open dataset, captures will not be saved: {}",
+                e
+            ));
+            None
+        }
+    };
+    let auto_rappy = AutoRappy::new(
+        offset_x,
+        offset_y,
+        client_size,
+        token.clone(),
+        dataset,
+        stats.clone(),
+        config,
+    )
+    .with_snapshots(config.snapshots);
+    let mut capture = DxgiCapture::new(hwnd)?;
+    worker.set_running();
+    stats.record(Event::WorkerStarted);
+    // 检查赌场币是否为1
+    let mut bet_coin_is_one =
+        auto_rappy.check(&auto_rappy.frame(&capture), &*auto_rappy.detectors.coin_one);
+    stats.record(Event::BetObserved {
+        one: bet_coin_is_one,
+    });
+    info!("Start task, check bet coin nums == 1: {}", bet_coin_is_one);
+    tx.send(format!(
+        "Start task, check bet coin nums == 1: {}",
+        bet_coin_is_one
+    ))
+    .unwrap_or_default();
+    // pse burst状态, 初始为false
+    let mut burst = false;
+    let mut keyboard = WindowsKeyboard::new(hwnd, token.clone());
+    // 按键失败等错误结束循环, 统计信息照常输出
+    let mut run = || -> Result<()> {
+        while auto_rappy.scheduler.is_running() {
+            // 本轮的帧, 刷新窗口前释放对 capture 的借用
+            let frame = auto_rappy.frame(&capture);
+            // 等待按下回车
+            if !auto_rappy.wait_for_key_ready(&frame, &mut bet_coin_is_one, tx) {
+                break;
+            }
+            // 赌场币为1枚,能量为4格时,增加赌场币到5枚,等待满能量pse
+            auto_rappy.try_increase_coin_while_energy_is_four(
+                &frame,
+                &keyboard,
+                &mut bet_coin_is_one,
+                &mut burst,
+                tx,
+            )?;
+            // 赌场币不为1枚，能力不足4格，将赌场币降低到1
+            auto_rappy.try_decrease_coin_while_energy_is_zero(
+                &frame,
+                &keyboard,
+                &mut bet_coin_is_one,
+                burst,
+                tx,
+            )?;
+            auto_rappy.process_rappy_qte(
+                &capture,
+                &frame,
+                &mut burst,
+                tx,
+                Box::new(|| keyboard.play_rappy()),
+            )?;
+            // 任务取消后投票提前结束, 结果不可信, 不再按回车或刷新窗口
+            if !auto_rappy.scheduler.is_running() {
+                break;
+            }
+            if auto_rappy.check(&frame, &*auto_rappy.detectors.key_ready) {
+                let link =
+                    auto_rappy.snapshot(&frame, "press enter", &loop_state(bet_coin_is_one, burst));
+                info!("Press enter key.{}", link);
+                tx.send(format!("Press enter key.{}", link))
+                    .unwrap_or_default();
+                keyboard.play_rappy()?;
+                auto_rappy.stats.record(Event::RoundStarted);
+                auto_rappy.detectors.reset();
+                auto_rappy.next_frame(&frame);
+            }
+            let coin_known = auto_rappy.check(&frame, &*auto_rappy.detectors.coin_known);
+            if !coin_known {
+                // 画面错位，刷新下这个窗口试下
+                let link = auto_rappy.snapshot(
+                    &frame,
+                    "refresh window",
+                    &loop_state(bet_coin_is_one, burst),
                 );
-                info!("Start task, check bet coin nums == 1: {}", bet_coin_is_one);
-                tx.send(format!(
-                    "Start task, check bet coin nums == 1: {}",
-                    bet_coin_is_one
-                ))
-                .unwrap_or_default();
-                ctx.request_repaint();
-                // pse burst状态, 初始为false
-                let mut burst = false;
-                let mut keyboard = WindowsKeyboard::new(hwnd);
-                loop {
-                    if WindowsKeyboard::state() {
-                        // 等待按下回车
-                        auto_rappy.wait_for_key_ready(&capture, &mut bet_coin_is_one, tx);
-                        // 赌场币为1枚,能量为4格时,增加赌场币到5枚,等待满能量pse
-                        auto_rappy.try_increase_coin_while_energy_is_four(
-                            &capture,
-                            &keyboard,
-                            &mut bet_coin_is_one,
-                            &mut burst,
-                            tx,
-                        );
-                        ctx.request_repaint();
-                        // 赌场币不为1枚，能力不足4格，将赌场币降低到1
-                        auto_rappy.try_decrease_coin_while_energy_is_zero(
-                            &capture,
-                            &keyboard,
-                            &mut bet_coin_is_one,
-                            tx,
-                        );
-                        ctx.request_repaint();
-                        auto_rappy.process_rappy_qte(
-                            &capture,
-                            &mut burst,
-                            tx,
-                            Box::new(|| keyboard.play_rappy()),
-                        );
-                        ctx.request_repaint();
-                        if check_game_shot(
-                            &capture,
-                            &CapturePos::key_ready(offset_x, offset_y),
-                            &TemplateImg::KEY_READY,
-                            0.9,
-                            true,
-                        ) {
-                            info!("Press enter key.");
-                            tx.send("Press enter key.".to_string()).unwrap_or_default();
-                            ctx.request_repaint();
-                            keyboard.play_rappy();
-                        }
-                        if !check_game_shot(
-                            &capture,
-                            &CapturePos::coin_count(auto_rappy.offset_x, auto_rappy.offset_y),
-                            &TemplateImg::COIN_ONE,
-                            0.85,
-                            true,
-                        ) && !check_game_shot(
-                            &capture,
-                            &CapturePos::coin_count(auto_rappy.offset_x, auto_rappy.offset_y),
-                            &TemplateImg::COIN_FIVE,
-                            0.85,
-                            true,
-                        ) {
-                            // 画面错位，刷新下这个窗口试下
-                            info!("Invalid window handle, updating window...");
-                            tx.send("Invalid window handle, updating window...".to_string())
-                                .unwrap_or_default();
-                            ctx.request_repaint();
-                            if let Some(_hwnd) = update_window(&window_name) {
-                                capture.update_hwnd(_hwnd);
-                                keyboard = WindowsKeyboard::new(_hwnd);
-                            }
-                        }
-                    } else {
-                        info!("Task ended.");
-                        let _ = tx.send("Task ended.".to_string());
-                        break;
-                    }
-                    ctx.request_repaint(); // 强制 UI 刷新以看到新日志
+                info!("Invalid window handle, updating window...{}", link);
+                tx.send(format!("Invalid window handle, updating window...{}", link))
+                    .unwrap_or_default();
+                auto_rappy.stats.record(Event::WindowRefreshed);
+                // 帧借用着 capture, 释放后才能更新窗口
+                drop(frame);
+                if let Some(_hwnd) = update_window(window_name) {
+                    capture.update_hwnd(_hwnd);
+                    keyboard = WindowsKeyboard::new(_hwnd, token.clone());
                 }
             }
-            Ok("Task ended.".to_string())
-        }
-        None => {
-            error!("Search window: {} failed", window_name);
-            Ok(format!("Search window: {} failed", window_name))
         }
-    }
+        Ok(())
+    };
+    let result = run();
+    stats.record(Event::WorkerStopped);
+    auto_rappy.report_stats(tx);
+    info!("Task ended.");
+    let _ = tx.send("Task ended.".to_string());
+    result.map(|_| "Task ended.".to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use opencv::core::Rect;
     use super::*;
+    use crate::capture_settings::CapturePos;
     use crate::dxgi_capture::show_image;
     use crate::logging::init_logger;
+    use crate::vision::{Image, ReadMode};
     use crate::windows_utils::get_window_client_offset;
-    use opencv::imgcodecs::imread;
 
     #[test]
-    fn test_grab_and_check() -> Result<(), Error> {
+    fn test_grab_and_check() -> Result<()> {
         init_logger("debug");
         match search_window_by_title("PHANTASY STAR ONLINE 2") {
             Some(hwnd) => {
                 let capture = DxgiCapture::new(hwnd)?;
                 info!("Check key ready");
-                let template = TemplateImg::ENERGY_FOUR;
-                let img = &template.img;
+                let img = &crate::template_img::ENERGY_FOUR.img;
                 info!(
                     "Template image channel: {}, rows: {}, cols: {}",
                     img.channels(),
-                    img.rows(),
-                    img.cols()
+                    img.height(),
+                    img.width()
                 );
                 if let Some((offset_x, offset_y)) = get_window_client_offset(hwnd) {
-                    let is_similar = check_game_shot(
-                        &capture,
-                        &CapturePos::energy_four(offset_x, offset_y),
-                        &template,
-                        0.9,
-                        false,
-                    );
+                    let detectors = Detectors::new(offset_x, offset_y);
+                    let is_similar = check_game_shot(&capture, detectors.energy_four.as_ref());
                     info!("Similar key_ready_shot: {}", is_similar);
                 }
                 Ok(())
@@ -531,12 +639,12 @@ mod tests {
     }
 
     #[test]
-    fn test_grab_coin_one() -> Result<(), Error> {
+    fn test_grab_coin_one() -> Result<()> {
         match search_window_by_title("PHANTASY STAR ONLINE 2") {
             Some(hwnd) => {
                 let capture = DxgiCapture::new(hwnd)?;
                 if let Some((offset_x, offset_y)) = get_window_client_offset(hwnd) {
-                    let coin_one = capture.grab(&CapturePos::coin_count(offset_x, offset_y));
+                    let coin_one = capture.grab(&CapturePos::coin_count(offset_x, offset_y))?;
                     show_image(&coin_one);
                 }
                 Ok(())
@@ -546,13 +654,23 @@ mod tests {
     }
 
     #[test]
-    fn test_match_qte() -> Result<(), Error> {
+    fn test_match_qte() -> Result<()> {
         match search_window_by_title("PHANTASY STAR ONLINE 2") {
             Some(hwnd) => {
                 let capture = DxgiCapture::new(hwnd)?;
                 let (tx, _) = std::sync::mpsc::channel();
-                if let Some((offset_x, offset_y)) = get_window_client_offset(hwnd) {
-                    let auto_rappy = AutoRappy { offset_x, offset_y };
+                if let (Some((offset_x, offset_y)), Some(client_size)) =
+                    (get_window_client_offset(hwnd), get_window_client_size(hwnd))
+                {
+                    let auto_rappy = AutoRappy::new(
+                        offset_x,
+                        offset_y,
+                        client_size,
+                        CancellationToken::new(),
+                        None,
+                        Arc::new(SessionStats::new()),
+                        &RunConfig::default(),
+                    );
                     auto_rappy.check_qte_appear(&capture, &tx);
                 }
                 Ok(())
@@ -562,54 +680,23 @@ mod tests {
     }
 
     #[test]
-    fn test_match_qte_from_picture() -> Result<(), Error> {
+    fn test_match_qte_from_picture() -> Result<()> {
         init_logger("debug");
-        if let Ok(qte_img) = imread("H:/RustProjects/pso2_rappy_machine/test_data/qte.jpg", opencv::imgcodecs::IMREAD_GRAYSCALE)
-        {
-            let rappy_qte_shot = qte_img.roi(CapturePos::qte(0,0).rect.into()).unwrap();
-            let mut resized_rappy_qte_shot = Mat::default();
-
-            if resize(
-                &rappy_qte_shot,
-                &mut resized_rappy_qte_shot,
-                Size::new(0, 0),
-                0.5,
-                0.5,
-                INTER_LINEAR,
-            )
-            .is_err()
-            {
-                error!("Failed to resize QTE image");
-            }
-
-            let mut res_mat = Mat::default();
-            if match_template(
-                &resized_rappy_qte_shot,
-                &TemplateImg::QTE.img,
-                &mut res_mat,
-                TM_CCORR_NORMED,
-                &no_array(),
-            )
-            .is_err()
-            {
-                error!("Failed to match template for QTE");
-            }
-
-            let mut max_val = 0f64;
-            if min_max_loc(&res_mat, None, Some(&mut max_val), None, None, &no_array()).is_err() {
-                error!("Failed to find max value in match result");
-            }
-
-            if max_val > 0.99 {
-                // 生成时间戳文件名
-                let png_name = chrono::Local::now().format("%Y%m%d%H%M%S%.6f").to_string();
-                info!("qte name:{}, sim: {:.6}", png_name, max_val);
-                // 确保目录存在 (Rust 不会自动创建目录，需使用 std::fs::create_dir_all)
-                let file_path = format!("{}/{}.png", QTE_DIR, png_name);
-                // 保存图片 (params 传空 Vector)
-                info!("qte image name: {}, sim: {}.", file_path, max_val);
-            }
-        }
+        let qte_img = Image::load("test_data/qte.jpg", ReadMode::Unchanged).unwrap();
+        let auto_rappy = AutoRappy::new(
+            0,
+            0,
+            (1600, 900),
+            CancellationToken::new(),
+            None,
+            Arc::new(SessionStats::new()),
+            &RunConfig::default(),
+        );
+        let qte = &auto_rappy.detectors.qte;
+        let rappy_qte_shot = qte_img.roi(qte.region().rect).unwrap();
+        let detection = qte.detect_shot(&rappy_qte_shot).unwrap();
+        info!("qte detection: {:?}", detection);
+        assert!(detection.passed);
         Ok(())
     }
 }
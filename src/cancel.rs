@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// 可取消等待中每次最多睡眠的时长, 决定停止任务的响应速度
+static CANCEL_SLICE: Duration = Duration::from_millis(20);
+
+/// 每个任务一个的取消令牌, clone 出的令牌共享同一状态
+/// * GUI 持有一份用于停止, 工作线程内的等待/按键都检查它
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// 等待 duration, 被取消时提前返回 false
+    pub fn sleep(&self, duration: Duration) -> bool {
+        self.sleep_until(Instant::now() + duration)
+    }
+
+    /// 等到 deadline, 被取消时提前返回 false
+    pub fn sleep_until(&self, deadline: Instant) -> bool {
+        loop {
+            if self.is_cancelled() {
+                return false;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return true;
+            }
+            sleep((deadline - now).min(CANCEL_SLICE));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sleep_returns_early_when_cancelled() {
+        let token = CancellationToken::new();
+        assert!(token.sleep(Duration::from_millis(10)));
+
+        let start = Instant::now();
+        let stopper = {
+            let token = token.clone();
+            std::thread::spawn(move || {
+                sleep(Duration::from_millis(100));
+                token.cancel();
+            })
+        };
+        assert!(!token.sleep(Duration::from_secs(10)));
+        stopper.join().unwrap();
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(token.is_cancelled());
+        // 已取消的令牌不再等待
+        assert!(!token.sleep(Duration::from_secs(10)));
+        // 新任务用新令牌, 不受上一个任务影响
+        assert!(!CancellationToken::new().is_cancelled());
+    }
+}
@@ -1,3 +1,7 @@
+/// 各区域按 1600x900 窗口模式的客户区布局
+pub const CLIENT_SIZE: (i32, i32) = (1600, 900);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct CapturePos {
     pub rect: (i32, i32, i32, i32),
 }
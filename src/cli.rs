@@ -0,0 +1,1104 @@
+//!
+//! 命令行模式: 无界面运行和离线工具, 不带参数启动时仍然打开界面
+//!
+//! ```text
+//! pso2_rappy_machine run [--config rappy.toml] [--export dir] [--output text|json]
+//! pso2_rappy_machine analyze <screenshot> [--offset x,y] [--annotate dir] [--output text|json]
+//! pso2_rappy_machine calibrate [--config rappy.toml] [--save client.png] [--output text|json]
+//! pso2_rappy_machine simulate <dir> [--offset x,y] [--output text|json]
+//! pso2_rappy_machine evaluate <dataset dir|manifest.csv> [--offset x,y] [--roc dir] [--output text|json]
+//! pso2_rappy_machine robustness [dir] [--offset x,y] [--seed n] [--output text|json]
+//! pso2_rappy_machine history [--days 7] [--db history.sqlite3] [--output text|json]
+//! pso2_rappy_machine export [--days 7] [--session id] [--db history.sqlite3] [--format csv|jsonl] [--out dir] [--output text|json]
+//! pso2_rappy_machine report [--session id] [--db history.sqlite3] [--dataset dir] [--snapshots dir] [--out dir] [--output text|json]
+//! ```
+//!
+//! * 事件输出到标准输出, 日志输出到标准错误和 logs/
+//! * json 格式每行一个对象, 都带有 time 和 event 字段
+//! * 退出码见 EXIT_* 常量
+//!
+use crate::analysis::Analysis;
+use crate::auto_rappy;
+use crate::cancel::CancellationToken;
+use crate::capture_settings::{CLIENT_SIZE, CapturePos};
+use crate::config::{OutputFormat, RunConfig};
+use crate::dataset::DATASET_DIR;
+use crate::detector::{Detector, Detectors, Screenshot, ShotSource};
+use crate::dxgi_capture::DxgiCapture;
+use crate::error::{RappyError, Result, ResultExt};
+use crate::evaluate;
+use crate::export::{self, EXPORT_DIR, ExportFormat};
+use crate::history::{EventRecord, HISTORY_DB, History, RoundRecord};
+use crate::logging::init_logger;
+use crate::report::{REPORT_DIR, SessionReport};
+use crate::robustness;
+use crate::snapshot::SNAPSHOT_DIR;
+use crate::stats::SessionStats;
+use crate::supervisor::{Supervisor, WorkerStatus};
+use crate::template_img::TemplateImg;
+use crate::vision::{Image, ReadMode};
+use crate::windows_utils::{
+    get_window_client_offset, get_window_client_size, search_window_by_title,
+};
+use log::{error, info};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 正常结束 (包括到达 max_minutes 后停止)
+pub const EXIT_OK: i32 = 0;
+/// 没有具体错误类型的失败, 例如工作线程 panic
+pub const EXIT_FAILED: i32 = 1;
+/// 参数或配置文件错误
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_WINDOW_NOT_FOUND: i32 = 3;
+pub const EXIT_CAPTURE: i32 = 4;
+pub const EXIT_INPUT: i32 = 5;
+/// 模板或图像处理错误
+pub const EXIT_DETECTION: i32 = 6;
+pub const EXIT_IO: i32 = 7;
+
+const USAGE: &str = "\
+Usage:
+  pso2_rappy_machine                       start the GUI
+  pso2_rappy_machine run [--config rappy.toml] [--export dir] [--output text|json]
+  pso2_rappy_machine analyze <screenshot> [--offset x,y] [--annotate dir] [--output text|json]
+  pso2_rappy_machine calibrate [--config rappy.toml] [--save client.png] [--output text|json]
+  pso2_rappy_machine simulate <dir> [--offset x,y] [--output text|json]
+  pso2_rappy_machine evaluate <dataset dir|manifest.csv> [--offset x,y] [--roc dir] [--output text|json]
+  pso2_rappy_machine robustness [dir] [--offset x,y] [--seed n] [--output text|json]
+  pso2_rappy_machine history [--days 7] [--db history.sqlite3] [--output text|json]
+  pso2_rappy_machine export [--days 7] [--session id] [--db history.sqlite3] [--format csv|jsonl] [--out dir] [--output text|json]
+  pso2_rappy_machine report [--session id] [--db history.sqlite3] [--dataset dir] [--snapshots dir] [--out dir] [--output text|json]
+  pso2_rappy_machine help";
+
+/// 检查状态和输出事件的间隔
+static STATUS_POLL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Help,
+    /// 无界面运行工作线程, export 为结束时导出本次会话的目录
+    Run {
+        config: Option<PathBuf>,
+        export: Option<PathBuf>,
+        output: Option<OutputFormat>,
+    },
+    /// 对一张客户区截图运行全部检测器, annotate 为标注图和各区域截图的输出目录
+    Analyze {
+        image: PathBuf,
+        offset: (i32, i32),
+        annotate: Option<PathBuf>,
+        output: OutputFormat,
+    },
+    /// 查找游戏窗口, 检查客户区大小并对当前画面运行全部检测器
+    Calibrate {
+        config: Option<PathBuf>,
+        save: Option<PathBuf>,
+        output: Option<OutputFormat>,
+    },
+    /// 按文件名顺序把一组截图当作连续的帧, 输出每帧主循环会做的操作
+    Simulate {
+        dir: PathBuf,
+        offset: (i32, i32),
+        output: OutputFormat,
+    },
+    /// 在标注数据集上评估各区域, roc 为 ROC 曲线 CSV 的输出目录
+    Evaluate {
+        dataset: PathBuf,
+        offset: (i32, i32),
+        roc: Option<PathBuf>,
+        output: OutputFormat,
+    },
+    /// 对模板和截图 (默认 test_data) 施加扰动, 输出各区域的分数和阈值余量
+    Robustness {
+        dir: PathBuf,
+        offset: (i32, i32),
+        seed: u64,
+        output: OutputFormat,
+    },
+    /// 汇总历史数据库中最近 days 天的会话和局
+    History {
+        db: PathBuf,
+        days: i64,
+        output: OutputFormat,
+    },
+    /// 把历史数据库中的局和事件导出为 CSV/JSON Lines, days 为 None 时不限时间
+    Export {
+        db: PathBuf,
+        days: Option<i64>,
+        session: Option<String>,
+        formats: Vec<ExportFormat>,
+        out: PathBuf,
+        output: OutputFormat,
+    },
+    /// 生成会话 (默认最近一次) 的 HTML 报告
+    Report {
+        db: PathBuf,
+        session: Option<String>,
+        dataset: PathBuf,
+        snapshots: PathBuf,
+        out: PathBuf,
+        output: OutputFormat,
+    },
+}
+
+/// 子命令之后的参数: 位置参数和 --name value 形式的选项
+struct Args {
+    positional: Vec<String>,
+    options: BTreeMap<String, String>,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>, allowed: &[&str]) -> Result<Self> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            options: BTreeMap::new(),
+        };
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+            if !allowed.contains(&name) {
+                return Err(RappyError::Config(format!("unknown option --{}", name)));
+            }
+            let Some(value) = args.next_if(|v| !v.starts_with("--")) else {
+                return Err(RappyError::Config(format!("--{} needs a value", name)));
+            };
+            parsed.options.insert(name.to_string(), value);
+        }
+        Ok(parsed)
+    }
+
+    fn path(&mut self, name: &str) -> Option<PathBuf> {
+        self.options.remove(name).map(PathBuf::from)
+    }
+
+    fn output(&mut self) -> Result<Option<OutputFormat>> {
+        self.options.remove("output").map(|v| v.parse()).transpose()
+    }
+
+    fn days(&mut self) -> Result<Option<i64>> {
+        let Some(value) = self.options.remove("days") else {
+            return Ok(None);
+        };
+        let days = value.parse().ok().filter(|days| *days > 0);
+        days.map(Some).ok_or_else(|| {
+            RappyError::Config(format!("--days expects a positive number, got {:?}", value))
+        })
+    }
+
+    fn offset(&mut self) -> Result<(i32, i32)> {
+        let Some(value) = self.options.remove("offset") else {
+            return Ok((0, 0));
+        };
+        let parsed = value
+            .split_once(',')
+            .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)));
+        parsed.ok_or_else(|| RappyError::Config(format!("--offset expects x,y, got {:?}", value)))
+    }
+
+    /// 唯一的位置参数
+    fn single(&mut self, what: &str) -> Result<PathBuf> {
+        match self.positional.len() {
+            1 => Ok(PathBuf::from(self.positional.remove(0))),
+            0 => Err(RappyError::Config(format!("missing {}", what))),
+            _ => Err(RappyError::Config(format!(
+                "unexpected argument {:?}",
+                self.positional[1]
+            ))),
+        }
+    }
+
+    fn no_positional(&self) -> Result<()> {
+        match self.positional.first() {
+            Some(arg) => Err(RappyError::Config(format!("unexpected argument {:?}", arg))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 解析程序名之后的参数
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command> {
+    let mut args = args.into_iter();
+    let Some(command) = args.next() else {
+        return Ok(Command::Help);
+    };
+    match command.as_str() {
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        "run" => {
+            let mut args = Args::parse(args, &["config", "export", "output"])?;
+            args.no_positional()?;
+            Ok(Command::Run {
+                config: args.path("config"),
+                export: args.path("export"),
+                output: args.output()?,
+            })
+        }
+        "analyze" => {
+            let mut args = Args::parse(args, &["offset", "annotate", "output"])?;
+            Ok(Command::Analyze {
+                image: args.single("screenshot path")?,
+                offset: args.offset()?,
+                annotate: args.path("annotate"),
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        "calibrate" => {
+            let mut args = Args::parse(args, &["config", "save", "output"])?;
+            args.no_positional()?;
+            Ok(Command::Calibrate {
+                config: args.path("config"),
+                save: args.path("save"),
+                output: args.output()?,
+            })
+        }
+        "simulate" => {
+            let mut args = Args::parse(args, &["offset", "output"])?;
+            Ok(Command::Simulate {
+                dir: args.single("screenshot directory")?,
+                offset: args.offset()?,
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        "evaluate" => {
+            let mut args = Args::parse(args, &["offset", "roc", "output"])?;
+            Ok(Command::Evaluate {
+                dataset: args.single("dataset path")?,
+                offset: args.offset()?,
+                roc: args.path("roc"),
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        "robustness" => {
+            let mut args = Args::parse(args, &["offset", "seed", "output"])?;
+            let dir = match args.positional.len() {
+                0 => PathBuf::from("test_data"),
+                _ => args.single("screenshot directory")?,
+            };
+            let seed = match args.options.remove("seed") {
+                Some(value) => value.parse().map_err(|_| {
+                    RappyError::Config(format!("--seed expects a number, got {:?}", value))
+                })?,
+                None => robustness::DEFAULT_SEED,
+            };
+            Ok(Command::Robustness {
+                dir,
+                offset: args.offset()?,
+                seed,
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        "history" => {
+            let mut args = Args::parse(args, &["days", "db", "output"])?;
+            args.no_positional()?;
+            Ok(Command::History {
+                db: args.path("db").unwrap_or_else(|| PathBuf::from(HISTORY_DB)),
+                days: args.days()?.unwrap_or(7),
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        "export" => {
+            let mut args =
+                Args::parse(args, &["days", "session", "db", "format", "out", "output"])?;
+            args.no_positional()?;
+            let session = args.options.remove("session");
+            // 指定会话时默认不限时间
+            let days = match args.days()? {
+                None if session.is_none() => Some(7),
+                days => days,
+            };
+            let formats = match args.options.remove("format") {
+                Some(value) => vec![value.parse()?],
+                None => ExportFormat::ALL.to_vec(),
+            };
+            Ok(Command::Export {
+                db: args.path("db").unwrap_or_else(|| PathBuf::from(HISTORY_DB)),
+                days,
+                session,
+                formats,
+                out: args
+                    .path("out")
+                    .unwrap_or_else(|| PathBuf::from(EXPORT_DIR)),
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        "report" => {
+            let mut args = Args::parse(
+                args,
+                &["session", "db", "dataset", "snapshots", "out", "output"],
+            )?;
+            args.no_positional()?;
+            Ok(Command::Report {
+                db: args.path("db").unwrap_or_else(|| PathBuf::from(HISTORY_DB)),
+                session: args.options.remove("session"),
+                dataset: args
+                    .path("dataset")
+                    .unwrap_or_else(|| PathBuf::from(DATASET_DIR)),
+                snapshots: args
+                    .path("snapshots")
+                    .unwrap_or_else(|| PathBuf::from(SNAPSHOT_DIR)),
+                out: args
+                    .path("out")
+                    .unwrap_or_else(|| PathBuf::from(REPORT_DIR)),
+                output: args.output()?.unwrap_or_default(),
+            })
+        }
+        _ => Err(RappyError::Config(format!("unknown command {:?}", command))),
+    }
+}
+
+pub fn exit_code(e: &RappyError) -> i32 {
+    match e {
+        RappyError::Config(_) => EXIT_USAGE,
+        RappyError::WindowNotFound(_) => EXIT_WINDOW_NOT_FOUND,
+        RappyError::Capture(_) => EXIT_CAPTURE,
+        RappyError::Input(_) => EXIT_INPUT,
+        RappyError::Template(_) | RappyError::Vision(_) => EXIT_DETECTION,
+        RappyError::Io(_) | RappyError::Database(_) => EXIT_IO,
+    }
+}
+
+/// 按格式把事件写到标准输出
+struct Output {
+    format: OutputFormat,
+}
+
+impl Output {
+    /// text: "时间: text"; json: {"time", "event", ...fields}
+    fn event(&self, event: &str, text: impl fmt::Display, fields: Value) {
+        let now = chrono::Local::now();
+        match self.format {
+            OutputFormat::Text => println!("{}: {}", now.format("%Y-%m-%d %H:%M:%S"), text),
+            OutputFormat::Json => {
+                let mut line = json!({
+                    "time": now.to_rfc3339(),
+                    "event": event,
+                });
+                if let (Some(line), Value::Object(fields)) = (line.as_object_mut(), fields) {
+                    line.extend(fields);
+                }
+                println!("{}", line);
+            }
+        }
+    }
+
+    /// 多行的结果: text 直接输出表格, json 与 event 相同
+    fn report(&self, event: &str, table: &str, fields: Value) {
+        match self.format {
+            OutputFormat::Text => println!("{}", table),
+            OutputFormat::Json => self.event(event, table, fields),
+        }
+    }
+
+    /// 工作线程发来的日志消息
+    fn message(&self, message: &str) {
+        self.event("message", message, json!({ "message": message }));
+    }
+
+    fn status(&self, status: &WorkerStatus) {
+        self.event(
+            "status",
+            format_args!("Status: {}", status),
+            json!({ "status": status.to_string() }),
+        );
+    }
+}
+
+/// 命令行入口, 返回进程退出码
+pub fn main(args: Vec<String>) -> i32 {
+    let command = match parse_args(args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+    let load = |path: &Option<PathBuf>| match path {
+        Some(path) => RunConfig::load(path),
+        None => Ok(RunConfig::default()),
+    };
+    let result = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            return EXIT_OK;
+        }
+        Command::Run {
+            config,
+            export,
+            output,
+        } => load(&config).map(|config| {
+            let _logger = init_logger(&config.log_level);
+            let output = Output {
+                format: output.unwrap_or(config.output),
+            };
+            run(&config, export.as_deref(), &output)
+        }),
+        Command::Calibrate {
+            config,
+            save,
+            output,
+        } => load(&config).and_then(|config| {
+            let _logger = init_logger(&config.log_level);
+            let output = Output {
+                format: output.unwrap_or(config.output),
+            };
+            calibrate(&config, save.as_deref(), &output).map(|_| EXIT_OK)
+        }),
+        Command::Analyze {
+            image,
+            offset,
+            annotate,
+            output,
+        } => {
+            let _logger = init_logger("info");
+            let output = Output { format: output };
+            analyze(&image, offset, annotate.as_deref(), &output).map(|_| EXIT_OK)
+        }
+        Command::Simulate {
+            dir,
+            offset,
+            output,
+        } => {
+            let _logger = init_logger("info");
+            simulate(&dir, offset, &Output { format: output }).map(|_| EXIT_OK)
+        }
+        Command::Evaluate {
+            dataset,
+            offset,
+            roc,
+            output,
+        } => {
+            let _logger = init_logger("info");
+            let output = Output { format: output };
+            evaluate(&dataset, offset, roc.as_deref(), &output).map(|_| EXIT_OK)
+        }
+        Command::Robustness {
+            dir,
+            offset,
+            seed,
+            output,
+        } => {
+            let _logger = init_logger("info");
+            let output = Output { format: output };
+            robustness(&dir, offset, seed, &output).map(|_| EXIT_OK)
+        }
+        Command::History { db, days, output } => {
+            let _logger = init_logger("info");
+            let output = Output { format: output };
+            history(&db, days, &output).map(|_| EXIT_OK)
+        }
+        Command::Export {
+            db,
+            days,
+            session,
+            formats,
+            out,
+            output,
+        } => {
+            let _logger = init_logger("info");
+            let output = Output { format: output };
+            export_history(&db, days, session.as_deref(), &formats, &out, &output).map(|_| EXIT_OK)
+        }
+        Command::Report {
+            db,
+            session,
+            dataset,
+            snapshots,
+            out,
+            output,
+        } => {
+            let _logger = init_logger("info");
+            let output = Output { format: output };
+            report(&db, session.as_deref(), &dataset, &snapshots, &out, &output).map(|_| EXIT_OK)
+        }
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit_code(&e)
+    })
+}
+
+/// 在监督线程下运行主循环直到结束, 崩溃时按最后一次错误返回退出码
+fn run(config: &RunConfig, export: Option<&Path>, output: &Output) -> i32 {
+    let (tx, rx) = mpsc::channel();
+    let last_error: Arc<Mutex<Option<RappyError>>> = Arc::new(Mutex::new(None));
+    let mut supervisor = Supervisor::new();
+    let task_config = config.clone();
+    let worker_error = last_error.clone();
+    let stats = Arc::new(SessionStats::new().with_history(History::open_or_log(HISTORY_DB)));
+    let task_stats = stats.clone();
+    supervisor.start(config.restart_policy(), move |worker| {
+        *worker_error.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        match auto_rappy::auto_rappy(&task_config, &tx, worker, &task_stats) {
+            Ok(msg) => {
+                info!("Auto rappy task completed: {}", msg);
+                Ok(())
+            }
+            Err(e) => {
+                let _ = tx.send(format!("Task error: {}", e));
+                *worker_error.lock().unwrap_or_else(|e| e.into_inner()) = Some(e.clone());
+                Err(e.into())
+            }
+        }
+    });
+
+    let deadline = config.max_runtime().map(|runtime| Instant::now() + runtime);
+    let mut last_status = None;
+    // 工作线程结束后发送端被释放, 此时已收到全部消息
+    loop {
+        match rx.recv_timeout(STATUS_POLL) {
+            Ok(msg) => output.message(&msg),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        let status = supervisor.status();
+        if last_status.as_ref() != Some(&status) {
+            output.status(&status);
+            last_status = Some(status.clone());
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline)
+            && status != WorkerStatus::Stopping
+        {
+            info!("Reached max_minutes ({}), stopping.", config.max_minutes);
+            supervisor.stop();
+        }
+    }
+    supervisor.join();
+    // 进程随后退出, 先等历史记录写完
+    stats.flush_history();
+
+    let snapshot = stats.snapshot();
+    output.report(
+        "stats",
+        &snapshot.to_string(),
+        serde_json::to_value(&snapshot).unwrap_or_default(),
+    );
+    // 导出失败不影响退出码
+    if let Some(dir) = export
+        && let Err(e) = export_files(
+            dir,
+            &stats.rounds(),
+            &stats.events(),
+            &ExportFormat::ALL,
+            output,
+        )
+    {
+        error!("Failed to export session: {}", e);
+    }
+    let status = supervisor.status();
+    if last_status.as_ref() != Some(&status) {
+        output.status(&status);
+    }
+    let code = match status {
+        WorkerStatus::Crashed { .. } => last_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map_or(EXIT_FAILED, exit_code),
+        _ => EXIT_OK,
+    };
+    output.event(
+        "exit",
+        format_args!("Exit code: {}", code),
+        json!({ "code": code, "error": supervisor.last_error() }),
+    );
+    code
+}
+
+fn load_screenshot(path: &Path) -> Result<Screenshot> {
+    if !path.is_file() {
+        return Err(RappyError::Io(format!("{} not found", path.display())));
+    }
+    let img = Image::load(path, ReadMode::Unchanged).context(path.display())?;
+    Ok(Screenshot { img })
+}
+
+fn analyze(
+    image: &Path,
+    offset: (i32, i32),
+    annotate: Option<&Path>,
+    output: &Output,
+) -> Result<()> {
+    TemplateImg::check_all()?;
+    let source = load_screenshot(image)?;
+    let analysis = Analysis::run(&source, &Detectors::new(offset.0, offset.1));
+    output.report(
+        "analysis",
+        &analysis.table(),
+        json!({
+            "image": image.display().to_string(),
+            "offset": [offset.0, offset.1],
+            "regions": analysis.regions,
+            "detectors": analysis.detectors,
+        }),
+    );
+    if let Some(dir) = annotate {
+        let stem = image
+            .file_stem()
+            .map_or("screenshot".into(), |stem| stem.to_string_lossy());
+        write_annotated(&analysis, &source.img, &stem, dir, output)?;
+    }
+    Ok(())
+}
+
+/// 写出标注了全部区域的整图, 以及每个区域的截图 (截取失败的区域跳过)
+fn write_annotated(
+    analysis: &Analysis,
+    img: &Image,
+    stem: &str,
+    dir: &Path,
+    output: &Output,
+) -> Result<()> {
+    std::fs::create_dir_all(dir).context(dir.display())?;
+    let path = dir.join(format!("{}_annotated.png", stem));
+    analysis
+        .annotate(img)?
+        .save_png(&path)
+        .context(path.display())?;
+    let mut saved = vec![path];
+    for region in &analysis.regions {
+        let Ok(crop) = img.roi(region.rect) else {
+            continue;
+        };
+        let path = dir.join(format!("{}_{}.png", stem, region.check.name));
+        crop.save_png(&path).context(path.display())?;
+        saved.push(path);
+    }
+    for path in saved {
+        output.event(
+            "saved",
+            format_args!("Saved {}", path.display()),
+            json!({ "path": path.display().to_string() }),
+        );
+    }
+    Ok(())
+}
+
+fn calibrate(config: &RunConfig, save: Option<&Path>, output: &Output) -> Result<()> {
+    TemplateImg::check_all()?;
+    let title = &config.window_title;
+    let hwnd = search_window_by_title(title)
+        .ok_or_else(|| RappyError::WindowNotFound(title.to_string()))?;
+    let (Some((offset_x, offset_y)), Some((width, height))) =
+        (get_window_client_offset(hwnd), get_window_client_size(hwnd))
+    else {
+        return Err(RappyError::WindowNotFound(format!(
+            "client area of {}",
+            title
+        )));
+    };
+    output.event(
+        "window",
+        format_args!(
+            "Client offset: ({}, {}), size: {}x{}",
+            offset_x, offset_y, width, height
+        ),
+        json!({ "offset": [offset_x, offset_y], "size": [width, height] }),
+    );
+    if (width, height) != CLIENT_SIZE {
+        output.event(
+            "warning",
+            format_args!(
+                "Client size should be {}x{}, regions will not line up",
+                CLIENT_SIZE.0, CLIENT_SIZE.1
+            ),
+            json!({ "expected_size": [CLIENT_SIZE.0, CLIENT_SIZE.1] }),
+        );
+    }
+    let client_rect = (offset_x, offset_y, width, height);
+    let capture = DxgiCapture::new(hwnd)?;
+    let frame = capture.frame(client_rect);
+    // 按配置的区域和 QTE 设置检测, 便于调整 rappy.toml 后核对结果
+    let detectors = Detectors::with_dataset(
+        offset_x,
+        offset_y,
+        None,
+        config.qte.matcher(),
+        &config.regions.settings(),
+        &CancellationToken::new(),
+    );
+    let analysis = Analysis::run(&frame, &detectors);
+    output.report(
+        "analysis",
+        &analysis.table(),
+        json!({ "regions": analysis.regions, "detectors": analysis.detectors }),
+    );
+    if let Some(path) = save {
+        let client = frame.grab_shot(&CapturePos { rect: client_rect })?;
+        client
+            .bgra_to_bgr()?
+            .save_png(path)
+            .context(path.display())?;
+        output.event(
+            "saved",
+            format_args!("Saved client area to {}", path.display()),
+            json!({ "path": path.display().to_string() }),
+        );
+    }
+    Ok(())
+}
+
+/// 一帧中各检测器的结果
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct FrameChecks {
+    key_ready: bool,
+    coin_one: bool,
+    energy_four: bool,
+    energy_zero: bool,
+    target: bool,
+    qte: bool,
+    coin_known: bool,
+}
+
+/// 按主循环的顺序给出这一帧会执行的操作
+fn decide(checks: &FrameChecks) -> Vec<&'static str> {
+    let mut actions = Vec::new();
+    if checks.coin_one && checks.energy_four {
+        actions.push("increase bet");
+    }
+    if !checks.coin_one && checks.energy_zero {
+        actions.push("decrease bet");
+    }
+    if checks.target {
+        actions.push("wait for QTE");
+    }
+    if checks.qte {
+        actions.push("press enter (QTE)");
+    }
+    if checks.key_ready {
+        actions.push("press enter");
+    }
+    if !checks.coin_known {
+        actions.push("refresh window");
+    }
+    actions
+}
+
+fn simulate(dir: &Path, offset: (i32, i32), output: &Output) -> Result<()> {
+    TemplateImg::check_all()?;
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .context(dir.display())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    ["png", "jpg", "jpeg", "bmp"].contains(&ext.to_lowercase().as_str())
+                })
+        })
+        .collect();
+    if paths.is_empty() {
+        return Err(RappyError::Config(format!(
+            "no screenshots in {}",
+            dir.display()
+        )));
+    }
+    paths.sort();
+    // 检测器在帧之间保留投票/迟滞/变化检测的状态, 和实际运行时一致
+    let detectors = Detectors::new(offset.0, offset.1);
+    for path in paths {
+        let source = load_screenshot(&path)?;
+        let passed = |detector: &dyn Detector| match detector.detect(&source) {
+            Ok(detection) => detection.passed,
+            Err(e) => {
+                error!("Failed to detect {}: {}", detector.name(), e);
+                false
+            }
+        };
+        let checks = FrameChecks {
+            key_ready: passed(&*detectors.key_ready),
+            coin_one: passed(&*detectors.coin_one),
+            energy_four: passed(&*detectors.energy_four),
+            energy_zero: passed(&*detectors.energy_zero),
+            target: passed(&*detectors.target),
+            qte: passed(&detectors.qte),
+            coin_known: passed(&*detectors.coin_known),
+        };
+        let actions = decide(&checks);
+        output.event(
+            "frame",
+            format_args!("{}: {}", path.display(), actions.join(", ")),
+            json!({
+                "image": path.display().to_string(),
+                "checks": {
+                    "key_ready": checks.key_ready,
+                    "coin_one": checks.coin_one,
+                    "energy_four": checks.energy_four,
+                    "energy_zero": checks.energy_zero,
+                    "target": checks.target,
+                    "qte": checks.qte,
+                    "coin_known": checks.coin_known,
+                },
+                "actions": actions,
+            }),
+        );
+    }
+    Ok(())
+}
+
+fn evaluate(dataset: &Path, offset: (i32, i32), roc: Option<&Path>, output: &Output) -> Result<()> {
+    TemplateImg::check_all()?;
+    if !dataset.exists() {
+        return Err(RappyError::Io(format!("{} not found", dataset.display())));
+    }
+    let samples = evaluate::load_dataset(dataset)?;
+    if samples.is_empty() {
+        return Err(RappyError::Config(format!(
+            "no labelled samples in {}",
+            dataset.display()
+        )));
+    }
+    let evaluations = evaluate::evaluate(&samples, &Detectors::new(offset.0, offset.1))?;
+    for evaluation in &evaluations {
+        output.report(
+            "evaluation",
+            &evaluation.report(),
+            serde_json::to_value(evaluation).unwrap_or_default(),
+        );
+    }
+    if let Some(dir) = roc {
+        std::fs::create_dir_all(dir).context(dir.display())?;
+        for evaluation in &evaluations {
+            let path = dir.join(format!("{}_roc.csv", evaluation.region));
+            std::fs::write(&path, evaluation.roc_csv()).context(path.display())?;
+            output.event(
+                "saved",
+                format_args!("Saved {}", path.display()),
+                json!({ "path": path.display().to_string() }),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn robustness(dir: &Path, offset: (i32, i32), seed: u64, output: &Output) -> Result<()> {
+    TemplateImg::check_all()?;
+    let images = robustness::load_images(dir)?;
+    let reports = robustness::run(&Detectors::new(offset.0, offset.1), &images, seed);
+    for report in &reports {
+        output.report(
+            "robustness",
+            &report.report(),
+            serde_json::to_value(report).unwrap_or_default(),
+        );
+    }
+    // 最后汇总每个区域在最差扰动下的余量
+    for report in &reports {
+        let (Some(baseline), Some(weakest)) = (report.results.first(), report.weakest()) else {
+            continue;
+        };
+        output.event(
+            "margin",
+            format_args!(
+                "{} threshold {}: baseline margin {:+.4}, weakest {} margin {:+.4}",
+                report.region,
+                report.threshold,
+                baseline.margin,
+                weakest.perturbation,
+                weakest.margin
+            ),
+            json!({
+                "region": report.region,
+                "threshold": report.threshold,
+                "baseline_margin": baseline.margin,
+                "weakest": weakest.perturbation,
+                "weakest_margin": weakest.margin,
+            }),
+        );
+    }
+    Ok(())
+}
+
+fn history(db: &Path, days: i64, output: &Output) -> Result<()> {
+    // 不存在时不新建空数据库
+    if !db.exists() {
+        return Err(RappyError::Io(format!("{} not found", db.display())));
+    }
+    let summary = History::open(db)?.last_days(days)?;
+    output.report(
+        "history",
+        &summary.to_string(),
+        serde_json::to_value(&summary).unwrap_or_default(),
+    );
+    Ok(())
+}
+
+/// 导出历史数据库中最近 days 天 (或某个会话) 的局和事件
+fn export_history(
+    db: &Path,
+    days: Option<i64>,
+    session: Option<&str>,
+    formats: &[ExportFormat],
+    out: &Path,
+    output: &Output,
+) -> Result<()> {
+    if !db.exists() {
+        return Err(RappyError::Io(format!("{} not found", db.display())));
+    }
+    let history = History::open(db)?;
+    let since = days.map(|days| chrono::Local::now() - chrono::Duration::days(days));
+    let rounds = history.rounds(since, session)?;
+    let events = history.events(since, session)?;
+    export_files(out, &rounds, &events, formats, output)
+}
+
+fn export_files(
+    dir: &Path,
+    rounds: &[RoundRecord],
+    events: &[EventRecord],
+    formats: &[ExportFormat],
+    output: &Output,
+) -> Result<()> {
+    let paths = export::export(dir, rounds, events, formats)?;
+    let files = paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>();
+    output.event(
+        "export",
+        format_args!(
+            "Exported {} rounds and {} events to {}",
+            rounds.len(),
+            events.len(),
+            files.join(", ")
+        ),
+        json!({ "rounds": rounds.len(), "events": events.len(), "files": files }),
+    );
+    Ok(())
+}
+
+fn report(
+    db: &Path,
+    session: Option<&str>,
+    dataset: &Path,
+    snapshots: &Path,
+    out: &Path,
+    output: &Output,
+) -> Result<()> {
+    if !db.exists() {
+        return Err(RappyError::Io(format!("{} not found", db.display())));
+    }
+    let report = SessionReport::load(&History::open(db)?, session, dataset, snapshots)?;
+    let path = report.write(out)?;
+    output.event(
+        "report",
+        format_args!(
+            "Report for session {} written to {}",
+            report.session.id,
+            path.display()
+        ),
+        json!({ "session": report.session.id, "path": path.display().to_string() }),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(args("help")).unwrap(), Command::Help);
+        assert_eq!(
+            parse_args(args("run --config rappy.toml --output json")).unwrap(),
+            Command::Run {
+                config: Some(PathBuf::from("rappy.toml")),
+                export: None,
+                output: Some(OutputFormat::Json),
+            }
+        );
+        assert_eq!(
+            parse_args(args("analyze test_data/target.jpg --offset 8,31")).unwrap(),
+            Command::Analyze {
+                image: PathBuf::from("test_data/target.jpg"),
+                offset: (8, 31),
+                annotate: None,
+                output: OutputFormat::Text,
+            }
+        );
+        assert_eq!(
+            parse_args(args("robustness --seed 7")).unwrap(),
+            Command::Robustness {
+                dir: PathBuf::from("test_data"),
+                offset: (0, 0),
+                seed: 7,
+                output: OutputFormat::Text,
+            }
+        );
+        for bad in [
+            "start",
+            "run extra",
+            "run --config",
+            "run --verbose 1",
+            "analyze",
+            "analyze a.png --offset 8",
+            "simulate a b",
+            "evaluate",
+            "evaluate data --roc",
+            "robustness a b",
+            "robustness --seed x",
+            "history --days 0",
+            "history extra",
+            "export --format xlsx",
+            "export --days -1",
+            "export --session",
+            "report extra",
+            "report --out",
+        ] {
+            let e = parse_args(args(bad)).unwrap_err();
+            assert_eq!(exit_code(&e), EXIT_USAGE, "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_analyze_screenshot() {
+        let output = Output {
+            format: OutputFormat::Json,
+        };
+        let dir = std::env::temp_dir().join(format!("rappy_analyze_{}", std::process::id()));
+        analyze(
+            Path::new("test_data/target.jpg"),
+            (0, 0),
+            Some(&dir),
+            &output,
+        )
+        .unwrap();
+        assert!(dir.join("target_annotated.png").is_file());
+        assert!(dir.join("target_QTE.png").is_file());
+        std::fs::remove_dir_all(&dir).unwrap();
+        let e = analyze(Path::new("test_data/missing.jpg"), (0, 0), None, &output).unwrap_err();
+        assert_eq!(exit_code(&e), EXIT_IO);
+    }
+
+    #[test]
+    fn test_decide_follows_main_loop() {
+        let checks = FrameChecks {
+            key_ready: true,
+            coin_one: true,
+            energy_four: true,
+            coin_known: true,
+            ..Default::default()
+        };
+        assert_eq!(decide(&checks), vec!["increase bet", "press enter"]);
+        let checks = FrameChecks {
+            energy_zero: true,
+            target: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            decide(&checks),
+            vec!["decrease bet", "wait for QTE", "refresh window"]
+        );
+    }
+}
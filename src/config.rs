@@ -0,0 +1,572 @@
+use crate::AUTO_RESTART;
+use crate::detector::{RegionSettings, Regions, TemporalConfig};
+use crate::error::{RappyError, Result, ResultExt};
+use crate::supervisor::RestartPolicy;
+use crate::vision::color::{ColorRule, Combine};
+use crate::vision::{ColorCheck, MatchMethod, MultiScaleMatcher, ThresholdMode};
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// 命令行输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// 每行 "时间: 消息", 给人看
+    #[default]
+    Text,
+    /// 每行一个 JSON 对象, 给脚本解析
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = RappyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(RappyError::Config(format!(
+                "unknown output format {:?}, expected text or json",
+                s
+            ))),
+        }
+    }
+}
+
+///
+/// 无界面运行的配置 (rappy.toml), 所有字段都可省略
+///
+/// ```toml
+/// window_title = "PHANTASY STAR ONLINE 2"
+/// log_level = "info"
+/// output = "json"
+/// auto_restart = true
+/// max_restarts = 5
+/// initial_backoff_secs = 2
+/// max_backoff_secs = 30
+/// # 运行 120 分钟后停止, 0 表示一直运行
+/// max_minutes = 120
+/// # 执行操作时把标注过的整个画面保存到 snapshots/
+/// snapshots = true
+///
+/// [qte]
+/// # 在 0.35..=0.65 的 7 个比例上搜索 QTE, 适应不同的 UI 缩放
+/// scale = 0.5
+/// scale_step = 0.05
+/// scale_levels = 3
+/// method = "ccoeff_normed"
+/// threshold = 0.8
+///
+/// # 各区域的设置, 见 [`RegionsConfig`]
+/// [regions.coin]
+/// preprocess = "otsu"
+/// vote = [2, 3]
+/// ```
+///
+/// * 图形界面启动时读取当前目录下的 rappy.toml (见 [`GUI_CONFIG`]), 使用其中的窗口标题,
+///   快照和检测设置; 自动重启由界面勾选, 日志级别, 输出格式和运行时长只用于命令行
+///
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RunConfig {
+    /// 按标题包含的文字查找游戏窗口
+    pub window_title: String,
+    pub log_level: String,
+    pub output: OutputFormat,
+    pub auto_restart: bool,
+    pub max_restarts: u32,
+    pub initial_backoff_secs: u64,
+    pub max_backoff_secs: u64,
+    pub max_minutes: u64,
+    /// 保存决策快照, 见 snapshot 模块
+    pub snapshots: bool,
+    pub qte: QteConfig,
+    pub regions: RegionsConfig,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            window_title: "PHANTASY STAR ONLINE 2".to_string(),
+            log_level: "info".to_string(),
+            output: OutputFormat::Text,
+            auto_restart: true,
+            max_restarts: AUTO_RESTART.max_restarts,
+            initial_backoff_secs: AUTO_RESTART.initial_backoff.as_secs(),
+            max_backoff_secs: AUTO_RESTART.max_backoff.as_secs(),
+            max_minutes: 0,
+            snapshots: false,
+            qte: QteConfig::default(),
+            regions: RegionsConfig::default(),
+        }
+    }
+}
+
+/// 图形界面启动时读取的配置文件
+pub(crate) static GUI_CONFIG: &str = "rappy.toml";
+
+impl RunConfig {
+    /// 读取 path, 文件不存在时返回默认配置
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> Result<Self> {
+        if !path.as_ref().exists() {
+            return Ok(Self::default());
+        }
+        Self::load(path)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| RappyError::Config(format!("read {}: {}", path.display(), e)))?;
+        Self::parse(&text).map_err(|e| e.context(path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let config: Self = toml::from_str(text).map_err(|e| RappyError::Config(e.to_string()))?;
+        if log::LevelFilter::from_str(&config.log_level).is_err() {
+            return Err(RappyError::Config(format!(
+                "unknown log level {:?}",
+                config.log_level
+            )));
+        }
+        config.qte.validate()?;
+        config.regions.validate()?;
+        if config.qte.method == MatchMethod::SqdiffNormed
+            && let Some(ColorCheck {
+                combine: Combine::Weighted { .. },
+                ..
+            }) = config.regions.qte.color
+        {
+            return Err(RappyError::Config(
+                "regions.qte: weighted color combine needs a higher-is-better qte method"
+                    .to_string(),
+            ));
+        }
+        if config.window_title.is_empty() {
+            return Err(RappyError::Config("window_title is empty".to_string()));
+        }
+        Ok(config)
+    }
+
+    pub fn restart_policy(&self) -> RestartPolicy {
+        if !self.auto_restart {
+            return RestartPolicy::NEVER;
+        }
+        RestartPolicy {
+            max_restarts: self.max_restarts,
+            initial_backoff: Duration::from_secs(self.initial_backoff_secs),
+            max_backoff: Duration::from_secs(self.max_backoff_secs),
+        }
+    }
+
+    /// 运行时长上限, None 表示一直运行
+    pub fn max_runtime(&self) -> Option<Duration> {
+        (self.max_minutes > 0).then(|| Duration::from_secs(self.max_minutes * 60))
+    }
+}
+
+///
+/// QTE 的模板匹配 ([qte] 表)
+///
+/// * 默认与最初的检测相同: 截图固定缩小一半, TM_CCORR_NORMED, 阈值 0.99
+/// * scale_levels > 0 时在 scale ± scale_step * 1..=scale_levels 上逐个比例匹配取最好的结果,
+///   耗时约为 2 * scale_levels + 1 倍, 一般配合 ccoeff_normed 和 0.8 使用
+///
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QteConfig {
+    /// 截图的缩放比例, 模板按 QTE 截图缩小一半截取
+    pub scale: f64,
+    pub scale_step: f64,
+    /// scale 上下各搜索的层数, 0 为只用 scale
+    pub scale_levels: u32,
+    pub method: MatchMethod,
+    pub threshold: f64,
+}
+
+impl Default for QteConfig {
+    fn default() -> Self {
+        Self {
+            scale: 0.5,
+            scale_step: 0.05,
+            scale_levels: 0,
+            method: MatchMethod::CcorrNormed,
+            threshold: 0.99,
+        }
+    }
+}
+
+impl QteConfig {
+    fn validate(&self) -> Result<()> {
+        if !(0.0..=1.0).contains(&self.threshold) {
+            return Err(RappyError::Config(format!(
+                "qte threshold {} is out of range 0..=1",
+                self.threshold
+            )));
+        }
+        if self.scale <= 0.0 || (self.scale_levels > 0 && self.scale_step <= 0.0) {
+            return Err(RappyError::Config(format!(
+                "qte scale {} and scale_step {} must be positive",
+                self.scale, self.scale_step
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn matcher(&self) -> MultiScaleMatcher {
+        let scales = MultiScaleMatcher::pyramid(self.scale, self.scale_step, self.scale_levels);
+        MultiScaleMatcher::new(scales, self.method, self.threshold)
+    }
+}
+
+///
+/// 各区域的检测设置 ([regions.<name>] 表), 未写的区域和字段沿用 [`Regions::DEFAULT`]
+///
+/// * name 为 key_ready, coin (COIN_ONE/COIN_FIVE), energy (ENERGY_FOUR/ENERGY_ZERO), target, qte
+/// * preprocess: 截图与模板比较前的二值化方式, 见 [`ThresholdMode`]
+/// * vote = [n, m]: 连续 m 帧中至少 n 帧通过, 见 [`crate::detector::Vote`]
+/// * hysteresis = [enter, leave]: 见 [`crate::detector::Hysteresis`]
+/// * vote/hysteresis 写空数组表示不使用; QTE 只看单帧, 不支持这两项
+/// * color: 与 SSIM (QTE 为模板匹配) 组合的颜色检测, 见 [`ColorCheck`], 直方图与彩色模板比较
+/// * coin_step (增减硬币时逐次检查) 和 coin_known (硬币为 1 或 5) 只有 vote/hysteresis
+///
+/// ```toml
+/// [regions.coin]
+/// preprocess = { fixed = 170 }
+/// vote = [3, 5]
+/// hysteresis = []
+///
+/// [regions.energy]
+/// preprocess = { adaptive_gaussian = { block_size = 11, c = 2.0 } }
+///
+/// [regions.coin_known]
+/// vote = [1, 1]
+///
+/// # QTE 提示需同时是橙色
+/// [regions.qte.color]
+/// rule = { in_range = { range = { lower = [10, 128, 128], upper = [30, 255, 255] }, min_ratio = 0.05 } }
+/// combine = "all"
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RegionsConfig {
+    pub key_ready: RegionConfig,
+    pub coin: RegionConfig,
+    pub coin_step: TemporalOverride,
+    pub coin_known: TemporalOverride,
+    pub energy: RegionConfig,
+    pub target: RegionConfig,
+    pub qte: RegionConfig,
+}
+
+impl RegionsConfig {
+    fn validate(&self) -> Result<()> {
+        for (name, region) in [
+            ("regions.key_ready", &self.key_ready),
+            ("regions.coin", &self.coin),
+            ("regions.energy", &self.energy),
+            ("regions.target", &self.target),
+            ("regions.qte", &self.qte),
+        ] {
+            region.validate().context(name)?;
+        }
+        self.coin_step.validate().context("regions.coin_step")?;
+        self.coin_known.validate().context("regions.coin_known")?;
+        if self.qte.vote.is_some() || self.qte.hysteresis.is_some() {
+            return Err(RappyError::Config(
+                "regions.qte: vote and hysteresis are not supported".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 按配置修改默认设置后的各区域设置
+    pub fn settings(&self) -> Regions {
+        let default = Regions::DEFAULT;
+        Regions {
+            key_ready: self.key_ready.apply(default.key_ready),
+            coin: self.coin.apply(default.coin),
+            coin_step: self.coin_step.apply(default.coin_step),
+            coin_known: self.coin_known.apply(default.coin_known),
+            energy: self.energy.apply(default.energy),
+            target: self.target.apply(default.target),
+            qte: self.qte.apply(default.qte),
+        }
+    }
+}
+
+/// 单个区域的配置, None 表示沿用默认值
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RegionConfig {
+    pub preprocess: Option<ThresholdMode>,
+    pub vote: Option<Vec<u32>>,
+    pub hysteresis: Option<Vec<f64>>,
+    pub color: Option<ColorCheck>,
+}
+
+impl RegionConfig {
+    fn validate(&self) -> Result<()> {
+        if let Some(
+            ThresholdMode::AdaptiveMean { block_size, .. }
+            | ThresholdMode::AdaptiveGaussian { block_size, .. },
+        ) = self.preprocess
+            && (block_size < 3 || block_size % 2 == 0)
+        {
+            return Err(RappyError::Config(format!(
+                "preprocess block_size {} must be an odd number >= 3",
+                block_size
+            )));
+        }
+        if let Some(color) = &self.color {
+            validate_color(color)?;
+        }
+        self.temporal().validate()
+    }
+
+    fn temporal(&self) -> TemporalOverride {
+        TemporalOverride {
+            vote: self.vote.clone(),
+            hysteresis: self.hysteresis.clone(),
+        }
+    }
+
+    fn apply(&self, default: RegionSettings) -> RegionSettings {
+        RegionSettings {
+            preprocess: self.preprocess.unwrap_or(default.preprocess),
+            temporal: self.temporal().apply(default.temporal),
+            color: self.color.or(default.color),
+        }
+    }
+}
+
+fn validate_color(color: &ColorCheck) -> Result<()> {
+    let error = |message: String| Err(RappyError::Config(format!("color: {}", message)));
+    match color.rule {
+        ColorRule::InRange { range, min_ratio } => {
+            if range.lower.0 >= 180 || range.upper.0 >= 180 {
+                return error(format!("hue of {:?} is out of range 0..180", range));
+            }
+            if !(0.0..=1.0).contains(&min_ratio) {
+                return error(format!("min_ratio {} is out of range 0..=1", min_ratio));
+            }
+        }
+        ColorRule::Histogram {
+            h_bins,
+            s_bins,
+            min_similarity,
+        } => {
+            if h_bins == 0 || s_bins == 0 {
+                return error("h_bins and s_bins must be positive".to_string());
+            }
+            if !(0.0..=1.0).contains(&min_similarity) {
+                return error(format!(
+                    "min_similarity {} is out of range 0..=1",
+                    min_similarity
+                ));
+            }
+        }
+    }
+    if let Combine::Weighted { ssim_weight, .. } = color.combine
+        && !(0.0..=1.0).contains(&ssim_weight)
+    {
+        return error(format!("ssim_weight {} is out of range 0..=1", ssim_weight));
+    }
+    Ok(())
+}
+
+/// 投票/迟滞的配置, None 表示沿用默认值, 空数组表示不使用
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemporalOverride {
+    pub vote: Option<Vec<u32>>,
+    pub hysteresis: Option<Vec<f64>>,
+}
+
+impl TemporalOverride {
+    fn validate(&self) -> Result<()> {
+        match self.vote.as_deref() {
+            None | Some([]) => {}
+            Some(&[n, m]) if 1 <= n && n <= m => {}
+            Some(vote) => {
+                return Err(RappyError::Config(format!(
+                    "vote {:?} should be [n, m] with 1 <= n <= m",
+                    vote
+                )));
+            }
+        }
+        match self.hysteresis.as_deref() {
+            None | Some([]) => {}
+            Some(&[enter, leave]) if leave <= enter => {}
+            Some(hysteresis) => {
+                return Err(RappyError::Config(format!(
+                    "hysteresis {:?} should be [enter, leave] with leave <= enter",
+                    hysteresis
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&self, default: TemporalConfig) -> TemporalConfig {
+        TemporalConfig {
+            vote: match self.vote.as_deref() {
+                None => default.vote,
+                Some(&[n, m]) => Some((n, m)),
+                Some(_) => None,
+            },
+            hysteresis: match self.hysteresis.as_deref() {
+                None => default.hysteresis,
+                Some(&[enter, leave]) => Some((enter, leave)),
+                Some(_) => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(RunConfig::parse("").unwrap(), RunConfig::default());
+        let config = RunConfig::parse(
+            r#"
+            output = "json"
+            auto_restart = false
+            max_minutes = 90
+            snapshots = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.output, OutputFormat::Json);
+        assert_eq!(config.restart_policy(), RestartPolicy::NEVER);
+        assert_eq!(config.max_runtime(), Some(Duration::from_secs(90 * 60)));
+        assert!(config.snapshots);
+        assert_eq!(RunConfig::default().restart_policy(), AUTO_RESTART);
+
+        let matcher = RunConfig::default().qte.matcher();
+        assert_eq!(matcher.scales, vec![0.5]);
+        assert_eq!(
+            (matcher.method, matcher.threshold),
+            (MatchMethod::CcorrNormed, 0.99)
+        );
+        let config = RunConfig::parse(
+            r#"
+            [qte]
+            scale_levels = 3
+            method = "ccoeff_normed"
+            threshold = 0.8
+            "#,
+        )
+        .unwrap();
+        let matcher = config.qte.matcher();
+        assert_eq!(matcher.scales.len(), 7);
+        assert!(matcher.scales.iter().all(|s| (0.34..0.66).contains(s)));
+        let config =
+            RunConfig::parse("[qte]\nscale = 0.6\nscale_step = 0.1\nscale_levels = 1").unwrap();
+        assert_eq!(config.qte.matcher().scales, vec![0.6, 0.7, 0.5]);
+        assert_eq!(
+            (matcher.method, matcher.threshold),
+            (MatchMethod::CcoeffNormed, 0.8)
+        );
+
+        for bad in [
+            "output = \"xml\"",
+            "log_level = \"loud\"",
+            "window = \"typo\"",
+            "max_restarts = -1",
+            "[qte]\nmethod = \"ccorr\"",
+            "[qte]\nthreshold = 1.5",
+            "[qte]\nmulti_scale = true",
+            "[qte]\nscale = 0",
+            "[qte]\nscale_levels = 2\nscale_step = -0.1",
+            "[regions.coins]\npreprocess = \"otsu\"",
+            "[regions.coin]\npreprocess = \"binary\"",
+            "[regions.coin]\npreprocess = { fixed = 300 }",
+            "[regions.energy]\npreprocess = { adaptive_mean = { block_size = 4, c = 2.0 } }",
+            "[regions.coin]\nvote = [3, 2]",
+            "[regions.coin]\nvote = [2]",
+            "[regions.key_ready]\nhysteresis = [0.8, 0.9]",
+            "[regions.coin_step]\npreprocess = \"otsu\"",
+            "[regions.qte]\nvote = [2, 3]",
+            "[regions.target.color]\ncombine = \"all\"",
+            "[regions.target.color]\nrule = { histogram = { h_bins = 0, s_bins = 8, min_similarity = 0.5 } }\ncombine = \"any\"",
+            "[regions.coin.color]\nrule = { in_range = { range = { lower = [10, 0, 0], upper = [200, 255, 255] }, min_ratio = 0.1 } }\ncombine = \"all\"",
+            "[qte]\nmethod = \"sqdiff_normed\"\n[regions.qte.color]\nrule = { histogram = { h_bins = 18, s_bins = 8, min_similarity = 0.5 } }\ncombine = { weighted = { ssim_weight = 0.5, threshold = 0.6 } }",
+        ] {
+            assert!(
+                matches!(RunConfig::parse(bad), Err(RappyError::Config(_))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_regions() {
+        assert_eq!(RunConfig::default().regions.settings(), Regions::DEFAULT);
+        let config = RunConfig::parse(
+            r#"
+            [regions.coin]
+            preprocess = "otsu"
+
+            [regions.energy]
+            preprocess = { adaptive_gaussian = { block_size = 11, c = 2.0 } }
+
+            [regions.target]
+            preprocess = { fixed = 120 }
+            "#,
+        )
+        .unwrap();
+        let regions = config.regions.settings();
+        assert_eq!(regions.coin.preprocess, ThresholdMode::Otsu);
+        assert_eq!(
+            regions.energy.preprocess,
+            ThresholdMode::AdaptiveGaussian {
+                block_size: 11,
+                c: 2.0
+            }
+        );
+        assert_eq!(regions.target.preprocess, ThresholdMode::Fixed(120));
+        // 只改二值化时投票/迟滞沿用默认值
+        assert_eq!(regions.energy.temporal, Regions::DEFAULT.energy.temporal);
+        // 未配置的区域沿用默认值
+        assert_eq!(regions.key_ready, Regions::DEFAULT.key_ready);
+        assert_eq!(regions.qte, Regions::DEFAULT.qte);
+
+        let config = RunConfig::parse(
+            r#"
+            [regions.coin]
+            vote = [3, 5]
+            hysteresis = []
+
+            [regions.coin_known]
+            vote = []
+            hysteresis = [0.9, 0.8]
+            "#,
+        )
+        .unwrap();
+        let regions = config.regions.settings();
+        assert_eq!(
+            regions.coin.temporal,
+            TemporalConfig {
+                vote: Some((3, 5)),
+                hysteresis: None,
+            }
+        );
+        assert_eq!(regions.coin.preprocess, Regions::DEFAULT.coin.preprocess);
+        assert_eq!(
+            regions.coin_known,
+            TemporalConfig {
+                vote: None,
+                hysteresis: Some((0.9, 0.8)),
+            }
+        );
+        assert_eq!(regions.coin_step, Regions::DEFAULT.coin_step);
+    }
+}
@@ -0,0 +1,378 @@
+//!
+//! 数据集: 正常游戏时保存各区域的截图和检测结果, 逐渐积累成标注数据
+//!
+//! ```text
+//! dataset/<区域名>/positive/<时间>.png    单帧通过
+//! dataset/<区域名>/near_miss/<时间>.png   未通过, 但分数与阈值相差不超过 NEAR_MISS_MARGIN
+//! dataset/<区域名>/<标签>/<时间>.json      分数, 阈值, 区域和会话 id
+//! ```
+//!
+//! * 标签是检测器自己的判定, 人工复核后移到 positive/negative 即可用 evaluate 命令评估
+//! * 同一区域同一标签每 min_interval 最多保存一张, 画面没有变化 (跳过打分) 时不保存
+//! * 总数或总大小超过限制时从最旧的开始删除
+//! * 写盘和清理都在 ImageWriter 的线程中进行, 检测循环只负责入队
+//!
+use crate::detector::{Detection, Detector, Pipeline};
+use crate::error::{Result, ResultExt};
+use crate::image_writer::{ImageWriter, WriteJob};
+use crate::vision::Image;
+use log::{debug, error, info};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub(crate) static DATASET_DIR: &str = "dataset";
+
+/// 分数与阈值相差不超过该值的失败结果视为险些通过
+static NEAR_MISS_MARGIN: f64 = 0.1;
+
+/// 写入队列长度, 磁盘跟不上时丢弃最旧的截图
+static WRITE_QUEUE: usize = 64;
+
+/// 数据集的保存频率和保留上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatasetLimits {
+    /// 图片总数上限 (不含 json)
+    pub max_files: usize,
+    /// 图片和 json 的总大小上限
+    pub max_bytes: u64,
+    /// 同一区域同一标签两次保存的最小间隔
+    pub min_interval: Duration,
+}
+
+impl DatasetLimits {
+    pub const DEFAULT: Self = Self {
+        max_files: 5000,
+        max_bytes: 512 * 1024 * 1024,
+        min_interval: Duration::from_secs(1),
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Label {
+    Positive,
+    NearMiss,
+}
+
+impl Label {
+    pub(crate) fn dir(&self) -> &'static str {
+        match self {
+            Label::Positive => "positive",
+            Label::NearMiss => "near_miss",
+        }
+    }
+
+    /// 通过为 positive, 险些通过为 near_miss, 其余不保存
+    pub fn of(pipeline: &Pipeline, detection: &Detection) -> Option<Self> {
+        if detection.passed {
+            Some(Label::Positive)
+        } else if (detection.score - pipeline.threshold()).abs() <= NEAR_MISS_MARGIN {
+            Some(Label::NearMiss)
+        } else {
+            None
+        }
+    }
+}
+
+/// 与图片同名的 json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sidecar {
+    pub region: String,
+    pub rect: (i32, i32, i32, i32),
+    pub label: Label,
+    pub score: f64,
+    pub threshold: f64,
+    pub passed: bool,
+    pub details: String,
+    pub session: String,
+    pub time: String,
+}
+
+/// 本次运行的保存/删除计数和数据集当前大小
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DatasetStats {
+    pub saved: u64,
+    pub pruned: u64,
+    /// 写入队列满时丢弃的截图
+    pub dropped: u64,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+impl fmt::Display for DatasetStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "saved: {}, pruned: {}, dropped: {}, files: {}, size: {:.1} MB",
+            self.saved,
+            self.pruned,
+            self.dropped,
+            self.files,
+            self.bytes as f64 / (1024.0 * 1024.0)
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct DatasetState {
+    /// (图片路径, 图片和 json 的大小), 按文件名即保存时间排序
+    files: VecDeque<(PathBuf, u64)>,
+    stats: DatasetStats,
+    last_saved: BTreeMap<(String, Label), Instant>,
+    /// 已入队的截图数, 用于区分同一微秒内的文件名
+    queued: u64,
+}
+
+pub struct Dataset {
+    root: PathBuf,
+    limits: DatasetLimits,
+    session: String,
+    /// 写入线程在写完后更新文件列表并清理
+    state: Arc<Mutex<DatasetState>>,
+    writer: ImageWriter,
+}
+
+impl fmt::Debug for Dataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dataset({})", self.root.display())
+    }
+}
+
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path).map_or(0, |m| m.len())
+}
+
+impl Dataset {
+    /// 创建目录并按限制清理已有的文件
+    /// * session: 会话统计的 id, 写入每张截图的 json, 报告按它筛选样本
+    pub fn open<P: AsRef<Path>>(root: P, limits: DatasetLimits, session: String) -> Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        std::fs::create_dir_all(&root).context(root.display())?;
+        let mut files = Vec::new();
+        for region in std::fs::read_dir(&root).context(root.display())? {
+            let region = region.context(root.display())?.path();
+            for label in [Label::Positive, Label::NearMiss] {
+                let dir = region.join(label.dir());
+                let Ok(entries) = std::fs::read_dir(&dir) else {
+                    continue;
+                };
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if path.extension().is_some_and(|ext| ext == "png") {
+                        let size = file_size(&path) + file_size(&path.with_extension("json"));
+                        files.push((path, size));
+                    }
+                }
+            }
+        }
+        files.sort_by(|(a, _), (b, _)| a.file_name().cmp(&b.file_name()).then(a.cmp(b)));
+        let mut state = DatasetState {
+            stats: DatasetStats {
+                files: files.len(),
+                bytes: files.iter().map(|(_, size)| size).sum(),
+                ..Default::default()
+            },
+            files: files.into(),
+            ..Default::default()
+        };
+        Self::prune(&limits, &mut state);
+        info!(
+            "Dataset {}: {} files, session {}",
+            root.display(),
+            state.stats.files,
+            session
+        );
+        Ok(Self {
+            root,
+            limits,
+            session,
+            state: Arc::new(Mutex::new(state)),
+            writer: ImageWriter::spawn(WRITE_QUEUE),
+        })
+    }
+
+    pub fn stats(&self) -> DatasetStats {
+        let mut stats = self.state.lock().unwrap_or_else(|e| e.into_inner()).stats;
+        stats.dropped = self.writer.stats().dropped;
+        stats
+    }
+
+    /// 等待已入队的截图全部写完
+    pub fn flush(&self) {
+        self.writer.flush();
+    }
+
+    /// 保存通过和险些通过的区域截图, 见 [`Dataset::record_then`]
+    pub fn record(
+        &self,
+        pipeline: &Pipeline,
+        shot: &Image,
+        detection: &Detection,
+    ) -> Option<PathBuf> {
+        self.record_then(pipeline, shot, detection, |_| {})
+    }
+
+    /// 截图入队后立即返回图片路径, 写完后在写入线程中调用 on_saved
+    /// * 不需要保存或被限频时返回 None; 写入失败只写日志, 不影响检测
+    pub fn record_then(
+        &self,
+        pipeline: &Pipeline,
+        shot: &Image,
+        detection: &Detection,
+        on_saved: impl FnOnce(&Path) + Send + 'static,
+    ) -> Option<PathBuf> {
+        let label = Label::of(pipeline, detection)?;
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (pipeline.name().to_string(), label);
+        if state
+            .last_saved
+            .get(&key)
+            .is_some_and(|last| last.elapsed() < self.limits.min_interval)
+        {
+            return None;
+        }
+        state.last_saved.insert(key, Instant::now());
+
+        let now = chrono::Local::now();
+        let dir = self.root.join(pipeline.name()).join(label.dir());
+        // 同一微秒内保存多张时用计数区分
+        let path = dir.join(format!(
+            "{}_{}.png",
+            now.format("%Y%m%d%H%M%S%.6f"),
+            state.queued
+        ));
+        state.queued += 1;
+        drop(state);
+        let sidecar = Sidecar {
+            region: pipeline.name().to_string(),
+            rect: pipeline.region().rect,
+            label,
+            score: detection.score,
+            threshold: pipeline.threshold(),
+            passed: detection.passed,
+            details: detection.details.clone(),
+            session: self.session.clone(),
+            time: now.to_rfc3339(),
+        };
+        let text = serde_json::to_string_pretty(&sidecar).unwrap_or_default();
+        let (state, limits, saved) = (self.state.clone(), self.limits, path.clone());
+        // 区域截图通常是整帧的 roi, 排队期间只保留区域本身, 整帧可以照常回收
+        let job = WriteJob::png(&path, &shot.compact())
+            .with_sidecar(path.with_extension("json"), text)
+            .on_written(move |size| {
+                let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+                state.files.push_back((saved.clone(), size));
+                state.stats.saved += 1;
+                state.stats.files += 1;
+                state.stats.bytes += size;
+                Self::prune(&limits, &mut state);
+                drop(state);
+                on_saved(&saved);
+            });
+        self.writer.submit(job);
+        Some(path)
+    }
+
+    /// 删除最旧的图片和 json 直到满足限制
+    fn prune(limits: &DatasetLimits, state: &mut DatasetState) {
+        while state.stats.files > limits.max_files || state.stats.bytes > limits.max_bytes {
+            let Some((path, size)) = state.files.pop_front() else {
+                break;
+            };
+            for file in [path.clone(), path.with_extension("json")] {
+                if let Err(e) = std::fs::remove_file(&file)
+                    && e.kind() != std::io::ErrorKind::NotFound
+                {
+                    error!("Failed to remove {}: {}", file.display(), e);
+                }
+            }
+            debug!("Pruned {}", path.display());
+            state.stats.files -= 1;
+            state.stats.bytes = state.stats.bytes.saturating_sub(size);
+            state.stats.pruned += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detector::{Detectors, Screenshot, ShotSource};
+    use crate::vision::ReadMode;
+
+    #[test]
+    fn test_record_labels_and_retention() {
+        let root = std::env::temp_dir().join(format!("rappy_dataset_rec_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let limits = DatasetLimits {
+            max_files: 2,
+            max_bytes: u64::MAX,
+            min_interval: Duration::ZERO,
+        };
+        let dataset = Dataset::open(&root, limits, "test-session".to_string()).unwrap();
+        let detectors = Detectors::new(0, 0);
+        let target = detectors
+            .pipelines()
+            .into_iter()
+            .find(|p| p.name() == "TARGET")
+            .unwrap();
+        let source = Screenshot {
+            img: Image::load("test_data/target.jpg", ReadMode::Unchanged).unwrap(),
+        };
+        let shot = source.grab_shot(target.region()).unwrap();
+        let detection = target.score_shot(&shot).unwrap();
+        assert!(detection.passed);
+
+        let positive = dataset.record(target, &shot, &detection).unwrap();
+        dataset.flush();
+        assert!(positive.starts_with(root.join("TARGET").join("positive")));
+        let sidecar: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(positive.with_extension("json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(sidecar["label"], "positive");
+        assert_eq!(sidecar["threshold"], 0.7);
+        assert_eq!(sidecar["session"], "test-session");
+
+        let miss = |score| Detection {
+            score,
+            passed: false,
+            details: String::new(),
+        };
+        let near_miss = dataset.record(target, &shot, &miss(0.65)).unwrap();
+        dataset.flush();
+        assert!(near_miss.starts_with(root.join("TARGET").join("near_miss")));
+        assert!(dataset.record(target, &shot, &miss(0.2)).is_none());
+
+        // 超过 2 张时删除最旧的
+        dataset.record(target, &shot, &detection).unwrap();
+        dataset.flush();
+        assert!(!positive.exists() && !positive.with_extension("json").exists());
+        let stats = dataset.stats();
+        assert_eq!((stats.saved, stats.pruned, stats.files), (3, 1, 2));
+
+        // 重新打开时按新的大小限制清理已有文件
+        let limits = DatasetLimits {
+            max_bytes: 1,
+            ..limits
+        };
+        let reopened = Dataset::open(&root, limits, "test-session".to_string()).unwrap();
+        assert_eq!(reopened.stats().files, 0);
+        assert!(!near_miss.exists());
+
+        let limits = DatasetLimits {
+            min_interval: Duration::from_secs(60),
+            ..DatasetLimits::DEFAULT
+        };
+        let throttled = Dataset::open(&root, limits, "test-session".to_string()).unwrap();
+        assert!(throttled.record(target, &shot, &detection).is_some());
+        assert!(throttled.record(target, &shot, &detection).is_none());
+        throttled.flush();
+        assert_eq!(throttled.stats().saved, 1);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,1218 @@
+//!
+//! 检测器: 截取区域 -> 预处理 (灰度/二值化) -> 打分 (SSIM/模板匹配/颜色) -> 阈值判定
+//!
+//! * [`Pipeline`] 是单个区域的完整流程, [`And`]/[`Or`] 用于组合, [`Vote`]/[`Hysteresis`] 用于多帧去抖
+//! * 游戏中用到的所有检测在 [`Detectors`] 中按名字给出
+//!
+use crate::cancel::CancellationToken;
+use crate::capture_settings::CapturePos;
+use crate::config::QteConfig;
+use crate::dataset::Dataset;
+use crate::error::{Result, ResultExt};
+use crate::rappy_checker::{get_mean_abs_diff, get_ssim, get_threshold_mat};
+use crate::template_img::{self, TemplateImg};
+use crate::vision::{Backend, ColorCheck, Image, ImageOps, MultiScaleMatcher, ThresholdMode};
+use log::info;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// 一次检测的结果, details 为写入日志的可读说明
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    pub score: f64,
+    pub passed: bool,
+    pub details: String,
+}
+
+/// 按 CapturePos 提供截图 (BGR 或 BGRA)
+pub trait ShotSource {
+    fn grab_shot(&self, pos: &CapturePos) -> Result<Image>;
+
+    /// 之后的截图取自新的一帧, 按帧缓存截图的实现需要重写
+    fn next_frame(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 一整张客户区截图, 用于离线分析/测试
+pub struct Screenshot {
+    pub img: Image,
+}
+
+impl ShotSource for Screenshot {
+    fn grab_shot(&self, pos: &CapturePos) -> Result<Image> {
+        Ok(self.img.roi(pos.rect)?)
+    }
+}
+
+/// 检测器会被移动到工作线程中使用, 需要 Send + Sync
+pub trait Detector: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn detect(&self, source: &dyn ShotSource) -> Result<Detection>;
+
+    /// 各区域跳过/重新打分的次数, 按检测器名字汇总
+    fn change_stats(&self, _stats: &mut BTreeMap<String, ChangeStats>) {}
+
+    /// 组成该检测器的所有单区域流程, 用于离线分析
+    fn pipelines<'a>(&'a self, _pipelines: &mut Vec<&'a Pipeline>) {}
+
+    /// 清除在多次调用间保留的判定状态 (迟滞), 新的一局开始或阶段切换时调用
+    fn reset(&self) {}
+}
+
+/// 画面变化检测的计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangeStats {
+    /// 区域有变化, 重新打分
+    pub rescored: u64,
+    /// 区域没有变化, 复用上次结果
+    pub skipped: u64,
+}
+
+impl std::fmt::Display for ChangeStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rescored: {}, skipped: {}", self.rescored, self.skipped)
+    }
+}
+
+/// 上次打分时的区域灰度图和结果
+#[derive(Debug)]
+struct ChangeCache {
+    max_mad: f64,
+    last: Option<(Image, Detection)>,
+    stats: ChangeStats,
+}
+
+/// 预处理后的打分方式
+#[derive(Debug, Clone)]
+pub enum Scorer {
+    /// SSIM > threshold
+    Ssim { threshold: f64 },
+    /// 多尺度模板匹配, 阈值由 matcher 决定
+    Match(MultiScaleMatcher),
+}
+
+/// 单个区域的检测流程
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    name: String,
+    region: CapturePos,
+    preprocess: ThresholdMode,
+    template: Image,
+    /// 预处理后的模板, 第一次打分时计算, 之后每帧复用
+    processed_template: OnceLock<Image>,
+    scorer: Scorer,
+    /// 与打分结果组合的颜色检测及所用的彩色模板
+    color: Option<(ColorCheck, Image)>,
+    /// clone 出的 Pipeline 共用同一份缓存 (区域和模板都相同)
+    change: Option<Arc<Mutex<ChangeCache>>>,
+    /// 重新打分后把截图和结果交给数据集, 离线打分 (score_once/score_shot) 不保存
+    dataset: Option<Arc<Dataset>>,
+}
+
+impl Pipeline {
+    pub fn ssim(
+        name: &str,
+        region: CapturePos,
+        template: &Image,
+        preprocess: ThresholdMode,
+        threshold: f64,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            region,
+            preprocess,
+            template: template.clone(),
+            processed_template: OnceLock::new(),
+            scorer: Scorer::Ssim { threshold },
+            color: None,
+            change: None,
+            dataset: None,
+        }
+    }
+
+    pub fn template_match(
+        name: &str,
+        region: CapturePos,
+        template: &Image,
+        preprocess: ThresholdMode,
+        matcher: MultiScaleMatcher,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            region,
+            preprocess,
+            template: template.clone(),
+            processed_template: OnceLock::new(),
+            scorer: Scorer::Match(matcher),
+            color: None,
+            change: None,
+            dataset: None,
+        }
+    }
+
+    /// 附加颜色检测, 与 SSIM 或模板匹配的结果按 combine 组合
+    /// * color_template 为未转灰度的模板, 直方图比较时使用
+    pub fn with_color(mut self, color_check: Option<ColorCheck>, color_template: &Image) -> Self {
+        self.color = color_check.map(|check| (check, color_template.clone()));
+        self
+    }
+
+    /// 区域灰度与上次打分时的平均差 <= max_mad 时不再打分, 直接复用上次结果
+    /// 只和上次打分的画面比较, 缓慢变化累积起来仍会触发重新打分
+    pub fn skip_unchanged(mut self, max_mad: f64) -> Self {
+        self.change = Some(Arc::new(Mutex::new(ChangeCache {
+            max_mad,
+            last: None,
+            stats: ChangeStats::default(),
+        })));
+        self
+    }
+
+    /// 检测时保存通过和险些通过的截图, None 表示不保存
+    pub fn record_to(mut self, dataset: Option<Arc<Dataset>>) -> Self {
+        self.dataset = dataset;
+        self
+    }
+
+    pub fn region(&self) -> &CapturePos {
+        &self.region
+    }
+
+    /// 单帧判定的阈值 (SSIM 或模板匹配)
+    pub fn threshold(&self) -> f64 {
+        match &self.scorer {
+            Scorer::Ssim { threshold, .. } => *threshold,
+            Scorer::Match(matcher) => matcher.threshold,
+        }
+    }
+
+    /// 只看分数时 score 是否达到 threshold (不含颜色检测), 用于离线评估阈值
+    pub fn passes(&self, score: f64, threshold: f64) -> bool {
+        match &self.scorer {
+            Scorer::Ssim { .. } => score > threshold,
+            Scorer::Match(matcher) => matcher.method.passes(score, threshold),
+        }
+    }
+
+    /// 分数超过阈值的余量 (只看分数), 负数表示未达到阈值
+    pub fn margin(&self, score: f64) -> f64 {
+        let threshold = self.threshold();
+        match &self.scorer {
+            Scorer::Match(matcher) if !matcher.method.is_better(1.0, 0.0) => threshold - score,
+            _ => score - threshold,
+        }
+    }
+
+    pub fn template(&self) -> &Image {
+        &self.template
+    }
+
+    /// 截图并打分一次, 不读写变化检测的缓存, 用于离线分析
+    pub fn score_once(&self, source: &dyn ShotSource) -> Result<Detection> {
+        let shot = source
+            .grab_shot(&self.region)
+            .with_context(|| format!("grab {}", self.name))?;
+        self.score_shot(&shot).context(&self.name)
+    }
+
+    /// 对已截取的区域图片打分, 区域没有变化时复用上次结果
+    pub fn detect_shot(&self, shot: &Image) -> Result<Detection> {
+        let Some(change) = &self.change else {
+            let detection = self.score_shot(shot).context(&self.name)?;
+            self.record(shot, &detection);
+            return Ok(detection);
+        };
+        let gray = Backend::to_gray(shot).context(&self.name)?;
+        let mut cache = change.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((last_gray, last)) = &cache.last
+            && get_mean_abs_diff(&gray, last_gray)? <= cache.max_mad
+        {
+            let detection = Detection {
+                details: format!("{} (unchanged, skipped)", last.details),
+                ..last.clone()
+            };
+            cache.stats.skipped += 1;
+            return Ok(detection);
+        }
+        let detection = self.score_shot(shot).context(&self.name)?;
+        cache.stats.rescored += 1;
+        cache.last = Some((gray, detection.clone()));
+        drop(cache);
+        self.record(shot, &detection);
+        Ok(detection)
+    }
+
+    fn processed_template(&self) -> Result<&Image> {
+        if let Some(template) = self.processed_template.get() {
+            return Ok(template);
+        }
+        let template = get_threshold_mat(&self.template, self.preprocess)?;
+        Ok(self.processed_template.get_or_init(|| template))
+    }
+
+    fn record(&self, shot: &Image, detection: &Detection) {
+        if let Some(dataset) = &self.dataset {
+            dataset.record(self, shot, detection);
+        }
+    }
+
+    /// 对已截取的区域图片打分, shot 需为未经处理的 BGR(A) 图
+    /// * 不读写变化检测的缓存
+    pub fn score_shot(&self, shot: &Image) -> Result<Detection> {
+        let processed = get_threshold_mat(shot, self.preprocess)?;
+        let template = self.processed_template()?;
+        let (score, passed, details) = match &self.scorer {
+            Scorer::Ssim { threshold } => {
+                let sim = get_ssim(&processed, template)?;
+                (
+                    sim,
+                    sim > *threshold,
+                    format!(
+                        "ssim: {:.6}, threshold: {}, mode: {}",
+                        sim, threshold, self.preprocess
+                    ),
+                )
+            }
+            Scorer::Match(matcher) => match matcher.find(&processed, template)? {
+                Some(m) => (
+                    m.score,
+                    matcher.is_match(&m),
+                    format!(
+                        "{}: {:.6}, threshold: {}, location: {:?}, scale: {:.2}",
+                        matcher.method, m.score, matcher.threshold, m.location, m.scale
+                    ),
+                ),
+                None => {
+                    return Ok(Detection {
+                        score: matcher.method.worst(),
+                        passed: false,
+                        details: "shot is smaller than the template at every scale".to_string(),
+                    });
+                }
+            },
+        };
+        let Some((color_check, color_template)) = &self.color else {
+            return Ok(Detection {
+                score,
+                passed,
+                details,
+            });
+        };
+        let color_score = color_check.rule.score(shot, color_template)?;
+        Ok(Detection {
+            score,
+            passed: color_check.combine.decide(
+                score,
+                passed,
+                color_score,
+                color_check.rule.threshold(),
+            ),
+            details: format!(
+                "{}, color: {:.6} ({}), combine: {}",
+                details, color_score, color_check.rule, color_check.combine
+            ),
+        })
+    }
+}
+
+impl Detector for Pipeline {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detect(&self, source: &dyn ShotSource) -> Result<Detection> {
+        let shot = source
+            .grab_shot(&self.region)
+            .with_context(|| format!("grab {}", self.name))?;
+        self.detect_shot(&shot)
+    }
+
+    fn change_stats(&self, stats: &mut BTreeMap<String, ChangeStats>) {
+        if let Some(change) = &self.change {
+            let cache = change.lock().unwrap_or_else(|e| e.into_inner());
+            stats.insert(self.name.clone(), cache.stats);
+        }
+    }
+
+    fn pipelines<'a>(&'a self, pipelines: &mut Vec<&'a Pipeline>) {
+        pipelines.push(self);
+    }
+}
+
+/// 所有子检测器都通过, score 取最小值
+/// * 现有的检测只用到 [`Or`], And 留给之后组合新的检测
+#[allow(dead_code)]
+pub struct And {
+    name: String,
+    detectors: Vec<Box<dyn Detector>>,
+}
+
+#[allow(dead_code)]
+impl And {
+    pub fn new(name: &str, detectors: Vec<Box<dyn Detector>>) -> Self {
+        Self {
+            name: name.to_string(),
+            detectors,
+        }
+    }
+}
+
+impl Detector for And {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detect(&self, source: &dyn ShotSource) -> Result<Detection> {
+        combine(&self.detectors, source, " & ", |a, b| a && b, f64::min)
+    }
+
+    fn change_stats(&self, stats: &mut BTreeMap<String, ChangeStats>) {
+        self.detectors.iter().for_each(|d| d.change_stats(stats));
+    }
+
+    fn pipelines<'a>(&'a self, pipelines: &mut Vec<&'a Pipeline>) {
+        self.detectors.iter().for_each(|d| d.pipelines(pipelines));
+    }
+
+    fn reset(&self) {
+        self.detectors.iter().for_each(|d| d.reset());
+    }
+}
+
+/// 任一子检测器通过, score 取最大值
+pub struct Or {
+    name: String,
+    detectors: Vec<Box<dyn Detector>>,
+}
+
+impl Or {
+    pub fn new(name: &str, detectors: Vec<Box<dyn Detector>>) -> Self {
+        Self {
+            name: name.to_string(),
+            detectors,
+        }
+    }
+}
+
+impl Detector for Or {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detect(&self, source: &dyn ShotSource) -> Result<Detection> {
+        combine(&self.detectors, source, " | ", |a, b| a || b, f64::max)
+    }
+
+    fn change_stats(&self, stats: &mut BTreeMap<String, ChangeStats>) {
+        self.detectors.iter().for_each(|d| d.change_stats(stats));
+    }
+
+    fn pipelines<'a>(&'a self, pipelines: &mut Vec<&'a Pipeline>) {
+        self.detectors.iter().for_each(|d| d.pipelines(pipelines));
+    }
+
+    fn reset(&self) {
+        self.detectors.iter().for_each(|d| d.reset());
+    }
+}
+
+/// 子检测器全部执行 (不短路), 保证每个分数都能记录到日志
+fn combine(
+    detectors: &[Box<dyn Detector>],
+    source: &dyn ShotSource,
+    sep: &str,
+    pass: fn(bool, bool) -> bool,
+    score: fn(f64, f64) -> f64,
+) -> Result<Detection> {
+    let mut result: Option<Detection> = None;
+    let mut details = Vec::with_capacity(detectors.len());
+    for detector in detectors {
+        let d = detector.detect(source)?;
+        details.push(format!("{}[{}]", detector.name(), d.details));
+        result = Some(match result {
+            None => d,
+            Some(r) => Detection {
+                score: score(r.score, d.score),
+                passed: pass(r.passed, d.passed),
+                details: String::new(),
+            },
+        });
+    }
+    let mut result = result.unwrap_or(Detection {
+        score: 0.0,
+        passed: false,
+        details: String::new(),
+    });
+    result.details = details.join(sep);
+    Ok(result)
+}
+
+/// 连续截取 m 帧 (间隔 interval), 至少 n 帧通过才算通过, score 取中位数
+/// 每帧的原始分数都会写入日志
+/// * 帧间等待检查取消令牌, 任务停止时不再截取剩余的帧, 结果为不通过
+pub struct Vote {
+    inner: Box<dyn Detector>,
+    n: u32,
+    m: u32,
+    interval: Duration,
+    token: CancellationToken,
+}
+
+impl Vote {
+    pub fn new(inner: Box<dyn Detector>, n: u32, m: u32, interval: Duration) -> Self {
+        let m = m.max(1);
+        Self {
+            inner,
+            n: n.clamp(1, m),
+            m,
+            interval,
+            token: CancellationToken::new(),
+        }
+    }
+
+    /// 帧间等待随 token 取消提前结束
+    pub fn cancel_on(mut self, token: CancellationToken) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// 连续 frames 帧都通过才算通过
+    #[allow(dead_code)]
+    pub fn debounce(inner: Box<dyn Detector>, frames: u32, interval: Duration) -> Self {
+        Self::new(inner, frames, frames, interval)
+    }
+}
+
+impl Detector for Vote {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn detect(&self, source: &dyn ShotSource) -> Result<Detection> {
+        let mut scores = Vec::with_capacity(self.m as usize);
+        let mut votes = 0;
+        let mut cancelled = false;
+        for i in 0..self.m {
+            if i > 0 {
+                if !self.token.sleep(self.interval) {
+                    cancelled = true;
+                    break;
+                }
+                source.next_frame()?;
+            }
+            let d = self.inner.detect(source)?;
+            info!(
+                "{} frame {}/{}: {}, {}",
+                self.inner.name(),
+                i + 1,
+                self.m,
+                d.passed,
+                d.details
+            );
+            scores.push(d.score);
+            if d.passed {
+                votes += 1;
+            }
+            // 剩余帧全部通过也不够 n 票, 或已经够 n 票时提前结束
+            if votes >= self.n || votes + (self.m - i - 1) < self.n {
+                break;
+            }
+        }
+        let frames = scores.len();
+        let mut sorted = scores.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Ok(Detection {
+            score: sorted[frames / 2],
+            passed: votes >= self.n,
+            details: format!(
+                "votes: {}/{} (need {} of {}{}), scores: {:.6?}",
+                votes,
+                frames,
+                self.n,
+                self.m,
+                if cancelled { ", cancelled" } else { "" },
+                scores
+            ),
+        })
+    }
+    fn change_stats(&self, stats: &mut BTreeMap<String, ChangeStats>) {
+        self.inner.change_stats(stats);
+    }
+
+    fn pipelines<'a>(&'a self, pipelines: &mut Vec<&'a Pipeline>) {
+        self.inner.pipelines(pipelines);
+    }
+
+    fn reset(&self) {
+        self.inner.reset();
+    }
+}
+
+/// 迟滞: 未激活时子检测器通过且 score > enter 才激活, 激活后 score < leave 才失效
+/// * 要求 enter >= leave
+/// * score 需越大越好 (SSIM, CCORR/CCOEFF), 不适用于 SqdiffNormed
+/// * 状态在多次调用间保留, 直到 [`Detector::reset`]
+/// * 子检测器的判定 (如颜色检测) 只约束激活, 激活后只看 score, 避免在阈值附近来回翻转
+pub struct Hysteresis {
+    inner: Box<dyn Detector>,
+    enter: f64,
+    leave: f64,
+    active: Mutex<bool>,
+}
+
+impl Hysteresis {
+    pub fn new(inner: Box<dyn Detector>, enter: f64, leave: f64) -> Self {
+        Self {
+            inner,
+            enter,
+            leave: leave.min(enter),
+            active: Mutex::new(false),
+        }
+    }
+}
+
+impl Detector for Hysteresis {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn detect(&self, source: &dyn ShotSource) -> Result<Detection> {
+        let d = self.inner.detect(source)?;
+        let mut active = self.active.lock().unwrap_or_else(|e| e.into_inner());
+        *active = if *active {
+            d.score >= self.leave
+        } else {
+            d.passed && d.score > self.enter
+        };
+        Ok(Detection {
+            score: d.score,
+            passed: *active,
+            details: format!(
+                "{}, hysteresis: enter > {}, leave < {}",
+                d.details, self.enter, self.leave
+            ),
+        })
+    }
+    fn change_stats(&self, stats: &mut BTreeMap<String, ChangeStats>) {
+        self.inner.change_stats(stats);
+    }
+
+    fn pipelines<'a>(&'a self, pipelines: &mut Vec<&'a Pipeline>) {
+        self.inner.pipelines(pipelines);
+    }
+
+    fn reset(&self) {
+        *self.active.lock().unwrap_or_else(|e| e.into_inner()) = false;
+        self.inner.reset();
+    }
+}
+
+/// 单个区域的时间维度配置
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemporalConfig {
+    /// (n, m): 连续 m 帧中至少 n 帧通过
+    pub vote: Option<(u32, u32)>,
+    /// (enter, leave): 见 [`Hysteresis`]
+    pub hysteresis: Option<(f64, f64)>,
+}
+
+impl TemporalConfig {
+    pub const NONE: Self = Self {
+        vote: None,
+        hysteresis: None,
+    };
+
+    /// 先套迟滞 (逐帧判定), 再套投票, 投票的帧间等待随 token 取消
+    pub fn apply(
+        &self,
+        detector: Box<dyn Detector>,
+        token: &CancellationToken,
+    ) -> Box<dyn Detector> {
+        let detector = match self.hysteresis {
+            Some((enter, leave)) => Box::new(Hysteresis::new(detector, enter, leave)),
+            None => detector,
+        };
+        match self.vote {
+            Some((n, m)) => {
+                Box::new(Vote::new(detector, n, m, VOTE_INTERVAL).cancel_on(token.clone()))
+            }
+            None => detector,
+        }
+    }
+}
+
+/// 单个区域的检测设置, 可在 rappy.toml 的 [regions.<name>] 表中修改
+/// (见 [`crate::config::RegionsConfig`])
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegionSettings {
+    /// 截图与模板比较前的二值化方式
+    pub preprocess: ThresholdMode,
+    /// QTE 只看单帧, 不使用
+    pub temporal: TemporalConfig,
+    /// 打分之外的颜色检测, None 表示不检测颜色
+    pub color: Option<ColorCheck>,
+}
+
+/// 各区域的检测设置, COIN_ONE/COIN_FIVE 共用 coin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regions {
+    pub key_ready: RegionSettings,
+    /// coin.temporal 用于每局检查一次的 COIN_ONE
+    pub coin: RegionSettings,
+    /// 增减硬币的按键循环中的 COIN_ONE/COIN_FIVE
+    pub coin_step: TemporalConfig,
+    /// COIN_ONE 或 COIN_FIVE
+    pub coin_known: TemporalConfig,
+    pub energy: RegionSettings,
+    pub target: RegionSettings,
+    pub qte: RegionSettings,
+}
+
+impl Regions {
+    /// 默认设置
+    /// * 二值化
+    ///   * 开始键提示: Otsu
+    ///   * 硬币数字: Otsu 在 1/5 之间来回跳, 改用固定阈值
+    ///   * 能量/目标/QTE: 只用灰度
+    /// * 投票/迟滞
+    ///   * 开始键: 在等待循环中轮询, 只加迟滞
+    ///   * 硬币: 单帧噪声会让 bet_coin_is_one 翻转, 3 帧 2 票 + 迟滞, 每局检查一次
+    ///   * 增减硬币的按键循环: 每按一次键检查一次, 投票会让每次按键多等两帧, 只看单帧
+    ///   * 硬币是否为 1/5: 单帧误判会触发刷新窗口, 3 帧 2 票, 每局检查一次
+    ///   * 能量: 3 帧 2 票, 每局检查一次
+    ///   * 目标/QTE: 对时延敏感, 不做处理
+    /// * 颜色: 都不检测, 亮度/泛光变化导致 SSIM 不稳时可配置 HSV 范围或直方图 (见 vision::color)
+    pub const DEFAULT: Self = Self {
+        key_ready: RegionSettings {
+            preprocess: ThresholdMode::Otsu,
+            temporal: TemporalConfig {
+                vote: None,
+                hysteresis: Some((0.9, 0.8)),
+            },
+            color: None,
+        },
+        coin: RegionSettings {
+            preprocess: ThresholdMode::Fixed(190),
+            temporal: TemporalConfig {
+                vote: Some((2, 3)),
+                hysteresis: Some((0.85, 0.75)),
+            },
+            color: None,
+        },
+        coin_step: TemporalConfig::NONE,
+        coin_known: TemporalConfig {
+            vote: Some((2, 3)),
+            hysteresis: None,
+        },
+        energy: RegionSettings {
+            preprocess: ThresholdMode::None,
+            temporal: TemporalConfig {
+                vote: Some((2, 3)),
+                hysteresis: None,
+            },
+            color: None,
+        },
+        target: RegionSettings {
+            preprocess: ThresholdMode::None,
+            temporal: TemporalConfig::NONE,
+            color: None,
+        },
+        qte: RegionSettings {
+            preprocess: ThresholdMode::None,
+            temporal: TemporalConfig::NONE,
+            color: None,
+        },
+    };
+}
+
+/// 区域灰度平均差不超过该值时视为画面未变化, 跳过打分
+static CHANGE_MAX_MAD: f64 = 1.0;
+
+/// 投票时相邻两帧的间隔
+static VOTE_INTERVAL: Duration = Duration::from_millis(30);
+
+fn coin_pipeline(
+    offset_x: i32,
+    offset_y: i32,
+    name: &str,
+    template: &TemplateImg,
+    settings: &RegionSettings,
+) -> Pipeline {
+    Pipeline::ssim(
+        name,
+        CapturePos::coin_count(offset_x, offset_y),
+        &template.img,
+        settings.preprocess,
+        0.85,
+    )
+    .with_color(settings.color, &template.color)
+    .skip_unchanged(CHANGE_MAX_MAD)
+}
+
+/// 游戏中用到的全部检测, 区域位置按窗口客户区偏移计算
+pub struct Detectors {
+    pub key_ready: Box<dyn Detector>,
+    pub coin_one: Box<dyn Detector>,
+    /// 减少硬币的按键循环中使用的 COIN_ONE, 单帧判定
+    pub coin_one_step: Box<dyn Detector>,
+    /// 只在增加硬币的按键循环中使用, 单帧判定
+    pub coin_five: Box<dyn Detector>,
+    pub energy_four: Box<dyn Detector>,
+    pub energy_zero: Box<dyn Detector>,
+    pub target: Box<dyn Detector>,
+    /// QTE 需要保存截图, 保留 Pipeline 以便先截图再检测
+    /// * 不记录到数据集, 由主循环在按键之后保存, 避免增加按键时延
+    pub qte: Pipeline,
+    /// 硬币数为 1 或 5, 都不是时说明画面错位
+    pub coin_known: Box<dyn Detector>,
+}
+
+impl Detectors {
+    /// 使用默认的区域设置和 QTE 匹配设置, 见 [`Regions::DEFAULT`] 和 [`QteConfig`]
+    pub fn new(offset_x: i32, offset_y: i32) -> Self {
+        Self::with_dataset(
+            offset_x,
+            offset_y,
+            None,
+            QteConfig::default().matcher(),
+            &Regions::DEFAULT,
+            &CancellationToken::new(),
+        )
+    }
+
+    /// 除 QTE 外的区域在检测时把截图记录到 dataset, 投票在 token 取消后不再等待
+    pub fn with_dataset(
+        offset_x: i32,
+        offset_y: i32,
+        dataset: Option<Arc<Dataset>>,
+        qte: MultiScaleMatcher,
+        regions: &Regions,
+        token: &CancellationToken,
+    ) -> Self {
+        let coin_one = coin_pipeline(
+            offset_x,
+            offset_y,
+            "COIN_ONE",
+            &template_img::COIN_ONE,
+            &regions.coin,
+        )
+        .record_to(dataset.clone());
+        let coin_five = coin_pipeline(
+            offset_x,
+            offset_y,
+            "COIN_FIVE",
+            &template_img::COIN_FIVE,
+            &regions.coin,
+        )
+        .record_to(dataset.clone());
+        Self {
+            key_ready: regions.key_ready.temporal.apply(
+                Box::new(
+                    Pipeline::ssim(
+                        "KEY_READY",
+                        CapturePos::key_ready(offset_x, offset_y),
+                        &template_img::KEY_READY.img,
+                        regions.key_ready.preprocess,
+                        0.9,
+                    )
+                    .with_color(regions.key_ready.color, &template_img::KEY_READY.color)
+                    .skip_unchanged(CHANGE_MAX_MAD)
+                    .record_to(dataset.clone()),
+                ),
+                token,
+            ),
+            coin_known: regions.coin_known.apply(
+                Box::new(Or::new(
+                    "COIN_KNOWN",
+                    vec![Box::new(coin_one.clone()), Box::new(coin_five.clone())],
+                )),
+                token,
+            ),
+            coin_one_step: regions.coin_step.apply(Box::new(coin_one.clone()), token),
+            coin_one: regions.coin.temporal.apply(Box::new(coin_one), token),
+            coin_five: regions.coin_step.apply(Box::new(coin_five), token),
+            energy_four: regions.energy.temporal.apply(
+                Box::new(
+                    Pipeline::ssim(
+                        "ENERGY_FOUR",
+                        CapturePos::energy_four(offset_x, offset_y),
+                        &template_img::ENERGY_FOUR.img,
+                        regions.energy.preprocess,
+                        0.9,
+                    )
+                    .with_color(regions.energy.color, &template_img::ENERGY_FOUR.color)
+                    .skip_unchanged(CHANGE_MAX_MAD)
+                    .record_to(dataset.clone()),
+                ),
+                token,
+            ),
+            energy_zero: regions.energy.temporal.apply(
+                Box::new(
+                    Pipeline::ssim(
+                        "ENERGY_ZERO",
+                        CapturePos::energy_zero(offset_x, offset_y),
+                        &template_img::ENERGY_ZERO.img,
+                        regions.energy.preprocess,
+                        0.9,
+                    )
+                    .with_color(regions.energy.color, &template_img::ENERGY_ZERO.color)
+                    .skip_unchanged(CHANGE_MAX_MAD)
+                    .record_to(dataset.clone()),
+                ),
+                token,
+            ),
+            target: regions.target.temporal.apply(
+                Box::new(
+                    Pipeline::ssim(
+                        "TARGET",
+                        CapturePos::target(offset_x, offset_y),
+                        &template_img::TARGET.img,
+                        regions.target.preprocess,
+                        0.7,
+                    )
+                    .with_color(regions.target.color, &template_img::TARGET.color)
+                    .skip_unchanged(CHANGE_MAX_MAD)
+                    .record_to(dataset),
+                ),
+                token,
+            ),
+            qte: Pipeline::template_match(
+                "QTE",
+                CapturePos::qte(offset_x, offset_y),
+                &template_img::QTE.img,
+                regions.qte.preprocess,
+                qte,
+            )
+            .with_color(regions.qte.color, &template_img::QTE.color)
+            .skip_unchanged(CHANGE_MAX_MAD),
+        }
+    }
+
+    /// 全部检测器, 按在主循环中出现的顺序, 用于离线分析
+    pub fn all(&self) -> [&dyn Detector; 8] {
+        [
+            &*self.key_ready,
+            &*self.coin_one,
+            &*self.coin_five,
+            &*self.energy_four,
+            &*self.energy_zero,
+            &*self.target,
+            &self.qte,
+            &*self.coin_known,
+        ]
+    }
+
+    /// 清除所有检测器的迟滞状态, 上一局的判定不带入新的一局
+    pub fn reset(&self) {
+        for detector in self.all() {
+            detector.reset();
+        }
+        self.coin_one_step.reset();
+    }
+
+    /// 全部单区域流程, 被多个检测器共用的区域 (如 COIN_ONE) 只出现一次
+    pub fn pipelines(&self) -> Vec<&Pipeline> {
+        let mut pipelines = Vec::new();
+        for detector in self.all() {
+            detector.pipelines(&mut pipelines);
+        }
+        let mut seen = std::collections::BTreeSet::new();
+        pipelines.retain(|p| seen.insert(p.name().to_string()));
+        pipelines
+    }
+
+    /// 所有区域的变化检测计数, 共用缓存的区域 (如 COIN_KNOWN 里的 COIN_ONE) 只计一次
+    pub fn change_stats(&self) -> BTreeMap<String, ChangeStats> {
+        let mut stats = BTreeMap::new();
+        for detector in [
+            &self.key_ready,
+            &self.coin_one,
+            &self.coin_five,
+            &self.energy_four,
+            &self.energy_zero,
+            &self.target,
+            &self.coin_known,
+        ] {
+            detector.change_stats(&mut stats);
+        }
+        self.qte.change_stats(&mut stats);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RunConfig;
+    use crate::vision::ReadMode;
+
+    fn screenshot(path: &str) -> Screenshot {
+        Screenshot {
+            img: Image::load(path, ReadMode::Unchanged).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_named_detectors_on_screenshots() {
+        let detectors = Detectors::new(0, 0);
+        let qte = screenshot("test_data/qte.jpg");
+        let target = screenshot("test_data/target.jpg");
+
+        assert!(detectors.qte.detect(&qte).unwrap().passed);
+        assert!(!detectors.qte.detect(&target).unwrap().passed);
+        assert!(detectors.target.detect(&target).unwrap().passed);
+        assert!(detectors.key_ready.detect(&target).unwrap().passed);
+        assert!(detectors.coin_one.detect(&target).unwrap().passed);
+        assert!(!detectors.coin_five.detect(&target).unwrap().passed);
+        assert!(!detectors.energy_zero.detect(&target).unwrap().passed);
+    }
+
+    #[test]
+    fn test_configured_preprocess() {
+        let mut regions = Regions::DEFAULT;
+        regions.coin.preprocess = ThresholdMode::Otsu;
+        let detectors = Detectors::with_dataset(
+            0,
+            0,
+            None,
+            QteConfig::default().matcher(),
+            &regions,
+            &CancellationToken::new(),
+        );
+        let target = screenshot("test_data/target.jpg");
+        let d = detectors.coin_five.detect(&target).unwrap();
+        assert!(d.details.contains("mode: otsu"), "{}", d.details);
+        let d = Detectors::new(0, 0).coin_five.detect(&target).unwrap();
+        assert!(d.details.contains("mode: fixed(190)"), "{}", d.details);
+    }
+
+    #[test]
+    fn test_configured_color_check() {
+        let detectors = |text: &str| {
+            let config = RunConfig::parse(text).unwrap();
+            Detectors::with_dataset(
+                0,
+                0,
+                None,
+                config.qte.matcher(),
+                &config.regions.settings(),
+                &CancellationToken::new(),
+            )
+        };
+        let qte = screenshot("test_data/qte.jpg");
+        let target = screenshot("test_data/target.jpg");
+
+        // QTE 提示为橙色, 模板匹配和颜色都通过
+        let orange = detectors(
+            r#"
+            [regions.qte.color]
+            rule = { in_range = { range = { lower = [10, 128, 128], upper = [30, 255, 255] }, min_ratio = 0.05 } }
+            combine = "all"
+            "#,
+        );
+        let d = orange.qte.detect(&qte).unwrap();
+        assert!(d.passed, "{}", d.details);
+        assert!(d.details.contains("combine: all"), "{}", d.details);
+        assert!(!orange.qte.detect(&target).unwrap().passed);
+
+        // 要求为蓝色时, 模板匹配通过也不算通过
+        let blue = detectors(
+            r#"
+            [regions.qte.color]
+            rule = { in_range = { range = { lower = [100, 128, 128], upper = [130, 255, 255] }, min_ratio = 0.05 } }
+            combine = "all"
+            "#,
+        );
+        assert!(!blue.qte.detect(&qte).unwrap().passed);
+
+        // 直方图与彩色模板比较
+        let histogram = detectors(
+            r#"
+            [regions.target.color]
+            rule = { histogram = { h_bins = 18, s_bins = 8, min_similarity = 0.4 } }
+            combine = "all"
+            "#,
+        );
+        let d = histogram.target.detect(&target).unwrap();
+        assert!(d.passed, "{}", d.details);
+        assert!(!histogram.target.detect(&qte).unwrap().passed);
+    }
+
+    #[test]
+    fn test_combinators() {
+        let detectors = Detectors::new(0, 0);
+        let target = screenshot("test_data/target.jpg");
+        assert!(detectors.coin_known.detect(&target).unwrap().passed);
+
+        let coin = &Regions::DEFAULT.coin;
+        let coin_one = coin_pipeline(0, 0, "COIN_ONE", &template_img::COIN_ONE, coin);
+        let coin_five = coin_pipeline(0, 0, "COIN_FIVE", &template_img::COIN_FIVE, coin);
+        let either = Or::new(
+            "COIN_ONE_OR_FIVE",
+            vec![Box::new(coin_one.clone()), Box::new(coin_five.clone())],
+        );
+        let d = either.detect(&target).unwrap();
+        assert!(d.passed);
+        assert!(d.details.contains("COIN_ONE[") && d.details.contains("COIN_FIVE["));
+
+        let both = And::new(
+            "COIN_ONE_AND_FIVE",
+            vec![Box::new(coin_one.clone()), Box::new(coin_five.clone())],
+        );
+        let d = both.detect(&target).unwrap();
+        assert!(!d.passed);
+        assert!(d.score < 0.85, "{:?}", d);
+
+        let debounced = Vote::debounce(Box::new(coin_one), 3, Duration::ZERO);
+        assert!(debounced.detect(&target).unwrap().passed);
+        let debounced = Vote::debounce(Box::new(coin_five), 3, Duration::ZERO);
+        assert!(!debounced.detect(&target).unwrap().passed);
+    }
+
+    /// 按顺序返回预设分数, 默认 score > 0.5 时通过
+    struct Scripted {
+        scores: Mutex<Vec<(f64, bool)>>,
+    }
+
+    impl Scripted {
+        fn new(scores: &[f64]) -> Box<Self> {
+            let scores: Vec<_> = scores.iter().map(|s| (*s, *s > 0.5)).collect();
+            Self::with_passed(&scores)
+        }
+
+        fn with_passed(scores: &[(f64, bool)]) -> Box<Self> {
+            let mut scores = scores.to_vec();
+            scores.reverse();
+            Box::new(Self {
+                scores: Mutex::new(scores),
+            })
+        }
+    }
+
+    impl Detector for Scripted {
+        fn name(&self) -> &str {
+            "SCRIPTED"
+        }
+
+        fn detect(&self, _source: &dyn ShotSource) -> Result<Detection> {
+            let (score, passed) = self.scores.lock().unwrap().pop().unwrap();
+            Ok(Detection {
+                score,
+                passed,
+                details: format!("score: {}", score),
+            })
+        }
+    }
+
+    fn empty_source() -> Screenshot {
+        Screenshot {
+            img: Image::from_vec(1, 1, 1, vec![0]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_vote_n_of_m() {
+        let source = empty_source();
+        // 单帧噪声不影响结果
+        let vote = Vote::new(Scripted::new(&[0.9, 0.1, 0.9]), 2, 3, Duration::ZERO);
+        assert!(vote.detect(&source).unwrap().passed);
+        let vote = Vote::new(Scripted::new(&[0.1, 0.9, 0.1]), 2, 3, Duration::ZERO);
+        let d = vote.detect(&source).unwrap();
+        assert!(!d.passed);
+        assert_eq!(d.score, 0.1);
+        // 前两帧已够票数, 不再截第三帧
+        let vote = Vote::new(Scripted::new(&[0.9, 0.9]), 2, 3, Duration::ZERO);
+        assert!(vote.detect(&source).unwrap().passed);
+        // 任务已停止时不再等待和截取剩余的帧
+        let token = CancellationToken::new();
+        token.cancel();
+        let vote = Vote::new(Scripted::new(&[0.9]), 2, 3, Duration::from_secs(10)).cancel_on(token);
+        let d = vote.detect(&source).unwrap();
+        assert!(!d.passed);
+        assert!(d.details.contains("cancelled"), "{}", d.details);
+    }
+
+    #[test]
+    fn test_hysteresis_keeps_state_between_thresholds() {
+        let source = empty_source();
+        let scores = [0.85, 0.95, 0.85, 0.82, 0.75, 0.85];
+        let expected = [false, true, true, true, false, false];
+        let hysteresis = Hysteresis::new(Scripted::new(&scores), 0.9, 0.8);
+        for (score, passed) in scores.iter().zip(expected) {
+            let d = hysteresis.detect(&source).unwrap();
+            assert_eq!(d.passed, passed, "score: {}", score);
+        }
+    }
+
+    #[test]
+    fn test_hysteresis_needs_inner_pass_to_enter() {
+        let source = empty_source();
+        // score 超过 enter 但子检测器未通过 (如颜色检测不符) 时不激活
+        let hysteresis = Hysteresis::new(
+            Scripted::with_passed(&[(0.95, false), (0.95, true)]),
+            0.9,
+            0.8,
+        );
+        assert!(!hysteresis.detect(&source).unwrap().passed);
+        assert!(hysteresis.detect(&source).unwrap().passed);
+    }
+
+    #[test]
+    fn test_reset_between_rounds() {
+        let source = empty_source();
+        // 上一局激活后, 新的一局需要重新超过 enter 才算通过
+        let key_ready = Regions::DEFAULT.key_ready.temporal.apply(
+            Scripted::new(&[0.95, 0.85, 0.85, 0.95]),
+            &CancellationToken::new(),
+        );
+        let rounds = [[true, true], [false, true]];
+        for (round, expected) in rounds.iter().enumerate() {
+            key_ready.reset();
+            for passed in expected {
+                assert_eq!(
+                    key_ready.detect(&source).unwrap().passed,
+                    *passed,
+                    "round {}",
+                    round
+                );
+            }
+        }
+        // 投票里的迟滞同样会被清除
+        let coin = Regions::DEFAULT.coin.temporal.apply(
+            Scripted::new(&[0.9, 0.8, 0.8, 0.8]),
+            &CancellationToken::new(),
+        );
+        assert!(coin.detect(&source).unwrap().passed);
+        coin.reset();
+        assert!(!coin.detect(&source).unwrap().passed);
+    }
+
+    #[test]
+    fn test_skip_unchanged_region() {
+        let detectors = Detectors::new(0, 0);
+        let target = screenshot("test_data/target.jpg");
+        let qte = screenshot("test_data/qte.jpg");
+        let first = detectors.target.detect(&target).unwrap();
+        let second = detectors.target.detect(&target).unwrap();
+        assert_eq!(first.score, second.score);
+        assert!(second.details.ends_with("(unchanged, skipped)"));
+        detectors.target.detect(&qte).unwrap();
+
+        // COIN_KNOWN 与 COIN_ONE/COIN_FIVE 共用缓存, 两者都投票两帧, 只有第一帧打分
+        detectors.coin_known.detect(&target).unwrap();
+        detectors.coin_one.detect(&target).unwrap();
+
+        let stats = detectors.change_stats();
+        let expected = ChangeStats {
+            rescored: 2,
+            skipped: 1,
+        };
+        assert_eq!(stats["TARGET"], expected);
+        let coin_one = stats["COIN_ONE"];
+        assert_eq!((coin_one.rescored, coin_one.skipped), (1, 3));
+        assert_eq!(stats["KEY_READY"], ChangeStats::default());
+    }
+}
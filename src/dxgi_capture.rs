@@ -1,16 +1,17 @@
+use std::cell::RefCell;
 use std::ffi::{c_void, CString};
+use std::fmt;
 use std::mem;
 use crate::capture_settings::CapturePos;
+use crate::detector::ShotSource;
+use crate::error::{RappyError, Result};
+use crate::vision::Image;
+use crate::windows_utils::{get_window_client_offset, get_window_client_size};
 use log::info;
-use opencv::core::AlgorithmHint::ALGO_HINT_DEFAULT;
-use opencv::core::{CV_8UC3, CV_8UC4, Mat, MatTrait};
-use opencv::imgproc;
-use opencv::imgproc::cvt_color;
 use windows::Win32::Foundation::HWND;
 use windows::Win32::UI::WindowsAndMessaging::SetProcessDPIAware;
 
 use memory_module_sys::{MemoryGetProcAddress, MemoryLoadLibrary, HMEMORYMODULE};
-use windows::core::{Error, HRESULT};
 
 // 初始化dxgi
 type InitDxgiFn = unsafe extern "C" fn(hwnd: HWND);
@@ -25,6 +26,78 @@ type GrabFn = unsafe extern "C" fn(
 // 销毁dxgi
 type DestroyFn = unsafe extern "C" fn();
 
+/// 单次抓图失败的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureError {
+    /// 窗口已关闭, 最小化或客户区为空
+    WindowUnavailable,
+    /// 截图区域超出当前客户区 (窗口被缩小或偏移变化)
+    OutOfBounds {
+        rect: (i32, i32, i32, i32),
+        client: (i32, i32, i32, i32),
+    },
+    /// dll 返回空指针
+    NullFrame {
+        rect: (i32, i32, i32, i32),
+    },
+    /// 整个区域都是黑色, dxgi 没有拿到新画面
+    BlackFrame {
+        rect: (i32, i32, i32, i32),
+    },
+    Buffer(String),
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureError::WindowUnavailable => {
+                write!(f, "window is minimised or has no client area")
+            }
+            CaptureError::OutOfBounds { rect, client } => {
+                write!(f, "rect {:?} is outside client area {:?}", rect, client)
+            }
+            CaptureError::NullFrame { rect } => write!(f, "dll returned no frame for {:?}", rect),
+            CaptureError::BlackFrame { rect } => write!(f, "black frame for {:?}", rect),
+            CaptureError::Buffer(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl From<CaptureError> for RappyError {
+    fn from(e: CaptureError) -> Self {
+        RappyError::Capture(e.to_string())
+    }
+}
+
+/// 检查 rect 是否在客户区内, 两者都是窗口坐标 (left, top, width, height)
+fn check_rect(
+    rect: (i32, i32, i32, i32),
+    client: (i32, i32, i32, i32),
+) -> std::result::Result<(), CaptureError> {
+    let (left, top, width, height) = rect;
+    let (client_left, client_top, client_width, client_height) = client;
+    if client_width <= 0 || client_height <= 0 {
+        return Err(CaptureError::WindowUnavailable);
+    }
+    if width <= 0
+        || height <= 0
+        || left < client_left
+        || top < client_top
+        || left + width > client_left + client_width
+        || top + height > client_top + client_height
+    {
+        return Err(CaptureError::OutOfBounds { rect, client });
+    }
+    Ok(())
+}
+
+/// BGRA 数据的颜色通道全为 0, 遇到第一个非黑像素就返回
+fn is_black(bgra: &[u8]) -> bool {
+    bgra.chunks_exact(4).all(|px| px[..3] == [0, 0, 0])
+}
+
 pub struct DxgiCapture {
     // 使用 'static 强转（仅当你能确保 lib 在结构体中一直存在时）
     _init_dxgi: InitDxgiFn,
@@ -33,17 +106,23 @@ pub struct DxgiCapture {
     // 必须先声明 lib，确保它在 Symbol 之后被销毁
     _lib: HMEMORYMODULE,
     hwnd: HWND,
+    // 整帧截图的缓冲区, Frame 用完后归还, 下一帧复用
+    frame_buffer: RefCell<Vec<u8>>,
+    // grab 的 BGRA 缓冲区, 转成 BGR 后归还
+    grab_buffer: RefCell<Vec<u8>>,
 }
 
 impl DxgiCapture {
-    pub fn new(hwnd: HWND) -> Result<Self, Error> {
+    pub fn new(hwnd: HWND) -> Result<Self> {
         unsafe {
             info!("Set process dpi aware.");
             let _ = SetProcessDPIAware();
             let dll_bytes = include_bytes!("../libs/dxgi4py.dll");
             let handle = MemoryLoadLibrary(dll_bytes.as_ptr() as *const c_void, dll_bytes.len());
             if handle.is_null() {
-                return Err(Error::new(HRESULT(0), "Failed to load dxgi4py.dll"));
+                return Err(RappyError::Capture(
+                    "Failed to load dxgi4py.dll".to_string(),
+                ));
             }
             let init_dxgi_addr = Self::find_function_addr_in_dll_module(&handle, "init_dxgi")?;
             let init_dxgi:InitDxgiFn = mem::transmute(init_dxgi_addr);
@@ -73,7 +152,9 @@ impl DxgiCapture {
                 _grab: grab,
                 _destroy: destroy,
                 _lib: handle,
-                hwnd: hwnd,
+                hwnd,
+                frame_buffer: RefCell::new(Vec::new()),
+                grab_buffer: RefCell::new(Vec::new()),
             })
         }
     }
@@ -81,45 +162,47 @@ impl DxgiCapture {
     fn find_function_addr_in_dll_module(
         module: &HMEMORYMODULE,
         func_name: &str,
-    ) -> Result<*mut c_void, Error> {
+    ) -> Result<*mut c_void> {
         let c_func_name = CString::new(func_name).unwrap();
         let addr = unsafe { MemoryGetProcAddress(*module, c_func_name.as_ptr()) };
         if addr.is_null() {
-            Err(Error::new(
-                HRESULT(0),
-                format!("Failed to get function {} address", func_name),
-            ))
+            Err(RappyError::Capture(format!(
+                "Failed to get function {} address",
+                func_name
+            )))
         } else {
             Ok(addr as *mut c_void)
         }
     }
 
-    pub fn grab(&self, pos: &CapturePos) -> Mat {
+    /// 截取 pos 区域 (BGR), 区域超出当前客户区或 dll 没有返回画面时报错, 不会返回旧画面
+    pub fn grab(&self, pos: &CapturePos) -> std::result::Result<Image, CaptureError> {
         let (left, top, width, height) = pos.rect;
-        let mat = self._grab(left, top, width, height);
-        let mut display_mat = unsafe { Mat::new_rows_cols(height, width, CV_8UC3) }.unwrap();
-        let _ = cvt_color(
-            &mat,
-            &mut display_mat,
-            imgproc::COLOR_BGRA2BGR,
-            0,
-            ALGO_HINT_DEFAULT,
-        );
-        display_mat
+        let mut buffer = self.grab_buffer.take();
+        let grabbed = self._grab_into(&mut buffer, left, top, width, height);
+        // 失败时缓冲区没有被用掉, 放回去下次复用
+        self.grab_buffer.replace(buffer);
+        let img = grabbed?;
+        let bgr = img
+            .bgra_to_bgr()
+            .map_err(|e| CaptureError::Buffer(e.to_string()))?;
+        if let Some(buffer) = img.try_into_vec() {
+            self.grab_buffer.replace(buffer);
+        }
+        Ok(bgr)
     }
 
-    pub fn grab_gray(&self, pos: &CapturePos) -> Mat {
-        let (left, top, width, height) = pos.rect;
-        let mat = self._grab(left, top, width, height);
-        let mut display_mat = unsafe { Mat::new_rows_cols(height, width, CV_8UC3) }.unwrap();
-        let _ = cvt_color(
-            &mat,
-            &mut display_mat,
-            imgproc::COLOR_BGRA2GRAY,
-            0,
-            ALGO_HINT_DEFAULT,
-        );
-        display_mat
+    /// 当前客户区的窗口坐标 (offset_x, offset_y, width, height)
+    fn client_rect(&self) -> std::result::Result<(i32, i32, i32, i32), CaptureError> {
+        match (
+            get_window_client_offset(self.hwnd),
+            get_window_client_size(self.hwnd),
+        ) {
+            (Some((offset_x, offset_y)), Some((width, height))) => {
+                Ok((offset_x, offset_y, width, height))
+            }
+            _ => Err(CaptureError::WindowUnavailable),
+        }
     }
 
     pub fn update_hwnd(&mut self, hwnd: HWND) {
@@ -130,13 +213,103 @@ impl DxgiCapture {
         }
     }
 
-    fn _grab(&self, left: i32, top: i32, width: i32, height: i32) -> Mat {
-        let mut mat;
-        unsafe {
-            mat = Mat::new_rows_cols(height, width, CV_8UC4).unwrap();
-            let _ = (self._grab)(mat.data_mut(), left, top, width, height);
+    /// 整帧截图模式: 返回的 Frame 只抓一次 client_rect 区域, 各 CapturePos 从中截取
+    ///
+    /// * client_rect: 客户区在窗口中的 (offset_x, offset_y, width, height)
+    pub fn frame(&self, client_rect: (i32, i32, i32, i32)) -> Frame<'_> {
+        Frame {
+            capture: self,
+            client_rect,
+            img: RefCell::new(None),
+        }
+    }
+
+    /// 抓图到 buffer, 成功时 buffer 的内存移交给返回的 Image, 失败时留在 buffer 中
+    fn _grab_into(
+        &self,
+        buffer: &mut Vec<u8>,
+        left: i32,
+        top: i32,
+        width: i32,
+        height: i32,
+    ) -> std::result::Result<Image, CaptureError> {
+        let rect = (left, top, width, height);
+        check_rect(rect, self.client_rect()?)?;
+        buffer.resize((width * height * 4) as usize, 0);
+        let data = unsafe { (self._grab)(buffer.as_mut_ptr(), left, top, width, height) };
+        if data.is_null() {
+            return Err(CaptureError::NullFrame { rect });
+        }
+        if is_black(buffer) {
+            return Err(CaptureError::BlackFrame { rect });
+        }
+        Image::from_vec(width, height, 4, std::mem::take(buffer))
+            .map_err(|e| CaptureError::Buffer(format!("grab {:?}: {}", rect, e)))
+    }
+}
+
+///
+/// 一帧客户区截图 (BGRA), 同一帧内的所有区域截图互相一致
+///
+/// * 第一次截取区域时才抓图, next_frame 之后重新抓
+/// * 区域截图是整帧的 roi, 不拷贝像素
+/// * 丢弃时把缓冲区还给 DxgiCapture, 仍有区域截图在使用时则不归还
+///
+pub struct Frame<'a> {
+    capture: &'a DxgiCapture,
+    client_rect: (i32, i32, i32, i32),
+    img: RefCell<Option<Image>>,
+}
+
+impl Frame<'_> {
+    fn image(&self) -> Result<Image> {
+        let mut img = self.img.borrow_mut();
+        if let Some(img) = img.as_ref() {
+            return Ok(img.clone());
         }
-        mat
+        let (left, top, width, height) = self.client_rect;
+        let mut buffer = self.capture.frame_buffer.take();
+        match self.capture._grab_into(&mut buffer, left, top, width, height) {
+            Ok(frame) => Ok(img.insert(frame).clone()),
+            Err(e) => {
+                // 抓图失败时缓冲区没有被用掉, 还给 DxgiCapture
+                self.capture.frame_buffer.replace(buffer);
+                Err(e.into())
+            }
+        }
+    }
+
+    fn recycle(&self) {
+        if let Some(buffer) = self.img.take().and_then(Image::try_into_vec) {
+            self.capture.frame_buffer.replace(buffer);
+        }
+    }
+}
+
+impl ShotSource for Frame<'_> {
+    fn grab_shot(&self, pos: &CapturePos) -> Result<Image> {
+        let (left, top, width, height) = pos.rect;
+        let (offset_x, offset_y, _, _) = self.client_rect;
+        Ok(self
+            .image()?
+            .roi((left - offset_x, top - offset_y, width, height))?)
+    }
+
+    fn next_frame(&self) -> Result<()> {
+        self.recycle();
+        Ok(())
+    }
+}
+
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        self.recycle();
+    }
+}
+
+impl ShotSource for DxgiCapture {
+    fn grab_shot(&self, pos: &CapturePos) -> Result<Image> {
+        Ok(self.grab(pos)?)
     }
 }
 
@@ -146,26 +319,39 @@ impl Drop for DxgiCapture {
     }
 }
 
-#[cfg(test)]
-pub fn show_image(display_mat: &Mat) {
+#[cfg(all(test, feature = "opencv-backend"))]
+pub fn show_image(display_img: &Image) {
     use opencv::highgui;
+    let display_mat = crate::vision::opencv_ops::to_mat(display_img).unwrap();
     // 创建窗口并显示
     let window_name = "Rust OpenCV Display";
     highgui::named_window(window_name, highgui::WINDOW_AUTOSIZE).unwrap();
-    highgui::imshow(window_name, display_mat).unwrap();
+    highgui::imshow(window_name, &display_mat).unwrap();
 
     // 等待按键（否则窗口会闪现即逝）
     highgui::wait_key(0).unwrap();
 }
 
-#[cfg(test)]
-pub fn show_as_video(img_generator: Box<dyn Fn()->Result<Mat, Error>>) {
+/// 没有 opencv highgui 时, 保存为 png 代替显示
+#[cfg(all(test, not(feature = "opencv-backend")))]
+pub fn show_image(display_img: &Image) {
+    let file_path = std::env::temp_dir().join(format!(
+        "show_image_{}.png",
+        chrono::Local::now().format("%Y%m%d%H%M%S%.6f")
+    ));
+    info!("Save image to {}", file_path.display());
+    display_img.save_png(&file_path).unwrap();
+}
+
+#[cfg(all(test, feature = "opencv-backend"))]
+pub fn show_as_video(img_generator: Box<dyn Fn() -> Result<Image>>) {
     use opencv::highgui;
     loop {
         // 3. 抓取一幀截圖
         if let Ok(frame) = img_generator() {
             // 4. 將 BGRA 數據轉為 Mat
             // 注意：大多數截圖庫返回的是平鋪的 u8 數組
+            let frame = crate::vision::opencv_ops::to_mat(&frame).unwrap();
 
             // 5. 顯示影像
             highgui::imshow("Screen Stream", &frame).unwrap();
@@ -184,6 +370,34 @@ mod tests {
     use crate::windows_utils::search_window_by_title;
     use log::error;
 
+    #[test]
+    fn test_check_rect_against_client() {
+        let client = (8, 31, 1600, 900);
+        assert_eq!(check_rect((8, 31, 1600, 900), client), Ok(()));
+        assert_eq!(check_rect((100, 100, 50, 50), client), Ok(()));
+        // 窗口缩小后右下角区域超出客户区
+        assert_eq!(
+            check_rect((1500, 850, 200, 100), client),
+            Err(CaptureError::OutOfBounds {
+                rect: (1500, 850, 200, 100),
+                client
+            })
+        );
+        assert!(check_rect((0, 0, 10, 10), client).is_err());
+        assert!(check_rect((100, 100, 0, 10), client).is_err());
+        // 最小化时客户区为 0x0
+        assert_eq!(
+            check_rect((100, 100, 50, 50), (8, 31, 0, 0)),
+            Err(CaptureError::WindowUnavailable)
+        );
+    }
+
+    #[test]
+    fn test_black_frame_ignores_alpha() {
+        assert!(is_black(&[0, 0, 0, 255, 0, 0, 0, 0]));
+        assert!(!is_black(&[0, 0, 0, 255, 0, 1, 0, 255]));
+    }
+
     #[test]
     fn test_dxgi_capture() {
         let _logger = init_logger("debug");
@@ -191,9 +405,11 @@ mod tests {
             Some(hwnd) => {
                 let dxgi = DxgiCapture::new(hwnd).unwrap();
                 // 假设有一个有效的HWND
-                let display_mat = dxgi.grab(&CapturePos {
-                    rect: (0, 0, 1600, 954),
-                });
+                let display_mat = dxgi
+                    .grab(&CapturePos {
+                        rect: (0, 0, 1600, 954),
+                    })
+                    .unwrap();
                 show_image(&display_mat);
             }
             _ => {
@@ -202,15 +418,18 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "opencv-backend")]
     #[test]
     fn test_dxgi_capture_as_video() {
         let _logger = init_logger("debug");
         match search_window_by_title("PHANTASY") {
             Some(hwnd) => {
                 let dxgi = DxgiCapture::new(hwnd).unwrap();
-                if let Some((offset_x, offset_y)) = crate::windows_utils::get_window_client_offset(hwnd) {
+                if let Some((offset_x, offset_y)) = get_window_client_offset(hwnd) {
                     info!("Window client offset: ({}, {})", offset_x, offset_y);
-                    show_as_video(Box::new(move || Ok(dxgi.grab(&CapturePos::energy_zero(offset_x,offset_y)))));
+                    show_as_video(Box::new(move || {
+                        Ok(dxgi.grab(&CapturePos::energy_zero(offset_x, offset_y))?)
+                    }));
                 } else {
                     error!("Failed to get window client offset");
                 }
@@ -0,0 +1,154 @@
+use crate::supervisor::WorkerError;
+use crate::vision::VisionError;
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, RappyError>;
+
+/// 程序中所有可报告的错误, 每个变体带有出错位置/对象的上下文描述
+#[derive(Debug, Clone, PartialEq)]
+pub enum RappyError {
+    /// dxgi 截图 (DLL 加载, 初始化, 抓图)
+    Capture(String),
+    /// 找不到游戏窗口或取不到客户区
+    WindowNotFound(String),
+    /// 内嵌模板图片解码失败
+    Template(String),
+    /// 图像处理 (预处理, SSIM, 模板匹配)
+    Vision(String),
+    /// 发送按键
+    Input(String),
+    /// 配置错误
+    Config(String),
+    Io(String),
+    /// 历史数据库 (SQLite)
+    Database(String),
+}
+
+impl RappyError {
+    /// 在错误信息前加上上下文, 例如 "SSIM of KEY_READY: size mismatch"
+    pub fn context(self, context: impl fmt::Display) -> Self {
+        let wrap = |message: String| format!("{}: {}", context, message);
+        match self {
+            RappyError::Capture(m) => RappyError::Capture(wrap(m)),
+            RappyError::WindowNotFound(m) => RappyError::WindowNotFound(wrap(m)),
+            RappyError::Template(m) => RappyError::Template(wrap(m)),
+            RappyError::Vision(m) => RappyError::Vision(wrap(m)),
+            RappyError::Input(m) => RappyError::Input(wrap(m)),
+            RappyError::Config(m) => RappyError::Config(wrap(m)),
+            RappyError::Io(m) => RappyError::Io(wrap(m)),
+            RappyError::Database(m) => RappyError::Database(wrap(m)),
+        }
+    }
+
+    /// 窗口/截图/按键问题可能在游戏恢复后消失, 值得重启任务重试
+    pub fn is_recoverable(&self) -> bool {
+        matches!(
+            self,
+            RappyError::Capture(_) | RappyError::WindowNotFound(_) | RappyError::Input(_)
+        )
+    }
+}
+
+impl fmt::Display for RappyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RappyError::Capture(m) => write!(f, "capture error: {}", m),
+            RappyError::WindowNotFound(m) => write!(f, "window not found: {}", m),
+            RappyError::Template(m) => write!(f, "template error: {}", m),
+            RappyError::Vision(m) => write!(f, "vision error: {}", m),
+            RappyError::Input(m) => write!(f, "input error: {}", m),
+            RappyError::Config(m) => write!(f, "config error: {}", m),
+            RappyError::Io(m) => write!(f, "io error: {}", m),
+            RappyError::Database(m) => write!(f, "database error: {}", m),
+        }
+    }
+}
+
+impl std::error::Error for RappyError {}
+
+impl From<VisionError> for RappyError {
+    fn from(e: VisionError) -> Self {
+        RappyError::Vision(e.message)
+    }
+}
+
+impl From<std::io::Error> for RappyError {
+    fn from(e: std::io::Error) -> Self {
+        RappyError::Io(e.to_string())
+    }
+}
+
+/// SQLite 的错误码 (如 DatabaseBusy, DatabaseCorrupt) 保留在信息中
+impl From<rusqlite::Error> for RappyError {
+    fn from(e: rusqlite::Error) -> Self {
+        match &e {
+            rusqlite::Error::SqliteFailure(code, _) => {
+                RappyError::Database(format!("{:?}: {}", code.code, e))
+            }
+            _ => RappyError::Database(e.to_string()),
+        }
+    }
+}
+
+/// 工作线程失败时按错误类型决定是否重启
+impl From<RappyError> for WorkerError {
+    fn from(e: RappyError) -> Self {
+        if e.is_recoverable() {
+            WorkerError::Recoverable(e.to_string())
+        } else {
+            WorkerError::Fatal(e.to_string())
+        }
+    }
+}
+
+/// 给 Result 的错误加上下文
+pub trait ResultExt<T> {
+    fn context(self, context: impl fmt::Display) -> Result<T>;
+
+    /// 只在出错时才生成上下文, 用于每帧都会调用的路径
+    fn with_context<C: fmt::Display>(self, context: impl FnOnce() -> C) -> Result<T>;
+}
+
+impl<T, E: Into<RappyError>> ResultExt<T> for std::result::Result<T, E> {
+    fn context(self, context: impl fmt::Display) -> Result<T> {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C: fmt::Display>(self, context: impl FnOnce() -> C) -> Result<T> {
+        self.map_err(|e| e.into().context(context()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_keeps_kind() {
+        let e: Result<()> = Err(VisionError::new("size mismatch")).context("SSIM of KEY_READY");
+        let e = e.unwrap_err();
+        assert_eq!(
+            e,
+            RappyError::Vision("SSIM of KEY_READY: size mismatch".to_string())
+        );
+        assert_eq!(
+            e.to_string(),
+            "vision error: SSIM of KEY_READY: size mismatch"
+        );
+        assert!(!e.is_recoverable());
+        assert!(RappyError::Capture("grab".to_string()).is_recoverable());
+    }
+
+    #[test]
+    fn test_sqlite_error_keeps_code() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let e: RappyError = conn
+            .execute("INSERT INTO missing VALUES (1)", [])
+            .unwrap_err()
+            .into();
+        let RappyError::Database(message) = &e else {
+            panic!("{:?}", e);
+        };
+        assert!(message.starts_with("Unknown: no such table"), "{}", message);
+    }
+}
@@ -0,0 +1,462 @@
+//!
+//! 标注数据集评估: 对每个区域的正/负样本打分, 输出混淆矩阵, 精确率/召回率和 ROC 曲线
+//!
+//! 数据集有两种形式:
+//!
+//! * 文件夹: `<root>/<区域名>/positive/*.png` 和 `<root>/<区域名>/negative/*.png`
+//! * CSV 清单: 每行 `path,region,label`, label 为 1/0, positive/negative 或 true/false,
+//!   path 相对于清单所在目录, 以 # 开头的行和表头 `path,region,label` 会被跳过
+//!
+//! 样本可以是区域截图 (与区域大小相同), 也可以是整张客户区截图 (按区域位置截取)
+//!
+use crate::detector::{Detector, Detectors, Pipeline};
+use crate::error::{RappyError, Result, ResultExt};
+use crate::vision::{Image, ReadMode};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+static IMAGE_EXTENSIONS: [&str; 4] = ["png", "jpg", "jpeg", "bmp"];
+
+/// 一个标注样本
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub path: PathBuf,
+    /// 区域名, 与 Pipeline 的名字相同 (如 KEY_READY, QTE)
+    pub region: String,
+    pub positive: bool,
+}
+
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// 文件夹或 .csv 清单
+pub fn load_dataset(path: &Path) -> Result<Vec<Sample>> {
+    if path.is_dir() {
+        load_folders(path)
+    } else {
+        load_manifest(path)
+    }
+}
+
+/// 读取 `<root>/<区域名>/{positive,negative}/` 下的图片
+pub fn load_folders(root: &Path) -> Result<Vec<Sample>> {
+    let mut samples = Vec::new();
+    for entry in std::fs::read_dir(root).context(root.display())? {
+        let region_dir = entry.context(root.display())?.path();
+        if !region_dir.is_dir() {
+            continue;
+        }
+        let region = region_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        for (label, positive) in [("positive", true), ("negative", false)] {
+            let dir = region_dir.join(label);
+            if !dir.is_dir() {
+                continue;
+            }
+            for entry in std::fs::read_dir(&dir).context(dir.display())? {
+                let path = entry.context(dir.display())?.path();
+                if is_image(&path) {
+                    samples.push(Sample {
+                        path,
+                        region: region.clone(),
+                        positive,
+                    });
+                }
+            }
+        }
+    }
+    samples.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(samples)
+}
+
+fn parse_label(label: &str) -> Option<bool> {
+    match label.to_lowercase().as_str() {
+        "1" | "positive" | "true" => Some(true),
+        "0" | "negative" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// 读取 `path,region,label` 格式的清单
+pub fn load_manifest(manifest: &Path) -> Result<Vec<Sample>> {
+    let text = std::fs::read_to_string(manifest).context(manifest.display())?;
+    let base = manifest.parent().unwrap_or(Path::new("."));
+    let mut samples = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line == "path,region,label" {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [path, region, label] = fields[..] else {
+            return Err(RappyError::Config(format!(
+                "{}:{}: expected path,region,label",
+                manifest.display(),
+                i + 1
+            )));
+        };
+        let positive = parse_label(label).ok_or_else(|| {
+            RappyError::Config(format!(
+                "{}:{}: unknown label {:?}",
+                manifest.display(),
+                i + 1,
+                label
+            ))
+        })?;
+        samples.push(Sample {
+            path: base.join(path),
+            region: region.to_string(),
+            positive,
+        });
+    }
+    Ok(samples)
+}
+
+/// 混淆矩阵
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Confusion {
+    pub tp: u32,
+    pub fp: u32,
+    pub tn: u32,
+    #[serde(rename = "fn")]
+    pub fn_: u32,
+}
+
+impl Confusion {
+    fn add(&mut self, positive: bool, predicted: bool) {
+        match (positive, predicted) {
+            (true, true) => self.tp += 1,
+            (false, true) => self.fp += 1,
+            (false, false) => self.tn += 1,
+            (true, false) => self.fn_ += 1,
+        }
+    }
+
+    fn ratio(a: u32, b: u32) -> f64 {
+        if a + b == 0 {
+            0.0
+        } else {
+            a as f64 / (a + b) as f64
+        }
+    }
+
+    pub fn precision(&self) -> f64 {
+        Self::ratio(self.tp, self.fp)
+    }
+
+    /// 召回率, 即 ROC 的真阳性率
+    pub fn recall(&self) -> f64 {
+        Self::ratio(self.tp, self.fn_)
+    }
+
+    /// 假阳性率
+    pub fn fpr(&self) -> f64 {
+        Self::ratio(self.fp, self.tn)
+    }
+
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 {
+            0.0
+        } else {
+            2.0 * p * r / (p + r)
+        }
+    }
+}
+
+/// ROC 曲线上的一点: 以 threshold 为阈值时的真阳性率和假阳性率
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RocPoint {
+    pub threshold: f64,
+    pub tpr: f64,
+    pub fpr: f64,
+}
+
+/// 一个区域的评估结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Evaluation {
+    pub region: String,
+    /// 当前配置的阈值
+    pub threshold: f64,
+    /// 当前阈值 (含颜色检测) 下的混淆矩阵
+    pub confusion: Confusion,
+    pub precision: f64,
+    pub recall: f64,
+    pub f1: f64,
+    /// 只看分数时的 ROC 曲线, 按假阳性率升序
+    pub roc: Vec<RocPoint>,
+    pub auc: f64,
+    /// 约登指数 (tpr - fpr) 最大的点, 没有正样本或负样本时为 None
+    pub optimal: Option<RocPoint>,
+    /// 读取或打分失败的样本
+    pub errors: Vec<String>,
+}
+
+/// 一个样本的打分结果: (分数, 当前阈值下是否通过, 是否正样本)
+type Scored = (f64, bool, bool);
+
+/// 由打分结果计算评估指标, passes(score, threshold) 与检测器的比较方向一致
+fn evaluate_scores(
+    region: &str,
+    threshold: f64,
+    scored: &[Scored],
+    passes: impl Fn(f64, f64) -> bool,
+    errors: Vec<String>,
+) -> Evaluation {
+    let mut confusion = Confusion::default();
+    for &(_, passed, positive) in scored {
+        confusion.add(positive, passed);
+    }
+
+    // 依次以每个分数为阈值, 再加上一个所有样本都不通过的点
+    let mut candidates: Vec<f64> = scored.iter().map(|&(score, _, _)| score).collect();
+    candidates.sort_by(|a, b| a.total_cmp(b));
+    candidates.dedup();
+    let mut roc: Vec<RocPoint> = candidates
+        .into_iter()
+        .map(|t| {
+            let mut c = Confusion::default();
+            for &(score, _, positive) in scored {
+                c.add(positive, passes(score, t));
+            }
+            RocPoint {
+                threshold: t,
+                tpr: c.recall(),
+                fpr: c.fpr(),
+            }
+        })
+        .collect();
+    roc.push(RocPoint {
+        threshold: f64::NAN,
+        tpr: 1.0,
+        fpr: 1.0,
+    });
+    roc.push(RocPoint {
+        threshold: f64::NAN,
+        tpr: 0.0,
+        fpr: 0.0,
+    });
+    roc.sort_by(|a, b| a.fpr.total_cmp(&b.fpr).then(a.tpr.total_cmp(&b.tpr)));
+    let auc = roc
+        .windows(2)
+        .map(|w| (w[1].fpr - w[0].fpr) * (w[1].tpr + w[0].tpr) / 2.0)
+        .sum();
+
+    let has_both = confusion.tp + confusion.fn_ > 0 && confusion.fp + confusion.tn > 0;
+    let optimal = has_both
+        .then(|| {
+            roc.iter()
+                .filter(|p| !p.threshold.is_nan())
+                .max_by(|a, b| (a.tpr - a.fpr).total_cmp(&(b.tpr - b.fpr)))
+                .copied()
+        })
+        .flatten();
+    // NaN 不能序列化为 JSON, 两端的点只用于计算面积
+    roc.retain(|p| !p.threshold.is_nan());
+
+    Evaluation {
+        region: region.to_string(),
+        threshold,
+        confusion,
+        precision: confusion.precision(),
+        recall: confusion.recall(),
+        f1: confusion.f1(),
+        roc,
+        auc,
+        optimal,
+        errors,
+    }
+}
+
+/// 样本与区域同样大小时直接使用, 否则视为整张客户区截图按区域截取
+fn region_shot(pipeline: &Pipeline, img: &Image) -> Result<Image> {
+    let (_, _, width, height) = pipeline.region().rect;
+    if (img.width(), img.height()) == (width, height) {
+        return Ok(img.clone());
+    }
+    Ok(img.roi(pipeline.region().rect)?)
+}
+
+fn score_sample(pipeline: &Pipeline, sample: &Sample) -> Result<(f64, bool)> {
+    let img = Image::load(&sample.path, ReadMode::Unchanged)?;
+    let detection = pipeline.score_shot(&region_shot(pipeline, &img)?)?;
+    Ok((detection.score, detection.passed))
+}
+
+/// 对每个区域评估, 按区域在主循环中的顺序输出; 样本中有未知的区域名时报错
+pub fn evaluate(samples: &[Sample], detectors: &Detectors) -> Result<Vec<Evaluation>> {
+    let pipelines = detectors.pipelines();
+    let mut by_region: BTreeMap<&str, Vec<&Sample>> = BTreeMap::new();
+    for sample in samples {
+        if !pipelines.iter().any(|p| p.name() == sample.region) {
+            return Err(RappyError::Config(format!(
+                "unknown region {:?} for {}",
+                sample.region,
+                sample.path.display()
+            )));
+        }
+        by_region.entry(&sample.region).or_default().push(sample);
+    }
+    let mut evaluations = Vec::new();
+    for pipeline in pipelines {
+        let Some(samples) = by_region.get(pipeline.name()) else {
+            continue;
+        };
+        let mut scored = Vec::with_capacity(samples.len());
+        let mut errors = Vec::new();
+        for sample in samples {
+            match score_sample(pipeline, sample).context(sample.path.display()) {
+                Ok((score, passed)) => scored.push((score, passed, sample.positive)),
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        evaluations.push(evaluate_scores(
+            pipeline.name(),
+            pipeline.threshold(),
+            &scored,
+            |score, threshold| pipeline.passes(score, threshold),
+            errors,
+        ));
+    }
+    Ok(evaluations)
+}
+
+impl Evaluation {
+    /// 文本报告: 混淆矩阵, 指标和最佳阈值
+    pub fn report(&self) -> String {
+        let c = &self.confusion;
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{} (threshold {}, {} samples)",
+            self.region,
+            self.threshold,
+            c.tp + c.fp + c.tn + c.fn_
+        );
+        let _ = writeln!(out, "              predicted +  predicted -");
+        let _ = writeln!(out, "  actual +    {:>11}  {:>11}", c.tp, c.fn_);
+        let _ = writeln!(out, "  actual -    {:>11}  {:>11}", c.fp, c.tn);
+        let _ = writeln!(
+            out,
+            "  precision: {:.4}, recall: {:.4}, f1: {:.4}, auc: {:.4}",
+            self.precision, self.recall, self.f1, self.auc
+        );
+        match &self.optimal {
+            Some(p) => {
+                let _ = writeln!(
+                    out,
+                    "  optimal threshold: {:.6} (tpr: {:.4}, fpr: {:.4})",
+                    p.threshold, p.tpr, p.fpr
+                );
+            }
+            None => out.push_str("  optimal threshold: needs positive and negative samples\n"),
+        }
+        for e in &self.errors {
+            let _ = writeln!(out, "  error: {}", e);
+        }
+        out
+    }
+
+    /// ROC 曲线的 CSV (threshold,tpr,fpr)
+    pub fn roc_csv(&self) -> String {
+        let mut out = String::from("threshold,tpr,fpr\n");
+        for p in &self.roc {
+            let _ = writeln!(out, "{},{},{}", p.threshold, p.tpr, p.fpr);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scores_roc_and_optimal_threshold() {
+        // 正样本 0.95/0.9/0.75, 负样本 0.8/0.5, 当前阈值 0.85
+        let scored = [
+            (0.95, true, true),
+            (0.9, true, true),
+            (0.75, false, true),
+            (0.8, false, false),
+            (0.5, false, false),
+        ];
+        let e = evaluate_scores("TARGET", 0.85, &scored, |s, t| s > t, Vec::new());
+        assert_eq!(
+            e.confusion,
+            Confusion {
+                tp: 2,
+                fp: 0,
+                tn: 2,
+                fn_: 1
+            }
+        );
+        assert_eq!(e.precision, 1.0);
+        assert!((e.recall - 2.0 / 3.0).abs() < 1e-9);
+        // 6 对正负样本中只有 (0.75, 0.8) 排序错误
+        assert!((e.auc - 5.0 / 6.0).abs() < 1e-9);
+        // 分数必须大于阈值, 阈值取 0.8 时负样本全部不通过
+        let optimal = e.optimal.unwrap();
+        assert_eq!(optimal.threshold, 0.8);
+        assert!((optimal.tpr - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(optimal.fpr, 0.0);
+        assert!(e.roc_csv().starts_with("threshold,tpr,fpr\n"));
+
+        // 越小越好的方法 (SQDIFF)
+        let scored = [(0.1, true, true), (0.6, false, false)];
+        let e = evaluate_scores("QTE", 0.3, &scored, |s, t| s < t, Vec::new());
+        assert_eq!(e.auc, 1.0);
+        assert_eq!(e.optimal.unwrap().threshold, 0.6);
+
+        let e = evaluate_scores("QTE", 0.3, &scored[..1], |s, t| s < t, Vec::new());
+        assert!(e.optimal.is_none());
+    }
+
+    #[test]
+    fn test_folders_and_manifest() {
+        let root = std::env::temp_dir().join(format!("rappy_dataset_{}", std::process::id()));
+        for (dir, file) in [
+            ("QTE/positive", "qte.jpg"),
+            ("QTE/negative", "target.jpg"),
+            ("TARGET/positive", "target.jpg"),
+        ] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+            std::fs::copy(Path::new("test_data").join(file), root.join(dir).join(file)).unwrap();
+        }
+        let samples = load_dataset(&root).unwrap();
+        assert_eq!(samples.len(), 3);
+        let evaluations = evaluate(&samples, &Detectors::new(0, 0)).unwrap();
+        // 按主循环中的顺序
+        let regions: Vec<&str> = evaluations.iter().map(|e| e.region.as_str()).collect();
+        assert_eq!(regions, vec!["TARGET", "QTE"]);
+        let qte = &evaluations[1];
+        assert_eq!(qte.confusion.tp + qte.confusion.tn, 2);
+        assert!(qte.errors.is_empty());
+
+        let manifest = root.join("manifest.csv");
+        std::fs::write(
+            &manifest,
+            "path,region,label\n# comment\nQTE/positive/qte.jpg,QTE,1\nmissing.png,QTE,negative\n",
+        )
+        .unwrap();
+        let samples = load_dataset(&manifest).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert!(samples[0].positive && !samples[1].positive);
+        let evaluations = evaluate(&samples, &Detectors::new(0, 0)).unwrap();
+        assert_eq!(evaluations[0].errors.len(), 1);
+
+        std::fs::write(&manifest, "a.png,NOT_A_REGION,1\n").unwrap();
+        let samples = load_dataset(&manifest).unwrap();
+        assert!(evaluate(&samples, &Detectors::new(0, 0)).is_err());
+        std::fs::write(&manifest, "a.png,QTE,maybe\n").unwrap();
+        assert!(load_dataset(&manifest).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+//!
+//! 导出: 把每一局和每个事件写成 CSV 或 JSON Lines, 用表格或 notebook 分析
+//!
+//! * 来源可以是当前会话 ([`SessionStats`](crate::stats::SessionStats), 界面按钮) 或历史数据库 (命令行 export)
+//! * 每种格式两个文件: rounds.csv/rounds.jsonl 和 events.csv/events.jsonl, 已存在时覆盖
+//! * CSV 第一行为列名, 空值为空字段; JSON Lines 每行一个对象, 空值为 null, 字段与 CSV 的列相同
+//! * 时间为本地时间 `YYYY-MM-DD HH:MM:SS.fff`
+//!
+//! rounds (每局一行):
+//!
+//! | 列 | 类型 | 说明 |
+//! |---|---|---|
+//! | session | 文本 | 会话 id, 同 stats/<session>.json |
+//! | started, ended | 时间 | 按回车开始, 到下一次按回车或工作线程结束 |
+//! | bet | 1/5 | 开局时的下注枚数, 未检测到为空 |
+//! | energy | four/zero | 本局检测到的能量, 都没有为空 |
+//! | qte | none/hit/timeout | |
+//! | latency_ms | 小数 | QTE 命中帧开始截图到按键发出, 仅 qte=hit |
+//! | outcome | burst/target/timeout/stopped/plain | 按此优先级取一个 |
+//! | coin_balance | 整数 | 保留, 目前总是为空 |
+//!
+//! events (每个事件一行):
+//!
+//! | 列 | 类型 | 说明 |
+//! |---|---|---|
+//! | session | 文本 | 会话 id |
+//! | time | 时间 | |
+//! | event | 文本 | worker_started, worker_stopped, round_started, qte_hit, bet_observed, bet_changed, energy_four, energy_zero, target_seen, burst_started, window_refreshed, timeout |
+//! | bet | 1/5 | 仅 bet_observed/bet_changed |
+//! | phase | idle/qte_wait | 仅 timeout |
+//! | latency_ms | 小数 | 仅 qte_hit |
+//!
+use crate::error::{RappyError, Result, ResultExt};
+use crate::history::{EventRecord, RoundRecord};
+use serde::Serialize;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub(crate) static EXPORT_DIR: &str = "exports";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Jsonl,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Jsonl];
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Jsonl => "jsonl",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = RappyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "csv" => Ok(ExportFormat::Csv),
+            "jsonl" => Ok(ExportFormat::Jsonl),
+            _ => Err(RappyError::Config(format!(
+                "unknown export format {:?}, expected csv or jsonl",
+                s
+            ))),
+        }
+    }
+}
+
+/// CSV 的一行, 列与 JSON 字段一一对应
+trait CsvRow: Serialize {
+    const HEADER: &'static str;
+    fn fields(&self) -> Vec<String>;
+}
+
+fn opt<T: Display>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+impl CsvRow for RoundRecord {
+    const HEADER: &'static str =
+        "session,started,ended,bet,energy,qte,latency_ms,outcome,coin_balance";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.session.clone(),
+            self.started.clone(),
+            self.ended.clone(),
+            opt(&self.bet),
+            opt(&self.energy),
+            self.qte.clone(),
+            opt(&self.latency_ms),
+            self.outcome.clone(),
+            opt(&self.coin_balance),
+        ]
+    }
+}
+
+impl CsvRow for EventRecord {
+    const HEADER: &'static str = "session,time,event,bet,phase,latency_ms";
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.session.clone(),
+            self.time.clone(),
+            self.event.clone(),
+            opt(&self.bet),
+            opt(&self.phase),
+            opt(&self.latency_ms),
+        ]
+    }
+}
+
+/// 含逗号, 引号或换行的字段加引号, 引号写两次 (RFC 4180)
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn render<T: CsvRow>(rows: &[T], format: ExportFormat) -> String {
+    let mut text = String::new();
+    match format {
+        ExportFormat::Csv => {
+            text.push_str(T::HEADER);
+            text.push('\n');
+            for row in rows {
+                let fields = row
+                    .fields()
+                    .iter()
+                    .map(|f| csv_field(f))
+                    .collect::<Vec<_>>();
+                text.push_str(&fields.join(","));
+                text.push('\n');
+            }
+        }
+        ExportFormat::Jsonl => {
+            for row in rows {
+                text.push_str(&serde_json::to_string(row).unwrap_or_default());
+                text.push('\n');
+            }
+        }
+    }
+    text
+}
+
+/// 把局和事件按各个格式写到 dir 下, 返回写入的文件
+pub fn export<P: AsRef<Path>>(
+    dir: P,
+    rounds: &[RoundRecord],
+    events: &[EventRecord],
+    formats: &[ExportFormat],
+) -> Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir).context(dir.display())?;
+    let mut paths = Vec::new();
+    for format in formats {
+        for (name, text) in [
+            ("rounds", render(rounds, *format)),
+            ("events", render(events, *format)),
+        ] {
+            let path = dir.join(format!("{}.{}", name, format.extension()));
+            std::fs::write(&path, text).context(path.display())?;
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::Event;
+    use std::time::Duration;
+
+    #[test]
+    fn test_export_csv_and_jsonl() {
+        let round = RoundRecord {
+            session: "a,\"b\"".to_string(),
+            started: "2026-01-01 10:00:00.000".to_string(),
+            ended: "2026-01-01 10:00:30.000".to_string(),
+            bet: Some(5),
+            energy: None,
+            qte: "hit".to_string(),
+            latency_ms: Some(12.5),
+            outcome: "target".to_string(),
+            coin_balance: None,
+        };
+        let events = vec![
+            EventRecord::new("s", Event::BetChanged { one: true }),
+            EventRecord::new(
+                "s",
+                Event::QteHit {
+                    latency: Duration::from_millis(30),
+                },
+            ),
+        ];
+        let csv = render(std::slice::from_ref(&round), ExportFormat::Csv);
+        assert_eq!(
+            csv.lines().nth(1).unwrap(),
+            "\"a,\"\"b\"\"\",2026-01-01 10:00:00.000,2026-01-01 10:00:30.000,5,,hit,12.5,target,"
+        );
+
+        let dir = std::env::temp_dir().join(format!("rappy_export_{}", std::process::id()));
+        let paths = export(&dir, &[round], &events, &ExportFormat::ALL).unwrap();
+        assert_eq!(paths.len(), 4);
+        let events_csv = std::fs::read_to_string(dir.join("events.csv")).unwrap();
+        assert_eq!(events_csv.lines().count(), 3);
+        assert!(events_csv.starts_with(EventRecord::HEADER));
+        let jsonl = std::fs::read_to_string(dir.join("events.jsonl")).unwrap();
+        let lines = jsonl
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            (lines[0]["event"].as_str(), lines[0]["bet"].as_u64()),
+            (Some("bet_changed"), Some(1))
+        );
+        assert_eq!(lines[1]["latency_ms"], 30.0);
+        assert!(lines[1]["phase"].is_null());
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!("xlsx".parse::<ExportFormat>().is_err());
+    }
+}
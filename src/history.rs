@@ -0,0 +1,704 @@
+//!
+//! 历史记录: 把每次会话, 每一局和每个事件写入本地 SQLite 数据库, 用于按天汇总 (如最近 7 天) 和导出
+//!
+//! * 由会话统计 ([`SessionStats`](crate::stats::SessionStats)) 转发事件, 与统计使用同样的事件
+//! * 一局从主循环按回车开始, 到下一次按回车或工作线程结束为止 ([`RoundTracker`]), 结束时写入 rounds 表
+//! * 会话在工作线程开始时写入, 每局开始和工作线程结束时更新计数
+//! * 时间按本地时间 `YYYY-MM-DD HH:MM:SS.fff` 保存, 可以直接按字符串比较和用 date() 分组
+//! * 写入失败只写日志, 不影响主循环
+//!
+//! 表结构:
+//!
+//! * sessions: id (会话 id, 同 stats/<session>.json), started, ended, 以及会话统计中的各项计数和时长
+//! * rounds: 同 [`RoundRecord`]
+//! * events: 同 [`EventRecord`]
+//!
+use crate::error::{Result, ResultExt};
+use crate::scheduler::Phase;
+use crate::stats::{Event, StatsSnapshot, hms};
+use chrono::{DateTime, Local, NaiveDateTime};
+use log::error;
+use rusqlite::{Connection, Row, params};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+pub(crate) static HISTORY_DB: &str = "history.sqlite3";
+
+/// 数据库中的时间格式 (本地时间)
+static TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
+/// WAL + NORMAL: 写入线程每次提交不等待刷盘, 写入时命令行 (summary/export) 仍可读取
+static SCHEMA: &str = "
+PRAGMA journal_mode = WAL;
+PRAGMA synchronous = NORMAL;
+CREATE TABLE IF NOT EXISTS sessions (
+    id               TEXT PRIMARY KEY,
+    started          TEXT NOT NULL,
+    ended            TEXT,
+    running_secs     REAL NOT NULL DEFAULT 0,
+    rounds           INTEGER NOT NULL DEFAULT 0,
+    enter_presses    INTEGER NOT NULL DEFAULT 0,
+    bet_changes      INTEGER NOT NULL DEFAULT 0,
+    bet_one_secs     REAL NOT NULL DEFAULT 0,
+    bet_five_secs    REAL NOT NULL DEFAULT 0,
+    energy_four      INTEGER NOT NULL DEFAULT 0,
+    energy_zero      INTEGER NOT NULL DEFAULT 0,
+    target_sightings INTEGER NOT NULL DEFAULT 0,
+    qte_hits         INTEGER NOT NULL DEFAULT 0,
+    qte_timeouts     INTEGER NOT NULL DEFAULT 0,
+    bursts           INTEGER NOT NULL DEFAULT 0,
+    window_refreshes INTEGER NOT NULL DEFAULT 0
+);
+CREATE TABLE IF NOT EXISTS rounds (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    session      TEXT NOT NULL REFERENCES sessions(id),
+    started      TEXT NOT NULL,
+    ended        TEXT NOT NULL,
+    bet          INTEGER,
+    energy       TEXT,
+    qte          TEXT NOT NULL,
+    latency_ms   REAL,
+    outcome      TEXT NOT NULL,
+    coin_balance INTEGER
+);
+CREATE TABLE IF NOT EXISTS events (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    session    TEXT NOT NULL REFERENCES sessions(id),
+    time       TEXT NOT NULL,
+    event      TEXT NOT NULL,
+    bet        INTEGER,
+    phase      TEXT,
+    latency_ms REAL
+);
+CREATE INDEX IF NOT EXISTS rounds_started ON rounds(started);
+CREATE INDEX IF NOT EXISTS sessions_started ON sessions(started);
+CREATE INDEX IF NOT EXISTS events_time ON events(time);
+";
+
+fn now() -> String {
+    Local::now().format(TIME_FORMAT).to_string()
+}
+
+/// 解析数据库和导出文件中的时间
+pub(crate) fn parse_time(time: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(time, TIME_FORMAT).ok()
+}
+
+/// 下注数 (是否为 1 枚) 转为枚数
+fn coins(one: bool) -> u8 {
+    if one { 1 } else { 5 }
+}
+
+/// 一局的记录, 写入 rounds 表, 也是导出的一行
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoundRecord {
+    pub session: String,
+    pub started: String,
+    pub ended: String,
+    /// 开局时的下注枚数 (1 或 5), 未知为 None
+    pub bet: Option<u8>,
+    /// 本局中检测到的能量: four/zero, 都没有为 None
+    pub energy: Option<String>,
+    /// none/hit/timeout
+    pub qte: String,
+    /// QTE 命中帧开始截图到按键发出
+    pub latency_ms: Option<f64>,
+    /// 按优先级: burst (PSE burst), target (出现目标), timeout (等待开始键超时),
+    /// stopped (工作线程结束时未完成), plain (以上都没有)
+    pub outcome: String,
+    /// 目前没有检测持有的赌场币数量, 总是 None
+    pub coin_balance: Option<i64>,
+}
+
+impl RoundRecord {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            session: row.get(0)?,
+            started: row.get(1)?,
+            ended: row.get(2)?,
+            bet: row.get(3)?,
+            energy: row.get(4)?,
+            qte: row.get(5)?,
+            latency_ms: row.get(6)?,
+            outcome: row.get(7)?,
+            coin_balance: row.get(8)?,
+        })
+    }
+}
+
+/// 一个事件的记录, 写入 events 表, 也是导出的一行; 只有相关的事件才有 bet/phase/latency_ms
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventRecord {
+    pub session: String,
+    pub time: String,
+    /// 事件名, 见 [`Event::name`]
+    pub event: String,
+    /// bet_observed/bet_changed 的下注枚数
+    pub bet: Option<u8>,
+    /// timeout 的阶段: idle/qte_wait
+    pub phase: Option<String>,
+    /// qte_hit 的按键耗时
+    pub latency_ms: Option<f64>,
+}
+
+impl EventRecord {
+    pub fn new(session: &str, event: Event) -> Self {
+        let mut record = Self {
+            session: session.to_string(),
+            time: now(),
+            event: event.name().to_string(),
+            bet: None,
+            phase: None,
+            latency_ms: None,
+        };
+        match event {
+            Event::BetObserved { one } | Event::BetChanged { one } => record.bet = Some(coins(one)),
+            Event::Timeout(phase) => record.phase = Some(phase.to_string()),
+            Event::QteHit { latency } => record.latency_ms = Some(latency.as_secs_f64() * 1000.0),
+            _ => {}
+        }
+        record
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            session: row.get(0)?,
+            time: row.get(1)?,
+            event: row.get(2)?,
+            bet: row.get(3)?,
+            phase: row.get(4)?,
+            latency_ms: row.get(5)?,
+        })
+    }
+}
+
+/// sessions 表的一行, 时长单位为秒
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SessionRecord {
+    pub id: String,
+    pub started: String,
+    /// 工作线程还在运行或异常退出时为 None
+    pub ended: Option<String>,
+    pub running_secs: f64,
+    pub rounds: u64,
+    pub enter_presses: u64,
+    pub bet_changes: u64,
+    pub bet_one_secs: f64,
+    pub bet_five_secs: f64,
+    pub energy_four: u64,
+    pub energy_zero: u64,
+    pub target_sightings: u64,
+    pub qte_hits: u64,
+    pub qte_timeouts: u64,
+    pub bursts: u64,
+    pub window_refreshes: u64,
+}
+
+impl SessionRecord {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            started: row.get(1)?,
+            ended: row.get(2)?,
+            running_secs: row.get(3)?,
+            rounds: row.get(4)?,
+            enter_presses: row.get(5)?,
+            bet_changes: row.get(6)?,
+            bet_one_secs: row.get(7)?,
+            bet_five_secs: row.get(8)?,
+            energy_four: row.get(9)?,
+            energy_zero: row.get(10)?,
+            target_sightings: row.get(11)?,
+            qte_hits: row.get(12)?,
+            qte_timeouts: row.get(13)?,
+            bursts: row.get(14)?,
+            window_refreshes: row.get(15)?,
+        })
+    }
+}
+
+/// 正在进行的一局
+#[derive(Debug, Clone)]
+struct OpenRound {
+    started: String,
+    bet_one: Option<bool>,
+    energy: Option<&'static str>,
+    qte: &'static str,
+    latency_ms: Option<f64>,
+    target: bool,
+    burst: bool,
+    timeout: bool,
+}
+
+impl OpenRound {
+    fn close(self, session: &str, stopped: bool) -> RoundRecord {
+        let outcome = if self.burst {
+            "burst"
+        } else if self.target {
+            "target"
+        } else if self.timeout {
+            "timeout"
+        } else if stopped {
+            "stopped"
+        } else {
+            "plain"
+        };
+        RoundRecord {
+            session: session.to_string(),
+            started: self.started,
+            ended: now(),
+            bet: self.bet_one.map(coins),
+            energy: self.energy.map(str::to_string),
+            qte: self.qte.to_string(),
+            latency_ms: self.latency_ms,
+            outcome: outcome.to_string(),
+            coin_balance: None,
+        }
+    }
+}
+
+/// 按事件划分每一局
+#[derive(Debug, Clone, Default)]
+pub struct RoundTracker {
+    /// 最近一次检测到的下注数 (是否为 1 枚), 作为下一局的下注
+    bet_one: Option<bool>,
+    round: Option<OpenRound>,
+}
+
+impl RoundTracker {
+    /// 处理一个事件, 有一局结束时返回它
+    pub fn record(&mut self, event: Event, session: &str) -> Option<RoundRecord> {
+        let mut closed = None;
+        match event {
+            Event::WorkerStopped => {
+                closed = self.round.take().map(|round| round.close(session, true));
+                self.bet_one = None;
+            }
+            Event::RoundStarted => {
+                closed = self.round.take().map(|round| round.close(session, false));
+                self.round = Some(OpenRound {
+                    started: now(),
+                    bet_one: self.bet_one,
+                    energy: None,
+                    qte: "none",
+                    latency_ms: None,
+                    target: false,
+                    burst: false,
+                    timeout: false,
+                });
+            }
+            Event::BetObserved { one } | Event::BetChanged { one } => self.bet_one = Some(one),
+            _ => {}
+        }
+        if let Some(round) = &mut self.round {
+            match event {
+                Event::EnergyFour => round.energy = Some("four"),
+                Event::EnergyZero => round.energy = Some("zero"),
+                Event::TargetSeen => round.target = true,
+                Event::BurstStarted => round.burst = true,
+                Event::QteHit { latency } => {
+                    round.qte = "hit";
+                    round.latency_ms = Some(latency.as_secs_f64() * 1000.0);
+                }
+                Event::Timeout(Phase::QteWait) => round.qte = "timeout",
+                Event::Timeout(Phase::Idle) => round.timeout = true,
+                _ => {}
+            }
+        }
+        closed
+    }
+}
+
+pub struct History {
+    conn: Connection,
+}
+
+impl History {
+    /// 打开 (或新建) 数据库并建表
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let conn = Connection::open(path).context(path.display())?;
+        conn.execute_batch(SCHEMA).context(path.display())?;
+        Ok(Self { conn })
+    }
+
+    /// 打开失败时写日志并返回 None, 不记录历史也能运行
+    pub fn open_or_log<P: AsRef<Path>>(path: P) -> Option<Self> {
+        Self::open(path)
+            .map_err(|e| error!("Failed to open history, sessions will not be saved: {}", e))
+            .ok()
+    }
+
+    pub fn insert_round(&self, r: &RoundRecord) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO rounds (session, started, ended, bet, energy, qte, latency_ms, outcome,
+                 coin_balance)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                r.session,
+                r.started,
+                r.ended,
+                r.bet,
+                r.energy,
+                r.qte,
+                r.latency_ms,
+                r.outcome,
+                r.coin_balance,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn insert_event(&self, e: &EventRecord) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO events (session, time, event, bet, phase, latency_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![e.session, e.time, e.event, e.bet, e.phase, e.latency_ms],
+        )?;
+        Ok(())
+    }
+
+    /// since 之后开始的局, 按时间顺序; since/session 为 None 时不限
+    pub fn rounds(
+        &self,
+        since: Option<DateTime<Local>>,
+        session: Option<&str>,
+    ) -> Result<Vec<RoundRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT session, started, ended, bet, energy, qte, latency_ms, outcome, coin_balance
+             FROM rounds WHERE (?1 IS NULL OR started >= ?1) AND (?2 IS NULL OR session = ?2) ORDER BY id",
+        )?;
+        let since = since.map(|since| since.format(TIME_FORMAT).to_string());
+        let rows = stmt.query_map(params![since, session], RoundRecord::from_row)?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// since 之后的事件, 按时间顺序; since/session 为 None 时不限
+    pub fn events(
+        &self,
+        since: Option<DateTime<Local>>,
+        session: Option<&str>,
+    ) -> Result<Vec<EventRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT session, time, event, bet, phase, latency_ms
+             FROM events WHERE (?1 IS NULL OR time >= ?1) AND (?2 IS NULL OR session = ?2) ORDER BY id",
+        )?;
+        let since = since.map(|since| since.format(TIME_FORMAT).to_string());
+        let rows = stmt.query_map(params![since, session], EventRecord::from_row)?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// id 为 None 时取最近开始的会话, 不存在时返回 None
+    pub fn session(&self, id: Option<&str>) -> Result<Option<SessionRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, started, ended, running_secs, rounds, enter_presses, bet_changes,
+                    bet_one_secs, bet_five_secs, energy_four, energy_zero, target_sightings,
+                    qte_hits, qte_timeouts, bursts, window_refreshes
+             FROM sessions WHERE ?1 IS NULL OR id = ?1 ORDER BY started DESC LIMIT 1",
+        )?;
+        let mut rows = stmt.query_map([id], SessionRecord::from_row)?;
+        Ok(rows.next().transpose()?)
+    }
+
+    /// 写入或更新会话的计数, ended 为 true 时记录结束时间
+    pub fn save_session(&self, s: &StatsSnapshot, ended: bool) -> Result<()> {
+        let started = DateTime::parse_from_rfc3339(&s.started)
+            .map(|t| t.with_timezone(&Local).format(TIME_FORMAT).to_string())
+            .unwrap_or_else(|_| now());
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sessions (id, started, ended, running_secs, rounds,
+                 enter_presses, bet_changes, bet_one_secs, bet_five_secs, energy_four,
+                 energy_zero, target_sightings, qte_hits, qte_timeouts, bursts, window_refreshes)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+            params![
+                s.session,
+                started,
+                ended.then(now),
+                s.running_secs,
+                s.rounds,
+                s.enter_presses,
+                s.bet_changes,
+                s.bet_one_secs,
+                s.bet_five_secs,
+                s.energy_four,
+                s.energy_zero,
+                s.target_sightings,
+                s.qte_hits,
+                s.qte_timeouts,
+                s.bursts,
+                s.window_refreshes,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 最近 days 天 (从现在往前) 的汇总
+    pub fn last_days(&self, days: i64) -> Result<HistorySummary> {
+        self.summary_since(Local::now() - chrono::Duration::days(days))
+    }
+
+    /// since 之后开始的会话和局的汇总, 按天分组
+    pub fn summary_since(&self, since: DateTime<Local>) -> Result<HistorySummary> {
+        let since = since.format(TIME_FORMAT).to_string();
+        let mut days: BTreeMap<String, DaySummary> = BTreeMap::new();
+        let mut stmt = self.conn.prepare(
+            "SELECT date(started), COUNT(*), SUM(running_secs), SUM(rounds), SUM(qte_hits),
+                    SUM(qte_timeouts), SUM(bursts), SUM(bet_one_secs), SUM(bet_five_secs)
+             FROM sessions WHERE started >= ?1 GROUP BY date(started)",
+        )?;
+        let rows = stmt.query_map([&since], |row| {
+            Ok(DaySummary {
+                day: row.get(0)?,
+                sessions: row.get(1)?,
+                running_secs: row.get(2)?,
+                rounds: row.get(3)?,
+                qte_hits: row.get(4)?,
+                qte_timeouts: row.get(5)?,
+                bursts: row.get(6)?,
+                bet_one_secs: row.get(7)?,
+                bet_five_secs: row.get(8)?,
+            })
+        })?;
+        for row in rows {
+            let row = row?;
+            days.insert(row.day.clone(), row);
+        }
+
+        let mut outcomes = BTreeMap::new();
+        let mut stmt = self
+            .conn
+            .prepare("SELECT outcome, COUNT(*) FROM rounds WHERE started >= ?1 GROUP BY outcome")?;
+        for row in stmt.query_map([&since], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, u64>(1)?))
+        })? {
+            let (outcome, count) = row?;
+            outcomes.insert(outcome, count);
+        }
+
+        let (qte_latency_ms, latency_samples) = self.conn.query_row(
+            "SELECT AVG(latency_ms), COUNT(latency_ms) FROM rounds WHERE started >= ?1",
+            [&since],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        Ok(HistorySummary {
+            since,
+            days: days.into_values().collect(),
+            outcomes,
+            qte_latency_ms,
+            latency_samples,
+        })
+    }
+}
+
+/// 一天内开始的会话的合计
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DaySummary {
+    /// YYYY-MM-DD, 合计时为 total
+    pub day: String,
+    pub sessions: u64,
+    pub running_secs: f64,
+    pub rounds: u64,
+    pub qte_hits: u64,
+    pub qte_timeouts: u64,
+    pub bursts: u64,
+    pub bet_one_secs: f64,
+    pub bet_five_secs: f64,
+}
+
+impl DaySummary {
+    fn add(&mut self, other: &DaySummary) {
+        self.sessions += other.sessions;
+        self.running_secs += other.running_secs;
+        self.rounds += other.rounds;
+        self.qte_hits += other.qte_hits;
+        self.qte_timeouts += other.qte_timeouts;
+        self.bursts += other.bursts;
+        self.bet_one_secs += other.bet_one_secs;
+        self.bet_five_secs += other.bet_five_secs;
+    }
+}
+
+/// 一段时间内的汇总
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistorySummary {
+    pub since: String,
+    pub days: Vec<DaySummary>,
+    /// 各结果的局数
+    pub outcomes: BTreeMap<String, u64>,
+    /// QTE 按键平均耗时, 没有命中时为 None
+    pub qte_latency_ms: Option<f64>,
+    pub latency_samples: u64,
+}
+
+impl HistorySummary {
+    pub fn total(&self) -> DaySummary {
+        let mut total = DaySummary {
+            day: "total".to_string(),
+            ..Default::default()
+        };
+        for day in &self.days {
+            total.add(day);
+        }
+        total
+    }
+}
+
+impl fmt::Display for HistorySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "History since {}", self.since)?;
+        writeln!(
+            f,
+            "{:<10} {:>8} {:>10} {:>7} {:>8} {:>12} {:>6} {:>10} {:>10}",
+            "DAY",
+            "SESSIONS",
+            "RUNNING",
+            "ROUNDS",
+            "QTE HITS",
+            "QTE TIMEOUTS",
+            "BURSTS",
+            "BET 1",
+            "BET 5"
+        )?;
+        for day in self.days.iter().chain([&self.total()]) {
+            writeln!(
+                f,
+                "{:<10} {:>8} {:>10} {:>7} {:>8} {:>12} {:>6} {:>10} {:>10}",
+                day.day,
+                day.sessions,
+                hms(day.running_secs),
+                day.rounds,
+                day.qte_hits,
+                day.qte_timeouts,
+                day.bursts,
+                hms(day.bet_one_secs),
+                hms(day.bet_five_secs)
+            )?;
+        }
+        let outcomes = self
+            .outcomes
+            .iter()
+            .map(|(outcome, count)| format!("{} {}", outcome, count))
+            .collect::<Vec<_>>();
+        writeln!(
+            f,
+            "round outcomes: {}",
+            if outcomes.is_empty() {
+                "none".to_string()
+            } else {
+                outcomes.join(", ")
+            }
+        )?;
+        match self.qte_latency_ms {
+            Some(ms) => write!(
+                f,
+                "mean QTE latency: {:.1} ms over {} hits",
+                ms, self.latency_samples
+            ),
+            None => write!(f, "mean QTE latency: no QTE hits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_rounds_events_and_last_days() {
+        let history = History::open(":memory:").unwrap();
+        let mut tracker = RoundTracker::default();
+        let mut snapshot = StatsSnapshot {
+            session: "s1".to_string(),
+            started: Local::now().to_rfc3339(),
+            ..Default::default()
+        };
+        history.save_session(&snapshot, false).unwrap();
+        let latency = Duration::from_millis(25);
+        for event in [
+            Event::WorkerStarted,
+            Event::BetObserved { one: true },
+            Event::RoundStarted,
+            Event::EnergyFour,
+            Event::BetChanged { one: false },
+            Event::TargetSeen,
+            Event::QteHit { latency },
+            Event::RoundStarted,
+            Event::WorkerStopped,
+        ] {
+            history
+                .insert_event(&EventRecord::new(&snapshot.session, event))
+                .unwrap();
+            if let Some(round) = tracker.record(event, &snapshot.session) {
+                history.insert_round(&round).unwrap();
+            }
+        }
+        snapshot.rounds = 2;
+        snapshot.qte_hits = 1;
+        snapshot.running_secs = 60.0;
+        history.save_session(&snapshot, true).unwrap();
+
+        let since = Some(Local::now() - chrono::Duration::days(1));
+        let rounds = history.rounds(since, Some("s1")).unwrap();
+        let summary = |r: &RoundRecord| {
+            (
+                r.bet,
+                r.energy.clone(),
+                r.qte.clone(),
+                r.latency_ms,
+                r.outcome.clone(),
+            )
+        };
+        assert_eq!(
+            rounds.iter().map(summary).collect::<Vec<_>>(),
+            vec![
+                (
+                    Some(1),
+                    Some("four".to_string()),
+                    "hit".to_string(),
+                    Some(25.0),
+                    "target".to_string()
+                ),
+                (
+                    Some(5),
+                    None,
+                    "none".to_string(),
+                    None,
+                    "stopped".to_string()
+                ),
+            ]
+        );
+        assert!(history.rounds(since, Some("other")).unwrap().is_empty());
+        let events = history.events(None, None).unwrap();
+        assert_eq!(events.len(), 9);
+        assert_eq!(
+            (events[4].event.as_str(), events[4].bet),
+            ("bet_changed", Some(5))
+        );
+        assert_eq!(events[6].latency_ms, Some(25.0));
+        let session = history.session(None).unwrap().unwrap();
+        assert_eq!((session.id.as_str(), session.rounds), ("s1", 2));
+        assert!(session.ended.is_some());
+        assert!(history.session(Some("other")).unwrap().is_none());
+
+        // 8 天前的会话不在最近 7 天内
+        let old = (Local::now() - chrono::Duration::days(8)).format(TIME_FORMAT);
+        history
+            .conn
+            .execute(
+                "INSERT INTO sessions (id, started, rounds) VALUES ('old', ?1, 100)",
+                [old.to_string()],
+            )
+            .unwrap();
+        let summary = history.last_days(7).unwrap();
+        let total = summary.total();
+        assert_eq!((total.sessions, total.rounds, total.qte_hits), (1, 2, 1));
+        assert_eq!(total.running_secs, 60.0);
+        assert_eq!(summary.outcomes["target"], 1);
+        assert_eq!(summary.latency_samples, 1);
+        assert!(summary.to_string().contains("mean QTE latency: 25.0 ms"));
+        assert_eq!(history.last_days(30).unwrap().total().sessions, 2);
+    }
+}
@@ -0,0 +1,283 @@
+//!
+//! 后台写图片: 编码 PNG 和写盘都在单独的线程中进行, 检测循环只负责入队
+//!
+//! * 队列有上限, 满了丢弃最旧的任务并计数, 不会阻塞调用方
+//! * drop 时写完队列中剩余的任务再退出
+//!
+use crate::error::{Result, ResultExt};
+use crate::vision::Image;
+use log::{debug, error, warn};
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+
+/// 写入成功后在写入线程中调用, 参数为图片和附带文件的总字节数
+pub type OnWritten = Box<dyn FnOnce(u64) + Send>;
+
+/// 编码前在写入线程中处理图片, 如打分和标注
+pub type Render = Box<dyn FnOnce(&Image) -> Result<Image> + Send>;
+
+/// 一次写入: PNG 图片和可选的附带文本文件 (如 json)
+pub struct WriteJob {
+    pub path: PathBuf,
+    pub image: Image,
+    pub sidecar: Option<(PathBuf, String)>,
+    pub render: Option<Render>,
+    pub on_written: Option<OnWritten>,
+}
+
+impl WriteJob {
+    pub fn png<P: Into<PathBuf>>(path: P, image: &Image) -> Self {
+        Self {
+            path: path.into(),
+            image: image.clone(),
+            sidecar: None,
+            render: None,
+            on_written: None,
+        }
+    }
+
+    pub fn with_sidecar<P: Into<PathBuf>>(mut self, path: P, text: String) -> Self {
+        self.sidecar = Some((path.into(), text));
+        self
+    }
+
+    pub fn render(mut self, f: impl FnOnce(&Image) -> Result<Image> + Send + 'static) -> Self {
+        self.render = Some(Box::new(f));
+        self
+    }
+
+    pub fn on_written(mut self, f: impl FnOnce(u64) + Send + 'static) -> Self {
+        self.on_written = Some(Box::new(f));
+        self
+    }
+
+    fn write(&mut self) -> Result<u64> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).context(dir.display())?;
+        }
+        if let Some(render) = self.render.take() {
+            self.image = render(&self.image).context(self.path.display())?;
+        }
+        self.image
+            .save_png(&self.path)
+            .context(self.path.display())?;
+        let mut size = file_size(&self.path);
+        if let Some((path, text)) = &self.sidecar {
+            std::fs::write(path, text).context(path.display())?;
+            size += file_size(path);
+        }
+        Ok(size)
+    }
+}
+
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path).map_or(0, |m| m.len())
+}
+
+/// 写入计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WriterStats {
+    pub written: u64,
+    /// 队列满时被丢弃的任务
+    pub dropped: u64,
+    pub failed: u64,
+}
+
+impl fmt::Display for WriterStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "written: {}, dropped: {}, failed: {}",
+            self.written, self.dropped, self.failed
+        )
+    }
+}
+
+#[derive(Default)]
+struct Queue {
+    jobs: VecDeque<WriteJob>,
+    /// 写入线程正在处理一个已出队的任务
+    busy: bool,
+    closed: bool,
+    stats: WriterStats,
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    /// 有新任务或关闭时通知写入线程
+    work: Condvar,
+    /// 队列清空时通知 flush
+    idle: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+pub struct ImageWriter {
+    capacity: usize,
+    shared: Arc<Shared>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ImageWriter {
+    /// 启动写入线程, 队列最多保留 capacity 个任务
+    pub fn spawn(capacity: usize) -> Self {
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue::default()),
+            work: Condvar::new(),
+            idle: Condvar::new(),
+        });
+        let worker = shared.clone();
+        let handle = std::thread::Builder::new()
+            .name("image-writer".to_string())
+            .spawn(move || Self::run(&worker))
+            .map_err(|e| error!("Failed to spawn image writer: {}", e))
+            .ok();
+        Self {
+            capacity: capacity.max(1),
+            shared,
+            handle,
+        }
+    }
+
+    fn run(shared: &Shared) {
+        loop {
+            let mut job = {
+                let mut queue = shared.lock();
+                loop {
+                    if let Some(job) = queue.jobs.pop_front() {
+                        queue.busy = true;
+                        break job;
+                    }
+                    queue.busy = false;
+                    shared.idle.notify_all();
+                    if queue.closed {
+                        return;
+                    }
+                    queue = shared.work.wait(queue).unwrap_or_else(|e| e.into_inner());
+                }
+            };
+            let result = job.write();
+            match &result {
+                Ok(_) => debug!("Saved {}", job.path.display()),
+                Err(e) => error!("Failed to save image: {}", e),
+            }
+            {
+                let mut queue = shared.lock();
+                match result {
+                    Ok(_) => queue.stats.written += 1,
+                    Err(_) => queue.stats.failed += 1,
+                }
+            }
+            if let (Ok(size), Some(on_written)) = (result, job.on_written) {
+                on_written(size);
+            }
+        }
+    }
+
+    /// 入队后立即返回, 队列已满时丢弃最旧的任务
+    pub fn submit(&self, job: WriteJob) {
+        let mut queue = self.shared.lock();
+        if self.handle.is_none() {
+            queue.stats.dropped += 1;
+            return;
+        }
+        if queue.jobs.len() >= self.capacity
+            && let Some(oldest) = queue.jobs.pop_front()
+        {
+            queue.stats.dropped += 1;
+            warn!(
+                "Image writer queue is full, dropped {}",
+                oldest.path.display()
+            );
+        }
+        queue.jobs.push_back(job);
+        self.shared.work.notify_one();
+    }
+
+    /// 等待已入队的任务全部写完
+    pub fn flush(&self) {
+        if self.handle.is_none() {
+            return;
+        }
+        let mut queue = self.shared.lock();
+        while !queue.jobs.is_empty() || queue.busy {
+            queue = self
+                .shared
+                .idle
+                .wait(queue)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    pub fn stats(&self) -> WriterStats {
+        self.shared.lock().stats
+    }
+}
+
+impl Drop for ImageWriter {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.work.notify_all();
+        if let Some(handle) = self.handle.take()
+            && handle.join().is_err()
+        {
+            error!("Image writer thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_queue_drops_oldest_and_flushes() {
+        let dir = std::env::temp_dir().join(format!("rappy_writer_{}", std::process::id()));
+        let img = Image::from_vec(4, 4, 3, vec![128; 48]).unwrap();
+        let writer = ImageWriter::spawn(2);
+
+        // 第一个任务写完后卡在回调里, 模拟磁盘变慢
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        writer.submit(
+            WriteJob::png(dir.join("0.png"), &img)
+                .with_sidecar(dir.join("0.json"), "{}".to_string())
+                .on_written(move |size| {
+                    let _ = entered_tx.send(size);
+                    let _ = release_rx.recv();
+                }),
+        );
+        assert!(entered_rx.recv().unwrap() > 0);
+        for i in 1..=3 {
+            writer.submit(WriteJob::png(dir.join(format!("{}.png", i)), &img));
+        }
+        release_tx.send(()).unwrap();
+        writer.flush();
+
+        assert!(dir.join("0.json").is_file());
+        assert!(!dir.join("1.png").exists());
+        assert!(dir.join("2.png").is_file() && dir.join("3.png").is_file());
+        assert_eq!(
+            writer.stats(),
+            WriterStats {
+                written: 3,
+                dropped: 1,
+                failed: 0
+            }
+        );
+
+        // drop 时写完剩余的任务
+        writer.submit(WriteJob::png(dir.join("4.png"), &img));
+        drop(writer);
+        assert!(dir.join("4.png").is_file());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,47 +1,60 @@
+use crate::cancel::CancellationToken;
+use crate::error::{RappyError, Result};
 use rand::random_range;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
 use windows::Win32::UI::Input::KeyboardAndMouse::{VK_DOWN, VK_RETURN, VK_UP};
-use windows::Win32::UI::WindowsAndMessaging::{SendMessageW, WM_KEYDOWN};
+use windows::Win32::UI::WindowsAndMessaging::{IsWindow, SendMessageW, WM_KEYDOWN};
 
 pub struct WindowsKeyboard {
     hwnd: HWND,
+    /// 任务取消后不再发送按键, 按键间隔也提前结束
+    token: CancellationToken,
 }
 
-static IS_RUNNING: AtomicBool = AtomicBool::new(false);
-
 impl WindowsKeyboard {
-    pub fn new(hwnd: HWND) -> Self {
-        Self { hwnd }
+    pub fn new(hwnd: HWND, token: CancellationToken) -> Self {
+        Self { hwnd, token }
+    }
+
+    /// 窗口已关闭时 SendMessageW 不会报错, 发送前先检查句柄
+    fn send(&self, msg: u32, vk: usize) -> Result<Instant> {
+        if !unsafe { IsWindow(Some(self.hwnd)) }.as_bool() {
+            return Err(RappyError::Input(format!(
+                "window {:?} is gone, key {:#x} not sent",
+                self.hwnd, vk
+            )));
+        }
+        unsafe { SendMessageW(self.hwnd, msg, Some(WPARAM(vk)), Some(LPARAM(0isize))) };
+        Ok(Instant::now())
     }
 
-    fn repeat_send(&self, times: u16, msg: u32, vk: usize) {
+    fn repeat_send(&self, times: u16, msg: u32, vk: usize) -> Result<()> {
         for _ in 0..times {
-            unsafe { SendMessageW(self.hwnd, msg, Some(WPARAM(vk)), Some(LPARAM(0isize))) };
-            sleep(Duration::from_millis(random_range(50..=100)));
+            if self.token.is_cancelled() {
+                break;
+            }
+            self.send(msg, vk)?;
+            self.token
+                .sleep(Duration::from_millis(random_range(50..=100)));
         }
+        Ok(())
     }
 
-    pub fn increase_rappy_coin(&self, num: u16) {
-        self.repeat_send(num, WM_KEYDOWN, VK_UP.0 as usize);
+    pub fn increase_rappy_coin(&self, num: u16) -> Result<()> {
+        self.repeat_send(num, WM_KEYDOWN, VK_UP.0 as usize)
     }
 
-    pub fn decrease_rappy_coin(&self, num: u16) {
-        self.repeat_send(num, WM_KEYDOWN, VK_DOWN.0 as usize);
+    pub fn decrease_rappy_coin(&self, num: u16) -> Result<()> {
+        self.repeat_send(num, WM_KEYDOWN, VK_DOWN.0 as usize)
     }
 
-    pub fn play_rappy(&self) {
-        unsafe {
-            SendMessageW(
-                self.hwnd,
-                WM_KEYDOWN,
-                Some(WPARAM(VK_RETURN.0 as usize)),
-                Some(LPARAM(0isize)),
-            );
-        }
-        sleep(Duration::from_millis(random_range(50..=100)));
+    /// 返回按键消息送达 (SendMessageW 返回) 的时间, 不含之后的随机间隔
+    pub fn play_rappy(&self) -> Result<Instant> {
+        let sent = self.send(WM_KEYDOWN, VK_RETURN.0 as usize)?;
+        self.token
+            .sleep(Duration::from_millis(random_range(50..=100)));
+        Ok(sent)
     }
 
 /*    pub fn is_scroll_lock_on() -> bool {
@@ -49,18 +62,6 @@ impl WindowsKeyboard {
         const VK_SCROLL: i16 = 0x91;
         unsafe { (GetKeyState(VK_SCROLL as i32) & 0x0001) != 0 }
     }*/
-
-    pub fn state() -> bool {
-        IS_RUNNING.load(Ordering::Relaxed)
-    }
-
-    pub fn stop_app() {
-        IS_RUNNING.store(false, Ordering::Relaxed);
-    }
-
-    pub fn start_app() {
-        IS_RUNNING.store(true, Ordering::Relaxed);
-    }
 }
 
 #[cfg(test)]
@@ -71,10 +72,10 @@ mod tests {
     #[test]
     fn test_windows_keyboard() {
         if let Some(hwnd) = find_window_by_title("PHANTASY STAR ONLINE 2 NEW GENESIS") {
-            let keyboard = WindowsKeyboard::new(hwnd);
-            keyboard.increase_rappy_coin(5);
-            keyboard.decrease_rappy_coin(4);
-            keyboard.play_rappy();
+            let keyboard = WindowsKeyboard::new(hwnd, CancellationToken::new());
+            keyboard.increase_rappy_coin(5).unwrap();
+            keyboard.decrease_rappy_coin(4).unwrap();
+            keyboard.play_rappy().unwrap();
         }
     }
 }
@@ -0,0 +1,246 @@
+use serde::{Serialize, Serializer};
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// 一次截图检测的时间点
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameStamps {
+    pub grab_start: Instant,
+    /// 截图返回, 画面内容介于 grab_start 和此刻之间
+    pub frame_acquired: Instant,
+    pub detected: Instant,
+}
+
+impl FrameStamps {
+    /// 截图前调用, 之后依次调用 acquired / detected
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Self {
+            grab_start: now,
+            frame_acquired: now,
+            detected: now,
+        }
+    }
+
+    pub fn acquired(&mut self) {
+        self.frame_acquired = Instant::now();
+    }
+
+    pub fn detected(&mut self) {
+        self.detected = Instant::now();
+    }
+}
+
+/// 一次 QTE 从截图到按键发出的耗时
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QteTiming {
+    /// 最后一次没检测到 QTE 的截图返回时间, QTE 在此之后才出现; 首帧即命中时为 None
+    pub last_miss: Option<Instant>,
+    pub frame: FrameStamps,
+    pub key_sent: Instant,
+}
+
+impl QteTiming {
+    /// 截图耗时
+    pub fn capture(&self) -> Duration {
+        self.frame.frame_acquired - self.frame.grab_start
+    }
+
+    /// 预处理和匹配耗时
+    pub fn detection(&self) -> Duration {
+        self.frame.detected - self.frame.frame_acquired
+    }
+
+    /// 检测完成到按键消息发出
+    pub fn input(&self) -> Duration {
+        self.key_sent - self.frame.detected
+    }
+
+    /// 命中帧开始截图到按键发出
+    pub fn total(&self) -> Duration {
+        self.key_sent - self.frame.grab_start
+    }
+
+    /// 最坏情况: QTE 在上一次未命中的截图之后立即出现, 包含轮询间隔
+    pub fn worst_case(&self) -> Option<Duration> {
+        self.last_miss.map(|miss| self.key_sent - miss)
+    }
+}
+
+impl fmt::Display for QteTiming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "capture: {:?}, detection: {:?}, input: {:?}, total: {:?}, worst case: {:?}",
+            self.capture(),
+            self.detection(),
+            self.input(),
+            self.total(),
+            self.worst_case()
+        )
+    }
+}
+
+/// 一组耗时的分位数, 序列化时单位为毫秒
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Percentiles {
+    pub count: usize,
+    #[serde(serialize_with = "as_ms")]
+    pub p50: Duration,
+    #[serde(serialize_with = "as_ms")]
+    pub p90: Duration,
+    #[serde(serialize_with = "as_ms")]
+    pub p99: Duration,
+    #[serde(serialize_with = "as_ms")]
+    pub max: Duration,
+}
+
+fn as_ms<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+impl Percentiles {
+    /// 最近秩法 (nearest-rank), 样本为空时返回 None
+    pub fn of(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let rank = |p: f64| {
+            let index = (p / 100.0 * samples.len() as f64).ceil() as usize;
+            samples[index.clamp(1, samples.len()) - 1]
+        };
+        Some(Self {
+            count: samples.len(),
+            p50: rank(50.0),
+            p90: rank(90.0),
+            p99: rank(99.0),
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+impl fmt::Display for Percentiles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "n: {}, p50: {:?}, p90: {:?}, p99: {:?}, max: {:?}",
+            self.count, self.p50, self.p90, self.p99, self.max
+        )
+    }
+}
+
+/// 从一次 QTE 中取出某个阶段的耗时
+type Stage = fn(&QteTiming) -> Option<Duration>;
+
+/// 分位数只统计最近这么多次 QTE, 长时间运行时内存和排序开销不再增长
+const SAMPLE_LIMIT: usize = 1000;
+
+/// 一次会话中最近 [`SAMPLE_LIMIT`] 次 QTE 的耗时
+#[derive(Debug, Clone, Default)]
+pub struct LatencyStats {
+    samples: VecDeque<QteTiming>,
+}
+
+impl LatencyStats {
+    pub fn push(&mut self, timing: QteTiming) {
+        if self.samples.len() >= SAMPLE_LIMIT {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(timing);
+    }
+
+    /// 各阶段耗时的分位数, 用于区分慢在截图, 匹配还是按键
+    pub fn report(&self) -> Vec<(&'static str, Percentiles)> {
+        let stages: [(&'static str, Stage); 5] = [
+            ("capture", |t| Some(t.capture())),
+            ("detection", |t| Some(t.detection())),
+            ("input", |t| Some(t.input())),
+            ("total", |t| Some(t.total())),
+            ("worst case", QteTiming::worst_case),
+        ];
+        stages
+            .into_iter()
+            .filter_map(|(name, stage)| {
+                Percentiles::of(self.samples.iter().filter_map(stage).collect()).map(|p| (name, p))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(v: u64) -> Duration {
+        Duration::from_millis(v)
+    }
+
+    fn timing(
+        last_miss: Option<u64>,
+        grab: u64,
+        acquired: u64,
+        detected: u64,
+        sent: u64,
+    ) -> QteTiming {
+        let base = Instant::now();
+        QteTiming {
+            last_miss: last_miss.map(|v| base + ms(v)),
+            frame: FrameStamps {
+                grab_start: base + ms(grab),
+                frame_acquired: base + ms(acquired),
+                detected: base + ms(detected),
+            },
+            key_sent: base + ms(sent),
+        }
+    }
+
+    #[test]
+    fn test_timing_stages() {
+        let t = timing(Some(0), 30, 38, 50, 51);
+        assert_eq!(t.capture(), ms(8));
+        assert_eq!(t.detection(), ms(12));
+        assert_eq!(t.input(), ms(1));
+        assert_eq!(t.total(), ms(21));
+        assert_eq!(t.worst_case(), Some(ms(51)));
+        assert_eq!(timing(None, 0, 1, 2, 3).worst_case(), None);
+    }
+
+    #[test]
+    fn test_percentiles_nearest_rank() {
+        let p = Percentiles::of((1..=100).map(ms).collect()).unwrap();
+        assert_eq!(
+            (p.p50, p.p90, p.p99, p.max),
+            (ms(50), ms(90), ms(99), ms(100))
+        );
+        let p = Percentiles::of(vec![ms(7)]).unwrap();
+        assert_eq!((p.p50, p.p99), (ms(7), ms(7)));
+        assert!(Percentiles::of(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_report_skips_missing_worst_case() {
+        let mut stats = LatencyStats::default();
+        stats.push(timing(None, 0, 10, 20, 21));
+        let report = stats.report();
+        assert_eq!(report.len(), 4);
+        assert_eq!(report[3], ("total", Percentiles::of(vec![ms(21)]).unwrap()));
+        stats.push(timing(Some(0), 30, 40, 50, 51));
+        let report = stats.report();
+        assert_eq!(report.len(), 5);
+        assert_eq!(report[4].1.count, 1);
+    }
+
+    #[test]
+    fn test_keeps_latest_samples() {
+        let mut stats = LatencyStats::default();
+        for i in 0..SAMPLE_LIMIT as u64 + 10 {
+            stats.push(timing(None, 0, 0, 0, i));
+        }
+        let total = stats.report()[3].1;
+        assert_eq!(total.count, SAMPLE_LIMIT);
+        assert_eq!(total.max, ms(SAMPLE_LIMIT as u64 + 9));
+        assert_eq!(stats.samples.front().unwrap().total(), ms(10));
+    }
+}
@@ -1,24 +1,58 @@
 #![cfg_attr(windows, windows_subsystem = "windows")]
 
-use crate::auto_rappy::{check_or_create_dir, QTE_DIR, TARGET_DIR};
-use crate::keyboard_utils::WindowsKeyboard;
+use crate::export::{EXPORT_DIR, ExportFormat};
+use crate::history::{HISTORY_DB, History};
+use crate::config::{GUI_CONFIG, RunConfig};
 use crate::logging::init_logger;
+use crate::stats::SessionStats;
+use crate::supervisor::{RestartPolicy, Supervisor, WorkerStatus};
 use eframe::egui;
+use std::sync::Arc;
 use std::sync::mpsc::{Receiver, Sender};
-use std::thread;
-use windows::core::Error;
+use std::time::Duration;
 
+mod analysis;
 mod auto_rappy;
+mod cancel;
 mod capture_settings;
+mod cli;
+mod config;
+mod dataset;
+mod detector;
 mod dxgi_capture;
+mod error;
+mod evaluate;
+mod export;
+mod history;
+mod image_writer;
 mod keyboard_utils;
+mod latency;
 mod logging;
 mod rappy_checker;
+mod report;
+mod robustness;
+mod scheduler;
+mod snapshot;
+mod stats;
+mod supervisor;
 mod template_img;
+mod vision;
 mod windows_utils;
 
+/// 勾选自动重启时, 截图初始化失败等可恢复错误的重启策略 (也是 rappy.toml 的默认值)
+static AUTO_RESTART: RestartPolicy = RestartPolicy {
+    max_restarts: 5,
+    initial_backoff: Duration::from_secs(2),
+    max_backoff: Duration::from_secs(30),
+};
+
 pub struct RappyApp {
-    is_running: bool,
+    config: RunConfig,
+    // 工作线程结束并 join 之后才允许再次开始
+    supervisor: Supervisor,
+    auto_restart: bool,
+    /// 本次会话的统计, 每次开始时清零
+    stats: Arc<SessionStats>,
     logs: String,
     // 用于接收从工作线程传回的日志
     rx: Receiver<String>,
@@ -29,15 +63,45 @@ pub struct RappyApp {
 impl RappyApp {
     pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
         let (tx, rx) = std::sync::mpsc::channel();
+        let mut logs = String::from("Program Ready...\n");
+        // 与命令行共用 rappy.toml 中的检测设置, 读取失败时使用默认值并显示原因
+        let config = RunConfig::load_or_default(GUI_CONFIG).unwrap_or_else(|e| {
+            log::error!("Failed to load config, using defaults: {}", e);
+            logs.push_str(&format!("Failed to load config, using defaults: {}\n", e));
+            RunConfig::default()
+        });
         Self {
-            is_running: false,
-            logs: String::from("Program Ready...\n"),
+            config,
+            supervisor: Supervisor::new(),
+            auto_restart: false,
+            stats: Arc::new(SessionStats::new().with_history(History::open_or_log(HISTORY_DB))),
+            logs,
             rx,
             tx,
         }
     }
 }
 
+impl RappyApp {
+    /// 把本次会话的局和事件导出到 exports/<session>/, 结果显示在日志中
+    fn export_session(&self) {
+        let session = self.stats.snapshot().session;
+        let dir = std::path::Path::new(EXPORT_DIR).join(&session);
+        let (rounds, events) = (self.stats.rounds(), self.stats.events());
+        let msg = match export::export(&dir, &rounds, &events, &ExportFormat::ALL) {
+            Ok(_) => format!(
+                "Exported {} rounds and {} events to {}",
+                rounds.len(),
+                events.len(),
+                dir.display()
+            ),
+            Err(e) => format!("Failed to export session: {}", e),
+        };
+        log::info!("{}", msg);
+        let _ = self.tx.send(msg);
+    }
+}
+
 impl eframe::App for RappyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         ctx.request_repaint();
@@ -48,16 +112,20 @@ impl eframe::App for RappyApp {
             let line_count = self.logs.lines().count();
 
             // 2. 如果已經達到或超過 20 條，移除第一行
-            if line_count >= 20 {
-                if let Some(first_newline_pos) = self.logs.find('\n') {
-                    // 移除從開頭到第一個換行符號（含）的內容
-                    self.logs.replace_range(..first_newline_pos + 1, "");
-                }
+            if line_count >= 20
+                && let Some(first_newline_pos) = self.logs.find('\n')
+            {
+                // 移除從開頭到第一個換行符號（含）的內容
+                self.logs.replace_range(..first_newline_pos + 1, "");
             }
             self.logs
-                .push_str(&format!("{}: {}\n", date_time.to_string(), msg));
+                .push_str(&format!("{}: {}\n", date_time, msg));
         }
 
+        // 工作线程自己结束 (找不到窗口/已停止/崩溃) 后回收句柄
+        self.supervisor.reap();
+        let status = self.supervisor.status();
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ctx.style_mut(|s| s.interaction.tooltip_delay = 0.1);
             let label_text = egui::RichText::new("❓ Usage Instructions").underline();
@@ -97,10 +165,13 @@ impl eframe::App for RappyApp {
 
             ui.separator();
             // 2. 开始/停止 按钮
-            let (button_label, button_color) = if self.is_running {
-                ("⏸ Stop Task", egui::Color32::from_rgb(200, 60, 60)) // 红色
-            } else {
+            let stopping = status == WorkerStatus::Stopping;
+            let (button_label, button_color) = if !self.supervisor.is_active() {
                 ("▶ Start Task", egui::Color32::from_rgb(60, 160, 60)) // 绿色
+            } else if stopping {
+                ("⏳ Stopping...", egui::Color32::GRAY)
+            } else {
+                ("⏸ Stop Task", egui::Color32::from_rgb(200, 60, 60)) // 红色
             };
             // 2. 使用 ui.add 构建自定义按钮
             let button_design = egui::Button::new(
@@ -113,32 +184,63 @@ impl eframe::App for RappyApp {
             .corner_radius(10)
             .min_size(egui::vec2(240.0, 70.0));
 
-            if ui.add(button_design).clicked() {
-                self.is_running = !self.is_running;
-
-                if self.is_running {
-                    WindowsKeyboard::start_app();
+            // 停止中的任务结束前不能再次开始, 避免同时存在两个工作线程
+            if ui.add_enabled(!stopping, button_design).clicked() {
+                if self.supervisor.is_active() {
+                    self.supervisor.stop();
+                } else {
+                    let policy = if self.auto_restart {
+                        AUTO_RESTART
+                    } else {
+                        RestartPolicy::NEVER
+                    };
                     let tx = self.tx.clone();
                     let ctx_clone = ctx.clone(); // 用于在子线程触发 UI 刷新
-                    // 模拟后台任务
-                    thread::spawn(move || {
-                        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                            let _ = auto_rappy::auto_rappy(&ctx_clone, &tx);
-                        })) {
-                            Ok(_) => {
-                                log::info!("Auto rappy task completed normally");
+                    let config = self.config.clone();
+                    self.stats.reset();
+                    let stats = self.stats.clone();
+                    self.supervisor.start(policy, move |worker| {
+                        let result = auto_rappy::auto_rappy(&config, &tx, worker, &stats);
+                        ctx_clone.request_repaint();
+                        match result {
+                            Ok(msg) => {
+                                log::info!("Auto rappy task completed: {}", msg);
+                                Ok(())
                             }
-                            Err(_e) => {
-                                log::error!("Auto rappy task panicked");
-                                let _ = tx.send("Task error: program encountered an unexpected issue".to_string());
+                            Err(e) => {
+                                let _ = tx.send(format!("Task error: {}", e));
+                                Err(e.into())
                             }
                         }
-                        WindowsKeyboard::stop_app();
                     });
-                } else {
-                    WindowsKeyboard::stop_app();
                 }
             }
+            ui.horizontal(|ui| {
+                ui.label(format!("Status: {}", status));
+                if self.supervisor.restarts() > 0 {
+                    ui.label(format!("(restarts: {})", self.supervisor.restarts()));
+                }
+                ui.checkbox(&mut self.auto_restart, "Auto restart");
+                ui.checkbox(&mut self.config.snapshots, "Save snapshots")
+                    .on_hover_text("Save an annotated frame to snapshots/ on every bet change, Enter press and window refresh");
+            });
+            if let Some(last_error) = self.supervisor.last_error() {
+                ui.colored_label(
+                    egui::Color32::from_rgb(200, 60, 60),
+                    format!("Last error: {}", last_error),
+                );
+            }
+
+            egui::CollapsingHeader::new("Session stats").show(ui, |ui| {
+                ui.monospace(self.stats.snapshot().to_string());
+                if ui
+                    .button("Export session")
+                    .on_hover_text("Write this session's rounds and events as CSV and JSON Lines to exports/<session>/")
+                    .clicked()
+                {
+                    self.export_session();
+                }
+            });
 
             ui.separator();
 
@@ -174,7 +276,7 @@ fn load_icon(bytes: &[u8]) -> egui::IconData {
     }
 }
 
-fn main() -> Result<(), Error> {
+fn main() -> error::Result<()> {
     // 设置全局恐慌处理器
     std::panic::set_hook(Box::new(|panic_info| {
         let msg = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
@@ -192,14 +294,20 @@ fn main() -> Result<(), Error> {
         log::error!("Program panic: {} at {}", msg, location);
     }));
 
+    // 带参数启动时进入命令行模式, 不打开窗口, 日志由各个命令按配置初始化
+    // dataset 等输出目录在写入时 (如 Dataset::open) 才创建
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        windows_utils::attach_parent_console();
+        std::process::exit(cli::main(args));
+    }
+
     let _logger = init_logger("info");
     let icon_bytes = include_bytes!("../resources/ico/rappy.ico");
     let icon = load_icon(icon_bytes);
-    check_or_create_dir(TARGET_DIR);
-    check_or_create_dir(QTE_DIR);
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([400.0, 320.0])
+            .with_inner_size([400.0, 360.0])
             .with_icon(icon),
         ..Default::default()
     };
@@ -1,186 +1,84 @@
-use opencv::core::AlgorithmHint::ALGO_HINT_DEFAULT;
-use opencv::core::{
-    add_weighted, divide2, mean, multiply, no_array, subtract, Mat,
-    MatTraitConst, Point, Size, BORDER_DEFAULT, CV_32F,
-};
-use opencv::imgproc;
-use opencv::imgproc::cvt_color;
-use std::cell::RefCell;
-use std::ops::Deref;
+use crate::error::{RappyError, Result, ResultExt};
+use crate::vision::{Backend, Image, ImageOps, ThresholdMode};
 use log::{debug, info};
 
-pub fn get_threshold_mat(mat: &Mat, thresh: u8) -> Mat {
-    let gray;
-    let mut gray_mat;
-    let channels = mat.channels();
-    if channels==3||channels==4 {
-        let cvt_code;
-        if channels == 4 {
-            cvt_code = imgproc::COLOR_BGRA2GRAY;
-        } else {
-            cvt_code = imgproc::COLOR_BGR2GRAY;
+/// 按 mode 灰度化/二值化, 具体实现由当前图像后端 (vision::Backend) 提供
+pub fn get_threshold_mat(mat: &Image, mode: ThresholdMode) -> Result<Image> {
+    let processed = match mode {
+        ThresholdMode::None => Backend::to_gray(mat),
+        ThresholdMode::Fixed(thresh) => Backend::threshold(mat, thresh, false),
+        ThresholdMode::Otsu => Backend::threshold(mat, 0, true),
+        ThresholdMode::AdaptiveMean { block_size, c } => {
+            Backend::adaptive_threshold(mat, block_size, c, false)
         }
-        gray_mat = Mat::default();
-        let _ = cvt_color(
-            mat,
-            &mut gray_mat,
-            cvt_code,
-            0,
-            ALGO_HINT_DEFAULT,
-        );
-        gray = &gray_mat;
-    }else{
-        gray = mat;
-    }
-    let mut thresh_mat = Mat::default();
-    let _ = imgproc::threshold(
-        &gray,
-        &mut thresh_mat,
-        thresh as f64,
-        255.0,
-        imgproc::THRESH_OTSU,
-    );
-    thresh_mat
+        ThresholdMode::AdaptiveGaussian { block_size, c } => {
+            Backend::adaptive_threshold(mat, block_size, c, true)
+        }
+    };
+    processed.with_context(|| format!("preprocess {} of {:?}", mode, mat))
 }
 
-/// 计算两个 Mat 之间的 SSIM
-/// 返回 Scalar channel 1或者channel 1 2 3的均值，因为 SSIM 是分通道计算的（BGR 每个通道一个值）
-/// 仿C++版本, unsafe部分是为了绕过&var和&mut var无法同时使用的问题
-///
-/// ## Overloaded Parameters
+/// 计算两张图片之间的 SSIM
+/// 多通道图片分通道计算后取均值
 ///
 /// ## Parameters
 ///
-/// * img1: opencv mat.
-/// * img2: opencv mat.
-/// * is_gray: mat.channels() == 1.
-pub fn get_ssim(img1: &Mat, img2: &Mat, is_single_channel: bool) -> opencv::Result<f64> {
-    if cfg!(debug_assertions){
+/// * img1: 游戏截图.
+/// * img2: 模板图片, 尺寸和通道数需要与 img1 一致.
+pub fn get_ssim(img1: &Image, img2: &Image) -> Result<f64> {
+    if cfg!(debug_assertions) {
         info!(
-                    "images channel: {} vs {}, rows: {} vs {}, cols: {} vs {}",
-                    img1.channels(),img2.channels(),
-                    img1.rows(),img2.rows(),
-                    img1.cols(),img2.cols()
-                );
+            "images channel: {} vs {}, rows: {} vs {}, cols: {} vs {}",
+            img1.channels(),
+            img2.channels(),
+            img1.height(),
+            img2.height(),
+            img1.width(),
+            img2.width()
+        );
     }
-    let c1 = 6.5025; // (0.01 * 255)^2
-    let c2 = 58.5225; // (0.03 * 255)^2
-
-    let mut i1 = Mat::default();
-    let mut i2 = Mat::default();
-    // 转换为 32 位浮点数以保证计算精度
-    img1.convert_to(&mut i1, CV_32F, 1.0, 0.0)?;
-    img2.convert_to(&mut i2, CV_32F, 1.0, 0.0)?;
-
-    let mut i1_2 = Mat::default();
-    let mut i2_2 = Mat::default();
-    let mut i1_i2 = Mat::default();
-
-    multiply(&i1, &i1, &mut i1_2, 1.0, -1)?; // i1^2
-    multiply(&i2, &i2, &mut i2_2, 1.0, -1)?; // i2^2
-    multiply(&i1, &i2, &mut i1_i2, 1.0, -1)?; // i1 * i2
-
-    // 均值计算 (μ)
-    let mut mu1 = Mat::default();
-    let mut mu2 = Mat::default();
-    let ksize = Size::new(7, 7);
-    let anchor = Point { x: -1, y: -1 };
-    imgproc::blur(&i1, &mut mu1, ksize, anchor, BORDER_DEFAULT)?;
-    imgproc::blur(&i2, &mut mu2, ksize, anchor, BORDER_DEFAULT)?;
-
-    let mut mu1_2 = Mat::default();
-    let mut mu2_2 = Mat::default();
-    let mut mu1_mu2 = Mat::default();
-    multiply(&mu1, &mu1, &mut mu1_2, 1.0, -1)?; // μ1^2
-    multiply(&mu2, &mu2, &mut mu2_2, 1.0, -1)?; // μ2^2
-    multiply(&mu1, &mu2, &mut mu1_mu2, 1.0, -1)?; // μ1 * μ2
-
-    // 方差与协方差计算 (σ)
-    let sigma1_2 = RefCell::new(Mat::default());
-    let sigma2_2 = RefCell::new(Mat::default());
-    let sigma12 = RefCell::new(Mat::default());
-    let mut_sigma1_2 = unsafe { &mut *sigma1_2.as_ptr() };
-    let mut_sigma2_2 = unsafe { &mut *sigma2_2.as_ptr() };
-    let mut_sigma12 = unsafe { &mut *sigma12.as_ptr() };
-    imgproc::blur(&i1_2, mut_sigma1_2, ksize, anchor, BORDER_DEFAULT)?;
-    subtract(
-        sigma1_2.borrow().deref(),
-        &mu1_2,
-        mut_sigma1_2,
-        &no_array(),
-        -1,
-    )?; // σ1^2 = E[i1^2] - μ1^2
-
-    imgproc::blur(&i2_2, mut_sigma2_2, ksize, anchor, BORDER_DEFAULT)?;
-    subtract(
-        sigma2_2.borrow().deref(),
-        &mu2_2,
-        mut_sigma2_2,
-        &no_array(),
-        -1,
-    )?; // σ2^2 = E[i2^2] - μ2^2
-
-    imgproc::blur(&i1_i2, mut_sigma12, ksize, anchor, BORDER_DEFAULT)?;
-    subtract(
-        &sigma12.borrow().deref(),
-        &mu1_mu2,
-        mut_sigma12,
-        &no_array(),
-        -1,
-    )?; // σ12 = E[i1*i2] - μ1*μ2
-
-    // 公式: SSIM = ((2*mu1*mu2 + C1) * (2*sigma12 + C2)) / ((mu1^2 + mu2^2 + C1) * (sigma1^2 + sigma2^2 + C2))
-    let t1 = RefCell::new(Mat::default()); // 2*mu1*mu2 + C1
-    let t2 = RefCell::new(Mat::default()); // 2*sigma12 + C2
-    let t3 = RefCell::new(Mat::default()); // t1 * t2
-
-    let mut_t1 = unsafe { &mut *t1.as_ptr() };
-    let mut_t2 = unsafe { &mut *t2.as_ptr() };
-    let mut_t3 = unsafe { &mut *t3.as_ptr() };
-
-    // t1 = 2 * mu1_mu2 + C1;
-    let _ = &mu1_mu2.convert_to(mut_t1, CV_32F, 2.0, c1)?;
-    // t2 = 2 * sigma12 + C2;
-    sigma12.borrow().convert_to(mut_t2, CV_32F, 2.0, c2)?;
-    // t3 = t1*t2
-    multiply(t1.borrow().deref(), t2.borrow().deref(), mut_t3, 1.0, -1)?;
-    // t1 = mu1_2 + mu2_2 + C1;
-    add_weighted(&mu1_2, 1.0, &mu2_2, 1.0, c1, mut_t1, -1)?;
-    // t2 = sigma1_2 + sigma2_2 + C2;
-    add_weighted(
-        sigma1_2.borrow().deref(),
-        1.0,
-        sigma2_2.borrow().deref(),
-        1.0,
-        c2,
-        mut_t2,
-        -1,
-    )?;
-
-    multiply(t1.borrow().deref(), t2.borrow().deref(), mut_t1, 1.0, -1)?;
+    let sim =
+        Backend::ssim(img1, img2).with_context(|| format!("SSIM of {:?} vs {:?}", img1, img2))?;
+    if cfg!(debug_assertions) {
+        debug!("Sim ({}): {}", Backend::NAME, sim);
+    }
+    Ok(sim)
+}
 
-    let mut ssim_map = Mat::default();
-    // ssim_map =  t3./t1
-    divide2(
-        t3.borrow().deref(),
-        t1.borrow().deref(),
-        &mut ssim_map,
-        1.0,
-        -1,
-    )?;
+/// 两张同尺寸图片逐像素差的绝对值均值, 用于判断画面是否变化
+pub fn get_mean_abs_diff(img1: &Image, img2: &Image) -> Result<f64> {
+    if img1.width() != img2.width()
+        || img1.height() != img2.height()
+        || img1.channels() != img2.channels()
+    {
+        return Err(RappyError::Vision(format!(
+            "Image shape mismatch: {:?} vs {:?}",
+            img1, img2
+        )));
+    }
+    let mut sum = 0u64;
+    for y in 0..img1.height() {
+        sum += img1
+            .row(y)
+            .iter()
+            .zip(img2.row(y))
+            .map(|(a, b)| a.abs_diff(*b) as u64)
+            .sum::<u64>();
+    }
+    Ok(sum as f64 / (img1.width() * img1.height() * img1.channels()) as f64)
+}
 
-    // 对全图取平均值
-    // mssim = average of ssim map
-    let mssim = mean(&ssim_map, &no_array())?;
-    if is_single_channel {
-        if cfg!(debug_assertions) {
-           debug!("Sim: {:?}", mssim);
-        };
-        Ok(mssim[0])
-    } else {
-        if cfg!(debug_assertions){
-            debug!("Sim: {:?}", mssim.0);
-        };
-        Ok(mssim[0]+mssim[1]+mssim[2] / 3f64)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_abs_diff() {
+        let a = Image::from_vec(2, 2, 1, vec![0, 10, 20, 30]).unwrap();
+        let b = Image::from_vec(2, 2, 1, vec![4, 10, 20, 26]).unwrap();
+        assert_eq!(get_mean_abs_diff(&a, &a).unwrap(), 0.0);
+        assert_eq!(get_mean_abs_diff(&a, &b).unwrap(), 2.0);
+        let c = Image::from_vec(1, 2, 1, vec![0, 0]).unwrap();
+        assert!(get_mean_abs_diff(&a, &c).is_err());
     }
-}
\ No newline at end of file
+}
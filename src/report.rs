@@ -0,0 +1,736 @@
+//!
+//! 会话报告: 从历史数据库, 数据集和快照生成一个 HTML 文件, 用浏览器离线查看
+//!
+//! * 汇总统计, 状态时间线, QTE 按键耗时直方图, 各区域分数分布 (标出阈值), QTE 截图和决策快照的缩略图
+//! * 图表为内联 SVG, 缩略图为 base64 JPEG, 样式写在文件内, 不引用任何外部资源
+//! * 数据集按 json 中的会话 id 归入会话, 快照取 snapshots/<会话 id>/ 下的图片, 只包含数据集保存的通过和险些通过的分数
+//! * 写到 reports/<session>.html, 已存在时覆盖
+//!
+use crate::dataset::{Label, Sidecar};
+use crate::error::{RappyError, Result, ResultExt};
+use crate::history::{EventRecord, History, RoundRecord, SessionRecord, parse_time};
+use crate::stats::hms;
+use crate::vision::{Image, ReadMode};
+use chrono::NaiveDateTime;
+use image::codecs::jpeg::JpegEncoder;
+use log::error;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+pub(crate) static REPORT_DIR: &str = "reports";
+
+/// 每种缩略图最多放入的张数, 超出时取最新的
+static MAX_THUMBNAILS: usize = 24;
+static THUMBNAIL_WIDTH: u32 = 320;
+const HISTOGRAM_BINS: usize = 20;
+
+/// 图表绘图区的位置和大小
+static CHART_LEFT: f64 = 110.0;
+static CHART_WIDTH: f64 = 760.0;
+static LANE_HEIGHT: f64 = 24.0;
+
+/// 时间线上标记的事件和颜色, 按显示顺序
+static MARKERS: [(&str, &str); 8] = [
+    ("round_started", "#777777"),
+    ("target_seen", "#1f77b4"),
+    ("qte_hit", "#2ca02c"),
+    ("timeout", "#d62728"),
+    ("energy_four", "#9467bd"),
+    ("energy_zero", "#8c564b"),
+    ("burst_started", "#e377c2"),
+    ("window_refreshed", "#ff7f0e"),
+];
+
+static STYLE: &str = "
+body { font-family: sans-serif; margin: 24px; color: #222; }
+h1 { font-size: 22px; } h2 { font-size: 18px; margin-top: 32px; }
+table { border-collapse: collapse; }
+td, th { border: 1px solid #ccc; padding: 3px 10px; text-align: left; }
+svg { display: block; margin: 8px 0; }
+svg text { font-size: 11px; fill: #444; }
+.note, .empty { color: #777; font-size: 13px; }
+.thumbs { display: flex; flex-wrap: wrap; gap: 8px; }
+figure { margin: 0; font-size: 12px; color: #555; }
+figure img { display: block; border: 1px solid #ccc; }
+";
+
+/// 一个区域在会话中保存的分数
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegionScores {
+    pub threshold: f64,
+    pub scores: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thumbnail {
+    pub caption: String,
+    /// data:image/jpeg;base64,...
+    pub data_uri: String,
+}
+
+pub struct SessionReport {
+    pub session: SessionRecord,
+    pub rounds: Vec<RoundRecord>,
+    pub events: Vec<EventRecord>,
+    pub scores: BTreeMap<String, RegionScores>,
+    pub qte_shots: Vec<Thumbnail>,
+    pub snapshots: Vec<Thumbnail>,
+}
+
+/// 会话的开始和结束时间, 结束时间依次取 ended, 最后一个事件, 开始后 1 秒
+struct Span {
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+}
+
+impl Span {
+    fn of(session: &SessionRecord, events: &[EventRecord]) -> Option<Self> {
+        let start = parse_time(&session.started)?;
+        let end = session
+            .ended
+            .as_deref()
+            .and_then(parse_time)
+            .into_iter()
+            .chain(events.iter().filter_map(|e| parse_time(&e.time)))
+            .max()
+            .filter(|end| *end > start)
+            .unwrap_or(start + chrono::Duration::seconds(1));
+        Some(Self { start, end })
+    }
+
+    fn x(&self, time: NaiveDateTime) -> f64 {
+        let total = (self.end - self.start).num_milliseconds().max(1) as f64;
+        let offset = (time - self.start).num_milliseconds() as f64;
+        CHART_LEFT + (offset / total).clamp(0.0, 1.0) * CHART_WIDTH
+    }
+}
+
+/// 文件名中的时间 HH:MM:SS
+fn file_time(path: &Path) -> String {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    match (name.get(8..10), name.get(10..12), name.get(12..14)) {
+        (Some(h), Some(m), Some(s)) => format!("{}:{}:{}", h, m, s),
+        _ => name.to_string(),
+    }
+}
+
+/// dir 下的 png, 按文件名即保存时间排序
+fn pngs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+fn read_sidecar(path: &Path) -> Option<Sidecar> {
+    let text = std::fs::read_to_string(path.with_extension("json")).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | ((*b as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                text.push(TABLE[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                text.push('=');
+            }
+        }
+    }
+    text
+}
+
+/// 缩小到 THUMBNAIL_WIDTH 宽 (小图不放大) 并编码为 JPEG
+fn thumbnail(path: &Path, caption: String) -> Result<Thumbnail> {
+    let img = Image::load(path, ReadMode::Unchanged).context(path.display())?;
+    let img = img.to_dynamic()?;
+    let img = if img.width() > THUMBNAIL_WIDTH {
+        img.thumbnail(THUMBNAIL_WIDTH, u32::MAX)
+    } else {
+        img
+    };
+    let mut bytes = Vec::new();
+    JpegEncoder::new_with_quality(&mut bytes, 80)
+        .encode_image(&img.to_rgb8())
+        .map_err(|e| RappyError::Vision(format!("jpeg encode: {}", e)))?;
+    Ok(Thumbnail {
+        caption,
+        data_uri: format!("data:image/jpeg;base64,{}", base64(&bytes)),
+    })
+}
+
+/// 最新的 MAX_THUMBNAILS 张, 失败的只写日志
+fn thumbnails(paths: &[PathBuf], caption: impl Fn(&Path) -> String) -> Vec<Thumbnail> {
+    paths[paths.len().saturating_sub(MAX_THUMBNAILS)..]
+        .iter()
+        .filter_map(|path| {
+            thumbnail(path, caption(path))
+                .map_err(|e| error!("Failed to make thumbnail: {}", e))
+                .ok()
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 按升序排列后的分位数
+fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    Some(sorted[((last as f64) * p).round() as usize])
+}
+
+/// 直方图, marker 为竖线 (值, 标签)
+fn histogram(values: &[f64], range: (f64, f64), marker: Option<(f64, &str)>) -> String {
+    if values.is_empty() {
+        return "<p class=\"empty\">no data</p>\n".to_string();
+    }
+    let (lo, hi) = range;
+    let bin_width = (hi - lo) / HISTOGRAM_BINS as f64;
+    let mut counts = [0usize; HISTOGRAM_BINS];
+    for value in values {
+        let bin = ((value - lo) / bin_width).floor().max(0.0) as usize;
+        counts[bin.min(HISTOGRAM_BINS - 1)] += 1;
+    }
+    let max = counts.iter().copied().max().unwrap_or(1).max(1);
+    let (top, height) = (10.0, 120.0);
+    let x = |value: f64| CHART_LEFT + (value - lo) / (hi - lo) * CHART_WIDTH;
+    let mut svg = format!(
+        "<svg width=\"{}\" height=\"{}\">\n",
+        CHART_LEFT + CHART_WIDTH + 20.0,
+        top + height + 40.0
+    );
+    for (i, count) in counts.iter().enumerate() {
+        let bar = *count as f64 / max as f64 * height;
+        let start = lo + bin_width * i as f64;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"#4c78a8\">\
+             <title>{:.3} - {:.3}: {}</title></rect>",
+            x(start) + 1.0,
+            top + height - bar,
+            (CHART_WIDTH / HISTOGRAM_BINS as f64 - 2.0).max(1.0),
+            bar,
+            start,
+            start + bin_width,
+            count
+        );
+    }
+    let bottom = top + height;
+    let _ = writeln!(
+        svg,
+        "<line x1=\"{l}\" y1=\"{b}\" x2=\"{r}\" y2=\"{b}\" stroke=\"#999\"/>\n\
+         <text x=\"{l}\" y=\"{t1}\" text-anchor=\"middle\">{lo:.3}</text>\n\
+         <text x=\"{r}\" y=\"{t1}\" text-anchor=\"middle\">{hi:.3}</text>\n\
+         <text x=\"{c}\" y=\"{t2}\" text-anchor=\"end\">max {max}</text>",
+        l = CHART_LEFT,
+        r = CHART_LEFT + CHART_WIDTH,
+        b = bottom,
+        t1 = bottom + 14.0,
+        c = CHART_LEFT - 8.0,
+        t2 = top + 10.0,
+    );
+    if let Some((value, label)) = marker {
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{x:.1}\" y1=\"{t}\" x2=\"{x:.1}\" y2=\"{b}\" stroke=\"#d62728\" \
+             stroke-width=\"2\" stroke-dasharray=\"4 2\"/>\n\
+             <text x=\"{x:.1}\" y=\"{l}\" text-anchor=\"middle\" style=\"fill:#d62728\">{label}</text>",
+            x = x(value),
+            t = top,
+            b = bottom,
+            l = bottom + 28.0,
+            label = escape(label),
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+impl SessionReport {
+    /// 读取会话 (None 为最近的一次) 的局, 事件, 数据集分数和缩略图
+    pub fn load(
+        history: &History,
+        session: Option<&str>,
+        dataset: &Path,
+        snapshots: &Path,
+    ) -> Result<Self> {
+        let Some(record) = history.session(session)? else {
+            return Err(RappyError::Io(match session {
+                Some(id) => format!("session {} not found", id),
+                None => "no sessions in history".to_string(),
+            }));
+        };
+        let rounds = history.rounds(None, Some(&record.id))?;
+        let events = history.events(None, Some(&record.id))?;
+        let mut report = Self {
+            session: record,
+            rounds,
+            events,
+            scores: BTreeMap::new(),
+            qte_shots: Vec::new(),
+            snapshots: Vec::new(),
+        };
+        let id = report.session.id.clone();
+        // 数据集中本会话的样本
+        let samples = |dir: &Path| {
+            pngs(dir)
+                .into_iter()
+                .filter_map(|path| Some((read_sidecar(&path).filter(|s| s.session == id)?, path)))
+                .collect::<Vec<_>>()
+        };
+
+        let regions = std::fs::read_dir(dataset)
+            .map(|entries| entries.flatten().map(|e| e.path()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        for region in regions {
+            for label in [Label::Positive, Label::NearMiss] {
+                for (sidecar, _) in samples(&region.join(label.dir())) {
+                    let scores = report.scores.entry(sidecar.region).or_default();
+                    scores.threshold = sidecar.threshold;
+                    scores.scores.push(sidecar.score);
+                }
+            }
+        }
+        let qte = samples(&dataset.join("QTE").join(Label::Positive.dir()))
+            .into_iter()
+            .map(|(_, path)| path)
+            .collect::<Vec<_>>();
+        report.qte_shots = thumbnails(&qte, |path| match read_sidecar(path) {
+            Some(sidecar) => format!("{} score {:.3}", file_time(path), sidecar.score),
+            None => file_time(path),
+        });
+        let shots = pngs(&snapshots.join(&id));
+        report.snapshots = thumbnails(&shots, |path| {
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+            let action = stem.split_once('_').map_or("", |(_, action)| action);
+            format!("{} {}", file_time(path), action.replace('_', " "))
+        });
+        Ok(report)
+    }
+
+    /// QTE 命中的按键耗时 (毫秒)
+    fn latencies(&self) -> Vec<f64> {
+        self.events
+            .iter()
+            .filter(|e| e.event == "qte_hit")
+            .filter_map(|e| e.latency_ms)
+            .collect()
+    }
+
+    fn summary(&self) -> String {
+        let s = &self.session;
+        let mut latencies = self.latencies();
+        latencies.sort_by(f64::total_cmp);
+        let ms = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.1} ms", v));
+        let mean =
+            (!latencies.is_empty()).then(|| latencies.iter().sum::<f64>() / latencies.len() as f64);
+        let hit_rate = match s.qte_hits + s.qte_timeouts {
+            0 => "-".to_string(),
+            total => format!("{:.1}%", s.qte_hits as f64 / total as f64 * 100.0),
+        };
+        let mut outcomes = BTreeMap::<&str, u64>::new();
+        for round in &self.rounds {
+            *outcomes.entry(round.outcome.as_str()).or_default() += 1;
+        }
+        let outcomes = outcomes
+            .iter()
+            .map(|(outcome, count)| format!("{} {}", outcome, count))
+            .collect::<Vec<_>>();
+        let rows = [
+            ("Started", s.started.clone()),
+            ("Ended", s.ended.clone().unwrap_or_else(|| "-".to_string())),
+            ("Running", hms(s.running_secs)),
+            ("Rounds", s.rounds.to_string()),
+            ("Round outcomes", outcomes.join(", ")),
+            ("Enter presses", s.enter_presses.to_string()),
+            ("Bet changes", s.bet_changes.to_string()),
+            ("Time at bet 1", hms(s.bet_one_secs)),
+            ("Time at bet 5", hms(s.bet_five_secs)),
+            (
+                "Energy four / zero",
+                format!("{} / {}", s.energy_four, s.energy_zero),
+            ),
+            ("Target sightings", s.target_sightings.to_string()),
+            (
+                "QTE hits / timeouts",
+                format!("{} / {}", s.qte_hits, s.qte_timeouts),
+            ),
+            ("QTE hit rate", hit_rate),
+            ("QTE latency mean", ms(mean)),
+            ("QTE latency median", ms(percentile(&latencies, 0.5))),
+            ("QTE latency p95", ms(percentile(&latencies, 0.95))),
+            ("Bursts", s.bursts.to_string()),
+            ("Window refreshes", s.window_refreshes.to_string()),
+        ];
+        let mut html = String::from("<table>\n");
+        for (name, value) in rows {
+            let _ = writeln!(
+                html,
+                "<tr><th>{}</th><td>{}</td></tr>",
+                name,
+                escape(&value)
+            );
+        }
+        html.push_str("</table>\n");
+        html
+    }
+
+    /// 工作线程运行区间, 下注数区间和各事件的标记
+    fn timeline(&self) -> String {
+        let Some(span) = Span::of(&self.session, &self.events) else {
+            return "<p class=\"empty\">no data</p>\n".to_string();
+        };
+        let events = self
+            .events
+            .iter()
+            .filter_map(|e| Some((parse_time(&e.time)?, e)))
+            .collect::<Vec<_>>();
+        let markers = MARKERS
+            .iter()
+            .filter(|(name, _)| events.iter().any(|(_, e)| e.event == *name))
+            .collect::<Vec<_>>();
+        let lanes = 2 + markers.len();
+        let height = lanes as f64 * LANE_HEIGHT + 30.0;
+        let mut svg = format!(
+            "<svg width=\"{}\" height=\"{}\">\n",
+            CHART_LEFT + CHART_WIDTH + 20.0,
+            height
+        );
+        let lane_y = |lane: usize| lane as f64 * LANE_HEIGHT + 4.0;
+        let bar = |svg: &mut String,
+                   lane: usize,
+                   from: NaiveDateTime,
+                   to: NaiveDateTime,
+                   color: &str,
+                   title: &str| {
+            let (x1, x2) = (span.x(from), span.x(to));
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\">\
+                 <title>{} {} - {}</title></rect>",
+                x1,
+                lane_y(lane),
+                (x2 - x1).max(1.0),
+                LANE_HEIGHT - 8.0,
+                color,
+                title,
+                from.format("%H:%M:%S"),
+                to.format("%H:%M:%S")
+            );
+        };
+        for (lane, name) in ["worker", "bet"]
+            .iter()
+            .chain(markers.iter().map(|(name, _)| name))
+            .enumerate()
+        {
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+                CHART_LEFT - 8.0,
+                lane_y(lane) + LANE_HEIGHT / 2.0,
+                name
+            );
+        }
+
+        let (mut running, mut bet) = (None, None::<(u8, NaiveDateTime)>);
+        for (time, event) in &events {
+            match event.event.as_str() {
+                "worker_started" => {
+                    running.get_or_insert(*time);
+                }
+                "worker_stopped" => {
+                    if let Some(from) = running.take() {
+                        bar(&mut svg, 0, from, *time, "#59a14f", "running");
+                    }
+                    if let Some((coins, from)) = bet.take() {
+                        bar(
+                            &mut svg,
+                            1,
+                            from,
+                            *time,
+                            bet_color(coins),
+                            &format!("bet {}", coins),
+                        );
+                    }
+                }
+                "bet_observed" | "bet_changed" => {
+                    if let Some(coins) = event.bet
+                        && bet.is_none_or(|(current, _)| current != coins)
+                        && let Some((previous, from)) = bet.replace((coins, *time))
+                    {
+                        bar(
+                            &mut svg,
+                            1,
+                            from,
+                            *time,
+                            bet_color(previous),
+                            &format!("bet {}", previous),
+                        );
+                    }
+                }
+                _ => {}
+            }
+            if let Some(lane) = markers.iter().position(|(name, _)| *name == event.event) {
+                let x = span.x(*time);
+                let detail = match (&event.phase, event.latency_ms) {
+                    (Some(phase), _) => format!(" {}", phase),
+                    (_, Some(ms)) => format!(" {:.1} ms", ms),
+                    _ => String::new(),
+                };
+                let _ = writeln!(
+                    svg,
+                    "<line x1=\"{x:.1}\" y1=\"{:.1}\" x2=\"{x:.1}\" y2=\"{:.1}\" stroke=\"{}\" \
+                     stroke-width=\"2\"><title>{} {}{}</title></line>",
+                    lane_y(lane + 2),
+                    lane_y(lane + 2) + LANE_HEIGHT - 8.0,
+                    markers[lane].1,
+                    time.format("%H:%M:%S%.3f"),
+                    event.event,
+                    escape(&detail),
+                );
+            }
+        }
+        if let Some(from) = running {
+            bar(&mut svg, 0, from, span.end, "#59a14f", "running");
+        }
+        if let Some((coins, from)) = bet {
+            bar(
+                &mut svg,
+                1,
+                from,
+                span.end,
+                bet_color(coins),
+                &format!("bet {}", coins),
+            );
+        }
+        let axis = lanes as f64 * LANE_HEIGHT + 4.0;
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{l}\" y1=\"{a}\" x2=\"{r}\" y2=\"{a}\" stroke=\"#999\"/>\n\
+             <text x=\"{l}\" y=\"{t}\">{start}</text>\n\
+             <text x=\"{r}\" y=\"{t}\" text-anchor=\"end\">{end}</text>",
+            l = CHART_LEFT,
+            r = CHART_LEFT + CHART_WIDTH,
+            a = axis,
+            t = axis + 16.0,
+            start = span.start.format("%H:%M:%S"),
+            end = span.end.format("%H:%M:%S"),
+        );
+        svg.push_str("</svg>\n");
+        svg.push_str(
+            "<p class=\"note\">worker: green while the worker thread runs; \
+             bet: blue at 1 coin, orange at 5 coins. Hover for times.</p>\n",
+        );
+        svg
+    }
+
+    fn score_distributions(&self) -> String {
+        if self.scores.is_empty() {
+            return "<p class=\"empty\">no dataset samples in this session</p>\n".to_string();
+        }
+        let mut html = String::from(
+            "<p class=\"note\">Only frames saved to the dataset (passed or near miss) are included.</p>\n",
+        );
+        for (region, scores) in &self.scores {
+            let (lo, hi) = scores
+                .scores
+                .iter()
+                .fold((scores.threshold, scores.threshold), |(lo, hi), v| {
+                    (lo.min(*v), hi.max(*v))
+                });
+            let pad = ((hi - lo) * 0.05).max(0.05);
+            let _ = writeln!(
+                html,
+                "<h3>{} ({} samples)</h3>",
+                escape(region),
+                scores.scores.len()
+            );
+            html.push_str(&histogram(
+                &scores.scores,
+                (lo - pad, hi + pad),
+                Some((
+                    scores.threshold,
+                    &format!("threshold {:.3}", scores.threshold),
+                )),
+            ));
+        }
+        html
+    }
+
+    fn gallery(thumbnails: &[Thumbnail]) -> String {
+        if thumbnails.is_empty() {
+            return "<p class=\"empty\">none saved in this session</p>\n".to_string();
+        }
+        let mut html = String::from("<div class=\"thumbs\">\n");
+        for thumb in thumbnails {
+            let _ = writeln!(
+                html,
+                "<figure><img src=\"{}\" alt=\"{caption}\"><figcaption>{caption}</figcaption></figure>",
+                thumb.data_uri,
+                caption = escape(&thumb.caption)
+            );
+        }
+        html.push_str("</div>\n");
+        html
+    }
+
+    pub fn render(&self) -> String {
+        let latencies = self.latencies();
+        let max_latency = latencies.iter().copied().fold(0.0, f64::max);
+        let title = format!("Session {}", escape(&self.session.id));
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+             <style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+        );
+        html.push_str("<h2>Summary</h2>\n");
+        html.push_str(&self.summary());
+        html.push_str("<h2>Timeline</h2>\n");
+        html.push_str(&self.timeline());
+        let _ = writeln!(html, "<h2>QTE latency ({} hits)</h2>", latencies.len());
+        html.push_str(&histogram(
+            &latencies,
+            (0.0, (max_latency / 10.0).ceil().max(1.0) * 10.0),
+            None,
+        ));
+        html.push_str("<h2>Detector scores</h2>\n");
+        html.push_str(&self.score_distributions());
+        let _ = writeln!(html, "<h2>QTE shots ({})</h2>", self.qte_shots.len());
+        html.push_str(&Self::gallery(&self.qte_shots));
+        let _ = writeln!(
+            html,
+            "<h2>Decision snapshots ({})</h2>",
+            self.snapshots.len()
+        );
+        html.push_str(&Self::gallery(&self.snapshots));
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// 写到 dir/<session>.html, 返回文件路径
+    pub fn write<P: AsRef<Path>>(&self, dir: P) -> Result<PathBuf> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).context(dir.display())?;
+        let path = dir.join(format!("{}.html", self.session.id));
+        std::fs::write(&path, self.render()).context(path.display())?;
+        Ok(path)
+    }
+}
+
+fn bet_color(coins: u8) -> &'static str {
+    if coins == 1 { "#4e79a7" } else { "#f28e2b" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::{EventRecord, RoundTracker};
+    use crate::stats::{Event, StatsSnapshot};
+    use std::time::Duration;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_report_is_self_contained() {
+        let root = std::env::temp_dir().join(format!("rappy_report_{}", std::process::id()));
+        let started = chrono::Local::now();
+        let history = History::open(":memory:").unwrap();
+        let snapshot = StatsSnapshot {
+            session: "s1".to_string(),
+            started: started.to_rfc3339(),
+            qte_hits: 1,
+            ..Default::default()
+        };
+        history.save_session(&snapshot, false).unwrap();
+        let mut tracker = RoundTracker::default();
+        for event in [
+            Event::WorkerStarted,
+            Event::BetObserved { one: true },
+            Event::RoundStarted,
+            Event::TargetSeen,
+            Event::QteHit {
+                latency: Duration::from_millis(42),
+            },
+            Event::WorkerStopped,
+        ] {
+            history
+                .insert_event(&EventRecord::new("s1", event))
+                .unwrap();
+            if let Some(round) = tracker.record(event, "s1") {
+                history.insert_round(&round).unwrap();
+            }
+        }
+        history.save_session(&snapshot, true).unwrap();
+
+        // 本会话和同一时间另一会话保存的 QTE 截图和快照
+        let name = started.format("%Y%m%d%H%M%S%.6f").to_string();
+        let qte_dir = root.join("dataset/QTE/positive");
+        let snapshots = root.join("snapshots");
+        std::fs::create_dir_all(&qte_dir).unwrap();
+        let img = Image::load("test_data/target.jpg", ReadMode::Unchanged).unwrap();
+        for (i, (session, score)) in [("s1", 0.93), ("s2", 0.5)].into_iter().enumerate() {
+            img.save_png(qte_dir.join(format!("{}_{}.png", name, i)))
+                .unwrap();
+            let sidecar = Sidecar {
+                region: "QTE".to_string(),
+                rect: (0, 0, 1, 1),
+                label: Label::Positive,
+                score,
+                threshold: 0.8,
+                passed: true,
+                details: String::new(),
+                session: session.to_string(),
+                time: started.to_rfc3339(),
+            };
+            std::fs::write(
+                qte_dir.join(format!("{}_{}.json", name, i)),
+                serde_json::to_string(&sidecar).unwrap(),
+            )
+            .unwrap();
+            std::fs::create_dir_all(snapshots.join(session)).unwrap();
+            img.save_png(
+                snapshots
+                    .join(session)
+                    .join(format!("{}_press_enter.png", name)),
+            )
+            .unwrap();
+        }
+
+        let report =
+            SessionReport::load(&history, None, &root.join("dataset"), &snapshots).unwrap();
+        assert_eq!(report.scores["QTE"].scores, vec![0.93]);
+        assert_eq!((report.qte_shots.len(), report.snapshots.len()), (1, 1));
+        assert!(report.snapshots[0].caption.ends_with("press enter"));
+        let path = report.write(root.join("reports")).unwrap();
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(html.contains("threshold 0.800"));
+        assert!(html.contains("<h2>QTE latency (1 hits)</h2>"));
+        assert!(html.contains("data:image/jpeg;base64,"));
+        assert!(!html.contains("http"));
+        assert!(SessionReport::load(&history, Some("missing"), &root, &root).is_err());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
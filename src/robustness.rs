@@ -0,0 +1,444 @@
+//!
+//! 鲁棒性测试: 对基准样本施加扰动后重新打分, 看每个区域的分数离阈值还有多少余量
+//!
+//! * 基准样本: 与区域大小相同的内置模板, 以及 test_data 等截图中单帧通过的区域
+//! * 扰动: 高斯噪声, 亮度/对比度, JPEG 压缩, 1-3 像素偏移, 90%-110% 缩放
+//! * 偏移和缩放在整张截图上重新采样, 区域外的像素取自截图而不是补边;
+//!   模板没有周围的画面, 超出部分按边缘像素补齐
+//! * 噪声由 seed 决定, 同样的输入和 seed 结果相同
+//!
+use crate::detector::{Detector, Detectors, Pipeline};
+use crate::error::{RappyError, Result, ResultExt};
+use crate::vision::draw::Canvas;
+use crate::vision::{Image, ReadMode};
+use image::codecs::jpeg::JpegEncoder;
+use serde::Serialize;
+use std::fmt::{self, Write};
+use std::path::Path;
+
+pub static DEFAULT_SEED: u64 = 0x5241_5050;
+
+static IMAGE_EXTENSIONS: [&str; 4] = ["png", "jpg", "jpeg", "bmp"];
+
+/// 一种扰动, 作用在区域截图上
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Perturbation {
+    /// 不做处理, 即基准分数
+    Identity,
+    /// 每个通道加均值为 0 的高斯噪声
+    Noise {
+        sigma: f64,
+    },
+    Brightness {
+        delta: i32,
+    },
+    /// 以 128 为中心拉伸
+    Contrast {
+        factor: f64,
+    },
+    /// 按质量 quality 编码为 JPEG 再解码
+    Jpeg {
+        quality: u8,
+    },
+    /// 截取位置偏移 (dx, dy) 像素
+    Offset {
+        dx: i32,
+        dy: i32,
+    },
+    /// 以区域中心缩放画面, 区域大小不变
+    Scale {
+        factor: f64,
+    },
+}
+
+impl fmt::Display for Perturbation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Perturbation::Identity => write!(f, "identity"),
+            Perturbation::Noise { sigma } => write!(f, "noise sigma {}", sigma),
+            Perturbation::Brightness { delta } => write!(f, "brightness {:+}", delta),
+            Perturbation::Contrast { factor } => write!(f, "contrast x{}", factor),
+            Perturbation::Jpeg { quality } => write!(f, "jpeg q{}", quality),
+            Perturbation::Offset { dx, dy } => write!(f, "offset {:+},{:+}", dx, dy),
+            Perturbation::Scale { factor } => write!(f, "scale {:.0}%", factor * 100.0),
+        }
+    }
+}
+
+impl Perturbation {
+    /// 默认的全部扰动, identity 在最前面
+    pub fn all() -> Vec<Self> {
+        let mut all = vec![Perturbation::Identity];
+        all.extend([5.0, 10.0, 20.0].map(|sigma| Perturbation::Noise { sigma }));
+        all.extend([-40, -20, 20, 40].map(|delta| Perturbation::Brightness { delta }));
+        all.extend([0.8, 1.2].map(|factor| Perturbation::Contrast { factor }));
+        all.extend([50, 30, 15].map(|quality| Perturbation::Jpeg { quality }));
+        for d in 1..=3 {
+            all.extend(
+                [(d, 0), (-d, 0), (0, d), (0, -d)].map(|(dx, dy)| Perturbation::Offset { dx, dy }),
+            );
+        }
+        all.extend([0.9, 0.95, 1.05, 1.1].map(|factor| Perturbation::Scale { factor }));
+        all
+    }
+
+    fn apply(&self, base: &Base, rng: &mut NoiseRng) -> Result<Image> {
+        let (dx, dy, scale) = match *self {
+            Perturbation::Offset { dx, dy } => (dx as f64, dy as f64, 1.0),
+            Perturbation::Scale { factor } => (0.0, 0.0, factor),
+            _ => (0.0, 0.0, 1.0),
+        };
+        let shot = resample(&base.context, base.rect, dx, dy, scale)?;
+        match *self {
+            Perturbation::Noise { sigma } => map_pixels(&shot, |v| v + rng.gaussian() * sigma),
+            Perturbation::Brightness { delta } => map_pixels(&shot, |v| v + delta as f64),
+            Perturbation::Contrast { factor } => {
+                map_pixels(&shot, |v| (v - 128.0) * factor + 128.0)
+            }
+            Perturbation::Jpeg { quality } => jpeg_round_trip(&shot, quality),
+            _ => Ok(shot),
+        }
+    }
+}
+
+/// 固定 seed 的噪声源 (splitmix64 + Box-Muller), 不依赖随机数库的版本
+struct NoiseRng(u64);
+
+impl NoiseRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// (0, 1] 之间的均匀分布
+    fn uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    fn gaussian(&mut self) -> f64 {
+        let (u1, u2) = (self.uniform(), self.uniform());
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+/// 扰动前的样本: BGR 画面和区域在画面中的位置
+struct Base {
+    name: String,
+    context: Image,
+    rect: (i32, i32, i32, i32),
+}
+
+/// 双线性采样出 rect 大小的图, 画面整体偏移 (dx, dy) 并以 rect 中心缩放 scale 倍
+/// * 超出 context 的像素取最近的边缘像素
+fn resample(
+    context: &Image,
+    rect: (i32, i32, i32, i32),
+    dx: f64,
+    dy: f64,
+    scale: f64,
+) -> Result<Image> {
+    let (x0, y0, width, height) = rect;
+    let (cx, cy) = (width as f64 / 2.0, height as f64 / 2.0);
+    let channels = context.channels();
+    let px = |x: i32, y: i32, c: i32| {
+        let x = x.clamp(0, context.width() - 1);
+        let y = y.clamp(0, context.height() - 1);
+        context.at(x, y, c) as f64
+    };
+    let mut data = Vec::with_capacity((width * height * channels) as usize);
+    for y in 0..height {
+        let sy = y0 as f64 + cy + (y as f64 + 0.5 - cy) / scale - 0.5 + dy;
+        let (iy, fy) = (sy.floor() as i32, sy - sy.floor());
+        for x in 0..width {
+            let sx = x0 as f64 + cx + (x as f64 + 0.5 - cx) / scale - 0.5 + dx;
+            let (ix, fx) = (sx.floor() as i32, sx - sx.floor());
+            for c in 0..channels {
+                let top = px(ix, iy, c) * (1.0 - fx) + px(ix + 1, iy, c) * fx;
+                let bottom = px(ix, iy + 1, c) * (1.0 - fx) + px(ix + 1, iy + 1, c) * fx;
+                data.push((top * (1.0 - fy) + bottom * fy).round().clamp(0.0, 255.0) as u8);
+            }
+        }
+    }
+    Ok(Image::from_vec(width, height, channels, data)?)
+}
+
+fn map_pixels(img: &Image, mut f: impl FnMut(f64) -> f64) -> Result<Image> {
+    let data = img
+        .to_vec()
+        .into_iter()
+        .map(|v| f(v as f64).round().clamp(0.0, 255.0) as u8)
+        .collect();
+    Ok(Image::from_vec(
+        img.width(),
+        img.height(),
+        img.channels(),
+        data,
+    )?)
+}
+
+fn jpeg_round_trip(img: &Image, quality: u8) -> Result<Image> {
+    let mut bytes = Vec::new();
+    JpegEncoder::new_with_quality(&mut bytes, quality)
+        .encode_image(&img.to_dynamic()?)
+        .map_err(|e| RappyError::Vision(format!("jpeg encode: {}", e)))?;
+    Ok(Image::decode(&bytes, ReadMode::Unchanged)?)
+}
+
+/// 某个区域的基准样本
+/// * 模板: 与区域大小相同时 (QTE 模板是缩小后截取的, 不参与)
+/// * 截图: 区域单帧通过时, 未通过的截图不是这个区域的正样本
+fn bases(pipeline: &Pipeline, images: &[(String, Image)]) -> Vec<Base> {
+    let (_, _, width, height) = pipeline.region().rect;
+    let mut bases = Vec::new();
+    let template = pipeline.template();
+    if (template.width(), template.height()) == (width, height)
+        && let Ok(context) = Canvas::from_image(template).into_image()
+    {
+        bases.push(Base {
+            name: "template".to_string(),
+            context,
+            rect: (0, 0, width, height),
+        });
+    }
+    for (name, img) in images {
+        let rect = pipeline.region().rect;
+        let passed = img
+            .roi(rect)
+            .ok()
+            .and_then(|shot| pipeline.score_shot(&shot).ok())
+            .is_some_and(|detection| detection.passed);
+        if passed {
+            bases.push(Base {
+                name: name.clone(),
+                context: img.clone(),
+                rect,
+            });
+        }
+    }
+    bases
+}
+
+/// 一种扰动下所有基准样本的分数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PerturbationScore {
+    pub perturbation: Perturbation,
+    pub samples: usize,
+    pub mean: f64,
+    /// 离阈值最近 (或超出阈值最多) 的分数
+    pub worst: f64,
+    /// worst 超过阈值的余量, 负数表示至少一个样本不再通过
+    pub margin: f64,
+    /// 单帧判定通过的样本数 (含颜色检测)
+    pub passed: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+/// 一个区域的鲁棒性结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Robustness {
+    pub region: String,
+    pub threshold: f64,
+    /// 基准样本的名字 (template 或截图文件名)
+    pub bases: Vec<String>,
+    pub results: Vec<PerturbationScore>,
+}
+
+impl Robustness {
+    /// 余量最小的扰动
+    pub fn weakest(&self) -> Option<&PerturbationScore> {
+        self.results
+            .iter()
+            .filter(|r| r.samples > 0)
+            .min_by(|a, b| a.margin.total_cmp(&b.margin))
+    }
+
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{} (threshold {}, bases: {})",
+            self.region,
+            self.threshold,
+            if self.bases.is_empty() {
+                "none".to_string()
+            } else {
+                self.bases.join(", ")
+            }
+        );
+        if self.bases.is_empty() {
+            let _ = writeln!(out, "  no passing sample to perturb");
+            return out;
+        }
+        let _ = writeln!(
+            out,
+            "  {:<18} {:>7} {:>8} {:>8} {:>8} {:>6}",
+            "PERTURBATION", "SAMPLES", "MEAN", "WORST", "MARGIN", "PASS"
+        );
+        for r in &self.results {
+            let _ = writeln!(
+                out,
+                "  {:<18} {:>7} {:>8.4} {:>8.4} {:>+8.4} {:>6}",
+                r.perturbation.to_string(),
+                r.samples,
+                r.mean,
+                r.worst,
+                r.margin,
+                format!("{}/{}", r.passed, r.samples)
+            );
+            for e in &r.errors {
+                let _ = writeln!(out, "    error: {}", e);
+            }
+        }
+        if let Some(weakest) = self.weakest() {
+            let _ = writeln!(
+                out,
+                "  weakest: {} (margin {:+.4})",
+                weakest.perturbation, weakest.margin
+            );
+        }
+        out
+    }
+}
+
+fn score_perturbation(
+    pipeline: &Pipeline,
+    bases: &[Base],
+    perturbation: Perturbation,
+    rng: &mut NoiseRng,
+) -> PerturbationScore {
+    let mut scores = Vec::with_capacity(bases.len());
+    let mut passed = 0;
+    let mut errors = Vec::new();
+    for base in bases {
+        let detection = perturbation
+            .apply(base, rng)
+            .and_then(|shot| pipeline.score_shot(&shot))
+            .with_context(|| format!("{} {}", base.name, perturbation));
+        match detection {
+            Ok(detection) => {
+                passed += detection.passed as usize;
+                scores.push(detection.score);
+            }
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    let worst = scores
+        .iter()
+        .copied()
+        .min_by(|a, b| pipeline.margin(*a).total_cmp(&pipeline.margin(*b)))
+        .unwrap_or(f64::NAN);
+    PerturbationScore {
+        perturbation,
+        samples: scores.len(),
+        mean: scores.iter().sum::<f64>() / scores.len().max(1) as f64,
+        worst,
+        margin: pipeline.margin(worst),
+        passed,
+        errors,
+    }
+}
+
+/// 对每个区域运行全部扰动, 按区域在主循环中的顺序输出
+/// * images: (名字, 客户区截图), 按检测器的区域位置截取
+pub fn run(detectors: &Detectors, images: &[(String, Image)], seed: u64) -> Vec<Robustness> {
+    let mut rng = NoiseRng(seed);
+    let perturbations = Perturbation::all();
+    detectors
+        .pipelines()
+        .into_iter()
+        .map(|pipeline| {
+            let bases = bases(pipeline, images);
+            let results = if bases.is_empty() {
+                Vec::new()
+            } else {
+                perturbations
+                    .iter()
+                    .map(|p| score_perturbation(pipeline, &bases, *p, &mut rng))
+                    .collect()
+            };
+            Robustness {
+                region: pipeline.name().to_string(),
+                threshold: pipeline.threshold(),
+                bases: bases.into_iter().map(|b| b.name).collect(),
+                results,
+            }
+        })
+        .collect()
+}
+
+/// 读取目录中的全部截图, 按文件名排序, 统一转为 BGR
+pub fn load_images(dir: &Path) -> Result<Vec<(String, Image)>> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .context(dir.display())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        })
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let img = Image::load(&path, ReadMode::Unchanged).context(path.display())?;
+            let name = path
+                .file_name()
+                .map_or_else(String::new, |n| n.to_string_lossy().into_owned());
+            Ok((name, Canvas::from_image(&img).into_image()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resample_offset_and_scale() {
+        let data = (0..16).collect();
+        let img = Image::from_vec(4, 4, 1, data).unwrap();
+        let same = resample(&img, (1, 1, 2, 2), 0.0, 0.0, 1.0).unwrap();
+        assert_eq!(same.to_vec(), img.roi((1, 1, 2, 2)).unwrap().to_vec());
+        let right = resample(&img, (1, 1, 2, 2), 1.0, 0.0, 1.0).unwrap();
+        assert_eq!(right.to_vec(), vec![6, 7, 10, 11]);
+        // 超出画面时取边缘像素
+        let edge = resample(&img, (2, 0, 2, 1), 2.0, -1.0, 1.0).unwrap();
+        assert_eq!(edge.to_vec(), vec![3, 3]);
+        // 放大后四个像素都向中心靠拢
+        let zoomed = resample(&img, (0, 0, 4, 4), 0.0, 0.0, 2.0).unwrap();
+        assert_eq!(zoomed.at(0, 0, 0), 4);
+        assert_eq!(zoomed.at(3, 3, 0), 11);
+    }
+
+    #[test]
+    fn test_robustness_on_test_data() {
+        let images = load_images(Path::new("test_data")).unwrap();
+        let detectors = Detectors::new(0, 0);
+        let reports = run(&detectors, &images, DEFAULT_SEED);
+        assert_eq!(reports.len(), detectors.pipelines().len());
+        let perturbations = Perturbation::all();
+        for name in ["KEY_READY", "TARGET", "QTE"] {
+            let report = reports.iter().find(|r| r.region == name).unwrap();
+            assert!(!report.bases.is_empty(), "{}", name);
+            assert_eq!(report.results.len(), perturbations.len());
+            // 基准样本都是单帧通过的
+            let identity = &report.results[0];
+            assert_eq!(identity.perturbation, Perturbation::Identity);
+            assert_eq!(identity.passed, report.bases.len(), "{}", name);
+            assert!(identity.margin > 0.0, "{}", name);
+            assert!(report.weakest().unwrap().margin <= identity.margin);
+        }
+        let qte = reports.iter().find(|r| r.region == "QTE").unwrap();
+        assert!(!qte.bases.contains(&"template".to_string()));
+        assert!(reports[0].report().contains("weakest: "));
+
+        // 同样的 seed 结果相同
+        assert_eq!(run(&detectors, &images, DEFAULT_SEED), reports);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::cancel::CancellationToken;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 主循环所处的阶段, 每个阶段有自己的轮询频率
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    /// 等待开始键可用 (上一局结束动画等)
+    Idle,
+    /// 目标出现后等待 QTE, 需要尽快反应
+    QteWait,
+}
+
+impl Phase {
+    /// 目标轮询频率 (Hz)
+    pub fn poll_rate(&self) -> f64 {
+        match self {
+            Phase::Idle => 2.0,
+            Phase::QteWait => 30.0,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Idle => write!(f, "idle"),
+            Phase::QteWait => write!(f, "qte_wait"),
+        }
+    }
+}
+
+/// 某个阶段的节拍统计
+/// * work: 上一拍开始到本拍到期之间的工作耗时 (截图 + 检测)
+/// * late: 本拍实际开始时间比计划晚了多少
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TickStats {
+    pub ticks: u64,
+    /// 工作耗时超过周期, 没有等待直接开始下一拍的次数
+    pub overruns: u64,
+    pub total_work: Duration,
+    pub max_work: Duration,
+    pub total_late: Duration,
+    pub max_late: Duration,
+}
+
+impl TickStats {
+    fn record(&mut self, work: Option<Duration>, late: Duration, overrun: bool) {
+        self.ticks += 1;
+        if overrun {
+            self.overruns += 1;
+        }
+        if let Some(work) = work {
+            self.total_work += work;
+            self.max_work = self.max_work.max(work);
+        }
+        self.total_late += late;
+        self.max_late = self.max_late.max(late);
+    }
+
+    fn mean(total: Duration, count: u64) -> Duration {
+        if count == 0 {
+            Duration::ZERO
+        } else {
+            total / count as u32
+        }
+    }
+}
+
+impl fmt::Display for TickStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 第一拍没有上一拍的工作耗时
+        let work_count = self.ticks.saturating_sub(1);
+        write!(
+            f,
+            "ticks: {}, overruns: {}, work avg/max: {:?}/{:?}, late avg/max: {:?}/{:?}",
+            self.ticks,
+            self.overruns,
+            Self::mean(self.total_work, work_count),
+            self.max_work,
+            Self::mean(self.total_late, self.ticks),
+            self.max_late
+        )
+    }
+}
+
+/// 按阶段频率轮询, 所有等待都会在任务取消时提前返回
+pub struct Scheduler {
+    token: CancellationToken,
+    stats: Mutex<BTreeMap<Phase, TickStats>>,
+}
+
+impl Scheduler {
+    pub fn new(token: CancellationToken) -> Self {
+        Self {
+            token,
+            stats: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.token.is_cancelled()
+    }
+
+    /// 等待 duration, 任务取消时提前返回 false
+    pub fn wait(&self, duration: Duration) -> bool {
+        self.token.sleep(duration)
+    }
+
+    /// 按 phase 的轮询频率开始一轮节拍
+    pub fn ticker(&self, phase: Phase) -> Ticker<'_> {
+        self.ticker_with_rate(phase, phase.poll_rate())
+    }
+
+    fn ticker_with_rate(&self, phase: Phase, rate_hz: f64) -> Ticker<'_> {
+        Ticker {
+            scheduler: self,
+            phase,
+            period: Duration::from_secs_f64(1.0 / rate_hz),
+            next: None,
+        }
+    }
+
+    fn record(&self, phase: Phase, work: Option<Duration>, late: Duration, overrun: bool) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        stats.entry(phase).or_default().record(work, late, overrun);
+    }
+
+    /// 各阶段的节拍统计
+    pub fn stats(&self) -> BTreeMap<Phase, TickStats> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// 一轮固定频率的节拍, 按到期时间而不是固定间隔等待, 检测耗时不会累加到周期上
+pub struct Ticker<'a> {
+    scheduler: &'a Scheduler,
+    phase: Phase,
+    period: Duration,
+    /// 上一拍开始时间和下一拍的计划时间
+    next: Option<(Instant, Instant)>,
+}
+
+impl Ticker<'_> {
+    /// 等到下一拍, 第一拍立即返回; 任务已停止时返回 false
+    /// * 工作耗时超过周期时不补拍, 直接从当前时间重新计时
+    pub fn tick(&mut self) -> bool {
+        let (work, deadline, overrun) = match self.next {
+            None => (None, Instant::now(), false),
+            Some((started, deadline)) => {
+                let now = Instant::now();
+                let overrun = now > deadline;
+                if !overrun && !self.scheduler.token.sleep_until(deadline) {
+                    return false;
+                }
+                (
+                    Some(now - started),
+                    if overrun { now } else { deadline },
+                    overrun,
+                )
+            }
+        };
+        if !self.scheduler.is_running() {
+            return false;
+        }
+        let started = Instant::now();
+        let late = started.saturating_duration_since(deadline);
+        self.scheduler.record(self.phase, work, late, overrun);
+        self.next = Some((started, deadline + self.period));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn test_ticker_keeps_rate_without_accumulating_work() {
+        let scheduler = Scheduler::new(CancellationToken::new());
+        let mut ticker = scheduler.ticker_with_rate(Phase::QteWait, 50.0);
+        let start = Instant::now();
+        for _ in 0..6 {
+            assert!(ticker.tick());
+            // 模拟检测耗时, 小于周期时不影响节拍
+            sleep(Duration::from_millis(15));
+        }
+        // 5 个周期 100ms, 固定 sleep 时会是 5 * (20 + 15) = 175ms
+        let elapsed = start.elapsed();
+        assert!(elapsed < Duration::from_millis(150), "{:?}", elapsed);
+        let stats = scheduler.stats()[&Phase::QteWait];
+        assert_eq!(stats.ticks, 6);
+        assert_eq!(stats.overruns, 0);
+        assert!(stats.max_work >= Duration::from_millis(15));
+    }
+
+    #[test]
+    fn test_overrun_is_counted() {
+        let scheduler = Scheduler::new(CancellationToken::new());
+        let mut ticker = scheduler.ticker_with_rate(Phase::Idle, 100.0);
+        assert!(ticker.tick());
+        sleep(Duration::from_millis(30));
+        assert!(ticker.tick());
+        assert_eq!(scheduler.stats()[&Phase::Idle].overruns, 1);
+    }
+
+    #[test]
+    fn test_waits_are_cancelled() {
+        let token = CancellationToken::new();
+        let scheduler = Scheduler::new(token.clone());
+        let mut ticker = scheduler.ticker_with_rate(Phase::QteWait, 0.1);
+        assert!(ticker.tick());
+        token.cancel();
+        let start = Instant::now();
+        // 下一拍在 10 秒后, 取消后立即返回
+        assert!(!ticker.tick());
+        assert!(!scheduler.wait(Duration::from_secs(10)));
+        assert!(!scheduler.ticker(Phase::Idle).tick());
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
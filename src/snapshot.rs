@@ -0,0 +1,118 @@
+//!
+//! 决策快照: 主循环执行操作 (改变下注/按回车/刷新窗口) 时保存整个客户区
+//!
+//! * 画出所有区域, 每个区域写上单帧分数和阈值, 左上角写操作和主循环状态
+//! * 打分, 标注和写盘都在写入线程中进行, 主循环只截图入队
+//! * 图片路径附在触发快照的日志后面
+//! * 保存到 snapshots/<会话 id>/<时间>_<操作>.png, 报告按目录取会话的快照
+//!
+use crate::analysis::{RegionScore, annotate_regions};
+use crate::capture_settings::CapturePos;
+use crate::detector::{Detectors, Screenshot, ShotSource};
+use crate::image_writer::{ImageWriter, WriteJob};
+use log::error;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub(crate) static SNAPSHOT_DIR: &str = "snapshots";
+
+/// 写入队列长度, 快照只在执行操作时产生, 不需要很长
+static WRITE_QUEUE: usize = 16;
+
+pub struct Snapshots {
+    dir: PathBuf,
+    /// 客户区在屏幕上的位置, 截图后按客户区坐标打分
+    client_rect: (i32, i32, i32, i32),
+    /// 偏移为 0 的检测器, 只用于单帧打分
+    detectors: Arc<Detectors>,
+    writer: ImageWriter,
+}
+
+impl Snapshots {
+    /// 快照保存在 dir/<session> 下
+    pub fn new<P: AsRef<Path>>(dir: P, session: &str, client_rect: (i32, i32, i32, i32)) -> Self {
+        Self {
+            dir: dir.as_ref().join(session),
+            client_rect,
+            detectors: Arc::new(Detectors::new(0, 0)),
+            writer: ImageWriter::spawn(WRITE_QUEUE),
+        }
+    }
+
+    /// 截取客户区并入队, 返回图片路径; 截图失败时只写日志, 返回 None
+    /// * action: 触发快照的操作, 也用于文件名
+    /// * state: 主循环状态, 写在标题中
+    pub fn take(&self, source: &dyn ShotSource, action: &str, state: &str) -> Option<PathBuf> {
+        let img = match source.grab_shot(&CapturePos {
+            rect: self.client_rect,
+        }) {
+            Ok(img) => img,
+            Err(e) => {
+                error!("Failed to grab snapshot for {}: {}", action, e);
+                return None;
+            }
+        };
+        let now = chrono::Local::now();
+        let path = self.dir.join(format!(
+            "{}_{}.png",
+            now.format("%Y%m%d%H%M%S%.3f"),
+            action.replace(' ', "_")
+        ));
+        let caption = format!("{} | {} | {}", action, state, now.format("%H:%M:%S%.3f"));
+        let detectors = self.detectors.clone();
+        self.writer
+            .submit(WriteJob::png(&path, &img).render(move |img| {
+                let source = Screenshot { img: img.clone() };
+                let regions = RegionScore::score_all(&source, &detectors);
+                annotate_regions(img, &regions, Some(&caption))
+            }));
+        Some(path)
+    }
+
+    /// 附在日志后面的快照路径, 没有快照时为空
+    pub fn link(path: Option<PathBuf>) -> String {
+        path.map(|path| format!(" [snapshot: {}]", path.display()))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vision::{Image, ReadMode};
+
+    #[test]
+    fn test_snapshot_is_annotated_in_background() {
+        let dir = std::env::temp_dir().join(format!("rappy_snapshot_{}", std::process::id()));
+        let img = Image::load("test_data/target.jpg", ReadMode::Unchanged).unwrap();
+        let client_rect = (0, 0, img.width(), img.height());
+        let source = Screenshot { img: img.clone() };
+        let snapshots = Snapshots::new(&dir, "s1", client_rect);
+        let path = snapshots
+            .take(
+                &source,
+                "press enter",
+                "bet_coin_is_one: true, burst: false",
+            )
+            .unwrap();
+        assert!(path.starts_with(dir.join("s1")));
+        assert!(path.to_string_lossy().ends_with("_press_enter.png"));
+        assert!(Snapshots::link(Some(path.clone())).contains("snapshot: "));
+        assert_eq!(Snapshots::link(None), "");
+        snapshots.writer.flush();
+
+        let saved = Image::load(&path, ReadMode::Unchanged).unwrap();
+        assert_eq!((saved.width(), saved.height()), (img.width(), img.height()));
+        // 左上角是标题的黑色背景
+        assert_eq!(
+            [saved.at(0, 0, 0), saved.at(0, 0, 1), saved.at(0, 0, 2)],
+            [0, 0, 0]
+        );
+
+        // 截图超出画面时不入队
+        let small = Snapshots::new(&dir, "s1", (0, 0, img.width() + 1, img.height()));
+        assert!(small.take(&source, "refresh window", "").is_none());
+        drop(snapshots);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
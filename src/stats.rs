@@ -0,0 +1,644 @@
+//!
+//! 会话统计: 主循环在各个操作处发出事件, 这里累计次数和下注为 1/5 枚的时长
+//!
+//! * 一次会话从点击开始 (或命令行 run) 到任务结束, 自动重启不会清零, 重新开始时 reset
+//! * 时长只计工作线程运行的时间, 重启之间的等待不计入
+//! * snapshot() 随时可取, 界面和命令行用同样的 Display 格式显示
+//! * 工作线程结束时保存到 stats/<session>.json, 同一会话多次保存会覆盖
+//! * 附加了 [`History`] 时, 事件同时交给后台线程写入数据库, 记录事件不等待 SQLite
+//! * 本次会话的每一局和每个事件也保存在内存中 (各最多 [`LIVE_LIMIT`] 条), 用于导出
+//!
+use crate::error::{Result, ResultExt};
+use crate::history::{EventRecord, History, RoundRecord, RoundTracker};
+use crate::latency::{LatencyStats, Percentiles, QteTiming};
+use crate::scheduler::Phase;
+use log::error;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+pub(crate) static STATS_DIR: &str = "stats";
+
+/// 内存中保留的局数和事件数上限, 超出时丢弃最早的
+pub(crate) const LIVE_LIMIT: usize = 100_000;
+
+/// 主循环发出的事件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// 工作线程开始检测, 开始计时
+    WorkerStarted,
+    /// 工作线程结束, 停止计时
+    WorkerStopped,
+    /// 主循环按回车开始一局
+    RoundStarted,
+    /// QTE 出现后按下回车, latency 为命中帧开始截图到按键发出
+    QteHit {
+        latency: Duration,
+    },
+    /// 检测到当前下注数, one 为 true 时是 1 枚
+    BetObserved {
+        one: bool,
+    },
+    /// 能量 4 格时加注或能量 0 格时减注
+    BetChanged {
+        one: bool,
+    },
+    EnergyFour,
+    EnergyZero,
+    TargetSeen,
+    /// 连续加注仍未到 5 枚, 判断为 PSE burst
+    BurstStarted,
+    WindowRefreshed,
+    /// 某个阶段等待超时, QteWait 超时即 QTE 超时
+    Timeout(Phase),
+}
+
+impl Event {
+    /// 导出和数据库中使用的事件名
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::WorkerStarted => "worker_started",
+            Event::WorkerStopped => "worker_stopped",
+            Event::RoundStarted => "round_started",
+            Event::QteHit { .. } => "qte_hit",
+            Event::BetObserved { .. } => "bet_observed",
+            Event::BetChanged { .. } => "bet_changed",
+            Event::EnergyFour => "energy_four",
+            Event::EnergyZero => "energy_zero",
+            Event::TargetSeen => "target_seen",
+            Event::BurstStarted => "burst_started",
+            Event::WindowRefreshed => "window_refreshed",
+            Event::Timeout(_) => "timeout",
+        }
+    }
+}
+
+/// 某一时刻的统计, 时长单位为秒
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StatsSnapshot {
+    pub session: String,
+    /// 会话开始时间 (RFC 3339)
+    pub started: String,
+    /// 工作线程运行的总时长
+    pub running_secs: f64,
+    pub rounds: u64,
+    /// 开局和 QTE 的回车都计入
+    pub enter_presses: u64,
+    pub bet_changes: u64,
+    pub bet_one_secs: f64,
+    pub bet_five_secs: f64,
+    pub energy_four: u64,
+    pub energy_zero: u64,
+    pub target_sightings: u64,
+    pub qte_hits: u64,
+    pub qte_timeouts: u64,
+    pub bursts: u64,
+    pub window_refreshes: u64,
+    /// 按阶段 (idle/qte_wait) 统计的超时次数
+    pub timeouts: BTreeMap<String, u64>,
+    /// 最近的 QTE 各阶段 (capture/detection/input/total/worst case) 耗时的分位数, 没有 QTE 时为空
+    pub qte_latency: Vec<(String, Percentiles)>,
+}
+
+/// 1h02m03s / 2m03s / 3s
+pub(crate) fn hms(secs: f64) -> String {
+    let secs = secs.max(0.0) as u64;
+    match (secs / 3600, secs / 60 % 60, secs % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m{:02}s", m, s),
+        (h, m, s) => format!("{}h{:02}m{:02}s", h, m, s),
+    }
+}
+
+impl fmt::Display for StatsSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timeouts = self
+            .timeouts
+            .iter()
+            .map(|(phase, count)| format!("{} {}", phase, count))
+            .collect::<Vec<_>>();
+        writeln!(
+            f,
+            "Session {}, running {}",
+            self.session,
+            hms(self.running_secs)
+        )?;
+        writeln!(
+            f,
+            "rounds: {}, enter presses: {}, bet changes: {}",
+            self.rounds, self.enter_presses, self.bet_changes
+        )?;
+        writeln!(
+            f,
+            "time at bet 1: {}, bet 5: {}",
+            hms(self.bet_one_secs),
+            hms(self.bet_five_secs)
+        )?;
+        writeln!(
+            f,
+            "energy four: {}, energy zero: {}, target sightings: {}",
+            self.energy_four, self.energy_zero, self.target_sightings
+        )?;
+        writeln!(
+            f,
+            "QTE hits: {}, QTE timeouts: {}, bursts: {}",
+            self.qte_hits, self.qte_timeouts, self.bursts
+        )?;
+        write!(
+            f,
+            "window refreshes: {}, timeouts: {}",
+            self.window_refreshes,
+            if timeouts.is_empty() {
+                "none".to_string()
+            } else {
+                timeouts.join(", ")
+            }
+        )?;
+        for (stage, percentiles) in &self.qte_latency {
+            write!(f, "\nQTE {} latency: {}", stage, percentiles)?;
+        }
+        Ok(())
+    }
+}
+
+struct State {
+    /// 计数部分, 时长在 snapshot 时计算
+    counts: StatsSnapshot,
+    /// 本次工作线程开始计时的时间
+    running_since: Option<Instant>,
+    running: Duration,
+    /// 当前下注数 (是否为 1 枚) 和开始计时的时间
+    bet: Option<(bool, Instant)>,
+    bet_one: Duration,
+    bet_five: Duration,
+    timeouts: BTreeMap<Phase, u64>,
+    latency: LatencyStats,
+    tracker: RoundTracker,
+    /// 本次会话已结束的局和所有事件
+    rounds: VecDeque<RoundRecord>,
+    events: VecDeque<EventRecord>,
+    /// reset 时保留
+    history: Option<HistoryWriter>,
+}
+
+impl State {
+    fn new() -> Self {
+        let now = chrono::Local::now();
+        Self {
+            counts: StatsSnapshot {
+                session: format!("{}-{}", now.format("%Y%m%d%H%M%S"), std::process::id()),
+                started: now.to_rfc3339(),
+                ..Default::default()
+            },
+            running_since: None,
+            running: Duration::ZERO,
+            bet: None,
+            bet_one: Duration::ZERO,
+            bet_five: Duration::ZERO,
+            timeouts: BTreeMap::new(),
+            latency: LatencyStats::default(),
+            tracker: RoundTracker::default(),
+            rounds: VecDeque::new(),
+            events: VecDeque::new(),
+            history: None,
+        }
+    }
+
+    /// 结束当前下注数的计时
+    fn close_bet(&mut self, now: Instant) {
+        if let Some((one, since)) = self.bet.take() {
+            let spent = now.saturating_duration_since(since);
+            if one {
+                self.bet_one += spent;
+            } else {
+                self.bet_five += spent;
+            }
+        }
+    }
+
+    fn set_bet(&mut self, one: bool, now: Instant) {
+        if self.running_since.is_none() || self.bet.is_some_and(|(current, _)| current == one) {
+            return;
+        }
+        self.close_bet(now);
+        self.bet = Some((one, now));
+    }
+
+    fn record(&mut self, event: Event, now: Instant) {
+        let counts = &mut self.counts;
+        match event {
+            Event::WorkerStarted => {
+                self.running_since.get_or_insert(now);
+            }
+            Event::WorkerStopped => {
+                self.close_bet(now);
+                if let Some(since) = self.running_since.take() {
+                    self.running += now.saturating_duration_since(since);
+                }
+            }
+            Event::RoundStarted => {
+                counts.rounds += 1;
+                counts.enter_presses += 1;
+            }
+            Event::QteHit { .. } => {
+                counts.qte_hits += 1;
+                counts.enter_presses += 1;
+            }
+            Event::BetObserved { one } => self.set_bet(one, now),
+            Event::BetChanged { one } => {
+                counts.bet_changes += 1;
+                self.set_bet(one, now);
+            }
+            Event::EnergyFour => counts.energy_four += 1,
+            Event::EnergyZero => counts.energy_zero += 1,
+            Event::TargetSeen => counts.target_sightings += 1,
+            Event::BurstStarted => counts.bursts += 1,
+            Event::WindowRefreshed => counts.window_refreshes += 1,
+            Event::Timeout(phase) => {
+                if phase == Phase::QteWait {
+                    counts.qte_timeouts += 1;
+                }
+                *self.timeouts.entry(phase).or_default() += 1;
+            }
+        }
+        let session = self.counts.session.clone();
+        let round = self.tracker.record(event, &session);
+        let record = EventRecord::new(&session, event);
+        if let Some(history) = &self.history {
+            // 只有这几个事件更新 sessions 表, 其余事件不生成快照
+            let snapshot = matches!(
+                event,
+                Event::WorkerStarted | Event::RoundStarted | Event::WorkerStopped
+            )
+            .then(|| self.snapshot(now));
+            history.submit(HistoryJob::Save(Box::new(PendingSave {
+                event,
+                snapshot,
+                round: round.clone(),
+                record: record.clone(),
+            })));
+        }
+        push(&mut self.events, record);
+        if let Some(round) = round {
+            push(&mut self.rounds, round);
+        }
+    }
+
+    fn snapshot(&self, now: Instant) -> StatsSnapshot {
+        let since = |since: Instant| now.saturating_duration_since(since);
+        let running = self.running + self.running_since.map_or(Duration::ZERO, since);
+        let (mut bet_one, mut bet_five) = (self.bet_one, self.bet_five);
+        match self.bet {
+            Some((true, start)) => bet_one += since(start),
+            Some((false, start)) => bet_five += since(start),
+            None => {}
+        }
+        StatsSnapshot {
+            running_secs: running.as_secs_f64(),
+            bet_one_secs: bet_one.as_secs_f64(),
+            bet_five_secs: bet_five.as_secs_f64(),
+            timeouts: self
+                .timeouts
+                .iter()
+                .map(|(phase, count)| (phase.to_string(), *count))
+                .collect(),
+            qte_latency: self
+                .latency
+                .report()
+                .into_iter()
+                .map(|(stage, percentiles)| (stage.to_string(), percentiles))
+                .collect(),
+            ..self.counts.clone()
+        }
+    }
+}
+
+fn push<T>(queue: &mut VecDeque<T>, item: T) {
+    if queue.len() >= LIVE_LIMIT {
+        queue.pop_front();
+    }
+    queue.push_back(item);
+}
+
+/// 会话开始, 每局开始和结束时更新会话, 之后写入结束的局和事件 (rounds/events 引用 sessions)
+fn save(
+    history: &History,
+    event: Event,
+    snapshot: Option<&StatsSnapshot>,
+    round: Option<&RoundRecord>,
+    record: &EventRecord,
+) -> Result<()> {
+    if let Some(snapshot) = snapshot {
+        history.save_session(snapshot, event == Event::WorkerStopped)?;
+    }
+    if let Some(round) = round {
+        history.insert_round(round)?;
+    }
+    history.insert_event(record)
+}
+
+/// 一个事件要写入的内容, 见 [`save`]
+struct PendingSave {
+    event: Event,
+    /// 会话开始, 每局开始和工作线程结束时的统计
+    snapshot: Option<StatsSnapshot>,
+    round: Option<RoundRecord>,
+    record: EventRecord,
+}
+
+enum HistoryJob {
+    Save(Box<PendingSave>),
+    /// 之前入队的记录都写完后回复
+    Flush(Sender<()>),
+}
+
+/// 写数据库的后台线程, 按入队顺序写入
+/// * drop 时写完已入队的记录再退出
+struct HistoryWriter {
+    tx: Option<Sender<HistoryJob>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl HistoryWriter {
+    fn spawn(history: History) -> Option<Self> {
+        let (tx, rx) = mpsc::channel::<HistoryJob>();
+        let handle = std::thread::Builder::new()
+            .name("history-writer".to_string())
+            .spawn(move || {
+                for job in rx {
+                    match job {
+                        HistoryJob::Save(job) => {
+                            let PendingSave {
+                                event,
+                                snapshot,
+                                round,
+                                record,
+                            } = *job;
+                            if let Err(e) =
+                                save(&history, event, snapshot.as_ref(), round.as_ref(), &record)
+                            {
+                                error!("Failed to write history: {}", e);
+                            }
+                        }
+                        HistoryJob::Flush(done) => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })
+            .map_err(|e| error!("Failed to spawn history writer: {}", e))
+            .ok()?;
+        Some(Self {
+            tx: Some(tx),
+            handle: Some(handle),
+        })
+    }
+
+    fn submit(&self, job: HistoryJob) {
+        if let Some(tx) = &self.tx {
+            let _ = tx.send(job);
+        }
+    }
+}
+
+impl Drop for HistoryWriter {
+    fn drop(&mut self) {
+        // 关闭通道, 写入线程处理完剩余的记录后退出
+        self.tx.take();
+        if let Some(handle) = self.handle.take()
+            && handle.join().is_err()
+        {
+            error!("History writer thread panicked");
+        }
+    }
+}
+
+/// 一次会话的统计, 工作线程记录, 界面线程读取
+pub struct SessionStats {
+    state: Mutex<State>,
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionStats {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State::new()),
+        }
+    }
+
+    /// 事件同时在后台线程写入历史数据库
+    pub fn with_history(self, history: Option<History>) -> Self {
+        self.lock().history = history.and_then(HistoryWriter::spawn);
+        self
+    }
+
+    /// 等待已记录的事件全部写入历史数据库
+    pub fn flush_history(&self) {
+        let (done, wait) = mpsc::channel();
+        match &self.lock().history {
+            Some(history) => history.submit(HistoryJob::Flush(done)),
+            None => return,
+        }
+        let _ = wait.recv();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 清零并开始新的会话
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        let history = state.history.take();
+        *state = State::new();
+        state.history = history;
+    }
+
+    pub fn record(&self, event: Event) {
+        self.lock().record(event, Instant::now());
+    }
+
+    pub fn snapshot(&self) -> StatsSnapshot {
+        self.lock().snapshot(Instant::now())
+    }
+
+    /// 当前会话的 id, 数据集和决策快照用它关联到会话
+    pub fn session(&self) -> String {
+        self.lock().counts.session.clone()
+    }
+
+    /// QTE 各阶段的耗时, 与 QteHit 事件一同记录, 分位数在 snapshot 中
+    pub fn record_qte_timing(&self, timing: QteTiming) {
+        self.lock().latency.push(timing);
+    }
+
+    /// 本次会话已结束的局, 按时间顺序
+    pub fn rounds(&self) -> Vec<RoundRecord> {
+        self.lock().rounds.iter().cloned().collect()
+    }
+
+    /// 本次会话的事件, 按时间顺序
+    pub fn events(&self) -> Vec<EventRecord> {
+        self.lock().events.iter().cloned().collect()
+    }
+
+    /// 把当前统计写入 dir/<session>.json, 返回文件路径
+    pub fn persist<P: AsRef<Path>>(&self, dir: P) -> Result<PathBuf> {
+        let snapshot = self.snapshot();
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).context(dir.display())?;
+        let path = dir.join(format!("{}.json", snapshot.session));
+        let json = serde_json::to_string_pretty(&snapshot).unwrap_or_default();
+        std::fs::write(&path, json).context(path.display())?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latency::FrameStamps;
+
+    fn secs(v: u64) -> Duration {
+        Duration::from_secs(v)
+    }
+
+    #[test]
+    fn test_counts_and_bet_time() {
+        let base = Instant::now();
+        let mut state = State::new();
+        // 开始计时前的下注不计时
+        state.record(Event::BetObserved { one: true }, base);
+        state.record(Event::WorkerStarted, base);
+        state.record(Event::BetObserved { one: true }, base);
+        state.record(Event::RoundStarted, base + secs(1));
+        state.record(Event::EnergyFour, base + secs(10));
+        state.record(Event::BetChanged { one: false }, base + secs(10));
+        state.record(Event::BurstStarted, base + secs(10));
+        state.record(Event::TargetSeen, base + secs(12));
+        state.record(
+            Event::QteHit {
+                latency: Duration::from_millis(20),
+            },
+            base + secs(15),
+        );
+        state.record(Event::Timeout(Phase::QteWait), base + secs(20));
+        state.record(Event::Timeout(Phase::Idle), base + secs(25));
+        state.record(Event::EnergyZero, base + secs(30));
+        state.record(Event::BetChanged { one: true }, base + secs(30));
+        state.record(Event::WindowRefreshed, base + secs(35));
+
+        let snapshot = state.snapshot(base + secs(40));
+        assert_eq!(snapshot.running_secs, 40.0);
+        assert_eq!(
+            (snapshot.bet_one_secs, snapshot.bet_five_secs),
+            (20.0, 20.0)
+        );
+        assert_eq!((snapshot.rounds, snapshot.enter_presses), (1, 2));
+        assert_eq!((snapshot.bet_changes, snapshot.bursts), (2, 1));
+        assert_eq!((snapshot.energy_four, snapshot.energy_zero), (1, 1));
+        assert_eq!((snapshot.target_sightings, snapshot.qte_hits), (1, 1));
+        assert_eq!((snapshot.qte_timeouts, snapshot.window_refreshes), (1, 1));
+        assert_eq!(
+            snapshot.timeouts,
+            BTreeMap::from([("idle".to_string(), 1), ("qte_wait".to_string(), 1)])
+        );
+
+        // 停止后不再计时, 重启后继续累计
+        state.record(Event::WorkerStopped, base + secs(40));
+        state.record(Event::WorkerStarted, base + secs(100));
+        state.record(Event::BetObserved { one: false }, base + secs(100));
+        let snapshot = state.snapshot(base + secs(105));
+        assert_eq!(snapshot.running_secs, 45.0);
+        assert_eq!(
+            (snapshot.bet_one_secs, snapshot.bet_five_secs),
+            (20.0, 25.0)
+        );
+        assert!(
+            snapshot
+                .to_string()
+                .contains("time at bet 1: 20s, bet 5: 25s")
+        );
+        assert_eq!(hms(3723.0), "1h02m03s");
+    }
+
+    #[test]
+    fn test_reset_and_persist() {
+        let dir = std::env::temp_dir().join(format!("rappy_stats_{}", std::process::id()));
+        let stats = SessionStats::new();
+        stats.record(Event::WorkerStarted);
+        stats.record(Event::RoundStarted);
+        let start = Instant::now();
+        stats.record_qte_timing(QteTiming {
+            last_miss: None,
+            frame: FrameStamps {
+                grab_start: start,
+                frame_acquired: start + Duration::from_millis(5),
+                detected: start + Duration::from_millis(8),
+            },
+            key_sent: start + Duration::from_millis(10),
+        });
+        assert!(
+            stats
+                .snapshot()
+                .to_string()
+                .contains("QTE total latency: n: 1, p50: 10ms")
+        );
+        let path = stats.persist(&dir).unwrap();
+        let saved: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved["rounds"], 1);
+        assert_eq!(saved["qte_latency"][3][0], "total");
+        assert_eq!(saved["qte_latency"][3][1]["p99"], 10.0);
+        assert_eq!(saved["session"], stats.snapshot().session.as_str());
+        assert_eq!(stats.events().len(), 2);
+        assert!(stats.rounds().is_empty());
+        stats.record(Event::WorkerStopped);
+        assert_eq!(stats.rounds()[0].outcome, "stopped");
+
+        stats.reset();
+        let snapshot = stats.snapshot();
+        assert_eq!((snapshot.rounds, snapshot.running_secs), (0, 0.0));
+        assert!(snapshot.qte_latency.is_empty());
+        assert!(stats.events().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_history_written_in_background() {
+        let path = std::env::temp_dir().join(format!("rappy_stats_{}.sqlite3", std::process::id()));
+        let stats = SessionStats::new().with_history(Some(History::open(&path).unwrap()));
+        for event in [
+            Event::WorkerStarted,
+            Event::RoundStarted,
+            Event::WorkerStopped,
+        ] {
+            stats.record(event);
+        }
+        stats.flush_history();
+        let session = stats.snapshot().session;
+        let history = History::open(&path).unwrap();
+        assert_eq!(history.events(None, Some(&session)).unwrap().len(), 3);
+        assert_eq!(history.rounds(None, Some(&session)).unwrap().len(), 1);
+        assert_eq!(history.last_days(1).unwrap().total().sessions, 1);
+
+        // drop 时写完剩余的记录
+        stats.record(Event::WorkerStarted);
+        drop(stats);
+        assert_eq!(history.events(None, Some(&session)).unwrap().len(), 4);
+        drop(history);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use crate::cancel::CancellationToken;
+use log::{error, info};
+use std::any::Any;
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// 工作线程的状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerStatus {
+    Idle,
+    /// 线程已启动 (或等待重启), 尚未进入主循环
+    Starting,
+    Running,
+    /// 已请求取消, 等待线程退出
+    Stopping,
+    Crashed {
+        reason: String,
+    },
+}
+
+impl fmt::Display for WorkerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkerStatus::Idle => write!(f, "Idle"),
+            WorkerStatus::Starting => write!(f, "Starting"),
+            WorkerStatus::Running => write!(f, "Running"),
+            WorkerStatus::Stopping => write!(f, "Stopping"),
+            WorkerStatus::Crashed { reason } => write!(f, "Crashed: {}", reason),
+        }
+    }
+}
+
+/// 工作函数的失败原因, 只有 Recoverable 会按 RestartPolicy 重启, panic 视为 Fatal
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerError {
+    /// 例如窗口暂时找不到, 截图初始化失败
+    Recoverable(String),
+    Fatal(String),
+}
+
+impl fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkerError::Recoverable(reason) => write!(f, "{}", reason),
+            WorkerError::Fatal(reason) => write!(f, "{} (fatal)", reason),
+        }
+    }
+}
+
+/// 可恢复错误的重启策略, 第 n 次重启前等待 initial_backoff * 2^(n-1), 最多 max_backoff
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RestartPolicy {
+    pub max_restarts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RestartPolicy {
+    pub const NEVER: RestartPolicy = RestartPolicy {
+        max_restarts: 0,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+
+    pub fn backoff(&self, restart: u32) -> Duration {
+        let factor = 2u32.saturating_pow(restart.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+#[derive(Debug)]
+struct Shared {
+    status: WorkerStatus,
+    last_error: Option<String>,
+    restarts: u32,
+}
+
+/// 传给工作函数, 用于检查取消和报告已进入主循环
+pub struct Worker {
+    token: CancellationToken,
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Worker {
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// 初始化完成 (找到窗口, 截图就绪) 后调用
+    pub fn set_running(&self) {
+        let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        if shared.status == WorkerStatus::Starting {
+            shared.status = WorkerStatus::Running;
+        }
+    }
+
+    fn set_status(&self, status: WorkerStatus) {
+        self.shared.lock().unwrap_or_else(|e| e.into_inner()).status = status;
+    }
+}
+
+/// 持有工作线程, 负责启动/停止/重启并记录状态和最后一次错误
+pub struct Supervisor {
+    shared: Arc<Mutex<Shared>>,
+    task: Option<(CancellationToken, JoinHandle<()>)>,
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Mutex::new(Shared {
+                status: WorkerStatus::Idle,
+                last_error: None,
+                restarts: 0,
+            })),
+            task: None,
+        }
+    }
+
+    /// 启动工作线程, 上一个线程还没结束时返回 false
+    pub fn start<F>(&mut self, policy: RestartPolicy, work: F) -> bool
+    where
+        F: Fn(&Worker) -> Result<(), WorkerError> + Send + 'static,
+    {
+        self.reap();
+        if self.task.is_some() {
+            return false;
+        }
+        {
+            let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.status = WorkerStatus::Starting;
+            shared.last_error = None;
+            shared.restarts = 0;
+        }
+        let token = CancellationToken::new();
+        let worker = Worker {
+            token: token.clone(),
+            shared: self.shared.clone(),
+        };
+        let handle = thread::spawn(move || supervise(&worker, policy, work));
+        self.task = Some((token, handle));
+        true
+    }
+
+    /// 请求停止, 不等待线程退出
+    pub fn stop(&self) {
+        if let Some((token, _)) = &self.task {
+            token.cancel();
+            let mut shared = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            if matches!(
+                shared.status,
+                WorkerStatus::Starting | WorkerStatus::Running
+            ) {
+                shared.status = WorkerStatus::Stopping;
+            }
+        }
+    }
+
+    /// 线程已结束时 join 并释放, 之后才能再次 start
+    pub fn reap(&mut self) {
+        if self
+            .task
+            .as_ref()
+            .is_some_and(|(_, handle)| handle.is_finished())
+        {
+            self.join();
+        }
+    }
+
+    /// 等待线程退出
+    pub fn join(&mut self) {
+        if let Some((_, handle)) = self.task.take()
+            && handle.join().is_err()
+        {
+            error!("Failed to join worker thread");
+        }
+    }
+
+    /// 线程还没有被 join (包括正在停止)
+    pub fn is_active(&self) -> bool {
+        self.task.is_some()
+    }
+
+    pub fn status(&self) -> WorkerStatus {
+        self.shared
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .status
+            .clone()
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.shared
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .last_error
+            .clone()
+    }
+
+    /// 本次 start 之后的重启次数
+    pub fn restarts(&self) -> u32 {
+        self.shared
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .restarts
+    }
+}
+
+impl Default for Supervisor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// panic 的 payload 一般是 &str 或 String
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Unknown panic".to_string()
+    }
+}
+
+fn supervise<F>(worker: &Worker, policy: RestartPolicy, work: F)
+where
+    F: Fn(&Worker) -> Result<(), WorkerError>,
+{
+    let mut restarts = 0;
+    loop {
+        let error = match catch_unwind(AssertUnwindSafe(|| work(worker))) {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(e),
+            Err(payload) => Some(WorkerError::Fatal(format!(
+                "panic: {}",
+                panic_message(payload.as_ref())
+            ))),
+        };
+        let Some(error) = error else {
+            info!("Worker finished.");
+            worker.set_status(WorkerStatus::Idle);
+            return;
+        };
+        error!("Worker failed: {}", error);
+        let reason = error.to_string();
+        worker
+            .shared
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .last_error = Some(reason.clone());
+        if worker.token.is_cancelled() {
+            worker.set_status(WorkerStatus::Idle);
+            return;
+        }
+        if !matches!(error, WorkerError::Recoverable(_)) || restarts >= policy.max_restarts {
+            worker.set_status(WorkerStatus::Crashed { reason });
+            return;
+        }
+        restarts += 1;
+        let backoff = policy.backoff(restarts);
+        info!(
+            "Restarting worker ({}/{}) in {:?}.",
+            restarts, policy.max_restarts, backoff
+        );
+        {
+            let mut shared = worker.shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.status = WorkerStatus::Starting;
+            shared.restarts = restarts;
+        }
+        if !worker.token.sleep(backoff) {
+            worker.set_status(WorkerStatus::Idle);
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static FAST_RESTART: RestartPolicy = RestartPolicy {
+        max_restarts: 2,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+    };
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let policy = RestartPolicy {
+            max_restarts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        let backoff: Vec<u64> = (1..=5).map(|n| policy.backoff(n).as_secs()).collect();
+        assert_eq!(backoff, vec![1, 2, 4, 5, 5]);
+    }
+
+    #[test]
+    fn test_recoverable_errors_restart_then_crash() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let mut supervisor = Supervisor::new();
+        let counter = attempts.clone();
+        assert!(supervisor.start(FAST_RESTART, move |_| {
+            let n = counter.fetch_add(1, Ordering::Relaxed) + 1;
+            Err(WorkerError::Recoverable(format!("window not found #{}", n)))
+        }));
+        supervisor.join();
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+        assert_eq!(supervisor.restarts(), 2);
+        assert_eq!(
+            supervisor.status(),
+            WorkerStatus::Crashed {
+                reason: "window not found #3".to_string()
+            }
+        );
+        assert_eq!(
+            supervisor.last_error().as_deref(),
+            Some("window not found #3")
+        );
+
+        // 恢复后正常结束
+        let counter = Arc::new(AtomicU32::new(0));
+        assert!(supervisor.start(FAST_RESTART, move |worker| {
+            if counter.fetch_add(1, Ordering::Relaxed) == 0 {
+                return Err(WorkerError::Recoverable("capture lost".to_string()));
+            }
+            worker.set_running();
+            Ok(())
+        }));
+        supervisor.join();
+        assert_eq!(supervisor.status(), WorkerStatus::Idle);
+        assert_eq!(supervisor.restarts(), 1);
+        assert_eq!(supervisor.last_error().as_deref(), Some("capture lost"));
+    }
+
+    #[test]
+    fn test_panic_and_fatal_errors_are_not_restarted() {
+        let mut supervisor = Supervisor::new();
+        supervisor.start(FAST_RESTART, |_| panic!("boom"));
+        supervisor.join();
+        assert_eq!(
+            supervisor.status(),
+            WorkerStatus::Crashed {
+                reason: "panic: boom (fatal)".to_string()
+            }
+        );
+        supervisor.start(FAST_RESTART, |_| Err(WorkerError::Fatal("bad".to_string())));
+        supervisor.join();
+        assert_eq!(supervisor.restarts(), 0);
+    }
+
+    #[test]
+    fn test_panicking_worker_is_reported_as_crashed() {
+        let mut supervisor = Supervisor::new();
+        supervisor.start(FAST_RESTART, |worker| {
+            worker.set_running();
+            panic!("index out of bounds");
+        });
+        supervisor.join();
+        assert_eq!(
+            supervisor.status(),
+            WorkerStatus::Crashed {
+                reason: "panic: index out of bounds (fatal)".to_string()
+            }
+        );
+        assert!(!supervisor.is_active());
+        assert!(supervisor.last_error().unwrap().contains("index out of bounds"));
+    }
+
+    #[test]
+    fn test_stop_cancels_running_worker() {
+        let mut supervisor = Supervisor::new();
+        supervisor.start(RestartPolicy::NEVER, |worker| {
+            worker.set_running();
+            while worker.token().sleep(Duration::from_millis(10)) {}
+            Ok(())
+        });
+        while supervisor.status() != WorkerStatus::Running {
+            thread::sleep(Duration::from_millis(1));
+        }
+        // 运行中不能再次启动
+        assert!(!supervisor.start(RestartPolicy::NEVER, |_| Ok(())));
+        supervisor.stop();
+        assert_eq!(supervisor.status(), WorkerStatus::Stopping);
+        assert!(supervisor.is_active());
+        supervisor.join();
+        assert_eq!(supervisor.status(), WorkerStatus::Idle);
+        assert!(!supervisor.is_active());
+    }
+}
@@ -1,64 +1,138 @@
-use opencv::core::Mat;
-use opencv::error::Result;
-use opencv::imgcodecs::IMREAD_UNCHANGED;
-use opencv::imgproc::{cvt_color_def, COLOR_BGRA2GRAY};
+use crate::error::RappyError;
+use crate::vision::{Backend, Image, ImageOps, ReadMode, Result};
+use log::error;
 use std::sync::LazyLock;
 
 pub struct TemplateImg {
-    pub img:Mat,
+    /// 与截图比较的模板, 按 EMBEDDED 中的设置转为灰度
+    pub img: Image,
+    /// 未转灰度的原图 (BGR/BGRA), 用于颜色检测
+    pub color: Image,
 }
 
 impl TemplateImg {
-    fn decode(img_code: &[u8]) -> Result<Mat> {
-        let mat = opencv::imgcodecs::imdecode(&img_code, IMREAD_UNCHANGED)?;
-        Ok(mat)
+    fn decode(img_code: &[u8]) -> Result<Image> {
+        Image::decode(img_code, ReadMode::Unchanged)
     }
 
-    fn decode_gray(img_code: &[u8]) -> Result<Mat> {
-        let mat = Self::decode(img_code)?;
-        let mut dst_mat = Mat::default();
-        cvt_color_def(&mat, &mut dst_mat, COLOR_BGRA2GRAY)?;
-        Ok(dst_mat)
+    fn decode_both(img_code: &[u8], gray: bool) -> Result<Self> {
+        let color = Self::decode(img_code)?;
+        let img = if gray {
+            Backend::to_gray(&color)?
+        } else {
+            color.clone()
+        };
+        Ok(Self { img, color })
     }
 
-    pub const KEY_READY: LazyLock<Self> = LazyLock::new(|| {
-        let code_key_ready = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x15\x00\x00\x00\x0c\x08\x06\x00\x00\x00\x8d\xce\xf6\xc5\x00\x00\x02uIDAT(\x15\xa5\xc1MH\x93q\x1c\xc0\xf1\xef\xffY\n\x19\xbaY\xa2\xe9lJ\xf4F\x04\xd5M\x8c\xe8P\xa6(\xf6v\xc80\xbbX\xa0Q!\x22Y\x1d\xd22\t*z\x13\x13z\xd1\x85`/\x96\x89\xb3\x99\x14\xce\xa42foRH\xd0%}\xcc\xa4\xcd\xd6Z\xf4\xb2\xb2\xe7\xd7<\x0c\xea\xd0\xa1\xfc|\xd4I\xd7S\xf1\xf9?#\x860Y\x9aIc\xba%\nU|\xbcQ\xde\r\x0e\x81\x08a\x96d\x1bf[\x22\xffJi\nKl4j\xdc0D\xe3O\x1b\x8a\x0e\x90\x9a\x9e\xce\xff\x88\x9d\x11\x8d\x92\x10\xdf\xc7\x00Nw?a=\xcf\x07\xb1\xa4$\xf1;\xff\xf0(~]\xc7b\xb3a\x99\x95\xc8\xdfXb\xa3Q\x122\xea\xf5Qz\xb8\x1eD0\'%\xf3\xf3\xc7\x0f\xfc#\xc3\x88\xfc$69\x05P|x3H\xf3\x99\xfd48\xbap\x0f\x8c0\x1e\x0c\xf2\xf9\xbd\x17\xa5\x99\x88\x99\x99\x84\x7fD\xc7b\xb51{\xe9|\x94\x84|\r~\xc70\x0c4\xa5(\xac\xacey\xda\x22\x8a\xd6d\xa0i\x8a\x9eg\x03\xd45:\xb8rb\x1f\x82\xa0)\x85\xeb\xc9\x0b\xe6X\x13\xb0\xc6\xc7a\x18\x06\x05\xe5\xc7\xb8||/\xcd\xae^\x1e\x0fyQ\x12\xf2\xe5[\x10\xf7\x8bWL1i\xd4\xd8\xbbX\xb1l.;\xf2\xb28}\xc9II~\x0e\xad\xddn\xf4\xd11vm\xca\xe6A\xffK\xba\xfb\x06\xd8\xb7m=;\x0f\x9e\xa5\xb6\xb2\x88SM7)\xdb\xb2\x16\x14x|\x01\x94\x84\xbc\xf1\x8cQr\xb8\x91\x88\xa8iDDE\x91\xb60\x9e\xe2u\x19\xe4n\xad\xa0\xbd\xbe\n\xc7\xfd\xc7\x9c\xb7;h\xbfP\xc5\x15W/W[\xee\xd2t\xa2\x8c\xa9\x91\x91L8u\xed\x16\xa5\x1b\xb3\xa9\xbex\x03}\xec\x1bJB\xbc>?\xcdw\x1e\x12&\x02\xdb7f\x92\x9d\xbf\x87\x8eKGh\xbb\xdb\xc7\xd9s\xd7\xe9h:\x8a\xa3\xa7\x8fs\xf5m\xb46\x1c\xa4\xe6\xb2\x93\xae\xce^@\xe8h:J\xf6\xe6r\x16\xacZ\x8d\x1a7\x0c\xd1\xf8S]\xebm\x8a\xd7e\x90[XA{C\x15m\xf7\x1eq\xc1\xee\xa0\xbd\xfe\x10J)\xaa\xed-d\xa5-f\xc9\xbcT&\xd4\\\xeb\xa44/\x87\xdc\xad\xfb\x99\x9f\xb9\x12U\xb0\xbbV<\xfak\xc40\x083\'\xa4\x12\xf0\x0ec\x8e\xb7\xf1\xd1\xa3c\x8e\xb7!\x08\x01\x8f\x8e\x18\x06\xe6\x84T\x10\xe1\x93\xef-\x13\xa2\xe3\xac\x04<:\xe6\x84\x14\xac\x8b\x17\xa0*\x9c\xbd\x12\x08|E\x0ca\xb24\x93F\x8cy*\xbf\x00\xd3\xc9\x1a\x8f\xfdR\xe6\x10\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode_gray(code_key_ready).unwrap()}
-    });
-    pub const ENERGY_FOUR: LazyLock<Self> = LazyLock::new(|| {
-        let code_energy_four = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00(\x00\x00\x00,\x08\x06\x00\x00\x00\x17o\xfa{\x00\x00\x11\x9fIDATX\t\x15\xc1\t\xb0\xe6WY\xe0\xe1\xdf{\xce\xf9o\xdf~\xb7\xee\xdb\xf7vw:Mw\x9bt\'\x10\x92\x08\x196Q\xa0d\xd4\x99\xa44\x11K(\xc4\x11\xd1\n\x11\xc1\x05\x81\xca\xe02#*\x19\xa0\xa0\x80)2A)\x142\x0e\x15\xa60\x06\x22\xa6\x98@\x94*\x9b\x10\x22N\x16\x92tzOw\xdf\xbe\xcbw\xbf\xfb-\xff\xe5\x9c\xf3\n\xcf#\xe3\x91\xd1\xd1\x1a\xac?\xa1,]\xab\xe4]\xd0&\xc5\xef\xecE\xa6\xbf\x8ch\x1b\x9d\x05\xc2v\x86\x86\x11\x1a=Q*\xc4$\xc4\xd2\x838\xe2d\x06\x8dEU0\x85E\x12\xc1\xa4 \x99 Y\x0f\x11A\xe2\x1c&/A\xc7\x84\xd6w\xb0\x0b\x8f\xe0\x8a\x111F&\x17\xc1\x16\x82\x05\x9am\x85\x86\x1f\x12P\x90\xe7\x1f5Z\x8e\x94\xddG\x15c\x848>\x8c\xee\xfc,\xda,!e\x0f\x9a\x0e~\xe7$~:\x05[\x11\'5D\x0fiN\x98\xd6\x10\x1a\xfc\x851\xd1\xe7\xc4i\xc4\xcc\x15\x90\x00\x05d\x8b9\xd6FL\x96\xa1\xea\xb0\xc5.l\xd1`Z5\xb8)\xa4\'1K\xf7\x83\xa9\x98\x0cA\x1a!mA\xb9\x06Z\x83q \xcf~\xdb\xe8\xe2!E\xa3!\xac\xff\x14L^\tb\x10;\x83p\x15\xe1R\x9f\xb0v\x06Q\x18?\xf7\x1dl\x92\x10\xec6\xd2J\xf0U\x89\t\tqZ\x13cBh\xc0f\x82\xc9\x1d\x88\x92\xcc;lS\xa2AIV\x0f#\xfd.f\xa9\xc4R`\xf0hc\x91|\x1b]\xf8\x1c\xb6}\x19_B5\x12\xf26\x94\xeb\x8a6 [khl\x12\xfc\xf9[1\xcd1\xfcx\x07t\x1bL\xa0\xb8n\x05m\n\xe2\xd9\x15\xc2\xf7{T\x97\x1fcr\xee8\xb4\x143_\xe0\xc73\xfcL\x89\xaah\x10P\x8bK\x1a\xd4{\x92$\xc1.\x14\xe8\xf6\x90tp\x80\xf4\xc0a\x8aW5\xd8\xeeY\x86\xdfMI\xc7\x82\xb4{\xd8v\x97\x106\xb1\xbb\x1f\xc4\xf6\x9f\xa6\xa9\xa1\xd9\x16\xd26T\xeb\x8a\xac\x9fs\xda\x9c~\x13\xa6\xdeO\xdc\x1eCT4>C\x187\xb4\x7f\xb6\xc0\xa6\x1e\x9a\x05\xc2\xa9\x9f\xa0||\x8d\xe9cg\xf0\xee42\xe7\xa8\xc73\xc0\x82\x17\xfcN V\x90\xce\t\x18\xc5\xc5\x88\xa2\xb4W\xae%=:OrUB\xb2x\x02\x92\xd3\\\xfe\xe6\x94\xfc\xf2\x00\xe9-a[\xbb\x91V\x07\x8d\x01]\xba\x8fl\xd7\x0fhJ\xf0\xa5`E\x91\x0b\xc7oV3=D\xd8\x9e\x10/\r1\xad\x8c\xb0y\x9ah\xa7t\xdf\x04\xc6\t\x10\x11]@G\xd71\xfdj\xca\xd6\xbf>\x84m\xa7\xd4\xc1#\xea\x08\x1b\x82\xba\x06j@\x1b|\x15H\xbb0x\xc9.\x8ak^Ir\xddyp\xcf`\xec\x0e0azf\x8c\xbf\xffj\xe8\xe4\xd0\xcf\x91V\x8ei\xf7\x90\xdcbW\xff\x1e\xdb\x7f\x86j\x07\x12g\x90\x0b\xff\xef]\x1a\xc73\xe2\xce\x04\x99\x8c\xf1\x97\'h\xbdIr\xc3%:\xaf\x9cC\xf9!\xd3\xc2\x10\xa1\\\xc5\x1f\x7f\x05\xeb\xff\xf0\r\xd4\xcd\xa8\xfd\x840\x11\xfc4\xa2\x85#TJ\xea\x94X\x83I<\xf3/\xbb\x86\xee-\x03\\\xf79\xd4l!DD\x02a\xe7,\xe3{\xe7\t\xa3>ni\t\xf2\x1cu\x19\xd2oc\xfa\x9e\xf4\xe0\x971\xc5\x88\xd9\xba \xe7\xbe\xfc6\xd5\xe1\x98\xfa\xf4\x16\xfe\xd2\x08[Xl{H\xfb\xb6\xf3d\x07\x0e\x10B\x85\x15\xc0$\xe8x\x85\xf0\xed\x9f\xe4\xf2?~\x9d\xc6\xec\x10BE\xa8\x84f\xc7\xa3j!Z\x92\xb6A\x83\x12\xb5b\xfee\xd7\xd3yc\x87b\xf5\x14*\xa7A\x03\xc6\xceA\xfd\x03\xb6\xee\x1e\x10\x87\x03\xd4&\x90wp\xbb\x06 \x06\xb3\xd0\xc6-\xee\x90\x1cz\x00\xc5#\xa7?\xfbs\x1a\xd6\x87L\x1e\xdf$mGPO\xda\x16\xf2\x9b\xcf\xd3\xba~\x9e\xa8\x16c*\x88)\xb3\x7f]\xa2y\xb4\xcf\xe5\xe3/\x10\x9dbT\xa9j!4\x06-\x15\xda\x06\x13\x1b\xc4\x08\xa1\x82\x85k\x95\xfcFK\xff\xb5\x1e\xe3\xb6Ar\xac]\xa2<\xf54\x93\xff9G\xac2\xc8S\xca\x91\x90\xeci\x91\\\xb1\x08\x99\xc5u2\xd2\x17=M\xb2\xf7)\xe4\xf9\x8f\xbeN\xeb\x13\x1b4\x1b\x15)\x15Zz\xf2\xfd9\xe6\xd0\x06\x9d_l\xb0\xd9\x1c\xa8\x87\xd0\xa2|\xa6\xcd\xc6\'\x95\xd2\xcf@\xa1\x1eW4\x92\xa1X$\x80Z\xc5\x99\n\x04BH\xd1\xe8\xd9\xf7\x96\xc8\xdc\xcfxB\xdc\xc0\xa5\x0b\xe0\x85\xed{\x87\xe8\xf7\x07\xf8\xb1!:CSg\xf8\x12\xdc\xfe6n\xb5O\xbe\xb4\x84\x99\xdf \xbb\xf6\x9f\x91\xef\xbf\xe7z\r\x17F@D.\xd7\xe4\xfd@kE\t1\x90\xbfeJ~U\x82\x11\x8bH\xa4\x1e\xd5\x9c{\x9f\xa5\xbcT@\x9e\xd04\x96\x10\x95\x18\x14\x1f\xc0Y\x08\x01\xc4\nV\x1b\x94\x9a\xab?6\xa1\xb8\x22%\x86\x80K\x1d\xd5E\xcf\xf8\xae\x16\xd6e\x84\xd2S\x8f\x94J\n|\x05\xf4\x13\xf2+\xdbd\xcb\xf3$\x8b9f\xf9\x04r\xfc\x96\x83*\xb3\x86j\xbb\xa6\xbf`\xc8\x16\x02E7\x12\xc5a\x0e\x8fh\xbf\xc9a3\x0b(h\xcd\xe6#\x8e\xf3\x1f\xc9\xb0yF=54\xd1\xe3c\x84\xa8\x08`\x8b\x0c\x04|\xd9\x90\x0ff\\{O\x83),\x22@TF\xff\xc7\xa0\x8f\x15\xa87T\xa3\x80V\x86\xd1f\xc4\xb4\x1c3\x0f\xf9\xee\x8c\xde\xb1e\xa4\x95\x91\x1d0\xc8\xf1[\x0ehuv\x0c1\xb0\xeb*\xc5\xa4B\x9a)&7\xf85\xa5\xf5\xee1\xf9\x91\x02\x05D\x84r\xad\xe4\xdf\xdeY \xb3\x84f\n\xb3\x08V\x01\x01\x8d\x8aq\x0e\x0f\x88\xaf\xb9\xfa]Sv\xddlP\xc0eB\xf5B\xcd\xe4\x93mt3\xc1\xb4\x85f\x18\x88A\xd98\x1di<H\x9aB\xcb\xd0\xbdf@\xbe\xd8!\xdb\x9d!\x8f\xbcv\x8f\xfa\xed\x8a\xc1b\xa0\xe8\x80\xc9\x85|\x0e\xb4Q\x18Z\xe2\x82\xa7\xff\x81\x06\xdb1hP\x88\x9e3\xf78N}%%\x8c\x85&@\x04<\x10\x22`\x00\x11\xfa\x85\xe7\xd5_\x1ecZ9&\xb1h\xa8\x19}Q\xd0\xe3\x1d\x10 \x83\xf2\x8c\x87\x14\x86\x1b\xc2\xcez\xc4\xf5\x13lb\xb0\xab9\xf3\xc7\x96p\x83\x02\xf9\xfa\x8d\x035\x01\xac\x85\x9e\x8dt\x16![\x06S\x82\x06h\x86\x8a{M`\xf0_*\xd4X\x8cQF\xcf\xc3\xf7nO\x88j\xa8#4\n\xea\x15\xa2\xd0\xca\xa1\x0c\x91C\xaf\x8a\x1c\xbe3\x12\xc5\x91\xa4\x82\x1f\xd5l}0%\xa9S\xd4)\x10i\xb6\xa0\x1e\xc2L\x84rd(sA\x12\xc1\xb6s\x06\x87\xda\xe4Wt\x91\xfb\x8f\xf6t\xd0\x17\xfcT\x18\x14J1P\x8a9\x90\x1a4\x81\xd8@3Q:\xef\xad\xc8\x8f)DE\x83\xd0L\r6\x01\xe3,b\x1dh\xe4Gb\x88\xa8\x02\ni\xcb\x13\xa2C\x04F_\xf7T_J\xc9:\x0e1J\xa8\x94z\x13\xfc\x04J\'\xd4\xb5\xb05VH\x0ci/\xa5\xb3\xbb\xa0}(G\x1e\xb8\xa6\xaf-\'\xe4idn\x0e$\x83$\x82-@\x22D`\xe7|\xc4\xed\xf7,\xfcy\xc08\xb0y\x82\xc9r\x88\x11U\x10)@\x1c\xaa\x13T\x1b\x8cq\x10=\xa1*\x11\x9b\xd0\x8c\x1b\xd6?\xe80\x17\x1cI*\xd8.\xa8\x87z\x1b\x82\x87q0\xf8 ll\xc2\xac\x12\x8a\x81\xa5\xe8\x15\x0c\x8e:\xe4\xfe\x17\xf75\x17e0\x80^\x1fL\x0b\xcc\x10l\x01b\xa1\\\x8fp\xa0a\xfe\xfd\x01;p\xa0\x11\x82\x22i\x02\xd6\xa21\x828\xc4\xa4h\x98`\x8cEU\xd1\xa6\x86\xa0\xd8<CC\xa0<\xe9\xd9\xf9\xb8\xc3\xec$\xd8\x02\x88PoC\xa8a\xa6P{\xc3h\xa2L*A\nC\xab\x9b2w\xb4@\xberCO\x13\x0f\xf3\x03\x18\x14\xe0\xda\xc0\x0e\x98\x04\x9aFInl\x18\xfcf\xc0\xb4\x04U\x031\xa2\xb5\x82\x80\xa4\x16\x15AL\x02\x22\xa034\x08\x1a\x22\xea\xc1$\x80I0\x06D\xa1:\xe5\x99|\xd6\xc1\x9a\x03\x03\xcd\x08\xfc\x0c\xa6\r\x84\x08\xe3\x08[3\xb0mC\x9a\x1a\x06\x87z\xc8\xdf]\xd7SSC\xbf\x05\xf3s`\xad\x92T\x82o\x94\xe4U\x15\x83\xdb#R8\x10\xd0\x10\x11\x01\xad\x15\x140\x02\t \x16\xb1\x16\r\x15D\xd0\x1aT@\x1c\x88\xb1\x80`\xac !0{R\x99~\xca!\x8d\xa3\xda\x81\xd96\x04\x0b\r\xb09\x83Y\x03\xa4\x06\xeb\x0c\x8bG\xba\xc8}Gzjj\xe8\xb5ai>\x92\xb4\x047\x01{\x95g\xf0A\x0f9\x88$\xa8\x11D\x14\xf5\x11b\x84\x08\x18\x03\x060\x16\x8cA4\xa2\xa1\x81\xa8D\x0f\xe2@\\\x82\x08 \x06C$L\x1aF\xf78\xc2\xa3\x0e_\t\xe5\x18b\x02\x93\n\x86%\xac\r!\xc9\r\n,\xef+\x90{\x0f\xf74W\x18\xf4`\xb1\x0f\x8a\x92L\x03\xf3\x9fiHW-`P\x9b \x22\x80\x82*\xea=b,*\x0e\x11\x8bj\x04\x22\x08\x10KD\x04U@\x04\xc4 b\x01\x8bh\x89\xe0\x99>\x0b\xa3?\xb4\xe0\x1d\xb3Ma\x96*A`}\x22H\rS\'\x90\xc2\xd2B\x0b\xb9\xf7XO\xf3\x00\x8b}\x98\x9f\x03?Q\xf2\x83\x9e\xa5?\x0bH\xeaPc\x01\x83\x06\x8f\x18E\xc4\x01\x06%\x01R \x01\xd3\x858\x02\x14d\x06Z\x01\x82\x88\xa0\xa1\x06\x05\xac\x03\xf5X\xf1\x842\xb0}\xb7\xa1\xfaFF92\x94x|&\x0c\'\x96`\xa1\x16\xa8\x81=\xcbm\xe4\xde\xa3=\xcdkXh\x05\xe6\xba\xa0\xaaton\x98{\xbb#\xaa@4`\x16\xc0\xf4 \x9eB5\x22\xb6\x05t\x10I\x10MQ\xc9\x11\xa9P\xf5\xa0%\xc6(\xaa\x9e\x18\xc7\xa0\x01\xc5`\xccMh|\x0c1\x13\x8c\xc9\xa9\xcf\x8fY\xbb\xddP\x0fSj\xa3\xd4D6+\x83W\x18\x07\x8bK\x85=+-\xe4s\xfbz\xea\x80\xc5<\xb2\xbb\xa3\x80\xb2\xf29O\xb2\xea\x881\xc5\xa5\x7f\x81\xb5\xaf\x07RT/\xd1\xd4o\x01.`\xec\x12\xe0P\n\x8cX\xa0\x01\r\x88\xccP\x15TK\xa2/\xb1\xc9[\xb1\xee\x1d sh\xfc\x01\xbe~+\xc6zh\x86\xac}(0\xf9\x96\xa3Rh4\xb26\x13\xce\r#\xddV\x828\xe1\xcaC]\xe4\xaf\xf6\xf44\xb3\xb0\xaf\xeb\xe9\xf5\x14\xb3+\xb2\xe7\xd3\x06\xd3\xde\x851_\xc6\x98\x01Q3T\x15# \xb2\x83\xafo\x06\xb3\x8d\x10@\x12 \x03\x04\xd4\x03s(\x91\xe8\xcf\x92f\xc7\x11\xe9\x12\xb5!j\x83\x91\x80\xc8\x98\xe8\xefC\xf8\x02\xd3g.r\xee\x8e\x94Y\x03\x1e\xd8\xac\x94qi0\x85\xa5Q\xe5\xc8\xb5=\xe4\xee\xe5\x9e\xb6\x13X)\x02\x83A\xa4\xf7\xf3\x9e\xf9\xb7\xf7\xc0\x0cp\xc9\x83ln=\xcd\xdf~\xf6\xe3l\x8dF\x1c\xbav?\xb7\xfe\xc2\xff\x00\x1e\xc7\xd7o\xc3X\x8f\x22\xa0\x02\x92#\x0446@\x81\xb1?\x85K>\xca\x93O}\x91{\xbf\xf0W\\^\x9fp\xdbm?\xcek_\xfb>4<\x88\xf0\x19\xe2\xf4\x22\x97\xee\x12\xd6\xbeiiT\x18\xfbH\xd5\x08c\x1cY\xdbr\xf0X\x17\xf9\xd4\x9e\xb6.e\x86\x85\x22\xd0m5\\\xf1\x97\x90\xee-\xb0\xee\xd7\xf0\xe1\xd7y\xef\x9d?\xcd\xdc\xea<\xcb\xab{x\xf8\x1b\x8f\xf1\x9f_\xb7\x9f[\xff\xd3\x07\x80\xbfA\xe3\x03\x10\r\x18\x83\x88\xa2QA\nD\x02.\xf9kN\x9c\xfc*\x1f\xfa\xc8\xfd\x1c<\xb6\xc4\xd2\xea*\x0f?\xf8\x18\x7f\xf4\xbb\xef\xe2\xd0\xc1U\xf0w\x80\x96l>\xa0\x9c\xfc\xb0E\xa3a\xcd\x0bM\x84F,\xf3\x0b\x19\xfb\x8e\xb5\x91O-\xb7u\x90\x1a2\t\x1c\xfd\xe9\x86\xe5;\xc1v\xf6#\xf6\x1666_\xcd\x1f\x7f\xf8\x9d\x1c\xb8j/6I\xf8\xdew\x9f\xa5\x9e\xc1=\x1f\xfdc\xb2\xf4[h\xb8\x0f\xd4\x22\xce`\x8c\x10C\x83\x06\xc1\xba\x04\xec\xe7\xb9\xfb/\xff\x94\xe7/\x0cY\xd9\xbf\xc4\xfat\xca\xe9\xa7/\xf3\xdew\xfc\x06\xd7\\}5\x1a\xde\x89\xe8\x90z]y\xe2\xad\x96j\xcb0,a\x92\x1a\xd4X\x96W\n\xe6V2\xe4\xee=-\xcd\xad\xb2\xbc+r\xc3\xbd\x01\xd3\x17\xd4:\\\xfaKl\xef\xfc\x12\x7f\xfa\x89\xdfb4\xa9\xf0!\xd2[N\xb0\x95\xe1O\xde\xfd\xe7\xe4\xe9\xdf\x13\xea/`\x92\x041@\x04\x05b\x14\xacI\xc0~\x85\xffu\xf7\xef1\x95\xc8\xd9S\x1b\\\x083r)x\xff\xaf\xff6\x87\x0e\xf6Q\xff\xbb\xe8l\x04\x1e\x86_3\x9c\xfc\x84c\xbd\x16J+T\xce\xb2oo\x8b\xc5\xfd]\xe4\xee\x95\x96\xb6\x8dr\xf4?\x04\xe6~2\x103e\xe1\x96\xdd\x98\xe4mln\xbf\x8c\x8f\xdds\'7\xbc\xe2\x06\xb6\xb6.\xb1\xb1q\x19\xea\x94;\xde|\x07\xa9\xfb\x12\x1a\x1f\xc4&)\x17/\xf4\xd9^\xcb8|\xcd\x1a!\x04\xac\xcd\xc0~\x91\x8f\xdd\xf5\x01\xe6\x0e\xf61E\x82\xef\xf79\xf9\x9d\xe7x\xfb/\xfc*W\xec\xab!\xfc\x05\xe3\xe3[\x94_3h\x0f\xce\xfd_\xc7\x85R(\x8dP\xf4\x12V\x0f\xb6\xe9\xadt\x91\xcf_\xd1\xd1\xc2GlP\x96:\xca\xae7\x04\x96\xfe\xa8\xc0\xe5G\xf1z\x17\xef\xff\xd0\x1d\xfc\xc1\xef\xdc\x855\t\xdf{\xe23\x9cy\xe2\xff\xf3+o\xfeU\xd0\xbfEx\x9c\xf5K\xfb\xb8\xff\xde\xc3\xecY\x1cq\xe4\x86-\xae\xbc\xfa,\xaa \xee\x93\x9c<\xf5O<~v\xc6M\xd7\xbd\x95\xb1o\x18n<\xc6K\xae\xb8\x9e4\xf9\'\x08\xf7\xb0y\xf7\x90\xf1\x97\x12&\xb50+\xe1r\x04\x9b\x1bz{3z{\x07\xf4\x0fO\x91/\x1d\xee\xab\xad\x1bl\x84\xf9DY\xba\xc1\xb3\xeb#\x06\xd3\x19 \xf6\x13\x8c\xc6\x9e\xcf\xfe\xf5\x7f\xe3\xe2\x0bS^}}\xc6\x7f\xbc\xf9\x0f\xb0\xfa\xcf\xc0?0\xde\x1e\xf3\xc5O\xbf\x94\x9b^5\xc0\xa6\xca\xc3\x0f\xce\xf8\x99\xdbN\xb0\xef\xd0&.\xfby07\xf2\xe4S\xf7\xf1\xd0\xd7\x9e\xe5\xc4%\xc7{n\x7f\r\x07\x0e\xfc\n\x84\x8f\xa1\xcdCl}\xb8a\xf85\xcb$\nM\x14\x86\xd6\x90u\x1d\x9d\xd5>\x9d\xbd\ts/\x7f\x01\xf9\xc7\x9b\x96t\xb2Q\x92\xf8@\xcf+\xdd\xd5\xc0\xbe\xbf\xf1H\'\x01\xf3\x1al\xfa\xcb\x04\xff\x0cM\xbdI\x92\xef\x03}\x02\x1b\x1f@\xb5\xe1\x7f\xdf\xb3J\xaf\xd5\xa7\x93]IH\x07\x9c=\xb9\xc1\x99s/\xf0\xdbw\x9e\xa1\xdd\x9b\x81\xa6\xd8\xf4=T\xd59Bh\x91\x15\x8b\x88~\x17\xa3\x8f\xa0\xcd&[\xffU\xd9yT\xd8\xaa\x0c>@\xd9Ih/\x15d\xf3=z?\xd6\xd0{\xf5i\xe4\x917.\xeb\xf4\xe2\x0c\xb3\xdd\xd0RH\xf3\xc8\xde\x8f\xd7\xb4^*\xc4h\x8954\xd5~\xd2vN\x94\x1a#\x9bX7\xe1\xc2\x89\x9c\xcf}j\x0f/\xb9q\x91\xee`?\xc6\xf4\xd8\xda\xd8\xe6_\x8e?\xcb\xad\xbf\xb8\xc6\xb57\xad\xf1#>.\x80\xb7D\xdf\x82p\x89\xa43\xc3&\x860,Y\xfb5\xa1\xde1\x8c+\xa1I\x0cf\xa1\x85\xeb\xa5\xb4\x0e\xf6\x98{\xcdI\xec\xa0B\x1e}\xf3\x8a\xd6\xe7g\x84Q\xa0S\x07\xac\rt\x8f\x05\xfa\xff=`;\x86P\x19\x98\xb4 \x0bH\x16\xc15\x18\x0b\xf5,\xe1\xdb\x7f\xb7\xc4\xb4>Bwq\x89\xc4\xf4\xd9\x19\xee\xf0\xbd\xc7\x9f\xe4\x1d\xef~\x9e\xee\xfc\x181\x80\x82V\t:rH/`[\x15D\x98~\x1f\xb6?(Do\x18\x07\x8b,&h\xbb \xbf\xa2\xa0\xb8z\x93\xfc\xd06\x1aA\xfe\xed\x8e+5\xacU4\x9b%\xd3\x1a\x9e\xaa,\x9d\x22\xe5\xc5\xb7OY}\xe5\x14\x9bDB\xe9@\x22b\x15I\x14\x04$B\xf4\tO\x1f\xdf\xc57\x1f\xda\xc5\xf6\xa6\xf2\x867\x8ex\xf1Ol\xe0\xb2\t\x8a\xc7\xa4\x10k\xd0\xc6\x80\xb1\xd8$\x00\x91\xea\x12l\xbe?!.\xef\xe6\xa9M\xcf.\x1a\\\x11\xe9\xf4\x06\xa4\xfbF\xb4\x7f\xfc\x12\x92)\xdb\xe7@\x9e\xfbtW\xeb\x13\x05\xa6e\xf8\x97o\xc3\x03k)\xd7\xedMY\\\xf5\xfc\xdc\xef\xaf\x91\xf7=\x22\x8a\x12!\x18\xc4\x82jD\x10\x04\x01\x12\x9a\xca\x01\x96$Ub\x0c\xe0*D\x14U@A\x0c\xa0\x06A\xd0\xc63;#\x0c\xff$\xe3Y\xd7\xe6\xe1\x91\xe1\xf5\xf3\x91\x95UKk\x9f\xa7\xf5\xe2ML\xc73[\x073\x10\xe4\xecC\xa8\xbf\x98\xd0\x7f\xc5\x02\xd3\xb2\xcb\xc9\xc7#\xdfz\xb0Eo\xd9r\xeb;Nc\xb3\x06k+l\x16\xd1`P\x1f1\x0e4\x82*\xa0\x821\x82\xf2C\n\x8a\x22\xa2(\x8az\x10gqy\xc4\xd7\x8a\x9fZP!\xf8\x04&s<z\x9f\xe3\xe9\xa7\x0b^\xbe0e\xe9E3\x8a#\x1b$s\x81\xf1\x86\x10*Xz\x85 \xa7\x1fB\xa5t\x90\xa5\xf4_\xba\x0b\x8ce:u\xa4\xa9\xd2\x94\x17iN\xe6d\xfbF\xb8\x8eGD\xd0\x10\x01%L\xc0$ \xa9 QP@P4(\xaa\x80\x80I\x00c\x11\xa34\xb5\xb2\xf3\x83\x0c\xdb\x85\xf6jA\xd6:\x8a\x0f9\xaa\x05a\xeb\x9b\x94\xa7#&\xdd\xa1\xde\x86\xf1\xa6\xb0\xf2zA\x1c\xc8\xb3\x0f\x8a\xa6\x99\xa25\xd8\x8e\xa1}\xa0\x87\xeb\xecCcI\xb5\xd5fv\xbc!=r\x9etyB\xf4\x8a1J5R\xd2\x16\xd8\x0c4\x18\x04A\x8dB\x00\xb1\x8az!\xfa\x88*`\x0c6\x83\xd0\xa4l}5E~\xac\xcd\xae\x1b\x0e\x00]\x8c\xf4hF\x0fS\x9e\\\x83\x08\xe3\xa1P\x0ea\xe5\r\x06\x97\x1b\xc4\x80l\x9c\xb1\xba}J1\xd3\x88M\x005\xe4/j\x91\x0e\xf6\x10\xc2.\xfc\xb0$\xec<\x8f\x9b\x9b\x12\x9a\x88\x06\xc5e\x8a\x180\xc2\x0f\t$\n*\xa0\x02A\xf9\x91\xe0#\xa8\x10\xd5`\x12\xa8\xb72L^P,\xaf \xd2G\xeb5f\xe7O\x107\x1a\xb00\xdb\x00o\x85\xa5\x97[L&\x88\x80\x08\xc8\xa5\'E\x11\xa8k!\\\x8a\x08\x10<d\x8b\x96l\xf7\x02&i\x11\xfd\x18\r5\x22\x01P\xacS\x82\x0fh\x04\x8cAl\x86F\x0f\xa2\xd04\x88\x01\xe3,b!zC9Ki\xf5s\\\xd1\'\xfa\x9ajx\x89\xea\xfc\x14\x02h\x80\xf1eH\x16\x85\xc1U\x06\xdb2\xfc\x881`\xad \x9f\x7f\xdf\x9c\xbe\xee\xb6m\xd2\x0e\xf8\x19\xd4[\x11\t\x10\x1a0N\xb0]\x8b\x9bKqy\x8e\x11\x8fI\x0c1\xd4\xa8\x8f`RL\xba\x82qGP\xdd!6\xcf!\xc6\x10\xeb\xcb\xb8\xd4\x11|C]&\xb8\xd4\x92d\x96j4\xa1\xbeX\xa2\x8d\x82\x82o`\xb4\x06\xf3/1\x14\x0b\x82+\x0c\x08D\x85\xc4\tb\xe0\xdf\x01\xb7w\xfe\xa7\xf5\t\x00\xe3\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode_gray(code_energy_four).unwrap()}
-    });
-    pub const ENERGY_ZERO: LazyLock<Self> = LazyLock::new(|| {
-        let code_energy_zero = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00(\x00\x00\x00,\x08\x06\x00\x00\x00\x17o\xfa{\x00\x00\x0e\x0bIDATX\tU\xc1[\xac\xaeWU\xc7\xe1\xdf\x18s\xbe\xdfZ{\x97\xd2r(\x85\x16\t\x01\x81p\xf0B\xd4\x1bN)J\xc5\x1ei\x91\x96\x02\x15\xb4\x14B\x83\xf6\x90\xa2!h\xd4DJ\xb0FTZb\x04\x05\x95\xd0\xc4\x0b\x12\x8d\x1cbCH\xa8\xc1S\x8c\xd4DC,\x12!F\xa3Pjw\xf7^\xeb{\xdf9\xc7\x18\x7f\xbb\xed\x95\xcfc\xcfz\xfa\xd3e\x881\x93\xf7\xff\xe2-\xdct\xf3\xed|\xf7\xbb\xff\xc1\xdd\xbf\xfe!8}\xc49\'\x0e\xd8\x0c\x86\x8cn\x22pH!\x89\x92\x836\xc8\xce\xf4$\x0b\x18A+15\x90\x19[A\x8dD2L\x02\x8c\xe3\xf5\x98g=\xe7\xa9\xbc\xe9\xba7s\xea\xd4\xff\xf0\xc1_\xfd\x08\x8f\x9ez\x8c\x86\xe38\x18\x98A\x01v\xc1y\xe7\xeb\xe0\xe4\x01\x0f\xfc\xd5\x979\xef\xc9O\xe3\xfe\xcf\x7f\x9a\xcf}\xf1/98\xe7\xc9\xecH\x9a%EPUD\x1a&qh\xc6\x14l\t\xb8h\x15dv$c\xad\xc9\x9c\x13\xd7d\xab\xa4\xa9\xe1%f\x14\x92\x13\x149V\xc66\x08\xe0\x9a7\xbc\x8e\x1fx\xd9K\xf9\x8d\xdf\xfc\x18\xff\xf4\xe07q\x1c!\xca\n\x03\xec\x07_\xf2b\xdd\xff\x95/qtt\x8a\x8f\xdf\xfbaN=\xfc\x08\xecN\x92\xdeX*\x90\x0cQ\x14\xa2#B \x89\x91FF\x01\x86\\\x10\x86\x85\x88&\xc6\x1cXnH\xb0\x15,)\xc6\x08$\xa7\xdc\xc9\xdc\xa3\x19l)\xb6\x08^\xf8\xbc\x8by\xeb\xdb\xae\xe7\x13\xf7\xfc1_\xfd\xdb\x07\x91\x01\x06.\xb0\xef}\xef\xbf\xf4\xf0#\xff\xc9o\xdf\xf5\xcb\xec\x0eO\xe2\xbd\x01\x86\nL\x0e.T\'\xb1\xdc\xc0\xcf0\xaa\xb3q\x00\xb5\xb1\xc4D\x88!\'%ZM2;\xe00\x8e\xd0\x08\xa2\x15\x89q\x1c\x82!\x84Q\x12\xdb\x9cD\x0e\x1c\xb1\x1e\x05\x17_\xf4\x0cn\xbc\xf9\xad\xdc\xf7\xc9O\xf3\xc0\x03_\xc3\xad\x91$\xf6\x8do>\xa8\x8f~\xf8\xd7p\x84\xedv\x98\x198\xa8\x0c\xf3\t4\x9cs\xa9\x18(\x8e\xa9\xdeH\x19\x99\x03\xa9\xb0\x84YFI\x18E\x85\x81\x1c\xd5\x86\xe6 \xaaH\xc4>\xa0\xa2\xc8\x12VFD2\xc6\x8a9d\x889\x92\x17<\xffb\xae\xbe\xfe\x1a\xee\xbd\xfbw\xf9\xfaC\xff\x06\x06\xf6\xde\x9b\xae\xd6rp\x0e\xb4\x05kFI,t\xba9\xc9\x19\nhz\x12X2sRU\xb8\x0b\xe1\x98\x8a}\xc26&\xdd\x8b]\x88Q\xa2\x99\xb3\xcfD\xdbi\xa2\x1a\xdd\x93m\xc2\x0c\x98S \x03\xc1\xe9\xe33\x98\x8b\x02\x14bl\xc1\x8b_\xf4\\\xae\xbd\xee2>\xf0\xf3w\xf3\x9dG\xbe\x87\xfd\xdc\xbb\x7fRmw\x02k\x0b\x1d\x90\x19\x8b9g\x85V\x1a\x8e\xb7\x1dJ#$\xd0FQd\x19\xe6N&\x8c\xb9\x92%\x1c\xf0\x08T\xc6jBs#g\xd2\x80\x19\x1b\xc7ad\x00a`p\xbc\x1d\xd13\xd8\x9bCB\x8c +\xb8\xfc\xc7^\xcd\xf7\xbd\xf0Y|\xe0\xfd\xbf\x85\xdd~\xeb;\xd4\xac@\xe2\xd0w\xcc\xee43\x90\xf0n\xe4\x04L\xa4A/P\x19\xb3D\xc9\xa0@\xbea\xb1\xb2\x9f\x8d\xc6\xc2\xa83\xf4(d\xce\xbe\x84\x86\xe1$\x91\xa7\xd8O\x18a\xcc\t]\xc6\xccc,\x935\xa1\n\xa2\x82u\xac\x9c\xcc\x93\xbc\xe7\xce7\xf3\xa5\xcf\x7f\x05\xbb\xe3\xf6w\xe9\xb0\x02\xb9\xf0\xc5\xa9j\x98\x81\x9903\xaa\x84\xdc0\x19B4\x89\xc4\x88\x14Y\x06$.1\x22\xb1h\xa4\xce\xd0\xb2\xd8Jl*4\x1d*\xa1\x8e\x19\x91\x9c\t\x91\x05m\x18k\x1d\xd3b\xb2OG\x05Q\xc1\xc8I\x1f\x8d\xf3\x9f\xf2$\xde}\xcb[\xb0;n}\x97\x0e]\xa8\x1b\xa5di\rI\xb4\x06\x99E3G\xdeP\x16B\x98\x19B\x80\xb1\xdf\x12\xb2\xd1\x10\xe9\x06\x03\xdc\x079\x07Gs\xa50\xb6\x02F\xd2\xd83\x03\xb61\x19\x12Lg\x8d#Z\x04kA\x96\x93\x19\xcc\nZ6T\xc9\xd5W\xbe\x06\xbb\xed\xd6\x9bt\xb04@,\xcd\x90\x04f\x18Fo\xc2Sl4\xcc\x85J\x18Pe\x98\x17\x921\xa6(\x0c\tL@\xee\xd1\xd8\xb3I\xccY\x949#D\xcbb\xce\xc96\xf6l\x05;\x19c&\xdb\xb62\x94\xb8\x8c\x88\xa2J\xa4\x8a\x9c\x93\xa7\x9d<\xc4\xdew\xc7\xcd\xf2nP\xa2\xbb\xd1L\xa89 \x16\x89\xe1\x07\x98\x06\x1d1i\xb8\n\xca(\xc4Y\xb3D\x86Q\x12`\x90{\xa8d\x8c\x95\x941\x13\xa2\n\xc2\xc9XY\xe7\x9e\x11\xe0\x88\x1a\xce\x16\xc1:\xf74A$P\x90JbNz\x0c\xec\xfd\xef{\xb7\x0ca&0\xc3\x80]\x03Y\xc3T\xc8:\xe6\x89\xd2p;Aj\x12\x9a8E\x951\x11-a\xa4(\x0cjE\x19\x1c\x8d$Ux9#&\x16\x06\xb5\xb2\x8e=\xc7C`\x8e\x0f\xd82\xa9\xd8\x93)f\x01\tQA\x95\x18c\xc5~\xe9\x8ewi4XLXo\x90E\xef\x86SH\x86\xac\xe1\x0e\xaa\x8e\xf9\t2W\x82\r\x17\xa8\xc4\x86h2\xb2D\xa5\x9150\x8a3k\x92\x15,\xea\xcc\x0cb\x0b\x8c\x95u\xecY\xa7a*j8\xb3\n\xc5\x11\x95\xc5\x1a\x0e\x05Q\xc1\x88IDbw\xdez\x83\xda\xee\x10g\xc1M,\xdd\xb0\x04\xef\x02\x8c*\xc3wF\xd5\x02\xb9\x90\x04h\xa5l\xc1\xd4i\xb6r\xbc\x06\xdd\x1b\xbd\xe0\xb8\xc0\x11\xa7\xb7\x95\x19\xc1\x8e\xc1\xf1,\\\x0e\xb12\xe7\x9e\xc7V0\x8c\xfd\xd8\xb0l\x9c5\xe6 gB\xc1\x8cIT\x92\x95\xd8\xfbn\xbd^K;\x07y\xc3\x1at7\x9a\t\x13\x8fs\xe8\x8e\xd1P9\x81\xd3\xa2\x98l\x88\x05o\x9d\xca=U\x85\tz\x19\xfb\x14\x18\xac1\xc9\nj\x1c\x13id\x81\xc5\xca\x98{\x8e6\x91r\xe6\xb6Q\xe9\x98\x19c\x0er&\x14D\x05#&Ra\xbfp\xdb\xcd\xean\xcc&\xcc\x8cRrn?\x9f\xc11;_0\x19\xf4D\xd9\xb0:\x00/VM\x9c\xa4H<\x13\x01%\x11a\x84\x92^Id\xa0\xda\xd8f\xb2\x85 \x9c\xaa\xe2x\x0e\xc6\xb6\xb2\x03\xce\x0c\xd1\xca\x98\x95ls#gBAT0b\x80\n\xbb\xf3go\xd6\x89nT\x87)0\x92\x93\xcb\x93\x91\t\xc71\x0b$\xc7d\xc8\x9dR\x11\x12\x8bM\x82\xa0B,\r\xb6\x103\x8dR\xd2+\x88*\x14\xc7\xcc\x82u\x88,\x83(\x1e[7\xac6Z\xc2\xe9)z\x19\xa1b\x9b+1\'\xa4\x13\x95\x8c\xd8\x90\n\xbb\xfd\xbd7j\xd7\x17\x16k\xd0\x8dB4sZ\x13 \xa2\x8c\x8eH7\x16;\x97P\xd1\xad\x11\xda\xa3\x1c\xf4~\x0e\xe0\x1c\xe5\x11\xbb\x14\xc7)\\\x93\x8a\xa0r\xcf\x1a\' &\xfb\nz\xc06\x8a3\xe3\x0c\xa8\x18#\xe9j\x84\x8am\x0eb\xdb\x83\x1aQ\xc9\x16\x83\xac\xc2n{\xcf\r\xf2~H\xf7N\xb9\xb1,\xc2qd\x86\xab0\x13\x92\xd1\x9bcv\x02S!\x133\x92\xa5\x173\x1d\xe1\x94\x06\xad\xc4q\x8a\xa6 rP\xb11\xe6B*\xd9\x22iQ\xe4\x0c\x1e\xdbV\xa4\xa2F\x229(9\x1e\x1b1\x06\xa8\x11\x15\xccHf\x05v\xc7-?%\xbcA[(\xe0`\x019\x8f\x13\x07\xcdP\ts\xa3a\x14\xe0\x88,@\x07\xb4\xd6\xd9\xd71\x92\xe8%\x1c\xe38\xa0#\xb6x\x94J\'G\xa0\x82\x19\xa0Z\x89\xb9\xf2\xe8V,e\xcca\x14\x22\xf341\x8dm\x06\xa81b%\xaa\x93\xb5a\xb7\xder\x83\xbc\x1d`\xcd)5\xdc\xe1\xb0\x19V\x22\xdch&\xdc\x1c\xb3\xa2\xe4\xb8\x15%ci\x8d\xc4\xc9\n4Es\xc8\x82)\x81\x92mn\xc4\x0c\x98\xc1\x04j\x02\xb92s\xe5\xcc\x06\x14\xc4H\x12\xa3U\xb0m\x93}\x0c$\xa7*\x98QD\x06v\xd3;\xaf\xd1\xb9\x87\'pk\xe0;d\x8d\x05a2\xd2\xc5\xb2\x00e`\x02\x0cC\xb4nT\x827\x18\x13\xaaDwc&\x94\x06dq<\x92T\xa19\x08\x15L\x87\xb92j\xe5\xf4\x146\x04\xe5\xa4 G0b\xb0\xd5\xc6\xc8I\xaf\xc6\xac\xa0fa7\xfd\xf45:X\x1a\xc29\xdc\x9d\x04\x8a\xde\x0f\x10\x06\x96d\x89\xee\x1d3\xb1t#B,\xcd\xc8\x04\x9a \x8d\x94\x98@\xc3\x88\x98\xa0b\xcc=3\x8b\x19\x052b\x16\x8a\x8d\x11\x1bc\x16\x1a\x06\x18S\xc5\xd8V*ET0SX\x16\xa9$F`\xef|\xc75bY\x00\xe3\xdc\xe5\x10C\xe0\x0b2\xa7\xb5\trv\xde\x91\xc44#\x05\x8b\x8b(p7\\\x02\x8c\x1080#\xc8\x0c*\x8e\x18!BF\x0b\xb1V\xb1n\xc7\xf4H\xc6\x14\x99\xc6Y#\x83\xfd\xb6\x87\x04\x04\xb3\xc0\x22\x98\x19D\x06\xf6\xf6\x1b\xaf\x95\xb9\xd8-\xce\xce\x0e0k\x98\x8a\xd9;\x876\xb1e\xa1e\xa1\xe6\x94w\xa4\x02\x15\xe6\x1d\xc9i8\x95\x22\xb4G\x12D#\xab\x98\xf3\x080\x22\x80J\xb6\xb9\xb1\x85\xc8\x9ch\x13\x81PN\x22&\xdb\xdc@N\x96\x13Y\xb4\x1al!*\x85\xbd\xe3\xad\xd7J\x8b\xd1T\xb4\xe5$\x8b\x0b\x07\x869\'{\xe2\xde@@s0\xe7\t\x82p\xbc-T\x82c\xac:\xc3\x1a\x9d\x9d\xc4:\xf74%\xc2\x88\x01\xcad?\x93\x91I\xcd\x15\xa5Q\x145\xf7\xcc(f\xac\x98\x0e\x88*\xb6\x18\xb4Jf\x88\x92ao\xbf\xe1j\x99;r8hNa,\xad\x909\x87\x8b\xc8\x14aFs\xa3#*;\xa3u\x16\x0b\xba\x92\xccCd\x8d\x99\xc7\xb8\xc4\x9aB\x0804\x80\x84\xa3y\xcc\x96\x13\x93\xd0\x00$f\x15\xdb\xdc\x93SX\x15%\x98\xb1\xc7$\xd6\x04RP\x86\xbd\xfd\xba\xcbe}\x87\xcc8\xb9\x83\x94!\x83\xa20\x19nP\x1d\x04\xb4\x12\xcd\x16\xccN\xd0}\xa54(\x1d2etm\xb4\x82c\x81\x0bb\x16\xc8\x993Xs#s\xd2f1\xe5\x98`\xcd\xc1\xbamP@\xc1\xac\x80X1`?\x00\x81J\xd8\xdb\xde\xf4Z\r-\xf4\xdd\tv\x9e`\x0e\xad\xa1\x84\x93\xdd0`C\xb8\xc3A\x15\t\x847\xcc@\x02\xb2\x00\'\x00E!\x1c/P\x9cb\xd4\x8e\x9a\x1b!\x18\xb3\xa0\x1c\x03\xc6\\\xc9\x98\xec3)\x01%f\x04\x91A\x13d\nd\x8c\x08\xec\xfak/Q\xb2`m\xe1\xc0&\xadw\x9a\xef0KP\xa7uc\xc8p+v\xda\x88jD\xeb(\x1dk\xc2\x04nF\x86\xa0\x0cK\x10b\x1f\x1b)\xc7\xe61c\x16\xc21A\x94\xd8\xc6\x11H\xac\x91\x94\n\nf\x04Y\xc9.a\x14Hb\x9b\x13\xbb\xee\xaaW\xa9\xf7\x13\xa4:\xbd\x0f\x16%j;\xac\x81t\x12kNV\xd1p0\x91%\x9a9rp`V2\x15\xecj\xa1\xe1d\x9db\x8db+\xd1\xe4\xe4V\x80c@\x95\xd8\xb6\xc9\xd4DU(\x8f\x99\x82\x08#UT\n+\x81\x8c\x11\x81\x9bc\xd7]\xf5ju\xeb@g\xb6d\xe7\x85\x9b\xe3fX;\x07\x95a\r\x94\xc2\x9bQ\x02KA3(X5\xd9b\xb2\xd0\x100s0U,2v\xd3X\x05\x06T\x15\xfbm\x00F\xe4\x04\x89\x99\x83L\x08\x05\xaaB\x01HH0f\xb0[:v\xd5\xeb/\x11\xdd\x90\xc1\t3\x0c\x81\x19\xcb\x22F\x8a]wJ0\xb3h\xcd\x90\xa0J4wR\x89\xb3\xa3\xd38\x9d\x03WQr\x10\xc4L:\x86p\xb2&\xb1\xad\x84 \x13\x10\xa4\x8aR1c\x92\x99\x90\x80D\x22\xe6\x0cT\xc5+^\xfe\x12\xec\xaa\xd7\xff\xa8\x84\xc0\xc1\x81\x8e\xa0\x81\x993)\xca\x1cG\x80\x10\x8e\x01\x0bEb$\xe2\x04\x8d&\xd8*\xa1`\xa6#\x04\x14\x08\xa2\x92\x9c\x93\xac \x13\x90\x13*J\xc9\x88Ie\x91)\x9a\x8c(Q$\xb9Mv\x87\x0b?\xf3\x96+\xb1\xcb/\xbdDA\x22+\x0el\x87a\xa0\x023\xd4\x85\xa5\xa3\x06Fa\xee,\x14\x078k\x15\xb3\x9c\xac\xa2\xc9I\x8a\x06\xa4\x1c\x01\xd2i$\xa8\x01QE\x96@FJ\x94\x8a\x19Ae\xa2\x84\x148\xb0\xcf\x80\x0c\x96\x98\xbc\xe1\xba+\xf8\xeb\x07\xfe\x1e{\xf5+\x7fD\x87\xbbF5\xb1\xd0\xe9@\xf9\x82\xe3T%\xcd\x1bg\xa5%\x8b7Z\x89\x94(\x9c\xffo\x12\x12\r\x91r\xb6X\xc9\x10\x91\x89\xc9@\xa2Td%Q\x85\n\x14\x02\xc1\x900A\xcd\x15e\xf2\xd2\x17\xbd\x80\x0b\x9f}1\x9f\xfd\xec\x17\xb0W\xbe\xe2\xe52\x13\x07K\xa3$\xcc\xa0\xb7\x13\xb85\xce*\x15n\x8e\x01\x02\x0cC\x08p\xcc\x0c)y\xc2\x1e!j\x83\x12DAI\xccL\xdc\x04\x9a\xcc\x00Q(\xa1\x12$Q\xc0\xc8\x82\n|l<\xf3\xa2\x0b\xb8\xec\xda\xab\xb8\xe7\xeeO0bb\xf7\x7f\xf1>}\xf0C\x1faVr`\r\xb9aUX\xeb\x1cH`\xc6*0\x13.\xc3\x11nF\x98!5\xaa\x12sP%U \x05Bx%%\'\x08\x14\xc2d$\xa2BT\x01\x82\xd4\xa0\x04\x9aIE\xf0\xd4\xa7\x9c\xc7\x157\\\xc9\xef\xdfs\x1f\xfb\xa3=\x86a_\xf8\xb3Oi\xcd\xe4\xa3\x1f\xfd8s\x9b\x1cx\xc3\xdc(\x8a%\x1dw\x98\xc6\xe3\n\x95\xd3\x1cJ\x90@\xf18\x15\x94s\x96\x01\x83=Q\x8d\xae$\xb2Q\x1a\x18\xd0\n\xa2\xa0\n\x10 \x985\x88L|\x06\xcfx\xfa\xf9\xbc\xfe\x8dW\xf0\xc9\x8f\xff\tg\x1e;\x83\xc98\xcb\x9e\xf3\x8cg\xea\xde\xdf\xbb\x8b\xc3\xc3\x93\xdc\xfb;\xf7\xf0\xf0w\x1e\xa1\xf5\x03\xbc5\xa0\x10I\xe1`\x06\xd6\xb0\x02\x97S\x88\x92a\x14g\x89\x04D\xca\x99\x19,*\x84\x98%\xfeOB\x950\x8c\xac\x22\xab\x181\xc8,^\xf6\xfc\xe7\xf0\xba\xcb^\xc3=\xf7\xdc\xc7c\x8f\x9d\xc6)\x8c\'\xd8\xf7_x\x81NGr\xdb\xed7\xf2\xdaK/\xe5\xbe?\xfc\x03\xbe\xf6\x0f\xdf&3\xb0\xd6pkl\x04P\x08\xb1\xb0\xd0p\np\x9c\xa20@\xda\xc8jd%\x92\xf0*JP\x02$\x90qVT\x11\x99D\x06\xe6p\xe9%?\xc43/\xbc\x80O\xfd\xd1\x9f\xb3?\x1a\x98\x04\x04P\x9ceo\xfc\xe1\x8b\xf4\xd0w\x82\xff>=x\xdes\x9f\xcd\xaf\xdcu\'\x8f>|\x8a\xbf\xf8\xdc\x03|\xf3[\xdf\xa6\xb2H\x15xQ\x82f\r)1k\xa4\x92\xa6\x02\xc4\x94(\x19\xa4@ \x81\xf18\x03\x04*\x911\xa8\x98xk\\t\xf1\x85\\v\xf9\xab\xf8\xe7\x07\xff\x95\xcf\xdf\xffURIS\xe7\t\x05$\xa5\xc2\xfe\xf4\xae\x17\xeb\xa1\x876\xbe\xfa\x8f\x83\xbf\xfb\xf7c\xbcw\xder\xc3\x95\\q\xd5\x15|\xe3_\xbe\xceW\xbe\xfc7<\xf4\xado\x91Y45\x84(+\xcc\x8c\xb3\x9cB\x05Y \t\x93\x81\x01\x12)@\x80`\xe6\xc4*\xb8\xe0\xbcs\xf9\xf1\x9f\xb8\x84\xb63>\xf3\x99/\xf2\xf0\xc3\x8fR\x14\r\xc78K\x18\x02\x12\xe5\xe4\x7f\x01\xc3>\xa1\xd9\xc5\x13\x0e\xa3\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode_gray(code_energy_zero).unwrap()}
-    });
-    pub const TARGET: LazyLock<Self> = LazyLock::new(|| {
-        let code_target = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x01\'\x00\x00\x002\x08\x02\x00\x00\x00\x91T\x8a\x8a\x00\x00 \x00IDATx\x01\xbc\xc1y\xb0\xa6\xd7]\xd8\xf9\xef\xef\x9c\xf3,\xef\xfb\xde}\xe9\xdb\xb7o\xafj\xb5Z\xdd\xda\xf7\xcd\x16\x96d\xb3\x9a\x18\x0c\x0c\xc3\x10\x88+\x99J&\xa9T1\x84\xd4\x0cTef2\xc5\xd4$a\x8aLR\x84\xad\xa8\xb0\x18\x1b\x83M\xc0K0x_dY\xabeYKK\xbdH\xbd\xef}\xf7w{\x9e\xe7\x9c\xdfo\xeem\xb7\x11 \xbb`\xe6\x8f\xf9|\xe4\xf1_\xf9\xc9\xba\x8a\x96\x87\xd5\xf5\x14\x1c\x13#\xa5\x9a\xf5c\x16%\x848\x10\xa3\x98j7U|\xea\xd9\xb3_:\xbc~e c\xae}Wy\xc3\xb8\x1b\x7f`\xf6\xfe\x9b\xfe\xe1#\xf1\xe5\xde\xd2\x0bGO\xac\x1c\xfaR\xf7\xb9\x17\x06\'\xce\xd7\xab7\xcc\x84_\xfa\xb5\xfb\x97\x8f\x9f\xf3[v\xb5\xe7oUK\xce\x92\xf3C\xd1z\xe5\xf8\xcb\x87>}\xa9Xp\xab\xefZ~al\xcd\xa4\x99X\xe9\x1f\xf8w\xfe\xce\xbf\xb7s\xcbMw\x9b+\x93\xb4\xa0\xe5euu\xf9\xfc\xeakgF\xc6\xfc\xd7\xfe\xec\xcc\xce\xa7\xde79w\xdb\xc8\x96\xad\x9d\xfd\xe3\xf5\xfd\x1d?\x13J5.$\xfd\xc40=\xd5\xad\xbb\x17\xfbz\xa4\xc7\xa9\x8b\xbc\xf1\xcf\xf3g\xc5{\x01\xc4\xc49\x01\x873a\xe7\x0c\xef}\xa8\xf0\xc3\xda\xd5\x03\xa0\xbdkN\xca\x19ABV\xf8\xcc\x9bzi\x86\x0c\xd7\xbc\xcb\xf2\xf6\x18AHU\xac\xba\x8dF\x08)\x1f\x15W\x88\xcf\\k:\xb4\xa6\x9c\xcf}1I\x98\xa8\xcdK\xb3\x9a9\xef\xf31\x93LD\xc0\xcc\xd4\xd2PcOS\xa3\xb1/\x82\x93\xcc\xf9L$\x80\x98\x13\x91\x80s \x0e\xe7$4\x92\x9b\x0b\x81\xe4\x0c\x11q\xa8\x9a\x89\x01\xc6\x06\xd5\x90\x1a\xb1\xe4L\x11QM\xe2\x83\x8a\x03T|\xf4\x99\x8a\x13\xe7\x80\x84\x8f.\x04K\x86E\t\xde\x92G\xc1\x101\x04\x0336\t\xd7\x18\x08\x1bDA\x106\x18&\x08\x1b\x0c\xc1\x14\xa9\xf1\x01\xf5\x161\x13\xcc\x9b\x22.s\xe6\xe3\xb0\x11o\xbeT\x033s\x01\'\x86\xab\xd59\xd3\xa0\xb5\x88\x80\x18&l0\xcc\xd4\x92Y\x82$\x98YB\x1b@LMk\x8bU\xb2(8\xf1-\xe7\x0b\xf1\xa5\x84\xd2\xc4c&\x98\xa4\xba\xaeWksE\xd6\xf2VYZO\xfd\x0b\xe2\x936\xeb6X\x16\'\x8d\xcfI\r!\xf7cs!\x1fu\x12b\xb5ZU\xc3$>\x97F,\x81\xa5f\xa8\xda8\x9af\xf5\xd2\xf0\xd2j\xaa\x93d\x0c\x1a>\xf7jx\xed\x827555\xc3T\r1\xb3\xa4\xa9\xfe\xb1\x9f\xe7\x86\xeb\xd97\xef\xb6\x94S#\xbcMx\x0c\xb6@\x17^\x84\x8f\xd5\x9c\x1aR\x9d\x1f\xa6W\xce?1\xf3\xb3\xad\xd1\xce\xf2\xc9\xe5\xe9\x87\xdf\x9b\xf2\t?2[0\xe9q5\xeb\xa7\xbe\xfe\xd5\xb3\xaf\xae\xac\xfewM\x1f)\x91\xfb\xd8r\xea#\x87\xf5\x8d\xd3\xf2\xe9_\xf9\xa7\xd2*\x9bT\xd7\x95\xb9N\xbbU\x8e\x98\xear?D\xf1\x1d_\x8b)\xb9OfO?\x7f\xe1\x8b\xaf\xafaa\xd4un-\xf6l\xcb\xe7o\x98\xd8\x7f\xfd\x8f\xde\x1b\x0f\xf7W\x0f\x9d<\xbd~\xf4\xa9\xee\x8b\xafUg/5\xeb\xd7M\xf9\x9f\xff\xc5\xdbWN\\,\x17\x16\xb2\xc9\xeb\xd0\xb6\xb1\xee|B\xd3\xd2\xa9#\xa7\x9f\xbc\xd8\x9a\x93\xd5\xb7\xaf\xbc6\xd6\x137\x18]Y\xbc\xf1\x97\xec\x86wL\xcf\xdf\xf1 \xe2\x1ak%?+~\xb5\xdf[Y;r\xaa5\x9a}\xfd\xb3\x97\xe7_\xfc\xf1\xb1-\x07\xda\x13\xd3\x9d=\xe3\xcd\x1d\xb9LP\x82\\F?\x17\xd3\x0b\xfd\xa6we\xa0o\x0c8\xb3\xcc\xd9\x7f\x93\xbd\xd2\xf3*\x88`8\'\xe0\xc4\x19\xccM\xd8\xf7\xdd\xd3vU\xed\xaa>Bg\xfb\xb4\x94\xd3\xe0Bp\x99\x88\xa5\x86\xa6.<\xc1\xb74\r\x9cU\x16\xfbfJh\xb9<4\xaeLVF\x0b!\xe4\xbe3c\xc5\x84\xcf&\xc9\'\xa2\xefH\xec\xfa\xd4\x17\x9f;\xdf\x11\xf1\x86b\x86E\xd3h\xa9\xb14\xd0T\x89\xf7\xcee\x22\x19\x88\x89\xc39\x11\x01\x11\xbcwY-\xb99\x97\x9b\nf\xe0E\xd1$jj&\x9a$5!\x0e\x83F1\x15LI&N}n\xde\xab\xcf\xd4\x17\xb8\xcc\x9c\x8f&\xd1\\\xed\xb2\xcc\x22X#\xc1[\x0c(`\x80\x81!\xfc%\x01\xe3*\x03\xc5\xc0\xd8 \x02\xc65\x22`\xe2k\xb2`MF\xf4b\x1e\xb0\x14\x9c8\x1a\x1d\x0e$\xcb$\xb4\xa2\xa2\x88!\xc9\\\x12\xd7\x90\x89iA23@06\x19fj\t\x0c\x12\x18\xa6\xd0\x08\xa0\x86\xd6\xa61\xa9\x22\xe2C\x07\x9f;\x97#\x81\xabL\xa3\xa5\x81\t\xb5ke\x1a\xbd\xf6\xa4Y\xd2\xe1%\x17\xb0\xe12\xae.;\xa3\xbel#\x19\xa1\x85\xf3\xd1\xb5p\x85\x0f\xe50\xa5\xba\xea\xbb\xe1\x92\xb3\x88w\x96\x1a\xd5\xe4h\x9a\xd5\xc5\xc1\x95u\x8b*\x9e~\xc3\x93o\xf8\xe3W\x9c}\x0bfj\x18\x16\xf1\xe9\xdd\xff\x84\x1d\x0b\xeci\xc9\x04\x13p\x0f<\x08\x930\x84\xc3\xf0\x198\x03\xc3u\xaa\xc3\xd5\'G~\xce\xaazxee\xf4\xde\xefK\xc5\xdc\x96\xfc\x9eIf\x81!z\xa2z~\xed\x8d\xf5\xa5\x03M\x22\x8c\xa0w\xd39\xfc\xca\x0b\'\xbe\xfa\xb4\xfc\xea\xcf\xfdt]NV8\x13I\xa3\xb3\xad\xf6\xa8\x08\x83\xc6\x12\x92yC\xadQ\xab\x93={f=*\xb9\xe4m)\xaf\xcb\xe7\xf7\xb5\xf7N\x8fl\xd9\xfa\xf0\xf6t\x92\xee\xf1\xf3\x97zg^\x1e\x1e;^]ZN\xfdmc\xee\xa7\xff\xf1\xbe\xb5\xd3K\x9d];\\9\x8d\x95H%\x12\xcd\xac{\xf1\xf8\xe2\xe3\x87\xc6\xb6s\xf9\x81\xe6\xe8\xc8`\xd4W\xa3\xdd\xc5m\xffv\xfd\xba\x07;\xdb\xef\xbc]\xdch\x83O~\xbe\x0e\x95\x0e\x97\xbbG\xdfp\xed\xb1\x17\x9f\xab\xc7\x8f\xfd\xe0DgGkb:\xdb\xd1\x92\x1b\x91qDa\x05}\x06=\x9c\x9aj\xa5\xd6s\rWz\\\xb9\xc2\xda\xc7\xb2K}I\x22 \x22\x88\x08\x82\xcdt\xf4\xc0<\xa3\xb9\xa2Hp\xd9h\xdb\xb7\xc7\xc5g\x81X\xe8\xa0\xe5M\xeau\xab\xfbb\xc9)N\xb0\xd8\xd7j\xa5\xc9F\x8b\x91i\xf1y\xf4\xed\x8a\xc2R\x8a\xd9\xa8v\xe6}9&Y[\xa4\x003\xadAE\x02\x92!\xe2p`\x98b\xc90\xd3\x1ap8\\\x00A\x04D\xc4\x818\xf1(\xe6\x9c\x88\x13\xc4b\xa3\x1a\x05sqP\xc6\x81b.U\xa1\xe9\x85\xa6\x1fR\xe5\x04A\x93\x88\x89OE+fm\xcd[\xde\xfb\xe4\x8b\xe4\xca>e\x92\x80 \x18\x88\x81\x80\x08\x98\x1a\x98\xaa\x19\x86\x01\xc6\xa6d\xc65\x16c\x14\'\xde{\xe7\x1d\x92\xc0\xcc\xd8`\xe2E<&\x98\t\x94\x0c=*4\xc1U)UK\x83\xc6y\xc9]\xd5\xb8\xb6\x12L\x1c\xb4\x87\x1aR\x93R\xd3\x8cd\x9d \x12B\xe6\xc4\x8b\x13\xc30\x14u\x08(f\xa0\x900\x84\x84%M\xc9\x14\xbcw.\x88\xe4\x88\x07\x0c\x13\xf1hm\x96\xc4\x07\x91\xa0\x9a|\xea\x16\xcd\x954\xb8diP\xa6\x95fl\xcb\xe8\xe4\xecDn\xb5J\xd4\xe0B\xabi\xcf%?jZ\xa96f`\xa4\xa6\xb2\xa6\xaf\xa91\x8dhL\xfd\xf5\xa6;\xd0F\xf1\\\xe9\xca\x89%\xb9\xb4\xeel\x03\x98\x19`j\x8a\xc4\x85=\xec\xbd\x89\xf91\xb6 \x81\x118\x08\xb7\xc0\x18xx\x03\xbe\x0c\xe7\xa1\x82\xfa$\xbf\xed\xfe\xf5`y\xd1\xa7\xaa8\xf0\xddU93\xc9\xdb\xb6\x11\x80\x01\\\xc1\x866X\x91\x98\xc8;\xd8\xf5\x94\x87.~\xee\xf5\xcf}R\x1e\xfeo\xfe\xc9\x03\x8f\xbds\xd7\xc2<\x10\xf2\xc29\xcf_a\xa61F\xc0\x89\x03<\xbe\xe5\xb2\t\xd7\x99-g\xcb\xac\xd3\xd9\xe5t\x85z9u\xeb\xd5Kqq)v\x07\xda\x8c\x95r\xc7\xbd\xd3\xf5\xea0\x9f\x1c7\xdf\x11\xf3&QD\xb1T\xad/\xad\x9f\xed\xe5cY\x7f^\x17\xb3x&\x1e\xeb\xae\x7ff\xc7\x7f\xb8\xb2\xf7\x9el\xeb\xedw;7V\xf9\x22\x85\xf9\xe8S\x15}\xf7\xe2Z\xd1\x9aY\xbfRl\x1d\xdc\xdc\xaa[\xa1]\xca\x0c2\x0b%\x9b\xfa\xd8\x19\xec\n\x1a1\xeb\'\xd6\x13u\xa4V\xa2\xb0\xc1\xc0\x0c\x04\x03\xc3\xd4\xd8\xa0|\x93w\x84\x00V\xf5\x87\xbd\x8b\x8b\xf5\x89\xd3\x9c>\xe5\xce\x9f\xf6\xcbW\xdc\xca2\xbd57\xec{\x8d\xcd\xfc\x82LM\xc7\xb2\x9dn\xbdc\xebco73B\xb0,36\x19\x1bL\xcc\xd0\x88\x19\xdf\x22 \xbc\xc90\x10\xc3\x00AD\x10@@\x040a\x83\t`\x02\xce\xbb\xc1\xeaj\xff\xf2\xa5x\xf1\x9c\xbbp\xd2\x9d9\x1a\xd6/f\x83\xa5\x9cA\xf0U\xeaHlw\x86\x93\xd3\xcd\xe4\x96j|V\'\xe6\xdd\x96]\xd9\x96]\xa3\x933\x85\x17I]\xc9K\t\x01A\x04\x04\x04\x10\x04\x04\x13\x10\xcc\t\x82\x89\x99\x80\x80`\x02\x82\x08\x08 \x22|\x93I.\x22\xe8\xbaJ\xdb$\xb34H\xa6\xbdj\xf5\xfc\xe2g\xea\xfa\xe8\xda\x00s\x92\x15\xe5\xc8\xd8#\xdb\'nS3\'\x05\x12\x9a\xd85\x82w\x9d\x18\xab\xa81!\x08\xd1$A\x82\xc6$\x81\x22\x06I\x8d\r\x06\xc67\x99\x02\xb2\x01\xc3\x0cL\x001\xc9|\xa1\xd8\xa0\xea\x17Y+\x84\xacj\x86)\xa6\x8b\xfd\xb5\xcf\xbf\xf1\xe4\xc0\xbbN92Z\xb4\x1e\xdc{\xdf\xbe\xd9\x05T\x83\xcf\x9d\x0blr \x18\x06IA\x04q  \x88\xf07\x08\x7f\x8d\x80\x87\x8co\x12(`\x0b\xccA\t\tV\xe0,\xf4\xe0%\xf8\xcaY~U\xff\xcf\xe1\xf2\x15\xd7\xf2\xe5\xde\x87\xfan\xf7\x04\xb7\xef\xe6;:\xc4\xf27\xfe\xe4?\xc9\x0f\xfd\xb3_\xfc\x99\x7f\xf6S\x0f\xdf\xb4+%\x8b1&\xddd\x18Wi\xd2\xa4I\x10\x8f\x80\x00A\\\xe9\x8a\xe0<\x1eJ0\x18\x822\xd4j\xa8ucZx\x19\x1bmA\x03%8\xde\xa4\xd0\x87\x12\x02W\xfd\x19/>s\xe5?_\xff[\x17v\x1e\xd4mw\xdcg\xf9T\xe3;\x8d\xdfR\xfb\x22\xd9h\xd1\xb8\xe9\xd1\x99Q-\xa7d\x9c>d0\xc6_\x13a\x08\n\xc6_a`\xbc\xc90\xe3\x1a\x03cCp\xe4\x013\xed7\x97.\xf7V\x8e_\\?|*\xbd\xf4Zq\xeaDq\xf1\x82_[\r\xeb\xebE]U\xf3[mzzmrF\x7f\xe0\xd1\xbb\xff\xe9\xf7\xb2\xa1Q\x9a\x88&68\x87\x80\x191b\xc6_c\xfc]x\xe1\x9b\x04\xccpB\x96\r\xbb\x83\x8b\x97\x97V\xcf\x9e\xee\x9ex\xad9\xf6\\\xb6t\xbc\xe8\x9d\xcb\xe3Z\xf0\xfd\xa6\x9d\xeb\xe4D\x9a\x9e\xae\xb6\xec\xa8f\xf7\x96\xdbn\x1f\x9b\xd935\xbd\xb0\xb55*$X\x83\x16d\xfcM\xc2\xff\x17-PX\x85\x11\xe8pU\xc5\xfa3\xab\xbf\xe6\xd33\xbdae>#LN\x8f\xbc\xef\xae\xe2a6%h`\x15\x02\x8cA\r\x91o1P$A\x82\x04\x8a(\x9b\x8ck\x8c7\x19\x9b\x8ckZ\xb4#i\x95\xf5QF\xda\x14@\x80\x97\x19\xfcO\x87>vIz\x9d\x91\xd1\x99\xf6\xf4\xbf\x9c~\xecm|{\x06\x89\xbfI\xf8\xdb\x19\x9b\x04\x04\x1c\xdf\xde\xe7\xe1\xff:\xc7\xbfM\xbf\xac\xeb\x97\xa5S\x86\x9d\x0f\xf6d\xfb\x14\x07\xf7\xf1\x1d\x1d\xa2\xf7\xdc\x7f\xfd-\xf9\xd9\xff\xf9w\x7f\xe2}\xdfw\xeb\xee\xa9\xdeZWl\x93&5\x8ckDU\xb9\xca\x19\x82+}\x1e\xf0\xc1\x85 \x05\xb3\x10a\rM\xd5j\xea\xf6\xb4j4\x95\x19\xf3\xdbF\xa1\x82\x11(\xc1\xc0\xd8\xa4\xb0\x16{\x1a:%\xe4\xd0\xfa\x14/>s\xe9\x03\x0b\xbfsq\xe7\x81f\xd7\xedw\xf5\xc2\x96Ptj7W\xb9\xe9\xb9\x91\xad{\xfc\xa8\x10S\xdd\xf7+c\x88\'\x876d\xbci\x00]H``\\el26\x19\x9b\x94k\x0c\x94o\x12\x1069!s8\xbfxf\xe5\xf8g^\xca\x9fyy\xec\xc8\x89XW\xf9\xeaz\xbb\xbbV\xcdN\xa5\x99\xa9+\x0b\xdb\xec\xfb\xee\xbf\xeb}\xf7\xd1\x8f\xf4\x064\x91\x181%\xf38\x01c\x93\xf1\x97\xcc0\xe3/\t\x9b\xcc\xb8\xc6\xd8 \x80!\xe0@\xc0\x813\x9cP\x14\x88`\x10\xb2s\'\x8e]:\xf4\x85\xb0\xf2\x8dV}$ou\xf3\xd6\xea`d\xc2\xc6\xc6B\x91-\xb7oKc\x0f\\7\xff\xd08\xe3\x90\xa0\x86\x08\n\x8e7\t\xdf\x91\xf1\xb7\xcb\xc1A\x02\x0f\x9eM\xedH\xff\xc5\xe67\x0by\xbe[\xa9\xf7\x017\xd6\xca\x7f\xf2&\xde\x0e=\xe8C\x05\xca&\x07\x0e\x1c\x9b\x84k\x0cP\x88H\x83T8\x05\x03\x03\x01c\x93\x82\x81a\xca&\x03\x83\x8c\\\x90\x84y\x82\xc7\x83\xb4(\x8eQ\xfd\xfc\xe2\xe7.\x84\xfeHgb.l\xf9\x17\xdc\xfa\x10t\xd9d\x90x\x93\x81\xf2&\x03\xe1M\xc65\x8e\xbfI\xc0\xc0\xb3)@\x87o\xe3\xf3\xf0\xcbg\xf9\xdf\xd2\xaf\xe8`%\x1f\xc9d\xfe\x81\xae\x9b\x9f\xe5\x86\x1b\xf9\x8e^a\xf8\xcc_\xfc\xae\xfc\xe7\xff\xfd\xe9G\xff\xfe]\xbb\xb7\xfb\xc1\xf2 \xc6\xa8IM\xd50\x03\x8f\xcb\\\xf0\x12\x92\xa5\xa81Y\x14C \x93PJ\xe1\xc8\xd8\xedY\x83\x15m\xb4\xbf\x9c\xd6\xd6\xd3\xb0\xb2\xd8\xce\xdc\xee\xeb\xc6!\xc2\x08\xe4lR6)t\xab\xb5:\xeb\x94\xf8Rh}\x96W\x9f8\xff\xe1m\xbf{f\xfb\xfef\xc7\x1dw\xd5\xc5\x16\x9f\x8f\xf7\xddBQ.\xecj\x8f\xcf\xe0\xa0\x17\xbb\xfd\xb04\x89oQ\xc2\x08\x14\xbc)B\x0f*P0\xfeN\x8cM\nQ\xc1pJ\xeeh\xf9f]_?\xb4\xdc\xfb\xd2\xebc\xcf\x9f\x91n\xafX^\x1d]\xeb\xd5\xd3Sif\xf4\xf2\xc2\x14\xef\xd8{\xebO\xece`T5\xc3\x9a\xa6\xc6\x12\x85G@ x\x84\xbf\xc6\x0c\xe3*C\x00\xc1\x94\rb\xa0`\x88\x82\xe2\xc0\x83\x13<H\x82H\xf0d\x01_B\xbb\xa2{v\xf5\xf4\xfa\xf2\xf3y\xef\xebcv\xca\xb3>\xf4c\xae]H\xd9^\xc9\xeek\x8f=\xb07\xec\x04\x07\x15T` \x10\xc0\xb3\xc9\x81\x80p\x8dq\x8d\x81\x81\xb1\xc9x\x93\xb0I@\xb8\xc6\xc0A\x0e\xc2&\x07\xadD\xf5\xb2\xfda[^\xea\xd5jR\xd6L\x8d\x86\xef=\xc0-\x22\xab0\x80\x1a\x94k\n\xf0l\x12\xded \xe0 \x18!\xe1#N\xb1\x86\x940E\x15S6\x19oRLp9\x85!\xe0\x05\xdf\x86\xa3\xf0\xaf\xea\x97\xaf\x04k[gV&~\xc6M\xddk\xd6\x87$\xa2\x90\xd8d \xfcM\x06\n\xc65\x89M\x02\x8eM\xc2&\x01\xc75\x1e<\x14P\xf2m|\x1e\xfe\xe3I~\xce~\x93\xc1b>5\x1efo\xe9\xb9\xeds\\\xb7\xdfLD\xf8v^\xa3\xff\xd5O\xbe_>\xf3k\x8b\xb7\xfe\xf0\xd4\x96\xad\xd0\x83\n\x1aP0\x0c\xdb \x1b\xbc\x98aj\xd8&\xa7X2\x17<\xb3B\t\'\x18\xd4\xab\xc9\xd2JZ\xebjU\x11\xdb\x85\xdf\xb7o\xc24\x8a\x1b\x83\x0c\x04\x0c\x0c4\xe9z\xd3MY\xa7\xc4\xe7B\xfb\xb3z\xf8\x0b\xc7?:\xffGol\xdb]-\xdcq\x8f\xeb\xccJk\xae\xcf\x8e\x1dS;\xb7\xfb\xbc\xa0\x07\xbd\xe1r\xbf\xbc2C6B\xcb1\x06-\xfe\x9a\x06\x1aP0\xfev\x06\xca\xa6\x085\x9b\x04<\xe4 \\\x1ep\xfc\x89\xb5\xe2\xc5\xc5li\xbd\xbd6\x98\xe8\xa7\xc1T\'N\x8c\xacL\xb6\xdd-\x137=\xd2bC\x03C%EL\t\x82\x13\x042\x8f\x03\x11\x106\x88a\x06\x02\x86\x00\n\x0e1H\x90 \xe1\x22\xa28\xc3\x83\x136\x19$\xa8\xc1 \x876\x94 ]\xe2+\xcb/\xda\xda\xf33\xe9x\xe6\xd6\xfbn\xb45RT\xd9X\x95\xdd\xbd\xbd\xbcm\n\x075\xd4\xa0\xe0\xc0A\x01\x01\x04\x1c\x08\x08\xd7\x08\x08\xd7(\x18\x18\x18o\x126\t\x9b\x04\x04\x22\x9bJp\xe0\xb9\xaa\xc2\xbe1|\xbc\xedNv+S\x8an\x1a\x9f\x0e7\xdc\x92o\xcf\xf3\x08\x15\xd4\xa0``P\x80\x07\x01\x07\x02\xc25\x02\x0e<d |\x07\x06\x06\x06\x86E\x92!\x1e/ `\xe0\xe0u\xf8O\x83\xd5%\x17\x8a&\x9f\x92\xf4\xdf\x8a\xeeS-\xf3<\xe4y\x03\xca5\xc6&a\x93\x81\x81\x81\x82\x82\x81\x82r\x8d\x03\x01\x01\x01\x0f\x0e\x1c\x088\x08\xe0\xf8\xf6\xbe\x08\xff\xf1u\xfeq\xf3\x9b\xae{\xc1MMt\xb6\xdd5(\xf6\xcc\xa5\xb9\x03\x22\xde{\xbe\x9dCT\xcf}\xf1\x03\xf2\xf5\x17l\xf7ML\x04\xbe=c\x93\x82\xb1\xc9@A!\x87\x1a=o\xc3\xe1Z\x226Vw\xad\xdf\xa3\x89\x9eV\xee\xf7\xee\x1d\xd7\x94\xbc\x1f\x81\x9cM\x06\x02)iWk\te\xa1xG\xfbS\xf1\xf0g_\xff\xe3m\x1fyc\xeb\xb6\xee\x8e\xdboct\xben\xed\x8ea\xf7\xdb\xa6wt\xb2\x0c\x96\xb1~\x7f\xb1W^\x9eu\xc5(\xad@\x07mA\x86\xb0I\xc0@\x8cM\xc6\xdf\xca\x0cK\x88 \x06\xca5\x02\x01<\x1b\x9e9Ju\xa6;\xde\xef\x8f\xd5\xd5\x84\xf3\xeb\xad\xd6\xb0\xd5\xea\x85\x90\xcd\xfa\x9b\xae\x17\xbe\xa9\x01\x83d\xe6\xc4\x040@\x9c\xe0\x11@\xd8d\\#l0\xe3*C\x14T00H\x8a9<\x9b\x12($0\xf0\x90C\x06\x1exb\xf1p\\\x7fuL\xcf\x8f\x855\xcb\nk\x8f\xaf\xfb-Eq\xe0`\xd8#RC\x05\r\x18\x9b\x1cx\xc3\x19\x08\x198A@\x0c\x84\r\xc65\x06\x06\x06\xc65\x02\x02\xc2&\xe1*C\xc1@\x84\x1c\x84\xab\x16\xa9\x9f9\xf3tG.\x0c\xacc~DC\xb9\xad\x98\xbf\xdeMf\x99\xe4\xb9\x88D\xae2Lq \x86:\x82\xc3s\x95\xf1M\x02\x0e\xbc!\xfc\x15\xc6\xb7a``\x18\x88\x03a\x93\xc1\x89\xc4\'.\\\xa9%\x1f\x95\xd4\xf6\xfaP\xe6f\xbd\xcf\xbc\xcf\xae\x12\x11\xfe\x0e\x0c\x94k\x1c\x08\xff\xaf=\x0e\xff\xf7\xeb\xfc\x83\xde\xaf\xd9\xe5\xe3an\xaa\\x`\xd0\xban\x9b\xcd\xde\xe2}\x9e\xe7|;\x87\x88\xcf>\xf1!\xf9\xfc\x7fX\xde\xffC\x13\xdbv\xb1\xa9\x81\x1a\x12f\xa8al\x12a\x83%4\x19jNM\x1b\xd5\x98bl4\xc6\xa8u\xb2\x94\\3\x94\xa6\xf2$G\xab\xcc\x16\x16F\xcc\xf0\xbe\r\x1e\x04L\xd8\xa0Q+\x8b\xf8<3\x82#\xfb\xf8\xea\xf3\x9f>\xfc\xfe\xed\x7fraf\xdbp\xe1\xa6\x9b\xfc\xf8\x02\x13\xd7\xd3\xda\xfb\xd8\xcc\x8e<\xcfE\x96\xb0AoqP.\xce\xb8|d=\xca\x92\xea\xe9\xa6\xe9\x9a\x9aG\xccPe\x93\xf1-\xc6[\x19\x08N\x10\'\x22!\xc8\xc2X6\xd3\t\x93\x85\xf7\xc2\x06\x11D\xc0\xb1\xe1\xf1S\xb1{\xa9;\xad\xc3q\x8bS\xb9\x1fd\xad:o]B:\x93\xe1\xb6Y\xc7_QGU\xb3#\x8b\xc33+\x95\xa9\x8a*\x96\x10\xc7\x06S\xded\xfc%QPP\x9c\x8a\x17\x1351\xcb\xcaNY\xec\x1c\xc9fZ\xd9\x98\x0f\x86\x81\t\x08\x19\x04\xe0S\xe7\xcfV\xab\'\'X\x9ajU\xa1\xd5\xa2\x18]uS\xa3\xed\xed\x07\xdc\x98\x88@\x03\x06\xcaU\x8bV]\xe8VgW\xabd\x86\x19\xa6\xa8\xb2A\xf8\x163\x8cM\xc65\xc2&a\x93p\x8d\x08\x86\x08\x08\x1b\xccL\r4\xc6j\xb0v\xb6t\xc3\xc6\xb7p\xb9\x16\x13{\xa6w\\\xef\\\x08\xa1\xddn{\xef!)6 \xbd\xb4\xdc[\xea7\x98\x9aF,\tb\xa6`l\x12\x103\x0f\x0e\x04\xc4\xd8 `|\x8b\x81\xb1\xc90\xc0\x04\x10\x10\xdb\xa0\xa6\xc80\xa5\xa5\xf5e\x95\x90g\xc1I\xba\xad\x9d\xdf01\xe1E\xb2,k\xb7\xdb\xce9\xfe\x7f\xf1q\xf8\xa5\'/\xff\xa3\xf5_\xb3\xcbgG\xf6,\x14\xf3\xb7\xeb\xe4\x8d\x0ba\xe1\xd6\x10\x8a\xa2\x10\x11\xde\xe2%\xf4\xa9\'\xffX~\xffg\xbf\xb8\xf7\x07n\xd8q\xfd\x14B\x8a1j\xaabe|\x8b\x99l\x08\xde\x10SCu$\xf3\xe3\x9e\xd4\xc4\x94R\x13\x1bMII\x92\xbb\xdaktn\xb1N\t\x9d\x9d.\xc49\x17\n\x11\xcf5\x06\x16\xab*U\x8d\xcb\x83\xb5}\x94\xf8\xf9\x93\xcf}\xf5k\xbf7\xf1\xa9\xee\xe4\x964\xb5k\xc7\x96\xbd\xfb\xa6\xae\xbb=\x1b\xdf\xf7\xe8\xcc\xee\x10B\x96u-\xf6\x86\xdd\xbaX\x9aj|~\xe2\xca\xf0\xf0\x85\xeekWz\xddfX\x14\x885\xa6\x11L\xf8\x163\xc0@\xf8&3\x10\xccDp^]H!\xb8\xb2\xbcnK\xe7\x86\xad#7\xce\x8d\xe4\xce\x99\x19W\x85,d\x99\xfb\xe4\xa1\xe5\x0b\'\xcfO\xc7\xe1\xacg\xae\xcc\xad=Fg\xe4\\\x18\xe9L\x85\xbb\xe6\x1c\xdf\x12\x9b\xd8\xadj\xb0?;t\xe5\x953\x8b\x12\xa3o\x06Y3\x10A\x10\x13\xc4\x14C\x040\xc3\x84\rf\x92D\x0cI&f!\xa8\xc3\xc4\x9a\xf6d\xab3\xb6\x7fv\xf4\xe0\x96\x89\xeb\xa7F\xcc4\xa5\xa4\xa9\xf1Y\x91\x87\xd2\x13\xfe\xf0\xf0\x1b+\x97\x8f\xcd\x87\x95\x99\x11+FG\xb2v\xa7\x1b\xa6&Fv\x1e\xf0c\xce\t4` \xa0C\xd2K\x97\x97_=\xb7\xf8\xfa\xa5\x15oUF$V\xa6\x8a\x99\xa0\x80\t\x98\x81\x99\x98\xb0\xc1\x0c\x13\x1c\x88!\x82\x031\x10\x04\x1c\x88\xe1\x00g\x8d\xd3:E\xcdDK\x17m\xd8\x15KC\xcb\x9b\xa8at\xf6\xee\xdb\xef\xbdyd\xa4U\x96\xedv\xdb{j\xaa*i\xaf\xd1?>t\xf6\xec\xf2j&\xea\x87\xeb\xc4\xbew\x8e\rf\x80\xb1\xc1\x1b\x1e\x9c!\xe0\x8cM\xc6\x0630\xcc\x0c\x03\x110\x141\x11\x03\xd1h)\xc6\x94\x10\xf1A\xe2\xb0[\'\x89\x92Uu\xb5ov\xf2\xb1\x9boj\xb5\xdbE\xab\x95\xb5\xdb\x8ds\xc6&\x03c\x93g\x93\x81\x83i\x98bS\x04\x05cS\x06\x8eM\x97a\t\x1c\x18\x18\x08\x08\x9b\x0c\x0c\x124\xd0\xb0\xc9\xe0\x93\xc7V\x7f\xef\x13\x9f\xff\x91\xc1\x7f\xc9c?\xdf\xba0w\xfd-\xd3\x07\xef\xdf\xd9\xde}k\x96\x85\x10\xb2,\xe3-^D\xbf\xf2\xa5\x8f\xc8?\x7f\xe4g\xc2\xf6\xed\xf9\xe4\xa4\xe6Y*\xb2\xda3\x8c\x95\n \xce0\x8dfd\x9d\x11i\xb7\xa5\xdd\x0e\x22\x07\x17\xa6\xdf{\xe7\xb6z0l\xaa:5\x8dZ\x92\xe0CY&\xcfJ\xd5|\xf8\x1bgL\xd7:\xe50\x949\xae\x14\x0b&\x98\xd5A$i3\xb8t\xa1YZ\xf3\xa3\xb2\xbau\xd0w\xbdjp\xa1^>6\xf6\xb1^g,N\xde\xb8o\xdb\xfe\xdb\xb6\xec\xbf-\x1f\xdb\xfd\xd8\xdc\xbe<\xcf\xb3\xac\xd2f\xb9\xa9\xd5.\xb5bh}\xf5\xe8\xea\x93/]\xd6\x95\xae\xd4\xbd\x82(.\x19*\x9a\x04\x03\x0c\x84M\x86\x01\x82\x19\x9b\x045\xe7\xd49\x9c\xa4<\x8b\x9d\x8e\x9f\x18\xdf\xb7g\xf6\x07n\x9d\xcbDLUSr\xce\xf9<\x14\xed\xe2\xe3O\x9f>y\xe8x\xb9ry:\xd6;\xda\xadb|6\x1b\x9f\\,[\xad\x85\xe9;\x0f\x8e\nBL\xa48\xac\x86\xbd\xa6\xc9\xcb\xf2O\x9e?\xf5\xea\xb13Y\x7f9\xeb\xaf\x85\xe1\x9a\xa4$N,\xe4b\x91\x14\x85M\xe6\x10K\x98\xe2\xb1\xe0!\x893\xf3\xc1\xbc\x93\xccWc\xf3qd6\x1f\x19\xbb\xef\xba\xf9w\xec\x99UM)EK\xc9gy\xc8\nO\xf1\x81o\xbct\xe9\xdckc\xf5\x85\xf9\xb1865\xde\x9a\x1c\x1f\x14sSc{\x0e\xe6[\x9dkD\x22\x18\xa0X/\xe9G_=\xf9\xc6\xc5\xf3\xb6v1\xc4\xf5\x9cZSBUH\x02\x86 \x08\x06\xc9H`\x90\x10\xc5\x1c\xe2\xc0\x81\x13\x9c\xe1\xc0\tb\x188@,a\xb56\x16$e.\xa5\xa6\x22\xc5A*\x87)\x0fc[\x1f\xbc\xe7\x91[\xc6\xa7\xda\x9b\n\xef\xb5\xa2i\x12\xbdh\x1fz\xf1\xf5KW.\x14i\xd5\r\x97%\xf6\x05\x11\x0c\x10\x13\xc3\x9b\x04,\x18^\xc5\x9b\xf9\x04\t\x0c3P3\xc5\x0c\x0c\x8cM\tT\xa41\x1a\x8d5Z7\xb1\x87$/U5\xa8cR#6:2\xbdu\xfe\x8e\xbb\x8b\xbc\xed|N\xa2Z\\\xd1\xa48\xe7D\xc0p.\xcbZ\xa1h\xf9\xa2\xed\xb3\xfc\x8e\xb9\xe2\'J\x0cj\x88` \xd0\x82\x00\x15|\xb0\xc7\xcb\x17\x06\xa9\xae\x9af\x98b\xe3\x92\xa9)f\x18*T\xc80\xd6\xfd\x94R\xa3M\xdd\xac]^\xbe\xf4\xc2\x8b?\x9d\x7f\xd29dz\xd7\xfc\xfe\x9bfo}dWg\xe1\xf6\xb2\xcc\xf3<\xcb2\xde\xe29\xd2\x97?\xf3a\xb9\xfb\xfa\xfb\xdc\xd8\x84/\xdb\x84\x9cv\'\x05\x1fUM\x106\x08\x82\x88\x0be\xdb\x8d\x8e\x87\x89\xe9lt\xe2\xee\xfd\xbb\xff\xc7\xc7\x0e\xf4\xba\xddj0l\xea\xda\x0c\x1f|\xd1.\x8b\xb2\\\xae\xf5\xdf\x7f\xeay\xe9\x9d\x9b\xdb\x12\x8b\x911i\x06>\x05M\r\x0c\xc5\x87T\xaf-\x1d:.\xc3\xa1\xdf\x1eNn_R\xed\x05[w\xf5\xda\xc8\xd7b\xf6z\xdaz\xd7\x9e\x99[\x1f\x9b\xbb\xfe`9\xb6\xe3\x9d\xf3\xfb[\xad2\x04\x85\x95zXw/J\x08\x9d\xcf\xbep\xf9\xc9\'\xcf\xb4W\x87\xf9j\xb7X\xebJj\\\x8c\xa8\n\x9bL\x14L0\x03!!\n\x98\x98\xd0\x98\x13\x0b\x98\'u\xf2z\xb4S\xcd\xce\xee\xb9\xe5\xba\x1f\x7f\xe4\x06\x9f\xd4b\xd4\x98\x9c\xf7\xbe\xc8\xb2N\xeb\x13_z\xed\xe8\xd3/\xa7K\xa7FW\x16w6\x83\xe9\x99\x9d\xad\xe9\xe9\xe5\xd1\x91\xf6\xcd\xfb\xee~\xf4fW\r\xa8+R3H\xdaW\ry\xfe\xb1\xc7_>\xfa\xe2\xa1r\xe9\\\xd6[r\x83U?\xe8\x81\xa9\xf7\x10\xa5V\x1c\x88\xe1T\x0c#\xe1\x94\xa0\xe6\x15\x17\xc9\xc42\'\xe3e=\xb5\xa3\x99\xdcQ\x8f/\xdcw\xcb]\xef\xbey\x7f]\xad\xa7\xd4\x98\xa9\xcf\xf2\x10Z\xce\xb5?\xf0\xfc\x97O\xbd\xf1dX9\xb6\xb5}elf||\xeb,#;g\xa6\x1f8\xd8\xbe\xd9\xfb\xbes\x11\x22\x84\x1a\xdf\xab\xfc\x07_x\xee\xfc\xe5\xa3\xad\xeaBV\x9d\x0b\xcd\xa2O\xb58\xc3LD\r56\x98XD\x14\xf1\x06b\xd1\xc4#\x01\x14D\x10C\x00\x01\x03\x0c\x01LAM\x05K\xa6\xd1\xa3\xcei/\x8du\xeb\xf10\xb6\xfb\x81\xfb\xde}\xeb\xf8\xc1V+\xb5\xdb!\x04\xa9\xc4\xc5\x14z\xd1>\xf4\xf5g\xaf\\9\\V\xe7\xb2\xb8,M\x97T\xa9)\xb6A\x0cQ\xc9#y\x22O\x84h\xa1&(\x9b\x8co\x12\x03E@\x15\x12$\xa36k\xd4\x224\xcai\xf5\x03\xe8i\xaa\x924x\x1d\xa601U\xdczS\x08Ef\xb9\x1b\x9a\\\\1\x13\x82\xc79\xf1\x01\xf1Yh\x85\xa2\x93\x8fL\x95\xed\xc9\x87\xb6o\xfd\x85q\x12\xf4\xa0\x06\x03\x07#\xd0\x82\x1a~\xf1\n\xcf\x9e:_W\xbd\xaa\xea\xc7z\xa0u\x1dcc\x9a\x04\'.SC5jJ:\xd4\xd8\x1f\xd4\x97\x96\xcbO\xfd\xfe\xfb\xee\xeee\xdb\xb67\xd9\xec\xcc\xde\xeb\xb7\xdc\xf2\xd8\xce\xce\xb6\xdb\xdb\xedV\xab\x15B\xe0-\x9e\xc0>\xfd\xb1\x0f\xca\xfc\x96\x9d\xaeh\xe1\x1c\xe2\\\xd9&\x045\xe3[$\xcf}^\x8as\xd9\xc8D>\xbb\xb5\xbdm\xcfCw\xde\xf2\x0b\xdf\x7f\xe7\xfa\xeaj\xaf\xdb\xab\xab\xca\xcc\xbc\xf7e\xbb=6>\xb6\xae\xfe\xdf}\xe4\xb3sc\x17\x8av\'h\xed\x9a~\xa8k\x89\x03H\xe6\xa4w\xf1\x8d\x95c\x17S\x1d\xdd\x9d\xee\xf4\xf4\xaai\x1f\x1b\x90\xea\xb1s\xae\xf5\x15?\xb9w|\xe6m?6\xbf\xef\xe6\x91\xa9]\x8f\xce\xed\xcb\xf3\xbc\xd5\xf2PU\xd5j=$\r\xdb\x9f{\xea\xcc3\x9f9T\xacT\xc5r\xb7\xb8x\xc9u\xbb4\x15N@\xc1\xc0@\xc1@!\x89\x98\xa1\xa0H\x8d%<\xa9U\xc6\x91\x22NMT\xf3\xdbw\xdd{\xebO\xbe\xe7\x1eW\xd7iXk\x8a.d\xa1\xc8\xf2\x91\x91\x8f\x7f\xf6\x85cO|\xbdY<\x1f\xce\x9d\x9cZ:\xb7uj~tn[oz\xcb\xd8\xbd\xb7\xdd\xff\xee\xfb]\xbfG5DS\x9d\x17\xfd\x94R\x8c\x1f\xfb\xd4\xd3\'\x9fy\xb6X<\xe3\xd7\x97\\\x7f=tWi\x86\x8a\x99\xc3\x14\xe7\xc4\x04\xf1`\x22$$\xe1\xd5\xbc\xe1\x05\x87\x15\x81\xd1\x918>\x1d\xa7\xb6W\xdbn\xbc\xfb\xe1w\xbd\xe7\x81;\xab\xf5\xc5X\x0f\x0c\xf5Y\x1e\x8a\xb6/\xda\x1fx\xfc3\xa7\x8e<\xee\x17\x8ft\xec\xbc\x1f\x99\x98\xda\xb1\xb5\xbde\xe7\xf6\xdd\x8f\x1d\x18\xbf%\x84~\x9e\x1b\xc4Dh\xb4\xacS\xf8\xfd\xa7?\x7f\xe1\xc27\xca\xb8X\x0cN\xe4\xc3\xb3\xaeY6\x03\x116\x08`\x06\x98Bc>G\x9cXe\x04sAH\x80\x13\x8cM\x86\x08\x86\x19\x08\x06\x82sh\xb2\x18MDq\xf4t\xa6\x17\xa7\xc2\xc4\xde\x07\x1e\xf8\xe1;\'nm\xb5b\xbb\x1d\x08D\xf2dy\xbf\xb1\x0f<\xf7\xb9\xa5\x0b_+\xfa\xaf\xfb\xb4j\xf5\x9a\r\xd7\x1aU\x90\xa4\x16U\x1a\xf1\x03B\x8d\xab\t5\xbe&$\x04P6\xa9\x91@\xcd\x80d\x96PM\xd6Wj\xa3AjdE\x8b&\x92\x10\x08J\xa0A&\xc7\xc3\xc1\xed\x92$\xb8\x96\x1f\n\xe7\xd6\xcd\x84,\xe0\x9d\xf3E\x08-\x17\x8aP\x8e\x96\xa3s#\x13\xbb\x1f\xb9\xfe\xc0\xbf\x9e)#ta\x08\t\x1c\x8c\xc08(\xfc\xcb3\xfd\xa7\x8e\x1e\xab\x87\xeb\xc3\xe1\xfa\xb0\xb7\x9a\xeaAS\x0f5E\xe7r\x91\xdc\x101\xa7\xb5\xea\xc0\xd2\xda\xba\x7f\xf2\xf1\x9f\x9a~q\xeb\xad;ll\xcf@\xb3\xe9\xeb\xae\x9f\xbc\xf1\xde=\xe3\xd7\xdd\xden\xe7y\xdej\xb5x\x8b\xcf\x12\xff\xe2\x8f\xfe@n\xdb1\x8f\xf7\xc9$\x99\x99\xf3\xd1\xa8\x92\x19\x08\x08H\xf0.\xcb}k4\x8cL\x84\xa9\xd9\x91\x1d\xfb\xee\xbf\xf7\xde\x7f\xf5\xde\xb7\xf7{\xdd~\xafWUCM\t\x91\xb2\xd5\x9e\x9c\x1c_O\xee\x13\xcf}ae\xd0\r\xa6a\xb8\xe8\xab\xaek\x06\x96\xa2\x13\x9f\x9a\xd5\xf3/\xbcX\xf5Sk*.\x1e\\^)\x9aD\x8a\xa6.i\xab\xb6-\x9f\xea\x98\xa5\xbd\xef\xf9\x81mw\xbe\xab5y\xddc[o\xc8\xf3\xbc,\xc5\x88\x8d\x0ec\x1d\x13\xd9\x9f?\xf1\xea\x93\x7f\xf6D\xfb\xd4\xd1\xfc\xca\x19\xbf\xbeL\xbfku\x8d\x98\x81b\xbc\xc90\x03\x03E\x0c3P\xbc\xd3\xb2\x93&g\x9b\xd9\xedi~\xcf\xde{\xee\xfb\x07?\xf4\xb0TU\xaa\x86\xa9\xae}\x96\x85\xbc\xc8GF?\xfa\xd9g\x8e|\xf5\x89t\xeeX\xbax2[\xbb\xbcmzz|\xdb\xce\xe1\xc2\r\xb3w\xde\xf7\xe8c\xf7\xb9AO\x87\x03\xc5bV\x0eqx\xff\x91?\xfb\xd2\xf1g\x1e\xcf/\x1e\xf3k\x97|oY\x86]\x8b\xb5\t&\x98\x13\x1c\x08\x9b\x1c\x88\x82\x99\x83\x80x\x91\xcc[\xd9\xa63\x9eF\xa7\xe3\xc4\xf6f\xee\xc0=w?\xf4\x83w\xdf\x9c\x9a&\xc6\xda4\x86,\xcf\x8a\x96/:\xbf\xf7\x85O\x1f\x7f\xe5q\xb9\xf2Z\xe8\x9d\x97\x22l\xdd1W\xcc\xdf\xb8\xe7\xc6w\xde\xbd\xeb\xce\x10\x06\x99\xa4\x98Rc\xb9J\x91\xc8\x7f\xfb\xcb\x9f\xbat\xfc\x89\xce\xfa\xab\xbe\x7f\xd1U\xcbV\xf7\xcc\x14\x11\xc5L@\xb8\xca6 \x9e\r\xa6j\x82\x88\x81\x19\xdfd\xbc\xc9\xc0Lp\x22bII\x8a\xe2\xd4\x18\xfa\xd9\xdaM\xe5S\xd7}\xd7;~\xf8\xed;\xef\xc8ZMV\x063\xa00)\xfa\x91\xdf\xfa\xf2g\xce\x9d\xf8\xaa_zE\x87+:X\xab\xfb\xddh\x16\xd5%#\x99$\x9c\nfl0\xa8\x9c3P0$! \xc6&g\xa8a\xa0P\xa9$\\4\xd4\xb9a\xf2IQ\x13\xc19s\x98\x0bc\xad\xce\xaeIK\x0e2\xad\xac\xbe\xd47\x15\x9c\x88w\xe2C\x96\xe7\xa1\x1c\xf7\xad\x89l|ndn\xff\x83\xb7\xdc\xfd\xbf\xee\xdf%\xd0\x87!D\x14\xe8\xe0F!\xc1\xcf?\x7f\xf4\xf9C\xdf\x88\xdd\xd5j\xf5r\xbdv%\x0e\x87VW\xa6\x8ax\\\x8e\x04M\xc9\xaa\xb8\xb5Y\xbc\xce_\xde\xaa\xe7G\x16\xb6d\xd3\xfbk\t\xfd\x18\xa6\xf7\xec\x99<x\xd7\xde\x89\x1bno\xb7\x8b\xa2(\xcb\x92\xb7\xf8$\xd5\x17?\xf6\xc7r\xe2\xa5\xbfo\x828\xba\xbd\xb4\xbaV\x9f\xbf0<\xf2\xfaj\x13\xd5y)2\xef\xb3 >h\xd6I\xd9X,\'\xea\x91];v\xdf\xf9\x8f\xeez\xb0\xa6\xdf\xd4\xfd\x14+3\x15\xc4\xbb<\x0b\xed\xd5$\x1f~\xee\xa9\xa5\xcb\x17C\xb5\xe2\x07\xcb\xaeZu\xcdP\xcc\x9bU\xcb\xaf\xbf\xb0zr9&\xf2\xe9t\xe2\xce\xc5\x9eS\x84\xc6(\x12\n\xfb>;\xdet\xd3\xae\x87n\xdf\xfb\xee\x9fjM\xdf\xf8\xcem\xfb[\xad\x96\xf7$\x06\xd1RJ\xa6j\x7f\xfe\x8dW\x9f~\xe9\xabc\xbd\xd7\xdb\xf1\xa2O=\xab\xbb\xa2\xd1\x10SK\t\xc40\xc5\x143\x8co\x11UC\xcd\x10\r\xed\xd8\x9a\xaa[[\xe3\xe8\xce\xdd\xd7\xdf\xfd\xd3o\x7f\xd0i\x9d\x9a*5\xb5\xf3!\x84,/G?\xfa\xdc\xd7\x8e\xbc\xfc\xd5t\xe9\xb5\xe6\xca\xe9\xb8~e~ajjng3\xb5\x7fn\xf7\xbd\xdf}\xe7}Rw\x9bj\x98T#\xa1J.\xe4\xad\x0f}\xf6\xcbG^\xf8\x8a\xbbt\xcc\xad^\x90\xde\x92V}\x8djj)\xa9)\x1b\xd40e\x83\x999\x0c\xc3\x143I\xe64\x94\xb4\xc6lt\x92\xe9\xed\xb6p\xe0\xe1\xb7?\xfcco\xbb\xbd\xea\xf7\x9aj\xa81\x86\xa2(Z\x9d\xa23\xf2+\xff\xe5/\x0e=\xfd\xe5t\xf6\xb0.\x9dw\xce\xcdm\x99k\xef:x\xcbC\x8f\xfe\xc0\xdb\xee\xd1z\x18c\x13c\x22\xe4\xe4\x85\xf9\xfcW\xff\xf4\xd3\xa7\x9e\xfdR~\xf2\xeb\xb6~\x85\xc1:u_\xcc\xc4a\x90\x00A\xb8\xcapA\x9c\xe0\x1c\xe2\x9c9o\x88x\xc1\x89\x93M\x08\x0es\xc1;\xc4\x04\x13sb\x22\x88`\xe2M\xe8\x8flM\xad\xc9\xf6\xdc\xce\x87\x1e\xfb{\x0f\xdct\xde>~/\x00\x00 \x00IDATk)1\xcf=\x02\xbeP\x9f\xf7\x22\xef\xff\xdc\xe7\x97O~\xad\xb5r\xd4U+R\xafI\xdd\x131\x84\x84\x98\t\x22\x888\x87 \xc8\x06\x87`b\x22\x82\x08\x88\x19`\x80\xb0\xc10\xcc\x07\x11\x01\x9c3\xe7\xcc\x8c\xa6Q\xd3d\x88\x89\x9b\x99*\x0e\xee\x9f0\xb4iXYO\xaf\x9f\xecW\xb59\'E\x99{\xef\\\x9ei>\xa6\xad\x89\xd4\x99nfn\xb8\xfe\xc0\xdb~b\xc7A\xae\x89\xa0\xe0\xc1s\xd5\x87N}\xfd\xd4\xb1gC\xf7\x82\xeb\x9e\xf3\xbd\xcb\x0c\xd75F5\x110\xc4\x0c\x89\xfd\xd8\xeb\x0f\x16{Z[1;\x15\xc6v%\xd7\x8e\x84\x81\xe5S\xd7\xed\x9d\xb9\xe5\xbe=\xe3\xd7\xdf92\xd2j\xb5B\x08\xbc\xc5\x87\xe9\x7f\xfdS\x7f*\xaf\xfd\xf9\xfd86\xf4\x86\xacv\x9b\xf3\x97\x9b\xa3\xa7\x86Q\x11\xf0^\x9c\x03\x9f\x91uR1f\xed\xa9z|\xcf\xce}\x0f\xfe\x0fo\xff\xdeZ\xfb\xd5p-V}3H.k\xb5Z#\x13k\x9a\xfd\xe1SO-_8\x9e\xf7.\xbb\xe1e\x1b\xac\xfa8\x14c\xfd\xfc\xcb\x17_8mj\xe2$\xee\xe5\xc4\r\xab\x11\xab!S\x11\xa8L\xae?2\x96\x9f\x0c3\xbb\xf7\xed\x7f\xcf\xf7N\xde\xf8\xdd\x8fn\xdd\xdfj\xb5\xbc\xd7Dl,\xaa\xa2\xe2?\xf3\xda\xb1\xc7\x9f{<\x9c\x7f\xad\xac\xcfg\xd6\xcb\xe9\x96\xde\xf22w\x1e\x01\xa7\xc6\x06SL\x05\x13\xc3\x1c\x08\xa6\x88Y\x8c.f\xa31\x1fo\xf2\x89~g\xe7\xf6]\xb7\xfd\xc4\x03\x0f8kRS\xc5\xa6\x16\x17\xbc\xcf\xf3\xd6\xe8\x9f>\xfb\xb5\xa3\xaf<\xa1W\x8eT\x8b\xa7\xe3\xfa\xd2\xdc\xd6\xc9\x99\xf9\xednz\xf7\xf4\xee\xfb\xdeu\xfb\xc3.u\x9bz\x18\x93\xd5\xd1\x86*e9\xf6\xa1\xc7\x9f|\xfd\xd5\xa7\xdd\xea)\xd7\xbb\xec{W:R\x07\x12\xe0\x9dK\xbd\xa8\x8d\x91\xccL\xd9`\x86\x22&8L\r\xc3$KYK;\x93\xf5\xd8\xb6j\xf6\x86\xfb\x1e~\xd7\x8f>tGw\xf1R=\x1c\xa8\xaa\xcf\x8b\xa2=\x92wF\x7f\xfdO?y\xeck_\xb2\xb3\x87\xd3\xf2\x05\x15\x99\x9b\xdb\xd2\xdeq\xf0\xc0\xfd\x8f=r\xf7\x1d>\xd5X\x8a)\xa9\xcb\xc9K\xf3\xd9o~\xfc3\xa7\xbf\xfexq\xfa\xeb\xd2]\xf4\xc3\xd5B\xea2\xb3\xcc#N\x9c \xe0\x04a\x83!^\xbcH\xe6\t\x99\xfa`\xc1\x8bw\xe2\xbd\x08f\x82\x13\xe7\xc49\x87\xf7Br\xce\xf0N\xd4\xc4Y\xc8\xf0b\xcb\xed\xdd:\xba5\x1b\xdfz\xe0\xe6Gn\xdd\xbe/\x0bU\x99\x05\x83D\xa6.\xeb\'\xfe\xe0\x89\xaf,\x9f}\xa5X?%\xc3\x95\x90zc\xa1\xe7E\xc4I\x08\xce\x89\xe1\xc4;\x87\x08\xe2L\xc4\xc0R\xd2\xa4Z7\xdaD1K\xa6$\x0c06\x98\x89\xf9\x80%\xcc\x9cC\x88B\xf2V;K\xa21x\xd7.\xdd\xe4T^G\x1bV\xb6\xba\xde\x1c?3\xac\x1a\x13\x11\xe7%\x04\x8f\xf7V\x8eZ{\xd2\xc6\xb6\xc4\xb9\x1b\xf7\xdd\xfe\xae\xf7\x1d|\x80k\xba\xe0 @\xceU\xef?\xfc\xd4\xa9C_\xf2\xcb\xc7\xfd\xday[\xbf,UW\x9b\xc6|f\xaa\x16\x9bX\xd7\xc3\xd5Z\xd5\xb5f\xa6\xf2\xf1m\x92\x8d\x99\x89J\x19\xc5\xd5.\x9f;\xb0o\xee\xb6\x87\xb6\xb5\xaf\xbb\xa3\xb5)\x84\xc0[\xbc\x9f\x95\xd7>\xf3_\xe5\x95/\xff\x88 \x02\xfd\x9a\xe5\xf3\x97\xd6\x9b\xfc\xe4\xeb\x97\xd4\x9cbb\xc2\x06\xe7\xd5\xb7Sk2\xb6\xa7\x9b\x89\xebv^\x7f\xdf?|\xf0\x91:u\xabA\xb7\xa9\xfa\x122\tE\x08EQ\x8c\xac\xaa\xfb\xc3\xa7\x9eY9\x7f$\xac\x9f\xf7\xd5e\x1f\xfb-\xaa\xba{\xf1\xdc\x93/\xd9\xa0\t\x85\xef\xa7x\xf9\xdd\xfd\xd5<6\xd8\xd0,\x13\xbc\x93\\\xfc|\xbf\x98\xf9\xfcD\xb1\xed\x86\xe9\x03w.<\xfc\xae\xef\xd9s\x7f\x9eg!\xa4\x846\xa6)b\xde\x7f\xe1\xc4\xe5\xaf\x1c}#\xef\x9e\x91\xe5#,\xbe\xd1\n\xda\xca\xb5\xcc\x08A\xf2\xcc\xfb\xbc\x14q\x22\x02\x02\x08\x98\x80\x18\x18\x22\x14\x9d\x98\x8dUV\xd4\xaeS\x15[\xe6\xe7\xf6\xbe\xf7\xc0\x01O\x8c\xa9\x8eM4\x13\xe7\xb32\x1f\xfd\xe8+/\x1f{\xe3\x05]<\xdc\xac\x9cc\xb0\xb6uazf\xfb.\xc6wLn\xb9\xf9\xed\xdbn\xf7~hTuLU\x93\xaa$Y\xd6\xfa\xe8KG\x0e\x9f<\xe2\xfb\x97d\xed\x8c\xeb^\x98\xe8\xb8V\xee$5\xc1\x9a\xa0&H\xaa\x1a\x8d\x1a\xbbu\x1a\xa6\xd4 *\x96,6\xaa\x8a\x89\xb3\xach\xc6\xb66c[\xab\xf6\x96{\xef\xb9\xff=\xf7\xdc\xd4]^\x8cu\xa5Hh\xb5\x8b\xf6h\xd6\xea\xfc\xea\x87\xff\xe4\x8dg>\xafg\x0f\xc7\xf5e\xf3\xf9\x96\xb9\x99r\xe1\xc0M\x0f>\xf6\xc8=w\xb8\xa6\x124&U\x17$+-d\xbf\xf1\xa7\x9f<\xf7\x8d\'\xc2\xf9#\xd2\xbd\x1c\x06\xcbc~\xd0\xce\xad\x08\x929\t\x01\'`\x08\x0e/\x1b,\xcf\xad=n>\xc39\xbc\xa4P\xa6r\xc4\xc7\x01\xce\xa5\xd0q\x99\x0b\x12C\xec\'_\x90\xe7\xb9\xd4h\xe3b\xdd\xf6\xfd\xcc\xa5\xd5\xf6.\x1b\xdf\xde\x8c\xee\xda\xb3\xef\xc1\xdb\xb6\xef\xf7\xd2\x0bN\xd4,Z\xc0\x17}\xe5\x0f\x9e}\xf1\xf2\xf9c\xd9\xdai\xd7\xbb\x90\xa7\xb5\xf1\xb6\x15\xb9\xf8 E\xe6\x82\x17\x9c\x17\xe75\xa96\xb1\x1eV\xa9\x89\x88`F2\x97e\xbe\xdd\x92\x10D\xbco\xb7E\x9c\xba\xe0}(|\x1a6\xa6\xaa\x99\r\xb0\x144\xb6\xdd\xc0\x9b\x82y\xc83\x8a\x9caU\x0fk\xba\x03\xbbp\xb9_\xf5\x87\x1aU|\xc0y\tA\xf3\x91\xd4\x9eJ\xa3sqz\xef\x9e\x03o\xfb\xf1\xed7rM\x03\n\x029W\xfd\xde\xa1gN\xbf\xfa\x84[:\xe1\xd6\xceK\x7f1O=bc\xb1\x11\'Z\xd7R\xb6\xb3\xb1-\xa1\x9c\xc2\xb5\x90\xcc\xc09\xc1\xd1x\x97\xf2\xd1\xa9\xbd{\xdb{\x0f\x8e\x17\x0b\x0f\x15\xb3y\x9e\x87\x10x\x8b\xdf\xe1\xc2\xb3/\x7fB\x8e\x1f\xfe?\xc0\xc0\xea\xc6\xc5hU\x15\xfb\xfda\x8c\x1ac\xaa\xebF\r\x13\xaf\xbe5\x0c\xed*L\xf6[\xdbf\xb6\xee\xff\x91\xeb\x0e*\xc3F\x87)51\x9a\xaa\xf9\x90\x15Eg-\xc9\x87\x9e}a\xe5\xdc\xaba\xedt\xa8\xd6Z\xd6o\xd5\'\xae|\xed\x10\xbdft2\x1bv\x9b\x8b\xef]]\x99h\xd6\xa0g\x86\x99\xf7\xe2s:Y\xd6\xce\xdc\xc2\xe5\x91\xf1\xaf\xde+\xdb\x0eN\xdep\xd7\x8f\xbe\xfd{\xbc\xcbB\xd0D]%MIL\xfc\x17O]y\xe2\xf89WwC\xb5,\xfd\xcb\xa3-7:\x92\x95eV\x16Y\x08N\x04\x11q\xe2\xc0#\x0eDHF\xe2*5m\xc8*i\x0f\xddH-\xc5\xc2\xd8\xcc\x0f\xee\\\xf0\xa4D\x9dRL*\x22\xbe\x08#\x1f?r\xe4\xf5\xe3/\xd9\xfa\xe9\xb8zA\x06+[\x17\xe6\xa6\xb7\xed\xd4\xd1\xf9\xf1\xa9\xeb\x1f\x9c\xde\x97e\t\xeaD\x1a\xd4q\xd0\xa4,+>~\xf8\xcc\xabgNK\xdd\x95\xfe\x92\x0c\xd7&\xc7\xb2\xd1N\xd6*}\xe6\x06\x19\x03G\x8cU\x95\x86\x8d\x0e+\x8d\x96\x86Q\x13i\x90\xfa\x97\xab\xc5\x0b\xd5\xc5A\x18\x86\xb6M\xce\x15\x933#\xe3\x13o\xbb\xe3\xa6\xef\xbe\xf5\xbaao\xbd\xae\x1b\x15\x97\x95-\xc9\xcb\xa1\xcb\x7f\xf9\xb7\xff\xf0\xe8\x13\x9f\xeb\x9f>\xd2_[-Z\xad\x99\x99\xd9b\xe7\xc1w<\xf6\xd8\x8f>v_\xaa\x86\x9aRL\xc9\x85\xcc\xe5\x85\xe4\xe5\xbfy\xff\xc7^\x7f\xe6+\xf1\xdc\x1b\xa1{\x81\xb5E\xeb\xf7|\x8aA$x\xc6K\xd7\x8f\xd6\xaf\xad\x93\xbbv\xe6\xc9JZ\x9d\xac=\xa6y\x8b\xa2\x0cE\x91\x8ft\xd6\xb4\xb4Aw\xa4\x15b\xd6\tA\xbcE\x1btC\xab\x15\xb2\xcc\xc7Ao\xb5/u\xbf\x18,9\xad\xd3\xf4\xce0\xb9-l\xdfw\xff;\xde\xf1\x8e\xdb\x0e4\x83^l\xaa:\x9a\x84\xe0\xf2\xbc\xaf\xf2\xbb_z\xf1\xe4\xa9SY\xbd*\xeb\x17tm\xa9#M\x11\xa43\x1a\xc6\xa6\xb2\xcex\xe6\xbcW\xc4m\xf0\xb8\x8cb\xb4\xf4eI\xd6\x91P\x22\x0e\x02\x92\x81\x03\xc5\x94\xd0\x12\'\x8eZ\rH\x995\x1e2b\x87\x9eW\xc5$Pe\xd2\xcf\xf2\xa0u?\x9a\xd4\xb5\r\x87MT\x9fR\xd6\xd4\x8d\x1a8\x9f\xb2NS\x8cW\xed\xd9\xe1\xf8\xce\xf9\xddw|O9\xc5w\xf0\xc13\xa7N\x1f{\xde]~\xdd\xad_\xce\x9a\xd5\x89\xd2\nO\x96\xf9,\xf3\xb2\xc1\x15\xe0\x00\xc3\x838!+\x82\x0b\xa6EGF\xe6l\xac8\xbf<\x98\x9e\x7f\xf0\xbbg\xf68\xe7B\x08\xbc\xc5\xefp\xe1\xe3\xaf\xbd_\x8e\x1f\xfe_\xb8\xaan,\xa9\xa4\xa4M\xd3\xa8JJ\xb1i\xc40\x13\x1f]Q\xf9\xd1\x9e\x9f\\\x0b\xb3S\xb37\xbeg\xc7~\xa8k\xfa\xb1ib\xd4\x944\x84\xbc(;\xeb\xea\xfe\xe0\xf9CK\xe7^-W\x8e\xe9\xfa\x92]y\x85\xa3GC\x93\xb2\xe0\x82\xc5\x17\xff\xfb%\x1dM\x04z\xc9L\xa5\x14\xa7\xce\x82\x93\xe0\x83d\xd2je\x0b\x17\xef\x9f~\xf5\x9e\xd6\xc2\xad\xefy\xfb#\xad\xdc;\x17\x12i\x18\xab\xa6N\xf8\xec\xf1SW\x9e>y6\xf9RSb\xb8>V\xc4\xb1\xb6\xe5y\xc8\x8bP\xe4\xc19\x9c\x08\x9b\x1coJ`f\x8a\x19X\x8f\xd1\x9e\x9b\x10\x91\xddc\xa3\xdf\xbfe\xda\x91\x121j\xa3I\xc4\xe5\x85o\x7f\xec\xc8\xe17N\x1c\xb2\xb5\x93i\xed\xbc\xf5\x17\xb7.\xccL\xcc-\xd4\xe5\xcc\xd4\xec\xc1\x87\xb7\xde\x92e\r\xd4\x91T\xd5q\xd8X\x96\xb7>q\xe4\xcc\xcb\xe7/(A\x9aJbo\xaaU\x8f\xb6\xf3V\xab\xe5}\xf0\xc1\x07\xd7\xa05:\xc0\xa2iLu\xd2\xaa\xb2X\xa5A\xd2*\xf5\xfb\xferwd\xb1j\x87\xf6\xe8]\x0b\xe3\xb7\xec^\xb8n\xdbL\x8a\xd1\xcc\x005\x96\x1aN\xac\xa7?\xfe\xc8\x87O?\xf5\x85x\xf9L\\\xbd\xa4\xbe\x98\xda\xb6ct\xf7\xcd\x0f<\xfa\xd8\x8f>z\xaf6uJ)\xc6\xc6\xf9\xcc\xe5\x85+\xca\x7f\xfb\xfe\x8f\xbd\xf6\xf4W\xd2\xb9\xd7\xeb\xe5\x0b\xac-N\xa5A\xcb\xa5 \xe2\x1dN\xf0N\xccP\xc4!6\xbeE\xda\xe3\xce\xe1\xc6\xa6mr\x0b#\x93\x84\xdc\x0b\xce\xe1\xc1\t\x88\xe0D\x04\x07\xce9\x11|\xdd-\xaa\x95r\xb8\xe4R]\x8f\xcfg\xad\x11\x99\xdbs\xeb\xfd\xefx\xe4\xb6\x1b\xe3\xb0W\r\xfa\xcd\x06\x15\xc9\xb2!\xfe\x03\x8f?\x7fvi%\xe5\xa3\xae^w\x83\xd5b\xb0<R05W\x94m\x9f\xb7\x8b\xd0\xcaC\x91\x85<sy&>\xc7\xb5M2\x93 \xcea\x8a\x19(\x18\x18\x96\x1c\xb1\xb1l@\xbb\xe5\x86\xb9\x0c\x81\xdc\xaa\x92~\x87\xaeX\x92\x18;\xd2/\xa4\xf6\x12\x9b:\x82D\x0bM4M\xaa\x9a\x9a\xbaQ\xc0\xf9\x18F\xeb|b\xd8\x9aYkm\x9f\xdbq\xc7;\xb3i\xbe\x83\x0f^\xbcx\xfc\xe8\xd7\xf2\x0b/\xe9\xca\xc5\xbc\xee\xce\x8d\xbbV\xee\x8a\x22\xcb\xb2<\xcf\xbc\x0f.xg&\xd1\xc4\xe1\x9c\x13\x17\x04\x8f\x94\x13\x8d\xd6\xe7\xdf8u\xb1\xd9\xb1\xfb\xbb~\xe8{g\xf6\xb8\xabx\x8b\xdf\xe0\xfc\'^\xf9\r9q\xf8\xe7\xd8dM2U\xd3\r\x86mr\x9aP0q\xd1\x95\x03\x99\xe8\xfb\xa9e7;>u\xe3\xf7o\xdd\x0f)\xd1o\xd2\xb0\xaecL\x9a\x85\xbc(;k\xea\xfe\xe8\x99g\x97\xcf\x1f\xf6KG\xd7\x8e<\xdf\xbc~r\xbe\x13\xb3@wep\xfe_\xac\x0fG\xe307\xe7)L\x9c\x92L\x06\xa2\xe2(|\xe6\x83\x0be\x96\xf9;\xf6,\xdf\xbd\xfd\xc2\x83\xdf\x7f\xef\xc391Y%E\xdeD\xeaF\x9d\xf7\x97\x06\xcd\xd9\xf5\x81\xcf;\xe0Rl\xf2L\xf2\\\x82w>\xb8\xab\x10\xc1\x0c\xe1/\x19\x18\x18\x18\xd7\x04\x93 \xd6\x8c\x07\xd9*9D\xa5\x89\xa61\x22\x92\x17a\xe4\xe3\x87_}\xfd\xe4\x8b\xac\x1eOk\xe7\x19\xac\xcc-L\x8f\xcf-\xf4\xf3\x85-[\x0e|\xd7\xfc\xcdYVC\x15-U\xb56\xc9B\xc8\xce\r\xd2\xe5~D\x9c\xaa\xa2\xb1\xc8-\x0f\x84\xe0\xc4y\xe7\x82\xf39.\x03\x8f)$,bI,\x99\xa9\xa5\xa81i4\xcc\xe7\xc2\x14u;\xcbs\x17R\x13\xcde.+\xa2q\xf4\xfc\xd2\xb3G\xde8\xf6\xb5/\x0e\x8f\xbfLwQ\xbbW\xd4\x17\xb3\xdb\xb6\x17;o:p\xdf\xa3\xef\xbc\xef\x1e\x17\x87X\x8a)\xa9d\x16rB\xf1\xeb\x7f\xf2\x89s/~5\xbf\xf4\x86\xae_\xcc\x06K\xf3E=^X\x99I;\x972\x972\x17\x035C\\\xd5\x9a\xad[S\xb13\x9e\xed\x7ft\xd7-\x0fK^\x82\x08F\xecc\xe2\x8a6\xcecIb\x0f_\x10J\xbc\x13K\xd5`i\xe5\xc4\x97\x8a\xfe\xc9u\nWt\xaa\xd6\xf6\x1d\xbb\xee\xbfu\xfez\xa1\xab\xf5\xb0n\x9aac\x887qo,\xafw\x93wY;\xc5\x86\xd4\xe4\xae\xc9[Y\x9eg\xae(\xbc\xcf\x9c\x03\x1c.\x07\x8f8,a\x11\x8b`l2P0\xae\x12k@\x94\x0c\xf1\x22\x085\xc6\xa0\xee\r\xd6\xbf\x91\xa7+\xaeZ.\xd3Jn\xc9\xa1\xa9I`1YR\xc5\x0c!\xaa\x19\xe0|\xcc\xc6\x87\xd9\xf4\xa0\x9c]\xcb\xb7\xcf,\xdc\xf90\xd3|\x07\x1f<s\xec\xe4\x91\xa7\xfd\xe2q]\xb9\x947\xeb;f\xc3H\xab\xc8\xf3\xa2,\xf3<\xf7YN\xf0b\x86\xaa\t\xe2\x9c\xf3\xce\'\xb4\xbfx\xf2\xec\xd7O5\x9d\x85\xfe\xcc\r\x93\x0f\xfe\xf0\xa3s\x07\xc6\x9c\xe3\xdb\xf9\xf7\\\xf8\xdc\xcb\xbf.\xa7\x8f\xfd\x02\x1b\x84\rMT5S3a\x83\x17\x91\xff\x8738\x8f\xd2\xf3<\xeb\x04\xfd\xbb\xefgy\x97o\xabU%\xa9$Y\x96-\xaf\xf2\x22\xcb[\x22[\x89w;\xb1\xe3\xb0\x9d\x19\x86\x9c$@H\x1f M\x87f\x084\x87@\x9f\x81\x9e\xeef\xe9,\x0c\x9d\x84\x00s\xba\x03\xc3N\x13\x07\'\xb1\xe38\xdew\xd9\x96\xbck)YUR\xa9T\xa5Z\xbf\xe5]\x9f\xfb\x1eU\x12\x8eC\xcf\xf4\x1f3\xd7\x05\x22!\xae)\x1aP\xbbO#\xcb\xb4\xa1\xd9>\xff\x96\xd1\x1d@\x00\xf2B\xf3\xb2\xac\xebZ\xac\xb1Q\xdc\xe8\n\xff\xc5\xd3O\xcc\x1fy&\x7f}\x7f>s\x22\x96\xee\x98\xe6\xf9 \x9b\xfb_{4\xa1\xc6ii\xd4\x83\x22B\x01\x145r\x12\xb1\xdap\x91\xb36\xb7d\xe4\xdd\x1dw\xd1y\xd55\x1fn\xdd\xe5\xb5,\xb2~U\x17\xeam\x805>f6g9\x13\x11,\x00\x0b\x87\xff?\x14(\x81\x1a\x10\xa0\x12\x84\xa0ZUJ\xe4#\xd7\xfc\xda\xa17\x8f\x1e\x7fU\xbb3am\x96\x06g6n\x19\x1f\xde\xb8%o\x9e3:r\xe1\r\xa3\x178W\x02E)uQ\xd4u\x10k\xads\x911\x9e\xc0\x04\x06``\x01\x02\x14`\x80\xb1\x8e\xf0?\xa4@\r\x04\xa0\x04z(k\t\xa1.\xea\xbc2}\xf1\xab\x83\xe2\xf5\xe3\'\x0e\xbe\xfa\xea\xda\xb1\x832wT\xfbK\xe8\xafT\xbe96yN\xba\xe5\x82\x8b\xaf\xbb\xe9\xd6\xeb\xaf\xe6*\x83J\x1dD\x8c\x83\x8d\xd4\xf8/\xfc\xdd\xd7f\x0f>e\x17\xa6d\xe5\xb4\xed/N&\xe5x\x83Z\tyK\xde\x91e\x10\xab\x02J\\$\xe3E\xba\xa1h\x8c\x8f\xed\xfd\xd0\xcd{\xdf\x8fw\xf4\xb0\xae\x89u\x01X\x01R \xc1w\x15(\x9e}\xe1\xaf\xfc\xca\xc1U\xf1\xe2\x9bU\xb2\xf1\xdc\xed\xd7^6\xb1\x03\xb2&e^Vu^\x8a(\x8c\xb5 \xe3\\d}L\xc4\xc6\x18F\x040\xc0\xf8\xffL\x81\x00(\xd69\xfc\x93\x0c\xf2\xea\xf2#Q5m\xf3\x85$\xac:-!\x02\x91\xba\x16\x11U\x15\x10\xd6\x11\x88\tl+\xd3\xce\xedh?\x1a[\x8b&\x87F\xae\xb8\x1e\x1d\xfc\x0f\xfc\xf9\xc9\xa33\x87\x9f\xe73S\xba\xb2\x10\x87\xfe\x8e\xcdQ3\xf6Q\x9c\xa6\r\x1fE\xd6\x18\x05\x14\x00AE\x04RI\x7fv\xe9\xd0\xe1\x95\xc3+\x9a\x0c\r\xda\x93\xcb\xe9\x8e\xeeGo\xba\x9dn\xbd\x1c\xff/\x16\x81/\xe1\xf8\xa3\x07\xff\x88\x8e\xbe\xf4Q\xfd\x1e\x82\xd4\xaaP5\x0c\x05\x81\x88\x19\xa4B\xb6\xa6(\xe3\xe1>\x8f-\xdbM\xcd\xceE\xb7O\\\x0e\xe4\x8aA)e\x91\xd7U-\xd6\xda8n\xacU\xf8\x93\xaf?|\xea\xf5\xe7u\xfae\xed\xce\xd3\xdaR:\xb6v\xfa\'\xfa\xb2A\xe3\x06;/\xb1%\x0f*\xa1\x19\xb4\x10\xb2\ne\xf8(*\r\x89!W\xdf\xd3v[7\x98K~&\xbd+\xcdCU\x0c\x8a</\xab\xbaR\xf5\x89\xb7ql\\\x84u\x04E#n3\x18\xc8\x01\x05\x1c\x90\x005\x90\xe1\xfb\x08H\x01\x06z\x80\x07\x0c0\x00j\x80\xb0N\x80ZQ\x07\x91 T\xd7D\xe4\xe3\xa8\xf5\xd5\xd7^;\xfc\xf6\x81\xbawZV\xa6M\x7fvbrtt\xe3\xa4\xb6\';\xa3\xbbn\xd8\xb4\xdb\xb9B\x90\x87 \x83A^U\xc19\xcf\xc6\x00D\xcc\x003\x9b\xd47\x00\x052\x80\xb1\x8e\x81\x06\xc0@\x1f\x08\x80\x05R@\x80\x020\x00\x01% @\r\x04@\x81\xa0U5\xd7\x97\xe3\xcb\xd5[\xd3s\xc7\xde>1?3\xad\xa7\x0e\xf3\xfc\x14w\x17\xc2`\xadll\x18;\xe7\xbc\xd6\xa6s.\xbdn\xdfm\xef\xba\x86\xaa\x0c\x22A!\xec\xc8E\x81\xec\x17\xfe\xeek\'\x0e<\xe5\xe6\x8f\x86\x95\xd3\xb6\xbf\xb89.\xc6\x13\x1eJ\xd92\x9c%g\xa1\x80\x12\x94\xa8h\x8cW\xe9x\xd1\xd8\xb0\xe1=\x1f\xba\xe5\x86\xbb\x81>B\t(\xa0\x00\x81\x08D\x08\x05\xb2U\xf8\x14Q\x13` \x1dh\xf9\xec3\x7fe\x97\x0e\x9e\x91N\x9e\x8es\xdc\xb9\xf0\xdc+\xae\xdc\xbc\x13a\xb5.\xb3\xb2,\xf3RD\xc0\xd6Z\xef\xd8\x18b\x16\x85\xf7Id\xda@\x05\x04@\x81\x08p@\x0f(\xb1\x8e\x00\x0f4\x80\x02\x18\x00\x04\x10@@\x03\x10 \x03\x180\x00\x01\x04X\xc0\x00\xe8\xa3:p\xf2\x1bQ~\xd4\xe4\x0bq\xb5\xe8$\'\x15HPU\x01\xab\x82\x98@\x00\x01D0\xb6\xb6\xed\xcc\x8e\x0c\x92\x8dk\xf1\xd6\xe1\r{\xde\x85\t\xbc#\x00\nX|\xd7WN\x1e{\xfb\xd03\xf6\xd4\x81\xb04\x17Uk\xe7n\xe4Vl\x1a\x8d4\x8e\xad\xf7\xc6:@\x15*a\xb0\x12\xf2b\xf5\xc8\x89\xee\x91\x15e\x22\xb2\x85ku\xfd\x86\x83\xbf\xb9#\xa2\xf7\xdeE?\xb4\x0f\x06\xff\x0f\'\x81?\xc1\x1b\x8f\xbf\xfc\xa7\xf4\xb5_\xda^\x17\x08!\x00L\xa0\x10\xd4X\x024\x08\x0c\xc3;\n\x81\xb9\xd9\x1a$#k~C>z\xde\xc8\xf6\xeb\x7f\xe2\xb6;\xeb|\xb5\x18\xf4\x8a\x22\xabj\xa9\x85\x9c\xf7I\xb3\xbdV\xd3\xff\xf9\xf5o\xce\x1f~a\xa9y0\xd7\xb9l\xe4Lc[\x8f\xda!i\xb0k\x90ZM<\x14\x08\x8a\xb3\x82P\xc2D\xa0\xd2ri\xc8\x1a\x1ba_\xc4\x17\x8e\xf3\x95?\x9d\xde\xe1P\x96\xe8iUKW\xaa\xae0\xb1u\x8e\x8dU\x85q\xce\xf9\x98 \x90@\x1a\x08\xb0\xd6X\xe7\xa1\x12\xeaB\x15\x8au\xd6\xc5\x80J])\xc8XK\xc4\xa1\xae\xa4.\x00\xa8\x84P\xd5!\x04U\x15b!\xc76N\xdb\x9d\xbf~\xfc\x85\xd7^z\xb2:s\xac:3\x1dVN\r\x0fwF6l\xe6\xf1\xed[/\xdb{\xcf\r\xfb\xb4\x18Ty&\xd0^oP\x05\x8d\xa2\xd8E\x1e \x22\x18\x1b\x19\xe7\r\x84\x99\x98YCI$D\xc4\xc6\x01$u\xa9*D\xc46\x226\x00UE\x06\r\xaa\x81IE\xa4\xcc\x85\x99|B\x1c\xf1|Ao\xafTo\xbc}r\xe6\xe4\xcc\xea\x89\xa9\xf2\xf8\xd1\xde\xb1\xb7\x8b\xf9S\x92\xf5\xa41\xbca\xeb\xb9\xcds.\xb8r\xef\xcd\xef\xbf\xe1\xeaP\x0c\x00\tA\xd4zv\x1e6\xfa\xec_\xde7\xbd\xffI:=\x15\x96Nsoq\x9c\x06#^;\x9ebO\x96\xd9\x10\xd6\x19b\xcbUk\xbch\x8c\x87\xce\x86\xad\xb7\x7f\xf8\xfd\xb7\x7f\x00\xa1\x8b\xaa\x84\x02P\x10\x00\x95PK]\x862c\xeb\xad\xf3d\x1d\xe2V\xb7\x08\x0f=\xf65\x99}1\x17H:\xa2\xcd\xb1K/\xba\xf6\xaa\xf3vj\xb1V\x96Y]W\x95@\x89\xe9,0\xc0\xa1\x14\x1b%\xd6{&\x81\x04\xd2\x9a\t\xc6zcL\xa8K\x95\x80uDl\x8c\xf5RU\x12*|\x1f\xb1\xf3\xa4\x1a\xea\x8a\xacc\xe3\xa0\n\x05y\x0br@rf\xb0\xfa\xe0?\xfe\x970\xf3\x12\xaf\xcc\xb8\xee\x9c\xad\xfbT\xd7Y\xaf\x8a#\x161\xb5@E\xc9\x90\xb1\x0c\x22\xb2V\xd2N\xdd\x1e\xab\x86\xb7\x94\x9b.\xdcr\xc5\xcdw]p5\xd6\x05 \x13\x84J(\xe2\x18\xf0\x00\xbe\xf0\xc4\xe3G_x\x98N\xbe^,\x9c\xe4\xee\xfc&7\xf0\x12\x9a\xa9sL\xb1gc\x08\n\x10\xa0\x12\x82\x10\xab%\x9e\xbdm\xb0\xb4)\x9c\xde\xec\xd66\x8c\x86\xf8\xe2!\xbc\xf7j\xdcs7\xb6O\xe0\x9f\xc9\x81G\x81\xfb\x07\xdfz\xe5\xc5\xaf\xd0\xe7?9\x82\xb3\x04 @\xb1\x8e\xe1bCP\xc3lJ\xad\xd5p\xd2*\xe3\x91\xa2\xb1\xb1\x18:\x7fr\xe7\r\x1f\xda\xfb\xbe:\xac\xe5\x83n\x91eU\x90:\x90s>\x1ak\xad\x80>\xbb\xff\xabSsO\x97\xbd\xc3\xe5\xdai\xe7\x96\x87\xb6\x0e\xa2!\xf1\r\n\x89\x92\x871P\x85e\xb2 @\x05p\xa0\xca\x98\xca\x90\xb3\xce\xea\x1ekvM\xd0\xd5\x1fk\xdciP\xe4\xe8\xd5(\xeb:\xd4\x85\x1ac\xa2`mpV\x9c\xf7\x89\x8f\xd2\xb2\xe8K\xa8\x9c\xb5\xcc \x02\x14P\x80p\x96*\xbe\x87\x08\xaa\x08!\x00\x88\x92\x96\x84\xaa\xcc\x07*u\xa8\xd7I]\x8b\x02\xc4`k\\\xd2h\x0f}\xe5\xd1\xe7^z\xfe\xd1|\xf6\x8d\xe2\xcc\xb4\x0c\x96\x87F\xdb#\x9b\xb7\x9a\xd1s\xb6\x9e\xbf\xf7C\xb7\xbcW\xea<\xcf\x06U-UY\xd6\n\xe7\xbd1\xdey\x0f\xc0\xba\xc8\xfb\xb8\xcc\xfb\xd6\xd98I\xcb<\x93P\x11@D\x00D\xbf\x8f\x99]\x14\xf9(\xcd\x07\xdd\x22\xcf\x99%\x04\r\xa2\x040K]\x06\xd5\xb0&\xbc,<\xd7\xed\x1d\x9f\x9fY8\xf1Zqz*?yluj\xa6\xb7\xb8\x16Lk|\xcb\xf6\xd6\x8e]{\xf6\xde\xf2\xfe\xeb\xae\xaa\x8b\x8cH\xeb\x10`\x1c[O.\xfe\xcc\xdf\xdcw\xec\xa5\'1?U\xaf\x9c\xb6\x83\xc5Q\xd7k{I\x1c5\x1c{\xcb\xceA\x02\x88\xc9E\xa6j\x8c\xe7\xf1x\xd9\x18\xdfq\xd3G~\xf8\xa6{\x81\x1c\xa8\xa1\xaa\xa1\x16\xa9\x89\xa8*\x07uU\x00\x81@\xc4\xc6\xd8\xc8\'\xed\x95J\xff\xe1\x99o\x95\xf3/\xa3\xea\x22J\xb5\xb9\xf9\x8aK\xf6^\xb7\xe5B\t+A\xaa\xb3\x06\xfd\xaa\xc8E\xd4\x10[6\x96\x8dM\xd2&\x13\x85j\xa0\nk\x98\t @\x01\xc5?!|\x8f\xaa\x02 @q\x16\x11\x8c\xa1 Jl\x88\x98\t\xc6Z\x851L\xc6$\xd3++\x7f\xf6\xd7_\x96\xf9\x03\xd1\xcaqZ;Ee\x9f\xa5\x8e+\xa4\xb5\xa9\x83\n\x93I\x8cOl\x08\x00\x93\xb2E\xa3#\xcd\xd1\xba\xb3\xa5\x18\xbe`\xeb\x9e[?x\xc5uXW\x07\xf4+\x95\xa0\x14sl\x10\x03\xf8\xcc\x13\x0f\x1d\xde\xff\x90\x9cz\xb3^>I\x83\xf9M\xc9\xa0\xe9\xb5\x91\x18k\xc9Z\xb2\x86\xceR\xd53\x97\x16\x8b\xdb\xeb\xf9\xf1z9\t\xa2\x08D`o\xe36\xc7\x17&n\xef\x0e\xdc\xf9Cx\xd7\x95\xf8g\x96\x80\xbf\x85>9\xff\xe7\xd3\xaf\xfc\x05\xfd\xc8\\\x0c\xa0R\xcd\xa0\x00D\x11\xa0\xa3\xcc\t\xb8R\xce\x82\xb1d\x15-\xc2\x08h\xf3\x90\xd9\xb1+\xdd\xf7/\x9bw\xf7\xb0\x94\xa1[\xa2\xa8*\x95\x8a\x9c\xf5\r\xdf\xe9\xc1|n\xff\xb7\x8f\x9f~\xaaZ;\x90\xaf\x9crv\xa5\xbd\xa9\xefF\xc4\xb44\xc4\xea<\xb5\x22c\x14\xaaP\xa0&-\xa11\xd8YW\x10\xacu\x8e\xf68\xde5\xceW\xffdz\x87E\x99cP\x22\xaf\xea\xba\xca\xc5:o\xd9\x18c\x9b\xdcq\x88\x00\x06\x84@\x06\x06\x00\xe1\xfb\x04P\x10\xbeK\xf1=*\x10\x810X\xa1\x01u\x0fk%\x8a\x1a! \x00\n\x85\t.\xe6\xa4\xc5C_\x9ez\xf6\xd1\x99G\xd7\xba\xaf\xe7\xab3\xa1XlL\xb6[\x93[\xa3\xe6\xf6+\x9b{\x7f\xae}s\xe9\xf3\x0cY@\xd0Z\xb5&2\xec\x8c\x8b9I\x90\x1aX\x80\x00\x01\xc0`\x06\x03 \xacS\xacS(\xa0\n\x10\x88\xc1\x02\x11\x84\x1a\x15\x83\x18\xeb2\x0c\x06\xe8+\x82G\xe4\x90\x12\x92\xfd\x0b\xd3\x0f\x1f}b\xf6\xccK\x83\xee!\x19\xccVu\x8fl{\xdb\xc5\xe7\xb6F/\xbbb\xfc\xa6{\xd2=\xc1\xe5b\xea\n\xb5\xc2\xb1z\xd2\xf8?=\xf7\xb5\xb7O>%\xf9\x91\xb27\xc7\xb24\xb2-KFC\xa3\xc3\x14\xa3\x8c\x94,\x1aD\x00zJ\x95\x8e71\xe1u\xe2\x82\xe6G~\xbcy/\xfeI\x85^\x8d\\\xa1\x05\x065\x8a\x80J\xa1\x00Y\xc4\t:k\xa0/\xcf}\xeb\xed\xe2\xc5\xac^\x22\x8e\x1dm\xbcm|\xdf\xbd|QE]\xf1u\xc5U\x95\x8b\nY\xe7\x99Ml\x92\x04-\x02c\x9d\x12\xd8\x82\tD\x80b\x9d\xe2\xbfGX\xa7\x00a\x9d@\tT\xa3\x10\xd4\nM\xd1\x01\x1c\xbek\x06\xcb\x7f~\xf2\x8f$\x7f9\xea\xcf \xccV\xb6wE\xdc\xdc9\xb84\x1a4\xa2\xa117v)G\x9b\x94\x14P\x10\x83MI\x94\x93\xefr|&\xab[\x9d\x8b\xaf\xc2&\xac+3\xf4\xd7 \x02\x8a\x11\xb7\x90\n\xf0\x9b\x0bO\xbe0\xf5P>\xffl\xbd|\x22\x84\x05?\xb1\xd6\x1c\n\xf1\x10\xd5\xb1\x96N\xad\xa7&\x08@\xa6\n\x80\x00\x05\x08 \x22\xc3\xae\x8c[&\xbe0r{\xda\xb8\xf1#\xf8\xc0\x8d\xf8g\xe6\x80\xbf\xc0\x89\x17\xa6\xbfz\xf2\xf0?\xd2\x9d\xb3\x11\x08\x04\x18\x80\x00\x052\xd1\x16\xb3\x03W\xc2AYa\r5\x81Q\xa6M\x1d\xdaqYs\xdf/4\xef\x19`%C\xb7@VT\x22\x15;\xe3\x9bQ\xa7\xab\xf4\xd9\xfd_\x9f\x9e{&\xf4\xde\xaaVN\x11/G\x9b\xbbnH\xd2\x11\xe2\x18\xf0\xf0\x96 d\x1954\x88*\x83\x80\x86\x8d\xf8,\xeb\x14\xbb\r\xef\xda\xc4\xd7\xfcdz\x87A\x91\xa3W\xa2\xaa\xeb\xa0\x95\xb1\xd6\x1f^\xeb\x1f\\^b\x22\xa8\x8ah09\xb82\xca\x00\x02\x83\x18D\x08\xa0Z\t\xdf\xa5\x00\x14J*\xa4\x00YJ\x98\r\x00\x02mM\x1b\xd7\x0e\x8d\x04TR\xd7D\xc4jc\x8e[<\xf4GG\x9f{t\xe6\x91\x95\xee\xeb\xd9\xdat\xc8\x97\xdb[\xda\xcdM\xe7D\x8d\x0b\xf6\xb4\xdf\xfd\xb3\x9dw\x17>\xcf\x91\x05\x04\xa9\xa15)\xf3++\xfdc\xdd\x1e u\x10\xa8\x8a\xc9\x85j(\x88\x89\x0c3\xe3{\x02\xd6\x11\xa0Xg\xb0\x8ea\x9d\x89\xadR\xcb\xfa\x9d\xed\xa1Mq\xd2 Sh\x16\xc3%\xd4t\xf0S\xf9\xda\xd3\xb3\xc7\x9f\x9dzpe\xe1\x05\xc9gsZ3Qg\xdb\xf9\x93C\x9d]\x97\x8f\xbd\xf7\xde\xd65\x95\x1d\xd4TU\xa8\x15\x8e\xd5\x93\xc6\xbf\xf3\xec}\xc7f\x9f4\x83\xa9\xbc\x7f\x9a\xe4L{\xeb\xc0\x8f\x86V\xc7\x98\x18U\x04\xef\xe0\x81\x12(AV7x\x8c\x02i\xc7\\\xb7!\xbd*\x08\xd7\xe2DcS\x1b\xaa\x9d\xd5\xe4\xca\x91\xf6\xd6\xc8\x07\x14\n\x01\xc8\x22N\xd0^\x05\xfd\xe7\xa9\x07\xdf\xea\xed/\xfa\x0b\t{\xef\'n\x9e|\xcf\xdd\xee\x02\xb1\x03N\x11\x8c\xd4%H\xad\x92}~aq\xa1\xc8T5\x88\n\x82\xda\x0c\n\x03\xb6\x0c0\x02A\x01\x0b0\xa0@\r\x10@X\xa7X\xa7\x00\x03\x8au\x86\x9c\x22\x88\x0c\x08N9\xb2L!\xe4\xddl\xe1\xf8\xea\x93\xb689\x91\xf5S9s^#\xbd`\xe4j\x13\xda\x9c-\xc6\x91\xba\xceFN&\xe0\x12\xe2\x88\xd8\x81M\x01\x0cDzj\x97\xcbfg\xfc\xfa\xdd\x18\xc5\xf7\xa9b\xad\x07\x01\xa2\x04i\x00~\xfd\xd4C/\x1f{\xb8\\<P.\xcdHX0\xe3k\xe9HpC@\x04\xf2\xb0\x1e\x06\xc4@\x065\x00\xe1\x1d\xc4\xa6\x8e\x12\x13\x9dk\xe3]\t\xde\xf5S\xf8\xf8-\xf8g\x8e\x03\x9f\xe9>t\xf4\xd8\x03\x8b\'\x1e\xa7{NE\n\x94\xaa\x0cX\x22\x00\xa2p`\x03\n\xcaA\x99`\x89\x9a\x84Q\xa2M\x1d\x9cwEs\xdf\xbfl\xdd=\xc0\xea\x00\xbd\x1c\xfd\xbaR\xad\xc8\x1a\xdf\x8c:]\xa5\xcf?\xff\xd5#\xa7\x9e.\x07G\xaa\xd5\xd3\xc6-\x0fo\xed7\xdapm\xd4\xb1&\x9e2#\x16d\x08\x01 \xc0\x83\x07\x08\x91u\xcc\x86\xac\rz\x9d\xe3\x8b6\x9b\xab\x7f&\xbd\xd3\xa2\xc8\xd0+Q\x06!\xae\x9da\xff\xe0\xc9S\xf7\xbf}X\xc2\x22B\xa6!\x97\xb0\xa6\x92s\x00)UP\x22\x18\xa0T*\x84\tP@\xa1\n\x08+\x00\x22\xc7v\x88m\xc2\xe4\xd8\xb4\xaf\xda\xb8\xfd\xe3\x97\\\x1cBVKM\x04C&\xb2q\x93\x86\xbe<\xf5\xdc\xa33\x8f\xaet\xdf\xea\xad\x1e\xaf\x8b3\x8d\xc9\xce\xc8\xa6s\xd2\xc6\xa5{\xda\xd7\xfd\x8b\xceu\x85\xcfsd5Dk\xaaJ\xb5\xc6\xfd\xe5\xd1\xb7\x9f\x9d\x9f\xae\xc3JU\x0f\x102\xa9{\x08\x15\x14J8\x8b\t\xeb\x14\x02(HA\x040\xc0\x80\x02jS\xe7:\x8e\xa2\xe1x\xe8\x8a\r\xdb\xde\xbd\xe9\x9c\xcb\x86\xc6W\xab%G\x14\xdb\x06\x81z\xe0\xb9\x9c\xfe\xec\xc0_O\xcf<\\\xf5g\\\xb3\x17\xb7GF6\x8f7\xe3\x9d\xbb\x87o\xfa`\xeb\x9a\xca\xf6+*+\x08\xe0X#\xd2\xe8w\x9f\xbd\xef\xc8\xec\x93<8Z\xf4O\xab\x9cio\x1d4G\xb5\xd5a\x1f\x03\x11\x82C\x80\x96@\x00;\x8c\x00-R\xab\xdaa\x0cQAy\xd1\xcc\xaa\x91|a\xb4.F\x8c\x1f\xbd\xf7\xfc\x0b\xf6m\x1e\x05\xca \x81\x84\xac\x89\x9a\xae\xb3,\xfa\xb9\x17\x1f8\xb4\xf0\xc2`y\x8e\xf2\xe0\x9bCw\\u\xc7\x8fu\xae\xd0\xb8\xe4\x14b\x10*b\xf8B\xcd\x7f}\xe3\xcdc+\'\xb4^\xa9B&!c\xe9R-* @\t\x81@\x02\x8bw\x08\xd6\t4\x00\x0c\x18\x10\xbe\x87\x889\x15\xa6\x10\xfa\x08\xa5P\xf0L\x08\xfdP\xaf\xf1\xea\xfc\x85\x88\xc6\xed\x0e\x19,\x8f\x0e\x99Kv\x0e\xa7\xbe\x11Gi\x84.\xf5\xe6\x11Efx#\xc7\x13\xc2\xc2\xa6Q\xa9\xede\x83\xb5\xae\xacuv\x0em\xbc\xf9J\xb4\xf1\xcf\x94\x80\x02Q\x01\xfc\xfa\x89\xaf\xbfr\xf4[\xd9\xea[\xc5\xf2\t\x96\x05?\xd9\xd5Ve[\x88\x132\x9e\x82S\x05\x1cP(\x18DP%X\xac+U\xe1\\\xec\xc7\xcb\xe6\xce&\xbf\xfbc\xf8\xf4\xed0\xf8\xae\x1a\xb0\xc0a\xe0W\x8f\xfd\xe1\xd2\xb1\x07\x8a\xc5W\xe8\x8eY_)j(\x03\x11\x91b\x9d%6\n\x80U,\xc1Zj\x90\x8e\x96\xd8\x98\xd2\x05\xbb[7\xfeR\xeb\xee.\x16st\x0b\x94R\xa9Td\x8doF\x9d>\xcc\x1f\xec\x7f\xe8\xf0\xa9\'\xcb\xee\xc1ru.\x98\xa5\xd6\xa6\xfe\xf0(l\x07\xa5\x97\xc8s\x14\x11\x01\x16\xa4@\x80\xaa\x02\x84\xdaXoM`\xa6\xfa}\xa9\xdb\xb6\xc9\\\xf6S\xe9\x1d\x06e\x86~\x85*(\xa8\xb6\xce$\xdf\x9c\x99\xbd\xff\xd8\xebU1S\x163R\xcc\x92\x06\x16!Q\x9c\xa5\x80\x80@\x03P\xa6lT5@\r@*\x04\x01\x98,\xf30\xb9\x96\xf7\x1d\xb6C\xbb7_\xf2\xaf\xae\xbc:hQ\xd6\xb9h\xed\x8d\x8fl\xd2@\xfbKS\xcf=6\xf3Dop\xb2\xbfz\xb4\x1aL\'[ZC\x1b\xb6D\xf1\xb6\xab\x86o\xfc\xc4\xf0\xcd\x85\xcf3\xe4\x82@\xe2\xa5&K\xf6\xbf\xbe\xf5\xe6\x93\xa7\xde(\xaa\xd3u9[T\x8b\\\x07\xa3\n\x85\x00\xaa \x81\x10\xc0j\x84\x84H\x15$`@\r\xce\x12r@\x9c\xc6\x1b\xdb\xc9\x96\xa1\xe6\xb6;v\\t\xf7\x96\xad\xab\xd5\x12Ke\x8c\xf5\xb6\xe1\x91*\x92\xdfy\xf9\xaf\x0e\xcd<TW\x87]\xd2\x8b\x1a\x93\x8d\xd1\x898\x9d\xbcn\xe4\xbd?\xdc\xb8\xba4\xdd\x8a\xaa\x00%xR\x0f\xf5\xbf\xff\xec}\x87g\x9f,\xfbG\xca\xc1i\x95\xc5\xce\xd6A:*\xb6\x8dF\x82fD\xb9\x83\x80\x1c\x180\x15\x8c &\x1d\x81\xf8\x18.\x14&\xcf\xdbU1\xbax\xb2S\x15\xa36\x19\xff\xe0\xc5\xd7\xde\xb2mR%\x0b!@a\xd85}gE\xf0\xd9\xfd\x0f\xbe5\xff\xfc`i\x11\x83*j4\xee\xbc\xe6\xce\x1f\x1b\xbe\x02Q\xa5.\xa8#\xc3\x8e\x10\x15b\xff\xf8\xb5W\x8f.\x1eA9\x83\xf2\x94\x94\xcb\xa4\x12\x82V*\x0c\x90\xb0\x8a\x12\x11\x13\xce\x92\xa08\x8b\x89\t\x01*P\x16\x82 \x18X\x22\x86\x8a\x96\xb5\xb2\xa2i\xb4\x1b\x909\x80T$\x94\x17\xe7+CU\x03u3\xd4\xd5\xf8ht\xe5e\xedF<\x91$\xed$q\xd60\xb4\x82\x94\xb5\xac\xce\x84\xd7\xdbn\xa4\x11]S\xbam=;\xb4\xe46\xb7\x93\xcbwc\x08\xef\x08@\x05x\x80k\xe0\xd3\xa7\x9fx\xe9\xe8\xb7\xb2\xc5\x17\xca\xa5\x19\x91\x05\xb3a\xcd\x0e\xd7i\x07\x88)8%\x07K\x14T\x0b\xa8\x05\x05UG\x14\x131(S)\x99`\x12\xdf>\xaf\xe5\xf7\xfd$\xfe\xb7\xf7\xa3\x89\xef\x12\x80\x81c\xc0/\x1e\xfe\xfd3G\xff\xb1:s\x80n>\xe9=\x93\xaa\x82\xa0\x8a\n\xcaJ\x11\xb1\x01CY\x95\t6\xa2\x060\xd2\xd7\x8d1]pUk\xdf\xa7Zw\xf7\xb1<@\xb7D^W\x82\x8a\x9d\xf5M\xdf\xe9)\x7fn\xff7\x8e\xce=]\xf4\xde(WN\t/\xb57\xf6G\xc6\xd8u$$\xf0\x11\x19\x8bR\xd4\x11\x81P\x05\xf5\x86\x00\x90s\xc6\x98\x81\x82D\xeb\xd2J\x00\x00 \x00IDAT\xf5\xa6\x86\xdd\xb9\xd9\\\xf9\x93\xe9\x1d\x06e\x81~\x8dZ\x14V<qr\xdf\xb1#\xdf8\xfa\\\x95OW\xc5\x8c\x16\'\x10\nB0\x80\xa8B \x01L\\0eJ\x22\xa8J\x18\x076\x10\xa8B\x05V\xd12~4nl\x88\xcc\xf8\xee\xcdW\xfc\xeb=\xfb\x84\xca2\xe4\xb5T\x8e\xac\xb7q\x13\x9d?\x9az\xee\xb1\x99\xc7{\xfd\xa9\xc1\xea\xf1A~\xb2=\xd9i\x8fo\xf6~\xcb\xee\x91\x1b\x7fa\xf4\xb6\xc2\x1792\x81X\xc4R\xb1g\xff\xa5\xd7\x9e\x7fb\xe6\xf9\xb2\x9c\xad\xaa\xd9\xaa\x9cg\xa9\t\n \x88\x06Q\xd4`C\x15\x8b\x01[\x02)i\x05\x10\xd4\x82@\x0e\x89\xa2\x11%\x9b\x1b\x8dMQ\xb4\xf9\xae\x9d\xd7\xfd/\xe7^\xbc&\xcb\xa1.\x0c!vi\x84\xd4\xa1\xf1;\xaf\xdd\xf7\xfa\x89o\x07:b\xdcB#\x1aK\x86&\xe2\xc6\xf6kGo\xfe@\xb2\'\xe7^I\xa5B\x19\x9e\xd5\xab\xba\xdfy\xee\xeb\x87O>!\xdd\xd7\xaal^t\xa5\xb5u\xe0G\x95\xdah%hD\xc4\x0e%\x10\x81\rx\x00u\x88,\xd2Jm\x1dLUp\xc8[\x92\x0f\xafM\x0f\xf7\x8b\rQk\xcb\x07/\xbe\xf1\xb6\xed\xe7\x06\xc9k\xa9\x18\xb0\xe4b\xdbZ\n\xfa\xf9\x03\x0f\x1e]\xd8?X\x99\x0fk\xb5\x8d\x9b\xef\xbb\xfa\xce\x1f\x1d\xbe\\\xa3\x12\\\xdb\xc88\x9f\x82\xe2\xbc\xe6/\x1e|zj\xe1U\xca\xa7\xear\xae\xaeV\x10\n\x82\x1a%QU\x81\x062\x06\xc4\x10\xa0\xac\xd5\x08\x9c#\x10\x04\xaa\x00\x05\n\xb5\x96N-\x93\x03j-+\x01\x87\xa63%!C\x00DT\xeb\xcbB\xd9\xae\x9a!k\x89\xc8\xc4\xb8\xbf\xe2\xf2\xe1F\xb2)M;i\x1a[\xe7 UU\xcd\x1e\xab\xf7\x7f\xa5>9\x0f\xfa\xd9\xe6\xe5[\xe3\xbb\x07~r\xd5\x9f\xd3\xf2\xbb\xaeD\x1b\xef\x08@\rx\x80*\xe0\xd7\xe7\xbes\xe0\xd8\xb7\xf3\xc5\x97\xfb\x8b\xd3R\xcf76\xf4h\xb8\xb2C01y\x8f(\xa2Z\x91\xab\x14\xaa\nDD\x0e\x14\x13\x05E\x06\xc1:\xb6\xed\x1dqc\xdf\x87\xf1\xbf\xdf\x8ba\xfc\x80\xe3\xc0/\x1c\xfa\xdd\xe5\xc3\xdf(\xce\xbcBw\xcc\xfa\x88\xd8\x025P\xa8\x08@\x04\xab\xc6\xa8\x81\xb2*\xd8\xb0\xd3&c,\xe8F`\xe7\xee\xf6\xbe_i\xdd=\xc0j\x86n\x86~U)U\xec\x8doD\x9d\xae\xd2g\x9f\xfb\x87\xc3\xa7\x9f\xe9\xf7\x8e`\xedtd\x97\x87\xb7\x0e\xccP\xd0&L\xa2\xde\x93\xb1$\xaa\x8e\x08@\x00\x12\xe2\x81H0\xa6\xe5]0&\xc8\xee\xc4\\>\xc9\xd7\xfcTz\x07\xa3\xc80\x08\x08\x00\x1b\xf1L\xd1\xdf\x1fy\xf5\xfe#\xdf6\xc5l\x9e\x9f(\xcaYS\xf5\xbd\x9654/\xd5\n*C5\x91\x80\x15\x08\x8aZ`\x18D8+@\x03\x19p\xea\xfc\x84K6\xfbh\xf3\xd5\x9b\xaf\xfd\xd4\x9e[\x02\x97\xa5\xe4AjC\xc6\x9b\xa8\x89\xce\x97\xa6\x9e{t\xfa\x91n\xef\xb5\xc1\xda\xc9<[hLvF6o\x8d\xd3sv\xb7\xf7~b\xe8\x96\xc2\x17\x05\xf2\x1a\xc1#Ae<\xf9\xcf\x1f|\xf4\xe9\x99\xc7\xcbj\xb6(g\xb5\x98\xafB!\x12@\x90\x1a\x08B\x0e\x08\xa8D\xada6P\x01\x03\x04\x94\x80\'\x8am\xcb\x99\x11o\xc6\xbd\x1f\xb7\x8d\x8dw^t\xfb\xc7\xce\xbfz\r\xcbY\x9d346>\xa6\xd4 \xf9\xfd\xd7\xbfy\xf8\xe4\xe3A\x0eq2\xd7H\x9bis,N.\xb8z\xf4\xd6{\x92=9\xf7\n*\x05\x81\x111\x22U\xff\x1f\x9f\xfb\xe6\xdb\'\x1e\x0f\xbd\x03\x83\xfei\xd2\xb5\x91my<*i\x07\xcd\x18\x12\x91:\x94\x00\x83\x1dP\x83\x03\xd0 @l\xa1.\x1f\x00y\xc2\xd9\xc8\xdcTg\xad\x98lt\xce\xff\xd1]\xb7\xde\xb1\xe3\xfcZ\x8b\x22\x14$\xe2\xd8E\xb69_\x85/\xbe\xfa\xad\x99\xc5\x97V\x96\xe7\x8bn\xe9m\xfb\x96K\xdf\xfbCc\xbb}\x1bQ\x04\xeb\x8d11!\x1eT\xf2\x85\x97\xbe\xfd\xf6\xc2\xfe:?,\xe5r\xa8V\xa5Z#\xa8\x11\xa0\x02\x11\xc8\xe2,\x05j@\x14\x11\xd6\t\xe1{X!@\r\x80p\x96BH\xc0d\r\x84\xa0R\xabBU%\x96z\xa7\xc6#a\xbc\xccu|\xbcq\xe5\xe5c\xcdtS\x9c\xb4\xe2D\x8d\x91J\x96\x0fe\xcf\xfdi\x98}\x9b\x82\xb2\x1d\xe2\xa1\xeb\xdd9\xd76\xdfm\xe3\x9b;\xf1U\xbb1\x8awT@\rx\xc0\x14\xc0\xaf\x9d\xb8\xff\xe0\xb1\x87\xcb\xe57\x06\xcb\'\x8c,\xa4\x9b\xbb\xbeSs\x0b.&\xf6\x10\x87B\x05\x80b]D\x04@\x01\x06*\xc0\x83\x0cqho\xa7\xe4=\x1f\xc1\xbf\xfb \x86\xf1\x03\x8e\x03\x9f8\xf4\xbb\xab\xc7\x9f\xaaV\x0f\xd3M\'}D\x88\x88s\x95\x02\n\xa0I\xccbU\r\x00U0\xdb\xa66\x99GK\xdd\xa8\xd8\xb9\xaby\xe3\xaf\xb4\xef\x1e`9\xc3j\x89\xb2\xaaDJv\xd6\xb7\xa2NW\xf9\x0f\xf6?\xf4\xd6\xdcS\xd9\xda\x01\xb3v\x9a\xcdr\xbc\xb9\x97\x8e\xa8\xef \xc4j,b\xcf\x02\x10\x14J\x96\xa9V-E\x8cs\x86\x8dw\xd6\xd0\xd5\x96vm\xa0\xab?\xd6\xb8\xd3\xa2*\x91\x95(\x15l\xc4[\x8a\xff\xee\xf0+\xf7\x1f~\xd0\xe4SYv<+Nq\xdd\xe7P\x90h]\xa3\x08\x941\xd5J\x06d\xa1\nR\x80@P\x9c%\xa4\x81\x99]\xd3\xb81\x8d7\xc5\x8d\xf3\xae\xdbr\xc3\xaf]{[Me)y\x15J\xcb66q\x8a\xd6\x97\xa7\x9e{t\xfa\x91\xa5\xee\xabkk3\x9a/\xa5\x9b\xdb\xa3\x93\xdb\x92\xd6\xf6\xdd\xcd\xbd?\xdf\xb9\xb9\xf0E\x8e\xbcF\xf0H\xa82\x8e\xdc\x1f\xbc\xfc\xc8\xe3\xd3\x8fV\xe5T]\xce\x15\xe5B]\xf5\xeb\xba\x16\x85\r`\x00\x06\x1c\xa0\nf\x94@P\x10\x81\x81\xa0\x88\x18\xa9iZ\xd7&\x1a5\xd1\xe6\xa8\xbd\xf3\xdeK\xee\xfc\xd8\x85\xd7\xaea\xa9\xa8s\x11\x89\xacO9\xf1H?\xfb\xda\x83\xafL?\x96go5\x1b\xa7\xd1\x1c\x8d\x86&\xda\xcdm\xd7\x8e\xde\xf4\xc1dO\xc9\xfd\x92\xca\x1a\xc1 bD\xaa\xfe\xf7\x9e{`j\xf6\xe9\xb2\xfff\xd6\x9d&93\xbc-o\x8fj\xdaA\x12\xa3\x8e\xa8\xe7 \x80\x07\x11PC+\xa5&\x98\x01\xab$\x99fY\x9c\xf7\x9b\xf3S\x9b\x07\xc5\xf6Fg\xe7\x8f]v\xfb]\xe7\x9d\xaf\xc8\xf3:\x17Qk\xac7\xcd3U\xf8\xe2+\x0f\xce\xac\xbc\xb1\xbc\xda-V\x97c\x93\xdet\xe1\x8d\x1f\x1c\xdf\xed;\xe4\x12v\x9e#\x930\xe2A\xa5\x7f\xf8\xc2\x833\xf3O\xcb\xe0`\xa8V\xcbr%\xab\xbb\x1a\xd4\x08|Mg\x89\xd1\xa0JJ\x0cb\x80\x80\x80u\x8cu\x02\x04\xa8\x01\xd5\xd0J\xd5\x13\x0c(@\x15\xf0 \x22\xac\x13\x95Z\xa8F\\\xc8V$\x17n\xde\xbe\xfb\xf2\x89Fc\x93\x89\xfa\xa7\xcd+\xcf`nE\xe5M\t\x8b\x10a\x22\xb6\x86;\x89\xdd0\xe6\xb7\xb7\xfd\xa5Wtn\xfb\x18\xf6\xe2\xfb\xfa@\xb1\x02r\x88\x22\xa4\x02|\xfa\xd4\xa3/N};[|\xa1X>\x11d!\x9eXk\x0c\x85\xa8\x03\x89!\x1ej\xb5\x82z\x10\x08P\x10\x91\x03\x02P\xa9&\xc4\x154\x22c[[$\xbd\xfcN\xfc\xf6\xcf\xe0B\xfc\x80c\xc0\xbfx\xf1\x97\x96N>\x19\xf2\x93\xf4\x9eY\x07\xc0\x10\x04\x04%RDj,\xd8+;@\x80\x9cLBM\xcfc\x95n\x04v\xeej\xbe\xe7\x97[\xef\xefb)G\xb7B\x1e*\xd5\x8a\x9c\xf5M\xdfYS\xfe\xec\xfeo\x1c9\xfdL\xd9}\xadZ\x99\x03-w6\xf5\x86\xc6$\x1e&\x8d\xb4\xb0\xcc\x96b\x16\x0f%B\x05\xea\x0bDa\x9dU2\xa9\xb7F\xf7\x10_\xb6\x91\xaf\xfex\xe3N\x8b\xb2\xc4\xa0D\r\x18\xab\x91A\xf4\xb7\x87\x0f\xde\x7f\xe8\x81\xd0\x7f\xa5\xcc\x8fW\xe5\x92\xd4=\x95\x92+VE\xa1\x08\x04UR\xd5\n\xaa\x8a\x98\xd8\x81*\x85@+\xa8\xb2\xf5\xb6\xe9\xfd\x98D\x9b|c\xc7\x9esn\xfc\xf5\xeb\xdeW\xa3(BVke\xc9z\x135\xd1\xfe\xf2\xd4s\x8fL?r\xa6\xfbZou\xba\xce\x96\x1a[\xda\xa3\x93\xdb\xd2\xc6\x85W\xb5\xdf\xf5\xf3\x9d\x1bK_\xe4\xc8k\x04\x87\x98*\xeb\xe0>\xf7\xf2w\x9e\x98~$\xcf\x8fh9W\x96g$\xf4B\xa84\x80\xb0N\x01\xc2:#P \x00\x81\xa0\n\xab`Ed\xe3\xd4u\x8c\x1bC\xba5n\\t\xef%w\xfd\xd4\x85\xd7\xae\xeaRQ\xe7\xaaj\xd9&6\x89\xd1\xf8O\xaf>x\xf0\xd8\xe3\xf9\xca\xa1(\x99\xf5C\xed\xc6\x86\x8d\xad\xf6\xb9\xd7\x8f\xddro|U\xce\xfd\x8a\xab\x1a\xc1\xc0\x93zU\xff\xb9\xe7\x1f<z\xea\xb9\xb2\x7f(\xeb\xce:]\x1c\xdf:\x88GT:\x14E\xa0\x88\xc4!W\x18 \x22V\x04Q\x10\\\x0bjUz\x99v3Wf\xed\x85\xb7\'Pl\xf3\xad\x0b~d\xd7mw\x9ew\xbeh^\x84\xb2V\xb1dc\xdbX\xaa\xe5?\xbf\xf2\xc0\xcc\xcakk++\xd5j\xcf\xbb\xd6M\x17\xdcp\xef\xd8\x95\xbeC\x9c0{NLb4\xca*\xfd?^xpf\xeei\xca\xdf\x08\xf5\xaaT\xabU\xe8\xe5u@P\x03\x02`\x89\xa0*\n\x0b\x8a\tg\x95\n\x01*\xa0Re\xc0\x131 \x80\x02\x06\n\xa0\x02*\x95\x94\xcf\x02A\x89\xb4.B^I\x16\xc4\x816\x8c\x9b\xc9\x8bm\x94\xd8,\x96i_-\x19\x11\x822bb&\x02[6\x1d6\x1b\x8c\xdf\xea\xdc%{\x92[\x7f\x05\xfb\x00\x08\xca>\x06\x19B\x00%\x88[H\x03\xf0\x1b\xa7\x1f\xd9\x7f\xec\xe1\xfe\xc2\xfe|i&\xd4\xf3\xd1x\xd7\x0f\xd7n\x08\x9a\x80=\xbc#&!EPf\x90a\x01T@\xa2$\x80B\x89\x0c\'\xa3Ig\xe7\xd5\xf8\xe5{q\xd7\xc5\x00\x03\x010\xc0#\xc0o=\xf6\xe1\xe5\xe9\'Q\xcf\xd3M\'\xbd\x02\x04\x22e\x03\xc2Y\n\x07j\x10\'D\x95\xa2\x0bfj\x10\x8dYl\x22\xbd\xe0\xd2\xe6\xbe_m\xdf\xdd\xc3J\x1f\xdd\x1c\x03\x94\x8a\x8a\xbc\xf5\xcd\xa8\xb3&\xf8\xbd\xe7\xff\xdb\xdb\xa7\x9f\xab\xfa\x87\x8b\xd5\xd3dW\x86\xb6\xf4[C\xa1\xd9\x86\x8d\xa1\x9e`!@\xad\xe4H=i\xa9\x94\x010V\xd8\xb6\x1cGt\rh\xd78]\xf3\xf1\xc6\x1d\x06E\x8e~\x80\x10\xacAD\xea\xff\xee\xd0\x81o\x1e\xfaF\xde{)\xcbf\xaa\xa2\xcbQO|\x15\x0c\' kH,\x94\xb4\x06*\xa8\x07%\xccF\x11\x14%\xb4\x84\n\x0cq\xd3\x9b\xb1\xc8m\x16\xb7y\xd7\xf8\xbe_\xbb\xec\xde\ny\x81,hm\xc48\x8eZ\xd4\xf9\xe3\xa3\xcf}g\xfa\xd1\xc5\xee\x1b\xbd\xb5\xe3Uv\xa6\xb1\xa532yN\x92\xec\xba\xa6s\xdd\xcf\r]_\xfa\x22G^#XD(MD\xd1\xe7^\xfa\xce\x133\x8f\xe4\xd9\xe1:\x9f\x0b\xd5\x22%}\xf2\x15\x19( \x0c\xf6\x04\xc2Y.@\x08g1`\x15\x0egQ`\xef\xb8e\xed\x06\xeb\xb6F\xd1\xc5\xef\xdf~\xc7G\xb6^\xbb\x82\xa5R\x0b\r\x81a\xbd\x8dc\xb4\xfe\xfd\xc1\x07\x0e\x1e\x7f\x9c\xbb\x87\x8d\x9cdoGvL4;\x97\\;v\xf3=\xf1U\xa5\xcd\xc4\x85\x1a5\xc42\x22\xa8\xfb\xec\xfe\x87\x0f\xcd>\xaf\xd9\x91\xaa\xbf\xc8XM7\xf7hX}\x8b\x1a\x11\xaa\x88\x12\x0b\x22\x10\x08j\x88J@\x03\xe2\x8e\x8aE\xbd\x92\xd1Z\x19\x15u\xbb\xec\xb5\xbdn5v\xe7\xfb\xb6\xddt\xcb\xc6\x1d\x15\xf2\x02\x95\x04\xf5jc\xdbX\xaa\xe4\x8b\xaf<8\xb3\xfc\xd2\xea\xf2|\xd5-\xbd\x1d\xba\xf9\xa2}\xf7\x8c]\xe9;\xa0\x84\xe19\xe2\xd4\xa8\x1bT\xfa\xa5\x17\xbf=3\xffb\x95\x1d+\xcbeS\xaf6\xd3\xaeX)\x19\xa4\x80%\xef`\x15\x04\x10`\x88\xa0\x00\xe1\xacL\x11\xa0\t\xc8\x10\xde\xa1\x02\x82\x02\xa2J\x8aA\xad\xb1Uo\x05R\x8b\xd4A\xab\xd2I\x96 \xebhi(X \x02\x1bD\xcc-fG %a[s\xa7\xb6\xe3\xd6o\xb5\xf6\xe2+\x93\xdb~\x057\x02\x18\xa0\xea\xa3\x17 \x00%\x88\x9bH\x05\xf8\xb5\xd9\xaf\xef\x9f\xfav\xbe\xf4F\xbe|\x22\xc8|\xb4\xa9K\x9d\xca7\x89\x13\xb0go\xc9\xb0X\x88\x82\x08g\x11\x14\x00\xd5J\x05\t\xa0 6\xd1p\xd2<\xef\x1c\xf7\x81\x8bq\xebF\\I\xa0\x0c\xcb\x05\xd6\x8e\xe0\x95\'\xbe\xf3\xc7\xdd\xb7_\xd6\xec4\xdd1\x133\xa0 \x80\xf0]\xa2\xb0D\x9e(\x02\x1b`Y\xb9\xa4\xd4\xd3X\x8b\'\x19;/l\xec\xfb7\xad\xbb{X\xeea\xad@^\x97\x8a\x8a\x9c\xf5\xad\xa8\xd3U\xfe\xcc\x0b\x0f\x1e9\xf5T\xd5}5_\x9b\x83Y\x89\xb7\x0c\x92\x8e4\x87\x94\x22\xae,\xc5^\x03P+\x19h\xc4\x22\x8aBA\xce*Y\xefl\xa2\xb7;\xb3}\x82/\xffD\xf3v\x8b*\xc3 \xa0V\x90\x91\xc8R\xfc7o\x1d\xfc\xc7\xb7\xbe9\xe8\xbd>\xc8\xa7\xab\xfe\xa2mvM\xab\x0c\x9e\x1b\x11\xa5\x11[Cb\xb4R\x05`\x89\x18\xe4\x80R\xd1\x17\x01\xe0\xd9Yj(\x8dy\xb3Y\xcd\xb9\x17u\xf6\xfe\xf2\xce;\x05U\x89\xbcBI\x81=GM\xea\xfc\xc9\x91g\x1f=\xf1\xe4Z\x7f\xba\xb7:U\x0ef\x1a\x93\x9d\xce\xc6I\x9f\x9c\xbbg\xf8\x86O\x0c\xdfT\xfa<G.\x08AX\x0bj\xda\xd6\x1f\xbc\xf4\xc8\xe33\x8f\x96\xfd\xc3R\xccU\xe5\x19\xd3\xee\x9bF\xcd1\xc8\x82\x1d\xc8\x12\x08B @\x01\x06\x1c\xa0\n\x15\x18@\x10\x11\xb7b;\xee\xcd6k/\xba}\xf3\x9d\x1f\x9d\xb8v\x15K\x05\xf2 \x81\xc5z\x1b\xc7h\xfd\xbbW\x1e\xd8\xff\xf6c\xe8\xbeU\x0cf\x8d\xb5\xe3;7\xb4F.\xbdn\xec\x96\x1fMwW.\x17\x1fj\x04\n\x96\xd4\x8a\xf0\xe7\x0f\xbc\xf0\xda\xdc\xab\x9a\x1d\x0f\xd9\x19\x91\x95x\x93\x9av\xed\x9b\xc1G*\x9e\xad\xd5\x00\xad\x95,\xb1A(\x15\nn\x13U\x22Y\xe5B\xcdV\x22\r\xa3\x22\x93\x919\xf7\x9eMW\xdf4\xbaEQU\xa8Ta\x82Ils\xb9\x92/\xbc\xf2\xe0\xf1\xe5\x97\xd6\x96\x96C\xb7\xf66\xb9\xe9\xa2\x1b?0\xbe\xdbv@\t\xb3g\x8b\x88\xd4\rj|\xe1\xc0\xb3S\x0b\xaf\xe6\xd9t\x9e-@V\xa2\xc6\x9aI\x8d\x8d\xacs\x00\x83,\x88@\x84u\n\x02\x0c\x11\x01\x01\xa8U\x05H@J\xc8U=Q-\x08\x80!xH_\xa4\x12mr\xed\xb8\xac(W*c\xaa\x95K\xf1u\x1d\xd3@I\x18\xec`-\x1b\xc0\xa8\x96\xd0\x16\x9b\xd8\xd8\x82:\x85\x197n\x8bq\x17_\x95\xdc\xf1)\xdc\x00 C9@?@\x14\x14#n\x22\x15\xe07N=\xba\xff\xd0\xb7\xb2\xe5\x97\xf2\xe5\x19\tg\xe2\xcd]\xdb\xa9L\x1b.fD\x14\x0c\x98@P\x02\x0c`\x84@\x00H\x95j\nBJ`\xe3\x9b\xdc\x18\x8b\x93\x0b:\xb8\xb8\x85\xdd5\xaa\x1c\'\xb3jeu\xfa\xc4\xa9\x17_)\xd7f\xa5\\\xa3\xbb\xa7S\x81*\x91\xe0\x1d\x06$\x80\x02\x0e\x14\xc8\x94H\r\x8d5h\xd2\xe0\x82\xcb\x9a\xef\xf9T\xeb}=,\x0f\xd0-P\x94\xa5Pe\x92(MmsY\xc2g\xf6?pt\xee\x85\xb2\xfb\xd6`\xf5\x84\xd2\x99db\x10\x8d\xa9\x1b\x82z\x16O\xce\xab*\x81\x94\xa0\xb5*\xceR\x88s\x91\xb1\xcc\x9c\xe0\xe6\xc4\x9d\xb7\x8dv\x7f\xb2u\x17!\x1f\xa0[\xa3\x02\x8c\x15g)\xf9\xab7\x0f\xfc\xc3\x9b\x0f\xe4\xd9[\xf9`:\xef\x9e6\xad\x9e\x1b\xaa\\\x93L\x93\xe2\x065\x89\x03\xb4\x862\x88\t\xa2p\xa0\x81j\x06% &\x9bR\x830\x16h3\xe8\xfc\xcb;\xfb\xfe\xf5\xb6[\x03\xca\x12Y\x85\x92\x83\xf1\x14\x0f\xf1\xd8\x97\x8e>\xf5\xd8\xccc\xdd\xee\xb1\xc1\xda\xd1\x22;92\xb9!\xdd0i\xfd\xc4\x15\xa3{\x7f~\xec6\xf5\xa1@Q\xa1\xac\x82ha\x86\xa2\x91\xcf\xbd\xf8\xf0S\xc7\x1f)\xfaG\xc2`\xae,O\xdb\xd1\xbei\x05\x97\x22j\x92\x8dq\x96\x02\x01\x00A\t\x0c@\xa18\x8b\x0cP\xa8SJ\x133\x11\xf39\x86/\xbe}\xe2}?=r\xfd2\xe6\x07\xc8D\x85\x82\x89L\x92P\xfb\xb7_}\xe0\xa5\xe9\xc7\x06+o\x0ez\xb3\x064r\xfe\xc4\xf0\x86]7N\xdc\xfe\xe3\xe9\x9e\xdc\xf5\xd5\x87\x80 5H\x8c\xaa\xfd\xdb\xa9\xc3/\xceM-\xaf\x9d\xac\xb2%E\xcf\x8f\x89kV\x9c\x06\xe3\xc1\x8e\xd4\xa0P\x014\x22\x04E\x01\x10Q\x03\xc8\x95j\xb568_9\xc1\x907\x1b\'\xe2-\xb7\x8c\x9f\x7fU:\x06\x84\x80\n\x00W\xb6\xe9:+\xb5\xfe\xc1\xc1\x07\xa6\x96^X]Y\x0ckul\xd2\x9b.\xbe\xe9G\xc6\xf7\xd8N\xd0\x84\xc8\x93QG\xea\xfa5\xbe\xf8\xcaK\x87\x17\xdf\xae\xcb\xc5<[\x08\xb2\xcc\x8d\xc26L\x9a\x18\x17\x83\x0c\x94@\x84\x1fTc\x9d\x01\x08\x10\xc0\x01\x05P)\x98`\xc0\x02\x88\x82\xa8\xacQ\xc1\xc0\x86\\\xc3\xa0v]5=\x87~\x82\x92\xac\x94^\x94\x18\x00\x81\xd8\x90\x88\x96\x90L\xb5\xc5f\xd8z2\xad\x82\xc6\xd9oqn\xd7\xee\xe4\x8eOa/\x80\x0cE\x86A\x80(\xb8\x81V\n[\x02\xff\xf6\xd4w^\x9ez$?\xf3J\xb6t,T\xa7\xa3\r=?\\\xf9a\xe2\x98$BiI\x95\x88\xc4\x12\xac\xc2\x8a!\x82\x92BH)\x08\x14J\xe4\xa2`\x13I\xdb.\x9d\x88q\x89\xa0(VO\xe6g\xf2\xc1\xccZ\xf7\xd5\xd30=\xa5\x92n\x99N\x15\xaa8\x8b\xf0O\x18P `]J\x06\x94\x16\x18\xb3\x98\x8c\xe8\xa2\xdd\xcd}\xff\xa6\xfd\xbe\x1eV3t\xfb\xc8B\xa9\\\xbbN:\xec\x11\x9d\xae\xfb\xbf\xf7\xfc?\x1c[8P\xf7O\xe7\xab\xc7\xc0\x0b\xd1\x96^<\xa4\xae\x05\x13\x93\xf1\x04\x8b\\\x98I\x01\xc9\x14\x16\x10\xc08\x97X\xeb,\xc7tE\xcaWn\xe3w\xffb\xe3\x1e\xc1\xea\x1aVj\xd4\x80\xb1\xe2\x1d%\x7f\xf5\xe6\x81\xaf\xbe\xf5\xc0\xa0\xf7\xc6\xa0w\xbc\x1c\xcc\xf9\xb1<\x1d\xadm\nM\xc9\xa4h8\x08\x10\x14L0\xa0J5\x80J\xa8\x00\x0ep0\x8e\x13\xabc\x056\xb3^\xb8\xa7\xbd\xef\x97&o\xaf\x91\xe7\x18\xd4\xa8XlD\xe98m\xfa\xc3\xa9\'\x1e\x9byhi\xe5`o\xed8\xb2\xc5\x91\xc9\xcdC\x9b\xb6\xdb\xe6\x86\xcb:\xd7~|\xf8f\xeb}\x81\xbc\x8f\x9e\x08\xb4\xb0C\xd1\xe8\xe7^\xfc\xf63o?\x9c\x0f\xa6\xb2\xdeL\x9e\xcfE\x93\xb9\xed\x04\x93\x92o\xc2FJ\x86\xa0\x10\x00\x04\xc2:Q\x10`\x88\x08:\x80\x15D)op\xd8\xee\xf8\xb2\xbb\xc6\xef\xfd\x99\xce\xbb\x161;@_ T\x9b\xc8\xa4)u~\xeb\xd5\x07^\x9e~\xac\xbb\xfaz\xd1\x9b\x05t\xe8\xdc\x8dC#\x97\xde\xb4\xe9\xae\x0f\xb7\xaf\x1f\xf85uU\x8dPU\x01\x81\xadM^=3\xf7\xf2\xfc\xfc\xcb\xf3\x0be\xd1\x87f\xb65\xb0\r1\xa9\xc2\x22\xb0\xb2\x81\x00\x96`\x80\n\xa8\x14\n8@\xa0A\x9946\x9a\x906&\xe2\xce\xd5#\x1b\xceOG6Q\x93\xa15*\x00\xb6\xf2\x1d7\xba\xa2\xf4\x99\x97\xffqj\xf1\x99\x95\xe5\xd9\xaa\x17b3z\xfb\xc5\xb7\xffO\xe3\xd7\xa0SH\x1c\xd4\x13\x8b\x85pV\xe3O\xdf|\xe3\xe8\xca\xa2\x06)\xf3\x85 ]\xdb\xa6(\x05E`\x076`\x02\x08D8K\x01Q\xd4\x8a\xb3<!\x22\x22\xa0\x02J\xd1\x00\x18\xc0\x92\x11\xa0\x06\x14=\xa1\x82\xa0\xd0<`\xad\xe2\xd3\x81\xe6\x85\x16=\t\x102S+\x83\x08g)\xc0\x80(j\xc0\x12\xb5\xd9\x1a\xdb\xae\xcd(\xf9s\x22\x7f\xc5u\xd1\xed\xbf\x8a\xbd\x002T\x03\xf4k\x04\x86\x1dB\xc7\x01\x03\xe0\xd3\'\xee;p\xec\xc9bm\xa6\x7f\xe6P\x1df\xd3\x89\x9e\xed\xd4\xb6\x05\x13\x93\x8d\xa1\x86KE\xc2\xca\xa4P\xa8\xb0\x90*`\x95\x18R\x011H4\t\xba\x810\x1e\xfb\xed\xad\xe4\xb2\xb8\x18\xaaW\x91\r\x16\x16\x17\x0e\x9d~\xfd\xf9\x80y\xa5\x15\xbak&u\x04\x01J\x01\x13\x1c\xa1V\x08\x90\x10\x18\xc8\x15\x1dk\x14i\xa9c\xaa\x93\x86.\xba\xac\xb9\xefS\xad\xf7\xf5\xb0Z\xa0;@\x16J\xd5\x8a\xbc\x8f\x86\xddX\x1f\xfe7\x9f\xfe\xca\xdbsO\xc9\xe0X\xd5=\xa3f\xc5l\xeaaH]\x0b&!vD\x96\x0c\xc1\x00\x85j\x806\x89\x15\x88\xac%kJc\x1cni\x9a\xf3\xb6\x9a\xcb>\xdex\x8fC\xa8\x90\t\x14`\x16k)\xf9\x9b\xb7\x0e\xdc\xf7\xd6\x03E\xef\x8d\xb2?]\xf6N\xb7\'\xb2\xf6\xc6ZS$\r\xb2)\x82\x051\x08 @\xb1\xaePR\x80\x80\x98\xc8\x80K\xc4AF*la\xb9\xe0\xca\xc6\xbe_\x98\xb8= /\x91\tD\x94P\x9b\xd4\xb5\xfe\xcb\xe1\xfd\xcf\xcc>uf\xf5`\xbf7\x87|\x10M\xe8\xa6\xad\xdb\xa3d\xd7\x95C\xd7}\xb4s\xad\xf5\xae@>@\xdf\x22\x0e%1\xf8\x0f_z\xf4\xb9\x99\'\xb2\xee\xd4`0S\xe6\xa7\xe3\xad\xb9\x1d\x0f&\x85k\xaa\x8f!\x06\x00\x05\x05\x88,)\xa0\xaa\x84\xb3\x08\x04\xd4b\x89\xa2\x18;\x18;X\xb7\xdf>v\xebGZW\x0f\xd0-Q(D\x03G\x9c\xa6\xd4\xf9\xedW\xbf\xb9\x7f\xfa\xb1\xbc\xfbF\xd5;\x19T[\xdb&\x86\xc6.\xbbe\xe3]\x1f\xee\xbc\xab\x88\x06\xb5-\n\x14U\x19H\x98\xc9\xae\x95\xf5J\x19V\n\x84P\xab\xd6\xe4\x85l G`\x05a\x82\xb8I\xd6\x90ap\x85zQ\xabE\x88%e@\x94@\x86\xc8\xa8\xd6\x89\xb3c6j\xc2$`E\x00\x88@N\x92&w\x16E~w\xff\x9f\x1d_\xd9\xdf_-u\x10{\x9b\xee\xdb\xb9\xe7\x9e\xd1+\xa2\x16\x9b\x06k\x04\xaa\x19b\x02xjm\xb0V\xd5$\x02\x01\xa0&&\xe34\x18\x05\x83\x18DP@A\x84u\x82u\x06 \xacc\x90@\x15P\x80\x81\xa0\x01d#J\x04!h U\x85\xcc\x95\xf3\x8f\xf4\xee[\xc5[\x95\xce\x07Z\r\xe8+\xd55\x01\x8c\xa0J\xa0\x98\xc9(A\x00\x22bcl\x93\xed8\xdc9\xb1\xbf\xe2]\xfe\xf6_\xc7\r\x00\xfa(\xfb\xe8\x07\x08@1\x92a$\x00>y\xe2\xfe\x17\x0f\x7f3\xef\x1e\x19\xac\xce\x96\xd5|\xbc\xa1\xe7:\xb5m\x91\x8d\xa9\x19\x91\xb5\x18@G\x98k\xa0\x80\x9eU)\x18\xf0DA)\x00\x1b\x88\x83\x1f\x81\xdd\xdep\xd7\xedh\xed=\x17W\xb41\xa20%z\xaf.?\xf7\xc4\x83_\xec\xaf\xbc\x1e\xcaY\xfa\xd9\xd94b\x14\x8a\xbe\xc0\x11RB\xa6\x10 \x22(P\x08\xda\xc6\x10\xa5A\xc6\x84&\xc1\x17mO\xde\xf3\xaf\x9a\xef\x1f`\xadB7GVU\xa2\x15[g;n\xac\x07\xff\xef\x9f\xfd\xca\xc9\xb9\xa7\xea\xfe\xb1\xaa\xb7\x10\xb0\x1c\xc6\x07\xf5\x88\xd00!\x22x\x82\xd5\x18\x1c\x13\xa9j\xcd\x9a\x80S&\xb5\xb6b\xee3Q\xb8\xb9i\xcf;\xcf\\\xf6\xf3\xad\x9b#\x84\x1a\x85B\x00&u\x86\xe2\xbf\x7f\xf3\xa5o\xbeu\xbf\xe9\x1f\xa9\x073uo\xae5\x91\xc7\x1b\xebx\x88\xda\r\xa2\x84\n\xab1\xc3\x13JU\x80\x08\xf0D\xf8\x1eE\x01\xee\xab\x13\x19\r:\x19\xe4\xc2\x0b\x93\xf7~|\xec\x0eEV\x22\x0b\x90\x00\x82\xb8\x84\xd3?;\xb2\xff\xd9\x93O.\xad\x1e\\\xeb\x9e\xca\xf2\x81\xdf\x14&\xb6ni\xa4\xbb\xafl_\xff\xd1\xceu\xd6\xfb\x12y\x86\x9eCC\x03k\xc0\xe7_|\xe8\xa9\xe9G\xb2\xde\xdb\xe5`\xb6.\xe6\xe3\xadE2QEC\xeaR\xe2Ha\t\x04\x05ja\xcbbH\x19 @\x81JI\xd4:\xf6N\xcf\x83\x9c\x07\xec\xb8m\xe8\xa6\x0f5\xae\x1d\xa0[\xa1\x12\xa8\n\xc5\x9c6\xd0\xf9\xadW\xbf\xf6\xe2\xcc#\xf9\xda\xa1\xb2\x7fR\x15\xcdm\xe3\xc3\xe3\x17^;~\xc7\x8f\x0e\xdd\x98F\x22\xb6,\x91WU@\rb\xb6\xc63;\x0bc\xc0L\xdc$\x8e\xc1\x16\x060\x80\xc1:\x05\x04\x10\x80\x00\x83u\x02\x08 \x82\x90Ar\x145\x82\x02\x82JP\t\x04`\x86\xf7H\x15\xf1\xf1\xac\xff\xc7\x07\xfel\xb6{\xa0X\r:\x88\x9cm\xdcp\xc1\xb5w\xb4w\xb9T\xd3\xe1\x98c\xd2\xc0\x12\x08\xcc\xce\xc4\x96-\xa9:\xf2\x86-\x03\x04U(\x00\xc5:\x05\x14\x84\xefR\x80\x00\x03(\xa0XG\x80\xe2\xfb\x04\n\x90\x85\xb5p\x0c#P\x0b\xf7\x16\xe6\x7f\x7f\xf9K\xf3\xe1\xa52\x9c\xccq*\xa0\x0b\r\x11H\x15\x01`\xc0\x11\xe1,\xc5:6j\x9a\xc6n\x88\xdcv\xe7/\xbf\xd6\xdd\xf1i\xec\x050@\xddC? \x10\xd8#\x1aFB\xc0\'O\xde\xff\xc2\xa1\xfb\x8b\xb5\xa9|\xe5dQ/\x98\xd1\x9e\x1d\xa9\xa3!r\x115#J,\x0f )\xb8\x840\x11\x01\x0c\x18\x22U\xf4U\x13\xe2\x04\xe4\xd3\r\x9a\\\xb0#\xfe\xe8\x8f\xe0\xc7\xae\xc4;\x1eC\xefs\xdf\xfa\xf9\xd5\xd9\xe7L6C\x9f\x9cK\xa1(\x14%\xd0\x00\x98\xa0\x80\x02P\x94@\x054\xd9\x04JI\xc6\x94&\x95.\xda\x91\xde\xf4\xc9\xe6=9\xba9z\x05\xfaUP\n&\xf5-\x0b\xbf \x83\xcf?\xff\xdf\xa6O\xbf\x9c\x0f\xe6te\xca\xda3\xad-\x037\xa4u\x0b\x99\xa7`y`\xd5\x83\xda\xcc\xa2\xda\'I\xc1\x9ei\x85\xd9[\xe7-W\xe1\xd2\x06]y\xa9\xbb\xe1\x93\xcd\x0f\x18\xf4s\xac\x95\xa8\x15l\x10\x19\xc4\x7f\xf9\xc63\xdf~\xf3\xef\xc2\xe0\x84\x0cfCo.\xd9\x98G\x1b\xeb\xb116\tJ\x0fk1l\t\x8a\x01\x84A\x16\x08 \x05,\xc0@\x01\xea\xa9W\x19\x15\x9d\x0cr\xe1E\xc9\xcd\x1f\x1f\xbbS\x91\x97\x18T\xa8\x02\xacG2\x8c\xf1/O=\xf1\xf4\xf1ou\xbb\x07\xfb\xdd\xe9\xe5\xfeBc\xdbPsrc\xb3\xb9\xf3\xb2\xe6\xde\x9f\xee\xdc\xec\xbc\xafP\xf4\xb0\x16\xa1\xc1\xc1{\x93\xfc\xc7g\xfe\xfe\xd9\xe3\xdf,\xba\'\x8b\xe2tY\x9eI\xb7f\xd1\x86:\x1a\x82O\xa0\x11\xc42\x08\x04\xd4\x02\x06\x88`X\t\xa8\x84\x98\xd4\x90!D\xc0\x04a\x87\r\xbb\xee\x1a\xba\xf7c\x8d\xbd\x8b8\x9d#\x13\x08\xa9\x89\xa9\xd1@\xfb\xd3\x07\xff\xfa\xc0\x89\x87\xb3\xdet\xd1\x9b\x85\xa0\xb1\xad\xd3\x9e\x98\xbcd\xe4\xf6[G\xde\xb33j\xc7VsduU\xab\xa0I\xd4r\xa9!\x0eUa\xb9a\x8dw`\xacc\xac#|_\x05\xad\x10\x14L`\x00\x0c\x10\xc0Xg\x00\x02\x04\xa8\x81A\x89|\r\xa1\x043\xa2\x04\xed\x93ey`\xe9\xd4\x83\xc7\xee\x9b_{-[\xeb\xcbZ\x15\xf9\xf1\x9b/y\xff\x0f\x8f]\x8bt\xe0\x9a\x0c\x0f\t\x08\x026\x1c\xbb\xd4\xc11\x88@\x00\x08\xcaP\x85\x02P\x80\xb0NA\x00\x02\xa0\x80\x01\x08Dx\x87@\x01\x08\xc8\xc2\x05\xd4\x03t\x13\xb4<b\x80\x124\xa6\xb0\xfa\xfb\xfd/\x9d\xaa_\xec\xe5ogzJ\xb0\x06\xad\x8d\xa8a\x22\x10\xa0\xa2\xb0D\x84\xefbS\x99&\xdb\xf1\xc4mw\xee\xf2\xeb\xfd\x9d\xbf\x81\xbd\x00J\xa0\x8fA@ \x98&\xd2\x08\x18\x00\xbf:\xfb\xd5\x17\x0f=R\xac\x9e\xe8/\x1f\x129\xcd\x1b\xbbv(p\x13&\xa1v\xc4m\xe6\x0c\x02\xc5Y)\xb3!\xb2D\x04\x14\xa2\x01\n\x90Bm2\x91\xb6/\xbd\xd5\xfe\xc6Ob\x0f~\xc0+\xc0\xbf}\xf4gz3\xaf\xf8\xf24\xfd\xc2\xa9T\x01\x06\xa0P\x02\x01\x04\xa8\x02\x84\xef\x11\x98\x01\xd2\x84\xc6@\x9br\x9a<?\xb9\xf5W\x9a\x1f*\xd1\x1b\xa0_\xa3\xa8\xa1\x14\xd8\x998As\x19\xf5\x7fx\xf6+GO=E\xfdcU\xef\xb45+\xad-Y2\xa4\xbe\x03x\xca\xadYa\x8d\x88<S\xae\xdaWi0\x07\xd55\xe6\xd4\xda\xd8\xd8\x88.\x1f\xf7\xe7\x9fo\xf6\xfet\xfc?;T9z\nU\x18\x85!\xf8\xff\xeb\x8d\'\xbf\xf5\xc6_\x17\xd9\t\x1e\x9c\x92\xde\xdc\xd0\xc6\xbc\xb19\xe808V\x17S\xcb1\xb3\nT\x01\x0f\x8a\x89N\x05\x01\xa8\xc5\xe4\x81\n4P_\xc9\xa8\xe8F\xc1\xa6\xf3\xa3\xdb?1\xf6\xe3@U#/Q\x01\xce#N\xd0\xfc\xc3\xc3\x0f\xbc0}\x7f\xd6?\x96uOf\x83\x85d\xdb\x90\x9d\x98\x88\xd2\xf3wun\xf9\xc5\xe1{\xc4W\x15\x8a\n\x15`B@D\xd1\x7fx\xf6\x1f\x9e\x9b\xfeF\xe8\x9f\xecg\xf3u\xb9\x90l\xcd\xfcx\x88\x86\xe1\x12hL\x15\x11\x00k\x14\x80\n\x11\x81\xa0\x02\x88\x92aU\x18\x95\xd8\xf1\x06C\x9b\x8d\x9es{\xe7\'~.\xbdu\xf5\xff\xae\x0b\xdeb,\xcd\xce\xb3\x00\xbf\xef\xb7\xd6\xfaO{\xef\xdaU\xddU]}\x98\x9e\x99\x9e\xce\xf4\x8c\xed\xb1\x07EqB\x0c\x08c\x89\x18\xc5\x8e\xb0#q\x81rglp\x14\x1c\x22P\x04\x02\x85\x0b\x12!EB(R\x04\xc2\xbe\xe2$\x90\x22_\x90\x04K\x88S\x88B\x88ql\xc7\x89=\x1e\xb7\xdd\xed\x99\xee\x9e\xae>TU\xd7\xae}\xf8Ok\xad\xef\xa3\xba\x93 G\x88\xe7\xc1b\xc4h0\x03K\xd45\xa6\x7f\xef\xf7\xff\xddW\xee\xfe\xd7qs7\xb6\x07P\x9d<?m.n_\xdd\xfa\xe1\x0fm\x7f\xe8G\xa6\xef=\xef\xa7@\x1fM\x93*H\x82\x00\xcc\x0c\xa0\x88\x0b\xa4\xe0\xff\x12\xc0\x01#\xe2\x10;\x05@\x11_\x96f\xd9r$\x01\x03\x0b\x81#@\x80\x80\x01\x04\x0c\x08\x80\x07\xaa\xaf-\xee\xfe\xf7\x83\xaf~\xf5\xf0\xbf\x9d\xb6o\xb7k\xc9\xab\xa1*\xa7\x7f\xe1\x95\x8f\xfd\x95\xbd\x0f\x86\xad\xcc\xca\xac0\x03L)\xcey\t\x0eB\x80\xa0\x01\x84\tLa\x00\x02\x82\x83O\x18\x15j\x80\xe2\x8c\x0b\x08\x8a\xa4H\xc4\x19\x02\x08(\r:`t(\x00dd\x8188\x825\xa6oa\xf9O\x16\x9f\xbd\x9f~o\xd5\xbd\xad\xfap\x90S0F5/\x14\x81\x1a\x12l&\x22 \xce8\'nF\xd9Cq\xb5,\xaf\xfd\xa0\xff\xc8\xcf\xe3\xc3xf\x002\x14\x90\n\x10<\xf53\xf7\xbf\xf0\xa5\x9b_\xe8\x96\xb7\xe2\xe9\xc1\x98\x0f\xc3\xc5\xb5\xcc\xb3\xdb\x82\x94\x9c\x95\xd28n\xcc\x00\x10\xd8\xa6\x04!\x9e\x89v\x06O\x19&\x93\xfd\xf9\xce\xfb>\xca_\xfa(\x9e\xc7\xf7x\x0b\xf8\xbb\xbf\xf3\xe9\xf5\xdb\xdfv\xe3\t?q\xd0\x10\xa8\t\x02=\xe0\x80`(\x04\xc4\x1f18e3b\'q\x17\xdc\xbf^\xfd\xa5\x9f\x9d~R\x91\x06\xb4\x03ZA\x10\x04\x81\xab1y\x8c\xe1\x17\xbe\xf8\xb9\xfb\x0f\xfe\xc7\xd8\xbe5\xacNDN\xeb\xfda\xba\xab\xd56\xab\x9a\x16p\xec\xd0\x19T\xa1\x02\x18+2+V\x8e\xc1\xb9\xbd\xc2\xed\xf9\xf7\xed\x94/l\xeb\x0f}\xb2\xfad\rF\x0c\x00\r\x04$\x01\xff\xfa\xcd\xdf\xf8\xcfo\xfc[\xd7\xdf\xef\xdb\x83\xb8yr~\x7f\xd8\xb9\x94\xdd\x0es\x93\x8b\x9a\xe2)\x02\x03h \xe1\xc0\xdet\x97\xce\x83\n\xcb\x90\xc1\xca\x85\xce\r\xbb\x8a\xddk\xe1G\xff\xd6\xde\xa7<,#fd\xc2\t\n\x0f\xf7\xcf\xbe\xfd\xeb\xdf\xb8\xfb\xab\xfd\xe6\xfeby\x7f\xe8\x8e.\xbc\xb0\x13\xf6\xf6\x9f\xf8+\xaf\xed~\xe4\x1f\xec\xfe\xb8\x15\xc8\x88\x00\x15\xd6#\x06\xf3\xbf\xf0\xc5_\xf9_o\xff\xc7a8\x88\xfdQl\x8f\'\xcf\xf7\xd5~\xf2sJ\x85\\\xc0<H8\x22\x90\x01\x040\xc0\x22,\x00\xa3\xc2LJ\x17\x02.8^t\xbc\xf4\xa1\xd9\'>\xd3\xfc\xe8\x80\x98\x91\x14f\xb0\x80\xc2#\xfc\xec\xef\xff\xab\xdf}\xeb?\r\x9b{\xaf\xb5\'W\xacx\xe5\xc5\x0bW\xcf\xdf\xe0\xce\xbbe\xfa\xca\xa5\xd9\x8d\xf3\xf5N\x01\x17\xa0\x04\x04T$@\r \x003\xa1\t@\xfc\xa1\x00\x14\xc0\x08(`\x80\x02\x02\x04\x1d\xc6\x1c\xa3*\x0cO\x85@\x12g(\x8e\x22F\xf4\x8a6\xdbjL_\xbf\xf3\xed\x87\xc7\xdf\xb2\xf5\xcd<<8J\xdd\xaa\xdd\xe4\xc2_{\xe5c\x1f\xd9\xfd\xd0d^\xb9\x8a,4J2\xb8\x92\x95B\x01\x03`\x00\x01\x01\x08(\x0c@\x89\xe0\x11F\x0c\x19\x19\xcf\x10\xce\xa1T\xa4\x84\x08\x80\x80\x81\x05J\x83\r\x18\x02J\x03\x22F\x0f\xef\xe1\t\x96hn\xe1\xf8\x9f\x1e~\xf6A\xfc\xea\xb2\xbb3\xd8\x83Q\x96\x90$4:\x8a\xc3`\xd6\xabN\x9d\x13<#.\xb8Y\xf0\xe7]\xb8\\\x96\x97\xdf\xef?\xfe\x8f\xf0\x97\xf1\x8c\x02\x82?\xe1\xa7\xdf\xf9\xb5/\xdf\xfc\x0f\xdd\xf2;\xdd\xe9a\x8e\xc7no\x1d\xce)\xe7\xe6\x1a\x96\xa5\xb8@\x00\x0e\x14`\xa6\xf4\xc6\x822B[\xd5\xb9\x83\x18\x84\xa8&\xfb\xd3\xf9\xeb?\x8e\x7f\xf1A\xec\xe3{\xdc\x01\xfe\xfeW~\xf2\xf4\xf6m\x1b6\xfck\x07M\x00\x1c\xa1\xc0`P`\x02T\x02\x02\x04\x0c\xc8p\x91\x8d\xb1Q\xcc\x9c=wm\xf2\xb1\xbf=\xf9\x14\x9eY\xe1\xa8\xc4V\x81\x02\xcf<@\xfb\x8f\x7f\xfb\x97\x1e>\xf8\xadny?\x0f\x0b+\xd6\xcd\x95a\xb2e\xd5\x04\xe5\x94R\xc1\x1c\xeee\xb4\x8a\x92((\x02&\xb5\xceQD\xae\x96~\xd7\xbfkR\xbd8\xd3\x0f\xfc\xf5\xf2\xd3%\x88\xef\x11a\x9f{\xe3\x0b\xff\xe5\x8d\xcfiw\xcf\xfac\xb4\xfd\xf6\xa5\xa1\xda\x8f\xf5\x8e\x84\t\xac6\x15\x94\x8e%\\\x86u\xc8\x0e\xccf\r\xb9\x05I\xb0\xd6\x98Q\xb5V\x05\xce\xcd\x9e\xbb\xea~\xec3\x17>\xed \xf8\x93~\xf9;\xbf\xf6\xe5;\x9f\xefW\xef\xa4\xc5\xc1\xb09\xdc\xbf\xbe\xbf}\xf5\xd2\xe3\xea\xfcK[\x1f\xf9\xb9s?\xe1\xbd\xc7\x1f\xeb\xa1\x19\xe3\xcf\xfd\xf6\xbf\xf9\x9fo\x7f>\x0e\x0f\xb4;\x8d\xfdiq\xb5\xaf/\xe6b\x8b\xa8\x81\x12\x0cp\x04\x7f\x92g\xf1\x00\x00\x10hIDAT\x01G\xc2P\x90\x19h\xa1b\xc8\x06\x01\x84\xa4]t\xb8\xe0y\xe9/n}\xeag\xa6?\x86\xff\xc7\xbf\xfc\xbd_yr\xfbwB\x7f4\xac\x96\x96\xe2\x95\xe7\xb6\xe6\xfb\x97\'\xbbWdr)\xf1r]_\xde\x9d\xec_.\xa6\x138<\x15\x15\x91 \x9e2\x82\x80\xc3S\x04\x088\xc0\x00\x03\x0cO\x19 \x80\x01\nd\xc0\x90M\x15\xa60\xc0\x85\x82\xe2\x01\xde]\x9c\xde\xbe\xff\xf0\xce\xdd[\x8f\xee\xde\x1c\x1e\xdd\x94\xfe\xc0\xc9\xb2\x1b\xa0\x93jza\xf7\xfa\xeb\x1f{\xffs?\xb8=\x9f\xd7u\x1dB\xd8\xc8\x13E\xd8\xc2|\xc4\x98\x10\x01\x18\x0c\xa0\x03\x88\xa7\x0c\x10\x10\xa0B\xf1\x0c\x01\xc3\x191\x18`x\xc6@\xe2\x0c\x15V\xa1\x89\x88K<\x99a\xbbA\x89g\xde\xc4\x93_|\xe7\x9f?\x1c\xbf\xb2\xee\xee\x8cz`~Mo\xb9\xd0\xc2\x93\xc4\x1a:\xa86\x8e\x1e\x02\xc0\xc4\x89\x9b\x86\xb0%nV\xb8k\x1f\xa8\x7f\xe2\xe7\xf1q\xfc\x7f\xfc\xcd\xbb\xff\xfe+o\xfej{\xf2V\xb7:4.\xfc\xe5\xae\x9c\x9b6ZL%\x94d\x80\x01\x04\x1c\xe82\x0bCM1`4-\x81ZP\x0b|\xb3?\x9b\xbf\xfea|\xf6\xc3\xb8\x80\xef\xf1\x0e\xf0\x0f\xdf\xfe\xc9\x93\x9b_c\xbb\xe4\xdf8h\xa6\x82l\xe8\x81\xdepf\x02\x08A\xa0 \x0c\xe8\xcde\xd4\x95\x14\xc2\n\xbc\xd8\x94?\xf0\\\xf1g5Y\x1a%\xf6.m\xf2\xb8\x89\xc1\xa9\x13\xebs\xf7\xcd\x83\xdf\\\x1d}C\xdb\xc7c?\xb0\x8e;/\x8e\xf5\x04E\x830\xa5\x14\xe8=Z\xe0$!\x01sq%\xa9\x8aC\xb1m\'\xdb^\xe6\xc5\xbb\xce\xd5{\x92\x9f;/?\xe4b\x15G\x0c=\xe2\x1a\xa9E;\xb6\xf7\x8e\xdexr\xfc\xbf\xc3x,\xfdI\xdf\xae\xab\xcb\xb1\xbeh\x93\x1d\xa9&L\x959\x8fF\\\x00GX\x846\x10\x81\t\xa0@4SHF\xd5\x9b\x07k\xc3\xfe$\xbf\xefR\xfd\x81\xb2\xa8-2\x0e\xd6\xaf,uF\xd1{\xa7\x7f\xb0X}clO\xd3\xe2`\xb39\xde}y~\xe5\x85\xdd0\xdf\xeb\xfd\xbb\xf6\x9a\xefG\xf6\x88\xd0Q\xba\xa5\xae\x9elb\x1c\xbes\xf4\x95\xa3\xf5\x1f\xe4q\xa1\xddJ\xc7\xb5\x7f>\x96\x17\xd4\xcd\xe9j\x14\rJ\x8fLD\x85\x12f\xf0 \x88A\x8d\x86B@\x22\x1bi\xfb\x05\xcf\x95\xd2\xfc\x88\xff\xe0{g\x1f\x08\xb1\xca]\xdd\x8fX,\xc6q\xc8\xce\xe1\xc9\xe1\xb7\xba\xc3\x9b\xec\x9e\xc4\xcdI\xc0p\xf5\xf9\xd9\xd6\xf9\xed\xc9\xce\xae+\xaaM[\x0c\xb8\xd0\r\xe7\xd6\xed\x84\xa1\x12\x07\'F!\xc5\x19`\xa0\xf3\x22N\xc4\x898/\xceS\x883:\x02jj4\x10\x06\xc0\xd4\xd42\x00\x02\x9a\xd4\x92\xe6.\xc5\x11\xed\xe98\xaeV\xabM\xb79=\xd5\xe5\xfday\x84\xfeI\x91\x8e\x1c6\x9b^TB\xe9\xeb\x17~\xe0\xfd{/\xbf\xbb\xd8\x9a\xaa\x9fh1\x8d\xa6\x19\xceW\xa5\xb17\xe6\xce\x86\xb5\xe5\x8eFo \x8c\xc8\x84\x08\t\x10\xb03\x80#\x00\x1a$\x02j\n\xc3\x19#@&\x98\x01\x82@B\xad\x17\x96\x19\x96\xf2\x9a\xc8\xa7\xf1\xf4\xd6\xe6\x8b\xedx\x7f\xe8\x0e\xc7\xf4\x00\xd5\xc6\xcf5W&\x05 \xc8b\xd1\xac\xa4\x04J\x86e\xc2\xfb\x99\xb8Z|U\xb8\xab7\x9a\x0f\xbd\x1f\x1fV0\xc2\x14DFj\x93I\xf6\x8eY\xf2o~\xfb\x0bo\xdf\xfe\xad\xe1\xf4\xbe\xb5\xad\xba>\xbc0\x943\x95\x06nB)\x00\x8f\x0cS\x18\x812\xbb\x1a\x0c\xa4\x9a\x11\x08@C4\x0e\xa1\xd9\x9f\xcd_\x7f?~\xf1\xa3\xb8Q\x03\x0e\x7f\xe4\x1b\xc0/o>q\xf4\xe6\x97|\xbb\xe0\'\x0f\x9a9Q\x10k\x83\x1a\xce\x90h\rf\x98\n&\xc4)\x9c\xa1\xac\xe1k\t\x94]+\x9eSwQ\x07\xb4\x9b\xb0Yn\xe5\x93M\xbbX9I\xa57\'y\xb5~0\xac\xdeI\xe3\xe1z\x01\x94\xba\x7f#o\x9dC\x98!{V\x05\xe8a\xc0\x83\x8cCE\t\xd4\x94@\xae`\xe7\xbc\xecy\xb7U\xbe4m\xb6\xcd&\x9a\xe7\xc32\xc4\x8dk{\xed\x17\xda\x9f`\xdd\xadsw\xec\xc6#\x9f6q\\\x0f\xedrzE\'\x97Xn\xb3\x98\xb0\xa8\xa5\xf2T\x22B\xa3\x9a\x17\xcc\xe0\x03\x0c\xc0\x00\x1da\x1e.X\xa9t\x19\xc5`\xdbj{&\xfb\xdeU\xda\x8bn\xf8\xe4Q\x1e\x96\xb9\x08Y\xf2\t\xf2b=\x0c\xed\xe6p\x1c\x96\xcd\x8b\xf5\xf7]\xdd\x9a\x9f\xdb\xbb\xa3[k\x99OB\xb0\xb5\xb4\'h\x8f\xd2\x93\xc7\x9ba\xe8\x92\x1e&;\xc9\xdd\xc6bG\x19\xca\xe7\xd4\xefZ\xd8bh\xe0jx\x07\x12\xd10\x02 \xbc\xc1\x91j\x80\xd9G\xac\xf9~\x9e\xdfv\xe7\xc3x\xa5\xc4\xcb,\xe7-\xdd\x98\xcb\xd3V\xbaN\xfb\xd1\x9e<\x19\xfbAE\xc4\xc7\xd5p\xfa(\xae\x0e\xe3\xe2\xa1t\'\x97\xf6\xab\xad\xa6\xa8\xc7PMf\xb8\xbc\xd7c\xfbT\xe7\xf7W\x93>y\xefEh\x0c%\xc2\x14 @\xb5\xa4\x10S\xc3\x19\x12g\xcc\x80\x010\x00\x04`g\x00U\xd8S\xaap\xa64\x03i\xa6\x92\x07\xd7\x9f\xe8\x98\xd3r\xdd?<\x1cN7\xab\xc7k]\x1e[\xees\xa8\xa5\x08\xbb;\xf5\xab\x7f\xeaE_o\x1d\xad\x18\x8bIk3\x94e\x0eBSq,&\xa4\x13?\x9f\x9a\xd0\xa0\xd1\xc5(\xc3c]\x1fY\xce\x80\x01\x86\xa7\x1c\xce0\x1b{\x83\xc2\x04gH \xc3\x12\xcc\x00\xa1\x9c12\xe6\x0c\x0c\xb4\xe5\x98\xc6ql-\x1ejl\xdbn3v\x8f\xddN\xb7\xf5\xaa\xf4eJ%B\r_\xd0\x14$\x0cJ\x88\xd0\x93%%\x84\xd0\x84r\xffr\xfd\xde=\\\xef\x81\x1eD;\xc5*v\'+\xf1\x89\xce(\xfa\xce\x83o\x1f\xdf\xfff\xec\x0f\xd2\x93,\x92\xe5\xa5\xb1<\xafn&\xbe\xa4\xf7\x94\x80\x0cs \x0c\x85\x12\x06!J2\x18`\xb6\xed\xe0\x05\xac\xf7\xfd\xfc\xf5W\xf1W\xdf\x8d\x1f\xae1\xf7\xa8\x152\x81{\x0b7\x7f\x03\x9fyr\xff[a\xbd\xe1O\x1d45!\xc4h8\x93\x81\x04(0\x02\x04\x02\x01cA\x11\x04\xc7Zd\x1ae;\xd9\\\x07\x8c\x9d\xf4K\x8e\x8f\xb4=\x8a\xe6G\xf1F\xc2\xe2\xa0\xddQ\xea\x0e7\x83\xf9\x1a\x17\xafk\xb3E?\x815L\x1e.\xa0!\x1ef<P8\xa0\x82\x14\x22#\xf0B\xe1\xf6\x9d\x98+\xb3\xbb`61H\xbb\xf2\xd2\x86n\xad\xed#l\x0e-\xd9\xe0\xf3R\xe2QT\xb38\x8c\x9bu\xf5\x02\x8b+\xf43\x16\r.7\xbe\xf4\x1c\xa8#4\x1b\xce8`\x00j\xb2\x87\x11\x98A\n\xb8\x01\x85G}j\x051+\xb0\x93\xb2\xeb7\x88k[<\xb0~\x91K\x9f\xbc>I\xf9t\x1d\xc7\xa1\x1b\xebl\xcdK\xe5\xa5\xcb\xb5;\xb7w\x97e\x1b\x04Y\xd8R\x17\xd2?\xd4\xd5\xe3\xde,\xc2\x8e\x92-\xf2hi\x18\x89\\]\x83\xdfC9\xa3k\xa0%\xca\x00\x12\xd9\xf0z\x0e\x7f\x9e\xdb\xcf\xf9\xfd\xedxn\x9e\xae\x89\x9f\xa7|\xda\xaf\xee\x83\xb0\xa2J~\xd6r[\xa5a\x98.7X\xaf\xe3\xeat\xf3\xe0\xe1\xa6m\xa3\xd3(\xcb\x87\xe3\xe1\xa3\xb8hu\xb5\xa9\xb6y\xfd\xdal\xe7\xea\xde\xb4\t\xf5\xf6v\x1bv\xba\\lz\xde{8\xae{\xa58)f\x08\xa5\x15\xdb\x92[\xa6M\xce\tg\xc8\xac\x06\x05\x88\xa7LA\xc0\x00\x18\xf1\x87\x8c@\x962K\xed\xe2\xca\xe5\x019[\x8a\x02\x13\xc2,\xd3\x94\x1a%m\xd8.\xd2\xe9\xe9\xea\xde\xe9\xb0\xce\xcb\x13V\xd3\xed\xe7\xf6\xb6B\x19z_L/4]\xc4\xc9\x1a\xd1\xe0\x84\x84\x18\x99G\x85\x989\xfaR|\xe9\xc3Vp\x93\x1a\x22(,7J\xa2\xb5\xd5;\\\x1f1\x1b\xb8\xce\x12\x8d\x02\x13\x22\xc3\xd4`\x00h\nU\x81\x01j&0\x98&\x1dsns\x7f\xa4\xa9\xcfqHmt{6{\r,\xd5M\xc8\xca\xe0A\xc0@\x18\n\xd2\x893\x113O?e\x98\xcd\xa6\x97=\xf7c\xc2\xd8\x1a\xd6>=\xcc\xfd\xe1h\x12\xe9\xb2s\x18\xdaU\xbbx0.\x1fh\x9f\x19\xb4z)\xfbmH\x037\xa1\x14\x80G\x86\x15\x10\x02[\x99\xd9\x90\x00\x07\x94\x80\x11/z\x0c\x064;~~\xedy\xfc\xe9\xcb\xb8A\\&\xa0h\x89\xa6\xc7\xc3\xaf\xe7\xcf/\xde|#\x9c\x1c\xf3\xa7\x0e\x1aOx\x22\x19\x06`\x04\xd4P\x12\n(\x10\x00GfCA\'\x0c\x03C\xb4JRc\x83Y\'\xa9\xe5\xe9\xdd\xbc~\x10\xc5%\rj\x02U\xb5\xb1O\xdd&\xaaM\xb6\xb8{\xcd\xc2\x8cU\x83<\xa1\x14\x08\x01%\xf18\xe3(#\x02\x01$\x08\xca\xab\xa5\xdb\x13d\xca\x88zD\xa0a\xd8\xf8\xd8\xf9\xe1\xd4\xd6\xf7\xb1xG]\xb0ZF\xb3\xa1O\x83\x8c\xe30\xc4\xe6\x1a\'W%4\xac\x1al7N\xbd\t\xe0I\x85%\xd8\xa0\xa6D 2P\x19\'\x94\r\xd0C\x04!B\x9c\x95N\'\xe8eh\xd1\xafls\xcf\x96G\xb1\x0c)8D\xb3\xd5x\xdaw1d\x9c\xbf\x11\xae\\)\xdc\xbcy\xa7\x90\xc7\x9e\xe8&\xae-\xaa\x8d;\xbes\xbay\xd8\x89\xcb\xe6:\xb3>E\xd3\x98-\xe5\xe6U\xd6\x17\x11j~\xbc\x9c\xfc\xb9\xe6\xdaE\xff\x92\x8b.\xe8^Nc\xbf~\x00\x01D\xe0\x05\x02\x11/.\xe0\x8csY\xfc\x98\x90\xcd\xe7\x91\xa7\'\x9b\xe5I{\xf8\xf6\xc9\xad\x9b\xeb\xb6\xcbU%\x85O\xa1\x84/$iv\xcc\xdf\xf7\xd2\xf4\xdcv3i\x8ar6i1\x8b\x12\x86\x1c\xee=\xca\xc7O\xda~\x84\x08\xc5{:GDjTU\xd2\x9c\xf3)\x9b\x9a\xc2H\x00\x84A\xa1\x0e\xcc\xc4\x19\xc2@de0\x06\xb1\x919\xe1\x8c)@E\x89~\xedr+\x04S/\xb13\xb5\x98R\x8aZ\x067\xab\xc2\xc5\xb9\x1f\x0f\xba\'\'h[\x9c\xb6|<T\xd2X\x99{)\xbd\x04\xe7\x0053\xb5\xaeUUs@JV4R\xce\x02\x83P\xe8+W_\x98\xbb\xa6B@*\xe2\xc0\xf1\xc4N\xef\xa0\xbb\xc3\x08\xc0\xf0\xd4`I\xe9\x9dL\x92\xb5\x96G\xa8\t\x22\x115I\xca]J+\xeb-\x9c\xe7\xe45\xf8Z\xa5&+\xd0\xa3\x10\x19\xcd\x00\xa8\xc1\x84^\xc4S\x94\xa5\xb9\xd2\xbbI\xb4\x89\xf4\xc2At\x08\xab\xb7\xc7\xe1^tn\x10\xafA\x98r\x8ec\x1bWK\x1d\x93\x9fbv\x1di\xaa\xaef1\xa3/\xe9<\x0c\xa0A\x88*3\x1a\x06\x03\x01\x0f\x14\xc4+\x01\x9d!L&akk\x0b\xbbS\xece\\\x17\x8c\x05\x1eo \t\xee\xfe\x9b\xc7\xab\xdb\xdf\x8cO\x8e\xf8\xd3\x0f\x1a\x1a\x8c\xc8\xc0\xc6 \x80\x03\x94 P\x03\xce\x90\x81\x91\x0cD\x22\x13H\x06I\x85u\xa6\x03S\x8f\xc7\xb7t}7\x85\x90 6\x12\x19&j\x165\x0fZ\xcel\xe7e\x99\xec\xb2\x99\x02%k\x0f:\x88\xe0a\xc6\x91B\x8d\n\xcb\x86\x00\xb9Q\xc8\xb6\x87P\x1c\xa4\xa7\x16\x90\xf5F\xba\xd6i\x87\xe3\xef\xea\xe2\xb6\x15\xc1D`@\xd4^b\xb2\xd1\xce]\x97\xd9\xf3\xe2\'\x0c\r\xad\x86y\xdb\xa2x`0\x15\x92@\x01\xb4\xa6JlA\x08\x9e\xc2\x08F\xc0\x04\xa2>\xe7\x82=\xadc\x1c\xec\xf0;\xba8H\xc1\xc5*\xcc\x92\x94m{<\xc4\x81\xc6\x17^\x0b\xfbWC\xd1\xf0V\xd0\xc7N\\;\xb7\xae\xe2R\x16\xb7\x8f\xfb\xfb+T\xd9\xc4\x00\xecg\xf7\x9e\\oc\xf2\xda\xb5\x9d\xeb\xe7_\xd8\xda\xd9\xf78E\xf7\xc8U\x8e\xde\x81\x01!\xd0y\x90t\x1e\x14\xc2h\x84\xc6<\xacr\x8a\xe3r=\x9clRk\xddw\xdb\x96\x856\xa1s\xf2\xdd#[\xf5\xa8&%\x12\x99\x12\xf28Z.+\xb9\xf1\xf2\xde\xf9\xdd\xe9t{\xaf\x9c\xed\x0cV\xa9-s\xd2\x07\x0f\xd7\x0f\x0f\x96\x8f\x0e;\xe7\xbd\x0f\xe2%\x9b)`8C\x05\xc4`8c\x04\rF\x03T\xd5T\x9d\x90\xc4\x19\x82\xd9L\x95ND\x003\x83E\xd0\xe70wq\xedrk9#\rTM\xc9\xc6!\x19xa\xee.]\xac\x9f\xbb\xd8\xf8\xc2/O\x87\xfe\xa4=Y\xa4\xaf\xbdi\xcb\xe3\x8e\xcb\xe5\x18Y\x16\xcey\xba@\t\xf0\xb5\xd7\xa4:\xe4\xd8\xe61\x1a\x8c\x00\xcd`\x80\x89\x95\x8d+\xbc\x98 \xcc}s\xa1\x96\xb2\xb2J\x8a\x9d\x1a\x9ek=\xfe2\x1e\xdf\x12!\xe6\x19K\xb56g-L\xa1\x80\xdb\x89\xb9\x1b\xc6\x13\x8cV\xefr\xfe\x9ah\xad\xd2@j\x06O\x03\x12\x10\xcd\xd4@\xb2\x14\xf1\x82HR\x9cH\x91\xb5\xca\x1d92\x0f\xdc\xdcN\xe9-\xf5>\xc2)\x085\x98\x99\xf6\xa3\xb6\xc9M8{\x05\xb6m2\x83\x96\x08\x81.\x80\x86Z$\x9b\x15\x89\nt\x06\x03\x1abB\xec;$\xc5VU\xd6M\x85\xb2\x0c2S\xec\r\xb0\x02\x9b5\xac;\xd2\xa3\xdf]\xb4\xf7\x8fm\xdd\xf1\xef<l20\x18\x0c0 \x00\x0e\x18\x01\x05\x04\x08\x00\x81\x04(9\x1aD\xe8M,3\r6\x0ep=\x1e\x7fK\x8f\xbf\xab\xde\x81^\xb3CJ\xa0\xa1P\xd3h~\x8es\xef\x91\xfa\x1c\xcb\x06\x93\x92\xceC<\xce\xdcK8T\x94\x10\x00\x19p\xc0\x15\xaf\xa5G\x01\xce\xe0\x85\xf4\xe0\xa6\xb3\xae\xb3\xa1\xb5\xc5-[\xde2/\xb6\x86f\x22\xa8\x99*\x15\x17o\xb8\xf3\xd7\x04\x13\xb2\xb4Ma\x85\xe3D(\xb0lF\xb2\x02\x03\xe0\x81\xd6\x0c\x84\x80\x06\xf1\xc0\x08\x80\x1cT\xa3\xd2\r\xb4\x91}g\xc7\xdf\xd4\x93\xbb\x99\xce .)\xa0\x99\xd9\x08\xbb\xfa\xba\xaf\xafH\x98\xb8{\xa5-\x1c\\\'\xb1\xe5\xb8\xc4\xeb7\xdd\xec\xa0\xd9\xf6;>M\xa1\xcci\x1c\xd7\'\xae\xe0\xbb^\xdd\xb9t\xb9\xa9\'e9\teA\xef\x03\x85\x22\x01$Laf\xaa\x80\xa5v\xb9\xb9\xfb\xa8\x7f\xb4\xcam\xc6\x90\xcbY\x90\xba(v\xa7R\x84\xd1\x8a\x0en\xbd\xc9o\xdc\\\x1e\x9f\x8cN\x08xx\x07\xe7\xb3\xa1\xa9\x8b\xf7\xbcg\xff\xdc\xeet2qEU\xc41\xc2RJy\xb1\x18\x8e\x8f\xbb\xc3\xc3\xae\x1fm\xcc\xa6)\x99)A\x11\x90f\x14\x98\x19\x8d\xa0\x19\x08\x18L\xd5\x00\x88\x80 \x01X2U\x05HO@\x00\x18\x94g\x1c\xa0\xc8\xa69C\x8d4@\x83G]\xba\xbdm9?w\xe7\xb7\n\xef\xd8\xf5\xcc\x86\xe5:\x7f\xe3\xe6\xe2d1x\x9a\xa6\x94\x96\xe3x4.\x8f\x86q\xd0\x108\x99{W\xfaz\xb7\x88\xab\xd8\x9f$<cj1f\xcb 0&3\x80\x04\x08fVS\x11/\xe5\xdco]?\xe7\x9aZ=S3\x8c\xae{4.\xee\xf8\xfe\xaeK1\xc9\x90\xb2ZBB\xd8\xc3\xf4\xbd\xc2\xdaX\xc1\xd5p\x81\x863\xec\xd5\x0cV\x8b\x04a\x82)\xe0\x9c\x90A\xb3K\x83\xa5\xc1\xac\xc3x\xcb\xc6[F1\xf1\x9a\xbci\x22\x15HY\xc7\xec\xb6X\xbf\x0b\xe5.\xad!\x82\xc1\x83\x1e\x8e,\xc9\xd1l\x9a\x19\x8c\x03@\xa0\x01j\xc19\xc2\x11%i\xe4@\xb8\xa2,g\xd3M\x8c\\o\xe2\xca\xd6\x07<\xbeUX\xa7\xae\xdb\xf03\x0f\x1a\x00#\x90\r5!\x00\xf1T\x07\x8c@\x05\x04`\x042P\x00\nd\xa3e\xc4\x88\x14\xad\xe8prK\x0f\xef\x98\x1a\xbc7\t\x88#\xa0(\t\x01\xaa\x1dN_\x11\xce\xe0K\xce*\x04\x818$\xe2^\xc4\xc2PS<\x99\x0c\xc9\xf4\xb2\xb3Z\xd4\x915\xa4\xa4#`=\xbb^\xbbN\x17wuu\xdb<\xb01k\xcd\xb6I3(p\xe1e\xd9y^lbZ0\x8a\x9d\xf3\x92a\x91\xe6\x8d\x04F\x9a\x03.AV\xb0%\xcc\x81%\xc4\x03\x1eL\xb0\xb5fM,\xcc\x8d\x83\xae6\xba\xf8\x8em\xde1!6f0\xab\x9d+H\x85\xee\xbf*\xba\xcf?SN\xdf=\xd9\xddn\xa6vX\xc8j\xb7O\xe1\x8d\xaf\xdf\xbd{\xf3\xb1\x041\'\xf4b\xe2(\xf4An\xbcr~\xff\xe2VS\x17E\x90\x10\x9cw4\xcd\xa6\xa3\x0e]\xde\xac\xd2\xaa[|\xedI\xdcd\n\\\xc1\xc9\xe5\xa6\xbe\xb4\xe5\xca\xc2\x04\xa4@\xc4\x92\xf5\x91\xfdh\x9bAo~ws\xbc&|I\x06\x88\x90\x02\xc3d\x12^}e\xf7\xdcy_\xd7\xf0\x9e\x06h\xb2a\x88}\x1f\xd7\xeba\xb9\x8c\'\xa7q\xbd\x1a\xbb!i\x06\t\xe7H* \x80\x02\x02\xc0L\t5\x18!\xf8c\x06\xa5%\xc3\x19\x05\x1c \x02\x12\x82\xa7H\x113\xe4\xa8\x00DrU\xd8d\xe2\xb7\xb7\xc2\xf6\xccO+\xd4%=]\xd7\xa5\x14\xb1n\xe3\xb7n\xad\x16\xa7\x03H38\xa2\x08\x1c\x87<v}\xffh\xb3:\xe852\x8e&\x82\xad\x0b\xc1\x15t\xa5\x88P\xa3\xc6U\xd2lC\xa7\xe3\xa0\xaa\x96\x13\x1c\x18\xa39\xcf\x14-\x04\x9a\x19\x1c\xab\x89\x94[n\x8cz\xe9\xf5\x9dj\xb7\x80\x9f\xf5~\xb3t\xcb/\xe9\xe2\xf6\xceX\xbf\x9b\xa1\x01KJ\r\x08Taf\xc9p\xa6\xa2\x90HP\x18(\x04\x04J\xcb\xb0\x08\xb4\x18o\xa3{K\xcd\xcc9\xe4`:\x1a3!\xc8\xaa\x98\xa3z\x85\xd56}-\xce\xc39\x9aG\x86\x8df\x00v\xb2\x14 \x00\x01\x02\xe0\x04/8x<\xd5\x03\xa3\xc1{\xc7\xa2hS\x1e\x87q\xba\xb1\xd5\x1d<\xfc\xba/\x0b\x047\xfe\x1f\xfb\xef\x22i\x8f\x9f\x00\xf6\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode_gray(code_target).unwrap()}
-    });
-    pub const QTE: LazyLock<Self> = LazyLock::new(|| {
-        let code_qte = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00%\x00\x00\x00\r\x08\x00\x00\x00\x00\x0b\n\xf8\x84\x00\x00\x01\xb0IDAT\x18\x19\x1d\xc1\xddKSq\x1c\x07\xe0\xcf\xe7w^\xb7SsT\x0eK\x8b\xa0\xa6\xad\x97Y\xa0\xe5\xad\x88y\x11\x04\xd1E\xd8]P\x10\xfd\x07\x92\xd7\xa2B\xd9\x8d\x17vS]\xf5\x06%\xeb&\x22j\x08\xb9\x11\xe1E\x0cDz!\x90\x1cE0\xda:k\xe7\xfc\xce\xce7\xd7\xf3\xf0I\xd5k\xf5\x96\xbbGi\x1b\x90?\x1a\x80\x88\x80R\xaf\xf6\xec\x16\x80T\xd3\x13E\xbe\x89\xc2\x9do_/*\x07\xdbX\xd7\x10\x81\x00\x8ccG@(X\xcfv\xbc\xe2{g\xf3\xf1p\xef\x11\x87\xe8\x88k\x02\x08\x84 A\x00\x8a*\\\xfa\xcb\xcaSg<\xb92f)\x88\x08X\x0f\x05\xff\x11\x80\xb2\x8cH`\xde\xbc\xc8\xf2\xcf\xbb\xd7\xc3\xac\xe7?\xec\xdb\x95O\xc6l6\x05\x02\x10\x84x.\x80@\x9b\x0f&\xb8\xfc}\xdf\xbd\xaei\x8b\xfe\xadj\xff\xd9\xe3\xa2\x7fC\xb0\x8d\x90\x94-\x80\xb0\x85Gd\xa1\x11g\xe7\xe7\x0c\xc0\\\xb9\xdf\x7f\xe1P\xbbF@@H\xc2\x03 \x10\x04\xc5Y\x96\xe0\'\x16fL*\xa8\xb5\xf9+c\xba\x0eA\x87J\x13\x14\x08\x10lMr5lf\xa6\x16M)\x7f\xd4\xb2:8\x15\xf8\x80\x00\x10\xcf5\x10\xc2`\x0c\xfd\xae\xc4\x97\x9f\xf4\xe8\xa5\x82\r\x85x\xfdy\xe6Z+\xa0\x8el\x00)\xbb\xb4\xfe\xc3\xd9\x9f\xcb\x1a\xfa\xc50\x176S\xf9\xa5\xdb.\xd1!\xca\xc7\xd7/=a\x1e\xaa\xabR\xf8\x10\r\xe5\xbc\x91t\xb8v\x92\xe72}Fc\xd2M\x10\x1d\xba\xbdQI\xee\xad\x1dK;\xe6\x9d\x8d\xabg\x18C\xd8,\x9e\xe0\xc8\xd1\\\xb5q\xe0|\xdbr)Q[\x95>\x1f\xfe\xb5\xe7\xdb\xc1\xd3vtC\x9f\x1a\x18w\x00l]n\xfd\x03\xe2\xea\xb7\xd9\xb9\x0e\xe3u\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode(code_qte).unwrap()}
-    });
-    pub const COIN_FIVE: LazyLock<Self> = LazyLock::new(|| {
-        let code_coin_five = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x0f\x00\x00\x00\x0f\x08\x02\x00\x00\x00\xb4\xb4\x02\x1d\x00\x00\x01\xf6IDAT(\x15U\xc1KH\x14\x01\x18\xc0\xf1\xff73;3\xbb\xeb\xa4\xd8\x8a\xaf\rt\xdf\n\xa1]\n/I\x97\xbaE\x1bt\x90\x82\x82\x02;\x84\x10\xd8\xa9\x9b\x87\x02\x0f]<D\x05\x11Q\x87\n\xe9\xe4\xa5\xb0C\xa4\x1d\x82@:t1BzJ\x89/\xdcu\xdd\x9d\xf9\x9a\xdd\x1e\xe4\xef\'=\xa9\x04\xc2.J\x9d\xb0\x8b\xa2 =\xe9\x04\x08\xc2\x1f\xaa\xd4\t\x82\x80\xd2\xa0\x80\xa2Ho:\x01\x82P\x1c\xdc>y\xa0\x02J\x9d\x00\xcb\x1b\xc6\xf8\xb4GH\x01U\x90\xdeL\x1b\x080q|\xf3\xcc\xc12\xffYZ1\x86\xae\xefq\xa36\xaa\x84T$\x95m\xa7No\x8e\xac\x1f\xed\xaf\x9c\xbe\x1d[\xf8d\xd2P\xf3u\xb5d4y.\x22\x80*\x92\xca\xb5\x13R\x9d\x1e]\x1dLV\x0bW\xbdr\xe0\x98\x96AH\x15\x10\x11D\x00U$\x95\xef@\x01\x9d\xbf\xf2\xa3\xdd\x0b\xae\xcd8\xc3\x05\xf5U\xe6>\xd8\xf7^\xc7\xfc\x80:\x11@\x15I\xe7:hX\x9c\xf8\x0elWy\xf7\xc5\xda\xdf\xed\xbb\x11}\xb5\x189\xff0\xe1\xfbJ\x83*\x92\xcew\x00\xa6!\xe7\x86\xb6\x0e\xed+\x8d?r?\xaf\x9a\xd9N\x9d\x19\xdbl\x89\xe9\xd9\xbb\xde\xdcR\x13(\xa1\x00\xc9\xe4;\xf9Mdg\xbbZ\xab\xf9\xaek\x1b\xa6L\x16\xd7\x8a\x83\xe5\xa9Y\xe7\xc6\x8b\x16+b\x00\x1a \x99B\x17\xd0\x1a\x0f\x0eg*\xa5\x1dy\xf6\xde\xa5a\xb2\xb8V\x1c(M\xcd:\x93\xcf=\xd7\xb5\t)\x92-t\x83\xb6y\xfe\xcb\xcb\xcb\xa6\xc1\xa9;\xad\x0b_\xa3={\xabO.\xfclv\x83\x91[\xf1\xb9\x8fQ7j\x83h\x80d\xfb\x92\xa0(\x97\x867\xc6\x8el\xd6\x02\xbe\xad\x9b]\xcd\xbei\xf0\xf4\xad}\xf1~,\xde\xe4Z\x11\x13\x11\r\x90\\\x7f\x92\xbf\x8e\xe57N\x0c\x94\xd3m\xfe\xca\x96\xf1\xf8\x8d\xfd`\xde\x11\xcb\x8a\xc5\x1d\x1aT\x91\\_\x12\xe1\x9f\xeaN\xadR\xa9i\x10\x88!\xb6m\xd9N\x84\x90\x12\n\x94_\x22\x06\xbc\xac\t\xff\xd6\x1c\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode_gray(code_coin_five).unwrap()}
-    });
-    pub const COIN_ONE: LazyLock<Self> = LazyLock::new(|| {
-        let code_coin_one = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x0f\x00\x00\x00\x0f\x08\x02\x00\x00\x00\xb4\xb4\x02\x1d\x00\x00\x01\x8bIDAT(\x15U\xc1\xbbn\xd3\x00\x18\x86\xe1\xf7WS\x13{)CP\x03dhsj\xcb\x00\\A\x11\x88\xb6\x12\xbd\x04\x98X\x1d\x1b\xc9v\x10N2\xa3n\\\x00\x8bege@L\x0c0\x96{\x22\xc6\x1fv\x13\x0ey\x1e;\xe8w0\xb6\x88\x86\xb1E\x08\xec`\xd0\x01\xc3\xd8\x90h\x18\xc6?\x02\x84\xb0\xc3A\x07\x0ccC\xba8\xbf|\xf5\xf2\xf5\xb7\xef_\xb3\xfc#k\x02$\xb0\xc3\xe1\x1d0j\xc6\xc5\xd9\x8b\xab\xf7\x1f\xf6\xf6n\x03\xc52K\x17\xb1\xe3\xb4\xa8I\xd4\x84\xf5G\xfb4\x04\x9c\x9f]\x86\x93\xf8\xe7j\xf5\xe8\xe1\xe3\xbc\xc8\xd2Y\xd4vw\xa9\x99\x01\xaa\xb0\xfex\x9f\x9a\x84\xc0(\xcb*\xf0\xe3$~\x97\x17Y:\x8b\xda\xee.53@\xc2\xfaG]\x04\x88\r{\x13\xc4\xe1$\xc9\x8bl6\x8f\xda\xaeC\xcd\x0c\x90\xb0\xc1\xb8\xcb\xb6p\x12\x87A\x92\x17\xd9l\x91\xb4]\x07\x89\x1b\x1268\xea\xd20\x8c\x86\x14\xfaq\x18$y\x91\xcd\x16I\xdbs\x10 j\x156<\xba\xcb\x9a\x19\x02\x14\xf8q\x18\xc4E\x91\xa5\x8b\xa9\xeb94\x04\xa8\xc2\x86\xc7\xf7\xd8\x16\xf8Q8\x89\x8be\x96\xce\xa7\xae\xe7\xf0\x97\xb0\xd1\xf1}\x10kF-\xf0\xe3\xc0\x8f\x8ae\x96\xce\xa7\x9e\xe7`\xdc0U\xd8\xe8\xa4\x07B4\x8cZ\xe0G\x81\x1f\x15\xcb,\x9d\xbf\xf5<\x07\xa3a\xa6\n\x1b?\xe8\xb1\xed\xe9\x93\xe7\xa7\xa7\xcf~\\_\x7f\xfe\xf2\xc9\xf5n\xf1\x87\x84\x8dOz\x18\xff\x93(W\xbf\xca\xb2\xdci\xed8N\x8b\x9a\xa8U\xe27\xba$\x92\xaan##\xcb\x00\x00\x00\x00IEND\xaeB`\x82";
-        Self{img:Self::decode_gray(code_coin_one).unwrap()}
-    });
+    fn embedded(name: &str) -> crate::error::Result<Self> {
+        let (_, code, gray) = EMBEDDED
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .ok_or_else(|| RappyError::Template(format!("no embedded template {}", name)))?;
+        Self::decode_both(code, *gray)
+            .map_err(|e| RappyError::Template(format!("{}: {}", name, e)))
+    }
+
+    /// 解码全部内嵌模板, 任务开始前调用, 避免在检测途中因模板损坏 panic
+    pub fn check_all() -> crate::error::Result<()> {
+        for (name, _, _) in EMBEDDED.iter() {
+            Self::embedded(name)?;
+        }
+        Ok(())
+    }
+
+    /// 解码失败时记录错误并返回占位图, 同样的错误由 check_all 在任务开始前返回
+    fn embedded_or_placeholder(name: &str) -> Self {
+        Self::embedded(name).unwrap_or_else(|e| {
+            error!("Failed to load template: {}", e);
+            Self {
+                img: Image::placeholder(),
+                color: Image::placeholder(),
+            }
+        })
+    }
 }
 
+/// 各区域的模板, 第一次使用时解码
+pub static KEY_READY: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("KEY_READY"));
+pub static ENERGY_FOUR: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("ENERGY_FOUR"));
+pub static ENERGY_ZERO: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("ENERGY_ZERO"));
+pub static TARGET: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("TARGET"));
+pub static QTE: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("QTE"));
+pub static COIN_FIVE: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("COIN_FIVE"));
+pub static COIN_ONE: LazyLock<TemplateImg> =
+    LazyLock::new(|| TemplateImg::embedded_or_placeholder("COIN_ONE"));
+
+/// 内嵌模板: 名字, PNG 数据, 是否转为灰度
+static EMBEDDED: [(&str, &[u8], bool); 7] = [
+    (
+        "KEY_READY",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x15\x00\x00\x00\x0c\x08\x06\x00\x00\x00\x8d\xce\xf6\xc5\x00\x00\x02uIDAT(\x15\xa5\xc1MH\x93q\x1c\xc0\xf1\xef\xffY\n\x19\xbaY\xa2\xe9lJ\xf4F\x04\xd5M\x8c\xe8P\xa6(\xf6v\xc80\xbbX\xa0Q!\x22Y\x1d\xd22\t*z\x13\x13z\xd1\x85`/\x96\x89\xb3\x99\x14\xce\xa42foRH\xd0%}\xcc\xa4\xcd\xd6Z\xf4\xb2\xb2\xe7\xd7<\x0c\xea\xd0\xa1\xfc|\xd4I\xd7S\xf1\xf9?#\x860Y\x9aIc\xba%\nU|\xbcQ\xde\r\x0e\x81\x08a\x96d\x1bf[\x22\xffJi\nKl4j\xdc0D\xe3O\x1b\x8a\x0e\x90\x9a\x9e\xce\xff\x88\x9d\x11\x8d\x92\x10\xdf\xc7\x00Nw?a=\xcf\x07\xb1\xa4$\xf1;\xff\xf0(~]\xc7b\xb3a\x99\x95\xc8\xdfXb\xa3Q\x122\xea\xf5Qz\xb8\x1eD0\'%\xf3\xf3\xc7\x0f\xfc#\xc3\x88\xfc$69\x05P|x3H\xf3\x99\xfd48\xbap\x0f\x8c0\x1e\x0c\xf2\xf9\xbd\x17\xa5\x99\x88\x99\x99\x84\x7fD\xc7b\xb51{\xe9|\x94\x84|\r~\xc70\x0c4\xa5(\xac\xacey\xda\x22\x8a\xd6d\xa0i\x8a\x9eg\x03\xd45:\xb8rb\x1f\x82\xa0)\x85\xeb\xc9\x0b\xe6X\x13\xb0\xc6\xc7a\x18\x06\x05\xe5\xc7\xb8||/\xcd\xae^\x1e\x0fyQ\x12\xf2\xe5[\x10\xf7\x8bWL1i\xd4\xd8\xbbX\xb1l.;\xf2\xb28}\xc9II~\x0e\xad\xddn\xf4\xd11vm\xca\xe6A\xffK\xba\xfb\x06\xd8\xb7m=;\x0f\x9e\xa5\xb6\xb2\x88SM7)\xdb\xb2\x16\x14x|\x01\x94\x84\xbc\xf1\x8cQr\xb8\x91\x88\xa8iDDE\x91\xb60\x9e\xe2u\x19\xe4n\xad\xa0\xbd\xbe\n\xc7\xfd\xc7\x9c\xb7;h\xbfP\xc5\x15W/W[\xee\xd2t\xa2\x8c\xa9\x91\x91L8u\xed\x16\xa5\x1b\xb3\xa9\xbex\x03}\xec\x1bJB\xbc>?\xcdw\x1e\x12&\x02\xdb7f\x92\x9d\xbf\x87\x8eKGh\xbb\xdb\xc7\xd9s\xd7\xe9h:\x8a\xa3\xa7\x8fs\xf5m\xb46\x1c\xa4\xe6\xb2\x93\xae\xce^@\xe8h:J\xf6\xe6r\x16\xacZ\x8d\x1a7\x0c\xd1\xf8S]\xebm\x8a\xd7e\x90[XA{C\x15m\xf7\x1eq\xc1\xee\xa0\xbd\xfe\x10J)\xaa\xed-d\xa5-f\xc9\xbcT&\xd4\\\xeb\xa44/\x87\xdc\xad\xfb\x99\x9f\xb9\x12U\xb0\xbbV<\xfak\xc40\x083\'\xa4\x12\xf0\x0ec\x8e\xb7\xf1\xd1\xa3c\x8e\xb7!\x08\x01\x8f\x8e\x18\x06\xe6\x84T\x10\xe1\x93\xef-\x13\xa2\xe3\xac\x04<:\xe6\x84\x14\xac\x8b\x17\xa0*\x9c\xbd\x12\x08|E\x0ca\xb24\x93F\x8cy*\xbf\x00\xd3\xc9\x1a\x8f\xfdR\xe6\x10\x00\x00\x00\x00IEND\xaeB`\x82",
+        true,
+    ),
+    (
+        "ENERGY_FOUR",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00(\x00\x00\x00,\x08\x06\x00\x00\x00\x17o\xfa{\x00\x00\x11\x9fIDATX\t\x15\xc1\t\xb0\xe6WY\xe0\xe1\xdf{\xce\xf9o\xdf~\xb7\xee\xdb\xf7vw:Mw\x9bt\'\x10\x92\x08\x196Q\xa0d\xd4\x99\xa44\x11K(\xc4\x11\xd1\n\x11\xc1\x05\x81\xca\xe02#*\x19\xa0\xa0\x80)2A)\x142\x0e\x15\xa60\x06\x22\xa6\x98@\x94*\x9b\x10\x22N\x16\x92tzOw\xdf\xbe\xcbw\xbf\xfb-\xff\xe5\x9c\xf3\n\xcf#\xe3\x91\xd1\xd1\x1a\xac?\xa1,]\xab\xe4]\xd0&\xc5\xef\xecE\xa6\xbf\x8ch\x1b\x9d\x05\xc2v\x86\x86\x11\x1a=Q*\xc4$\xc4\xd2\x838\xe2d\x06\x8dEU0\x85E\x12\xc1\xa4 \x99 Y\x0f\x11A\xe2\x1c&/A\xc7\x84\xd6w\xb0\x0b\x8f\xe0\x8a\x111F&\x17\xc1\x16\x82\x05\x9am\x85\x86\x1f\x12P\x90\xe7\x1f5Z\x8e\x94\xddG\x15c\x848>\x8c\xee\xfc,\xda,!e\x0f\x9a\x0e~\xe7$~:\x05[\x11\'5D\x0fiN\x98\xd6\x10\x1a\xfc\x851\xd1\xe7\xc4i\xc4\xcc\x15\x90\x00\x05d\x8b9\xd6FL\x96\xa1\xea\xb0\xc5.l\xd1`Z5\xb8)\xa4\'1K\xf7\x83\xa9\x98\x0cA\x1a!mA\xb9\x06Z\x83q \xcf~\xdb\xe8\xe2!E\xa3!\xac\xff\x14L^\tb\x10;\x83p\x15\xe1R\x9f\xb0v\x06Q\x18?\xf7\x1dl\x92\x10\xec6\xd2J\xf0U\x89\t\tqZ\x13cBh\xc0f\x82\xc9\x1d\x88\x92\xcc;lS\xa2AIV\x0f#\xfd.f\xa9\xc4R`\xf0hc\x91|\x1b]\xf8\x1c\xb6}\x19_B5\x12\xf26\x94\xeb\x8a6 [khl\x12\xfc\xf9[1\xcd1\xfcx\x07t\x1bL\xa0\xb8n\x05m\n\xe2\xd9\x15\xc2\xf7{T\x97\x1fcr\xee8\xb4\x143_\xe0\xc73\xfcL\x89\xaah\x10P\x8bK\x1a\xd4{\x92$\xc1.\x14\xe8\xf6\x90tp\x80\xf4\xc0a\x8aW5\xd8\xeeY\x86\xdfMI\xc7\x82\xb4{\xd8v\x97\x106\xb1\xbb\x1f\xc4\xf6\x9f\xa6\xa9\xa1\xd9\x16\xd26T\xeb\x8a\xac\x9fs\xda\x9c~\x13\xa6\xdeO\xdc\x1eCT4>C\x187\xb4\x7f\xb6\xc0\xa6\x1e\x9a\x05\xc2\xa9\x9f\xa0||\x8d\xe9cg\xf0\xee42\xe7\xa8\xc73\xc0\x82\x17\xfcN V\x90\xce\t\x18\xc5\xc5\x88\xa2\xb4W\xae%=:OrUB\xb2x\x02\x92\xd3\\\xfe\xe6\x94\xfc\xf2\x00\xe9-a[\xbb\x91V\x07\x8d\x01]\xba\x8fl\xd7\x0fhJ\xf0\xa5`E\x91\x0b\xc7oV3=D\xd8\x9e\x10/\r1\xad\x8c\xb0y\x9ah\xa7t\xdf\x04\xc6\t\x10\x11]@G\xd71\xfdj\xca\xd6\xbf>\x84m\xa7\xd4\xc1#\xea\x08\x1b\x82\xba\x06j@\x1b|\x15H\xbb0x\xc9.\x8ak^Ir\xddyp\xcf`\xec\x0e0azf\x8c\xbf\xffj\xe8\xe4\xd0\xcf\x91V\x8ei\xf7\x90\xdcbW\xff\x1e\xdb\x7f\x86j\x07\x12g\x90\x0b\xff\xef]\x1a\xc73\xe2\xce\x04\x99\x8c\xf1\x97\'h\xbdIr\xc3%:\xaf\x9cC\xf9!\xd3\xc2\x10\xa1\\\xc5\x1f\x7f\x05\xeb\xff\xf0\r\xd4\xcd\xa8\xfd\x840\x11\xfc4\xa2\x85#TJ\xea\x94X\x83I<\xf3/\xbb\x86\xee-\x03\\\xf79\xd4l!DD\x02a\xe7,\xe3{\xe7\t\xa3>ni\t\xf2\x1cu\x19\xd2oc\xfa\x9e\xf4\xe0\x971\xc5\x88\xd9\xba \xe7\xbe\xfc6\xd5\xe1\x98\xfa\xf4\x16\xfe\xd2\x08[Xl{H\xfb\xb6\xf3d\x07\x0e\x10B\x85\x15\xc0$\xe8x\x85\xf0\xed\x9f\xe4\xf2?~\x9d\xc6\xec\x10BE\xa8\x84f\xc7\xa3j!Z\x92\xb6A\x83\x12\xb5b\xfee\xd7\xd3yc\x87b\xf5\x14*\xa7A\x03\xc6\xceA\xfd\x03\xb6\xee\x1e\x10\x87\x03\xd4&\x90wp\xbb\x06 \x06\xb3\xd0\xc6-\xee\x90\x1cz\x00\xc5#\xa7?\xfbs\x1a\xd6\x87L\x1e\xdf$mGPO\xda\x16\xf2\x9b\xcf\xd3\xba~\x9e\xa8\x16c*\x88)\xb3\x7f]\xa2y\xb4\xcf\xe5\xe3/\x10\x9dbT\xa9j!4\x06-\x15\xda\x06\x13\x1b\xc4\x08\xa1\x82\x85k\x95\xfcFK\xff\xb5\x1e\xe3\xb6Ar\xac]\xa2<\xf54\x93\xff9G\xac2\xc8S\xca\x91\x90\xeci\x91\\\xb1\x08\x99\xc5u2\xd2\x17=M\xb2\xf7)\xe4\xf9\x8f\xbeN\xeb\x13\x1b4\x1b\x15)\x15Zz\xf2\xfd9\xe6\xd0\x06\x9d_l\xb0\xd9\x1c\xa8\x87\xd0\xa2|\xa6\xcd\xc6\'\x95\xd2\xcf@\xa1\x1eW4\x92\xa1X$\x80Z\xc5\x99\n\x04BH\xd1\xe8\xd9\xf7\x96\xc8\xdc\xcfxB\xdc\xc0\xa5\x0b\xe0\x85\xed{\x87\xe8\xf7\x07\xf8\xb1!:CSg\xf8\x12\xdc\xfe6n\xb5O\xbe\xb4\x84\x99\xdf \xbb\xf6\x9f\x91\xef\xbf\xe7z\r\x17F@D.\xd7\xe4\xfd@kE\t1\x90\xbfeJ~U\x82\x11\x8bH\xa4\x1e\xd5\x9c{\x9f\xa5\xbcT@\x9e\xd04\x96\x10\x95\x18\x14\x1f\xc0Y\x08\x01\xc4\nV\x1b\x94\x9a\xab?6\xa1\xb8\x22%\x86\x80K\x1d\xd5E\xcf\xf8\xae\x16\xd6e\x84\xd2S\x8f\x94J\n|\x05\xf4\x13\xf2+\xdbd\xcb\xf3$\x8b9f\xf9\x04r\xfc\x96\x83*\xb3\x86j\xbb\xa6\xbf`\xc8\x16\x02E7\x12\xc5a\x0e\x8fh\xbf\xc9a3\x0b(h\xcd\xe6#\x8e\xf3\x1f\xc9\xb0yF=54\xd1\xe3c\x84\xa8\x08`\x8b\x0c\x04|\xd9\x90\x0ff\\{O\x83),\x22@TF\xff\xc7\xa0\x8f\x15\xa87T\xa3\x80V\x86\xd1f\xc4\xb4\x1c3\x0f\xf9\xee\x8c\xde\xb1e\xa4\x95\x91\x1d0\xc8\xf1[\x0ehuv\x0c1\xb0\xeb*\xc5\xa4B\x9a)&7\xf85\xa5\xf5\xee1\xf9\x91\x02\x05D\x84r\xad\xe4\xdf\xdeY \xb3\x84f\n\xb3\x08V\x01\x01\x8d\x8aq\x0e\x0f\x88\xaf\xb9\xfa]Sv\xddlP\xc0eB\xf5B\xcd\xe4\x93mt3\xc1\xb4\x85f\x18\x88A\xd98\x1di<H\x9aB\xcb\xd0\xbdf@\xbe\xd8!\xdb\x9d!\x8f\xbcv\x8f\xfa\xed\x8a\xc1b\xa0\xe8\x80\xc9\x85|\x0e\xb4Q\x18Z\xe2\x82\xa7\xff\x81\x06\xdb1hP\x88\x9e3\xf78N}%%\x8c\x85&@\x04<\x10\x22`\x00\x11\xfa\x85\xe7\xd5_\x1ecZ9&\xb1h\xa8\x19}Q\xd0\xe3\x1d\x10 \x83\xf2\x8c\x87\x14\x86\x1b\xc2\xcez\xc4\xf5\x13lb\xb0\xab9\xf3\xc7\x96p\x83\x02\xf9\xfa\x8d\x035\x01\xac\x85\x9e\x8dt\x16![\x06S\x82\x06h\x86\x8a{M`\xf0_*\xd4X\x8cQF\xcf\xc3\xf7nO\x88j\xa8#4\n\xea\x15\xa2\xd0\xca\xa1\x0c\x91C\xaf\x8a\x1c\xbe3\x12\xc5\x91\xa4\x82\x1f\xd5l}0%\xa9S\xd4)\x10i\xb6\xa0\x1e\xc2L\x84rd(sA\x12\xc1\xb6s\x06\x87\xda\xe4Wt\x91\xfb\x8f\xf6t\xd0\x17\xfcT\x18\x14J1P\x8a9\x90\x1a4\x81\xd8@3Q:\xef\xad\xc8\x8f)DE\x83\xd0L\r6\x01\xe3,b\x1dh\xe4Gb\x88\xa8\x02\ni\xcb\x13\xa2C\x04F_\xf7T_J\xc9:\x0e1J\xa8\x94z\x13\xfc\x04J\'\xd4\xb5\xb05VH\x0ci/\xa5\xb3\xbb\xa0}(G\x1e\xb8\xa6\xaf-\'\xe4idn\x0e$\x83$\x82-@\x22D`\xe7|\xc4\xed\xf7,\xfcy\xc08\xb0y\x82\xc9r\x88\x11U\x10)@\x1c\xaa\x13T\x1b\x8cq\x10=\xa1*\x11\x9b\xd0\x8c\x1b\xd6?\xe80\x17\x1cI*\xd8.\xa8\x87z\x1b\x82\x87q0\xf8 ll\xc2\xac\x12\x8a\x81\xa5\xe8\x15\x0c\x8e:\xe4\xfe\x17\xf75\x17e0\x80^\x1fL\x0b\xcc\x10l\x01b\xa1\\\x8fp\xa0a\xfe\xfd\x01;p\xa0\x11\x82\x22i\x02\xd6\xa21\x828\xc4\xa4h\x98`\x8cEU\xd1\xa6\x86\xa0\xd8<CC\xa0<\xe9\xd9\xf9\xb8\xc3\xec$\xd8\x02\x88PoC\xa8a\xa6P{\xc3h\xa2L*A\nC\xab\x9b2w\xb4@\xberCO\x13\x0f\xf3\x03\x18\x14\xe0\xda\xc0\x0e\x98\x04\x9aFInl\x18\xfcf\xc0\xb4\x04U\x031\xa2\xb5\x82\x80\xa4\x16\x15AL\x02\x22\xa034\x08\x1a\x22\xea\xc1$\x80I0\x06D\xa1:\xe5\x99|\xd6\xc1\x9a\x03\x03\xcd\x08\xfc\x0c\xa6\r\x84\x08\xe3\x08[3\xb0mC\x9a\x1a\x06\x87z\xc8\xdf]\xd7SSC\xbf\x05\xf3s`\xad\x92T\x82o\x94\xe4U\x15\x83\xdb#R8\x10\xd0\x10\x11\x01\xad\x15\x140\x02\t \x16\xb1\x16\r\x15D\xd0\x1aT@\x1c\x88\xb1\x80`\xac !0{R\x99~\xca!\x8d\xa3\xda\x81\xd96\x04\x0b\r\xb09\x83Y\x03\xa4\x06\xeb\x0c\x8bG\xba\xc8}Gzjj\xe8\xb5ai>\x92\xb4\x047\x01{\x95g\xf0A\x0f9\x88$\xa8\x11D\x14\xf5\x11b\x84\x08\x18\x03\x060\x16\x8cA4\xa2\xa1\x81\xa8D\x0f\xe2@\\\x82\x08 \x06C$L\x1aF\xf78\xc2\xa3\x0e_\t\xe5\x18b\x02\x93\n\x86%\xac\r!\xc9\r\n,\xef+\x90{\x0f\xf74W\x18\xf4`\xb1\x0f\x8a\x92L\x03\xf3\x9fiHW-`P\x9b \x22\x80\x82*\xea=b,*\x0e\x11\x8bj\x04\x22\x08\x10KD\x04U@\x04\xc4 b\x01\x8bh\x89\xe0\x99>\x0b\xa3?\xb4\xe0\x1d\xb3Ma\x96*A`}\x22H\rS\'\x90\xc2\xd2B\x0b\xb9\xf7XO\xf3\x00\x8b}\x98\x9f\x03?Q\xf2\x83\x9e\xa5?\x0bH\xeaPc\x01\x83\x06\x8f\x18E\xc4\x01\x06%\x01R \x01\xd3\x858\x02\x14d\x06Z\x01\x82\x88\xa0\xa1\x06\x05\xac\x03\xf5X\xf1\x842\xb0}\xb7\xa1\xfaFF92\x94x|&\x0c\'\x96`\xa1\x16\xa8\x81=\xcbm\xe4\xde\xa3=\xcdkXh\x05\xe6\xba\xa0\xaaton\x98{\xbb#\xaa@4`\x16\xc0\xf4 \x9eB5\x22\xb6\x05t\x10I\x10MQ\xc9\x11\xa9P\xf5\xa0%\xc6(\xaa\x9e\x18\xc7\xa0\x01\xc5`\xccMh|\x0c1\x13\x8c\xc9\xa9\xcf\x8fY\xbb\xddP\x0fSj\xa3\xd4D6+\x83W\x18\x07\x8bK\x85=+-\xe4s\xfbz\xea\x80\xc5<\xb2\xbb\xa3\x80\xb2\xf29O\xb2\xea\x881\xc5\xa5\x7f\x81\xb5\xaf\x07RT/\xd1\xd4o\x01.`\xec\x12\xe0P\n\x8cX\xa0\x01\r\x88\xccP\x15TK\xa2/\xb1\xc9[\xb1\xee\x1d sh\xfc\x01\xbe~+\xc6zh\x86\xac}(0\xf9\x96\xa3Rh4\xb26\x13\xce\r#\xddV\x828\xe1\xcaC]\xe4\xaf\xf6\xf44\xb3\xb0\xaf\xeb\xe9\xf5\x14\xb3+\xb2\xe7\xd3\x06\xd3\xde\x851_\xc6\x98\x01Q3T\x15# \xb2\x83\xafo\x06\xb3\x8d\x10@\x12 \x03\x04\xd4\x03s(\x91\xe8\xcf\x92f\xc7\x11\xe9\x12\xb5!j\x83\x91\x80\xc8\x98\xe8\xefC\xf8\x02\xd3g.r\xee\x8e\x94Y\x03\x1e\xd8\xac\x94qi0\x85\xa5Q\xe5\xc8\xb5=\xe4\xee\xe5\x9e\xb6\x13X)\x02\x83A\xa4\xf7\xf3\x9e\xf9\xb7\xf7\xc0\x0cp\xc9\x83ln=\xcd\xdf~\xf6\xe3l\x8dF\x1c\xbav?\xb7\xfe\xc2\xff\x00\x1e\xc7\xd7o\xc3X\x8f\x22\xa0\x02\x92#\x0446@\x81\xb1?\x85K>\xca\x93O}\x91{\xbf\xf0W\\^\x9fp\xdbm?\xcek_\xfb>4<\x88\xf0\x19\xe2\xf4\x22\x97\xee\x12\xd6\xbeiiT\x18\xfbH\xd5\x08c\x1cY\xdbr\xf0X\x17\xf9\xd4\x9e\xb6.e\x86\x85\x22\xd0m5\\\xf1\x97\x90\xee-\xb0\xee\xd7\xf0\xe1\xd7y\xef\x9d?\xcd\xdc\xea<\xcb\xab{x\xf8\x1b\x8f\xf1\x9f_\xb7\x9f[\xff\xd3\x07\x80\xbfA\xe3\x03\x10\r\x18\x83\x88\xa2QA\nD\x02.\xf9kN\x9c\xfc*\x1f\xfa\xc8\xfd\x1c<\xb6\xc4\xd2\xea*\x0f?\xf8\x18\x7f\xf4\xbb\xef\xe2\xd0\xc1U\xf0w\x80\x96l>\xa0\x9c\xfc\xb0E\xa3a\xcd\x0bM\x84F,\xf3\x0b\x19\xfb\x8e\xb5\x91O-\xb7u\x90\x1a2\t\x1c\xfd\xe9\x86\xe5;\xc1v\xf6#\xf6\x1666_\xcd\x1f\x7f\xf8\x9d\x1c\xb8j/6I\xf8\xdew\x9f\xa5\x9e\xc1=\x1f\xfdc\xb2\xf4[h\xb8\x0f\xd4\x22\xce`\x8c\x10C\x83\x06\xc1\xba\x04\xec\xe7\xb9\xfb/\xff\x94\xe7/\x0cY\xd9\xbf\xc4\xfat\xca\xe9\xa7/\xf3\xdew\xfc\x06\xd7\\}5\x1a\xde\x89\xe8\x90z]y\xe2\xad\x96j\xcb0,a\x92\x1a\xd4X\x96W\n\xe6V2\xe4\xee=-\xcd\xad\xb2\xbc+r\xc3\xbd\x01\xd3\x17\xd4:\\\xfaKl\xef\xfc\x12\x7f\xfa\x89\xdfb4\xa9\xf0!\xd2[N\xb0\x95\xe1O\xde\xfd\xe7\xe4\xe9\xdf\x13\xea/`\x92\x041@\x04\x05b\x14\xacI\xc0~\x85\xffu\xf7\xef1\x95\xc8\xd9S\x1b\\\x083r)x\xff\xaf\xff6\x87\x0e\xf6Q\xff\xbb\xe8l\x04\x1e\x86_3\x9c\xfc\x84c\xbd\x16J+T\xce\xb2oo\x8b\xc5\xfd]\xe4\xee\x95\x96\xb6\x8dr\xf4?\x04\xe6~2\x103e\xe1\x96\xdd\x98\xe4mln\xbf\x8c\x8f\xdds\'7\xbc\xe2\x06\xb6\xb6.\xb1\xb1q\x19\xea\x94;\xde|\x07\xa9\xfb\x12\x1a\x1f\xc4&)\x17/\xf4\xd9^\xcb8|\xcd\x1a!\x04\xac\xcd\xc0~\x91\x8f\xdd\xf5\x01\xe6\x0e\xf61E\x82\xef\xf79\xf9\x9d\xe7x\xfb/\xfc*W\xec\xab!\xfc\x05\xe3\xe3[\x94_3h\x0f\xce\xfd_\xc7\x85R(\x8dP\xf4\x12V\x0f\xb6\xe9\xadt\x91\xcf_\xd1\xd1\xc2GlP\x96:\xca\xae7\x04\x96\xfe\xa8\xc0\xe5G\xf1z\x17\xef\xff\xd0\x1d\xfc\xc1\xef\xdc\x855\t\xdf{\xe23\x9cy\xe2\xff\xf3+o\xfeU\xd0\xbfEx\x9c\xf5K\xfb\xb8\xff\xde\xc3\xecY\x1cq\xe4\x86-\xae\xbc\xfa,\xaa \xee\x93\x9c<\xf5O<~v\xc6M\xd7\xbd\x95\xb1o\x18n<\xc6K\xae\xb8\x9e4\xf9\'\x08\xf7\xb0y\xf7\x90\xf1\x97\x12&\xb50+\xe1r\x04\x9b\x1bz{3z{\x07\xf4\x0fO\x91/\x1d\xee\xab\xad\x1bl\x84\xf9DY\xba\xc1\xb3\xeb#\x06\xd3\x19 \xf6\x13\x8c\xc6\x9e\xcf\xfe\xf5\x7f\xe3\xe2\x0bS^}}\xc6\x7f\xbc\xf9\x0f\xb0\xfa\xcf\xc0?0\xde\x1e\xf3\xc5O\xbf\x94\x9b^5\xc0\xa6\xca\xc3\x0f\xce\xf8\x99\xdbN\xb0\xef\xd0&.\xfby07\xf2\xe4S\xf7\xf1\xd0\xd7\x9e\xe5\xc4%\xc7{n\x7f\r\x07\x0e\xfc\n\x84\x8f\xa1\xcdCl}\xb8a\xf85\xcb$\nM\x14\x86\xd6\x90u\x1d\x9d\xd5>\x9d\xbd\ts/\x7f\x01\xf9\xc7\x9b\x96t\xb2Q\x92\xf8@\xcf+\xdd\xd5\xc0\xbe\xbf\xf1H\'\x01\xf3\x1al\xfa\xcb\x04\xff\x0cM\xbdI\x92\xef\x03}\x02\x1b\x1f@\xb5\xe1\x7f\xdf\xb3J\xaf\xd5\xa7\x93]IH\x07\x9c=\xb9\xc1\x99s/\xf0\xdbw\x9e\xa1\xdd\x9b\x81\xa6\xd8\xf4=T\xd59Bh\x91\x15\x8b\x88~\x17\xa3\x8f\xa0\xcd&[\xffU\xd9yT\xd8\xaa\x0c>@\xd9Ih/\x15d\xf3=z?\xd6\xd0{\xf5i\xe4\x917.\xeb\xf4\xe2\x0c\xb3\xdd\xd0RH\xf3\xc8\xde\x8f\xd7\xb4^*\xc4h\x8954\xd5~\xd2vN\x94\x1a#\x9bX7\xe1\xc2\x89\x9c\xcf}j\x0f/\xb9q\x91\xee`?\xc6\xf4\xd8\xda\xd8\xe6_\x8e?\xcb\xad\xbf\xb8\xc6\xb57\xad\xf1#>.\x80\xb7D\xdf\x82p\x89\xa43\xc3&\x860,Y\xfb5\xa1\xde1\x8c+\xa1I\x0cf\xa1\x85\xeb\xa5\xb4\x0e\xf6\x98{\xcdI\xec\xa0B\x1e}\xf3\x8a\xd6\xe7g\x84Q\xa0S\x07\xac\rt\x8f\x05\xfa\xff=`;\x86P\x19\x98\xb4 \x0bH\x16\xc15\x18\x0b\xf5,\xe1\xdb\x7f\xb7\xc4\xb4>Bwq\x89\xc4\xf4\xd9\x19\xee\xf0\xbd\xc7\x9f\xe4\x1d\xef~\x9e\xee\xfc\x181\x80\x82V\t:rH/`[\x15D\x98~\x1f\xb6?(Do\x18\x07\x8b,&h\xbb \xbf\xa2\xa0\xb8z\x93\xfc\xd06\x1aA\xfe\xed\x8e+5\xacU4\x9b%\xd3\x1a\x9e\xaa,\x9d\x22\xe5\xc5\xb7OY}\xe5\x14\x9bDB\xe9@\x22b\x15I\x14\x04$B\xf4\tO\x1f\xdf\xc57\x1f\xda\xc5\xf6\xa6\xf2\x867\x8ex\xf1Ol\xe0\xb2\t\x8a\xc7\xa4\x10k\xd0\xc6\x80\xb1\xd8$\x00\x91\xea\x12l\xbe?!.\xef\xe6\xa9M\xcf.\x1a\\\x11\xe9\xf4\x06\xa4\xfbF\xb4\x7f\xfc\x12\x92)\xdb\xe7@\x9e\xfbtW\xeb\x13\x05\xa6e\xf8\x97o\xc3\x03k)\xd7\xedMY\\\xf5\xfc\xdc\xef\xaf\x91\xf7=\x22\x8a\x12!\x18\xc4\x82jD\x10\x04\x01\x12\x9a\xca\x01\x96$Ub\x0c\xe0*D\x14U@A\x0c\xa0\x06A\xd0\xc63;#\x0c\xff$\xe3Y\xd7\xe6\xe1\x91\xe1\xf5\xf3\x91\x95UKk\x9f\xa7\xf5\xe2ML\xc73[\x073\x10\xe4\xecC\xa8\xbf\x98\xd0\x7f\xc5\x02\xd3\xb2\xcb\xc9\xc7#\xdfz\xb0Eo\xd9r\xeb;Nc\xb3\x06k+l\x16\xd1`P\x1f1\x0e4\x82*\xa0\x821\x82\xf2C\n\x8a\x22\xa2(\x8az\x10gqy\xc4\xd7\x8a\x9fZP!\xf8\x04&s<z\x9f\xe3\xe9\xa7\x0b^\xbe0e\xe9E3\x8a#\x1b$s\x81\xf1\x86\x10*Xz\x85 \xa7\x1fB\xa5t\x90\xa5\xf4_\xba\x0b\x8ce:u\xa4\xa9\xd2\x94\x17iN\xe6d\xfbF\xb8\x8eGD\xd0\x10\x01%L\xc0$ \xa9 QP@P4(\xaa\x80\x80I\x00c\x11\xa34\xb5\xb2\xf3\x83\x0c\xdb\x85\xf6jA\xd6:\x8a\x0f9\xaa\x05a\xeb\x9b\x94\xa7#&\xdd\xa1\xde\x86\xf1\xa6\xb0\xf2zA\x1c\xc8\xb3\x0f\x8a\xa6\x99\xa25\xd8\x8e\xa1}\xa0\x87\xeb\xecCcI\xb5\xd5fv\xbc!=r\x9etyB\xf4\x8a1J5R\xd2\x16\xd8\x0c4\x18\x04A\x8dB\x00\xb1\x8az!\xfa\x88*`\x0c6\x83\xd0\xa4l}5E~\xac\xcd\xae\x1b\x0e\x00]\x8c\xf4hF\x0fS\x9e\\\x83\x08\xe3\xa1P\x0ea\xe5\r\x06\x97\x1b\xc4\x80l\x9c\xb1\xba}J1\xd3\x88M\x005\xe4/j\x91\x0e\xf6\x10\xc2.\xfc\xb0$\xec<\x8f\x9b\x9b\x12\x9a\x88\x06\xc5e\x8a\x180\xc2\x0f\t$\n*\xa0\x02A\xf9\x91\xe0#\xa8\x10\xd5`\x12\xa8\xb72L^P,\xaf \xd2G\xeb5f\xe7O\x107\x1a\xb00\xdb\x00o\x85\xa5\x97[L&\x88\x80\x08\xc8\xa5\'E\x11\xa8k!\\\x8a\x08\x10<d\x8b\x96l\xf7\x02&i\x11\xfd\x18\r5\x22\x01P\xacS\x82\x0fh\x04\x8cAl\x86F\x0f\xa2\xd04\x88\x01\xe3,b!zC9Ki\xf5s\\\xd1\'\xfa\x9ajx\x89\xea\xfc\x14\x02h\x80\xf1eH\x16\x85\xc1U\x06\xdb2\xfc\x881`\xad \x9f\x7f\xdf\x9c\xbe\xee\xb6m\xd2\x0e\xf8\x19\xd4[\x11\t\x10\x1a0N\xb0]\x8b\x9bKqy\x8e\x11\x8fI\x0c1\xd4\xa8\x8f`RL\xba\x82qGP\xdd!6\xcf!\xc6\x10\xeb\xcb\xb8\xd4\x11|C]&\xb8\xd4\x92d\x96j4\xa1\xbeX\xa2\x8d\x82\x82o`\xb4\x06\xf3/1\x14\x0b\x82+\x0c\x08D\x85\xc4\tb\xe0\xdf\x01\xb7w\xfe\xa7\xf5\t\x00\xe3\x00\x00\x00\x00IEND\xaeB`\x82",
+        true,
+    ),
+    (
+        "ENERGY_ZERO",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00(\x00\x00\x00,\x08\x06\x00\x00\x00\x17o\xfa{\x00\x00\x0e\x0bIDATX\tU\xc1[\xac\xaeWU\xc7\xe1\xdf\x18s\xbe\xdfZ{\x97\xd2r(\x85\x16\t\x01\x81p\xf0B\xd4\x1bN)J\xc5\x1ei\x91\x96\x02\x15\xb4\x14B\x83\xf6\x90\xa2!h\xd4DJ\xb0FTZb\x04\x05\x95\xd0\xc4\x0b\x12\x8d\x1cbCH\xa8\xc1S\x8c\xd4DC,\x12!F\xa3Pjw\xf7^\xeb{\xdf9\xc7\x18\x7f\xbb\xed\x95\xcfc\xcfz\xfa\xd3e\x881\x93\xf7\xff\xe2-\xdct\xf3\xed|\xf7\xbb\xff\xc1\xdd\xbf\xfe!8}\xc49\'\x0e\xd8\x0c\x86\x8cn\x22pH!\x89\x92\x836\xc8\xce\xf4$\x0b\x18A+15\x90\x19[A\x8dD2L\x02\x8c\xe3\xf5\x98g=\xe7\xa9\xbc\xe9\xba7s\xea\xd4\xff\xf0\xc1_\xfd\x08\x8f\x9ez\x8c\x86\xe38\x18\x98A\x01v\xc1y\xe7\xeb\xe0\xe4\x01\x0f\xfc\xd5\x979\xef\xc9O\xe3\xfe\xcf\x7f\x9a\xcf}\xf1/98\xe7\xc9\xecH\x9a%EPUD\x1a&qh\xc6\x14l\t\xb8h\x15dv$c\xad\xc9\x9c\x13\xd7d\xab\xa4\xa9\xe1%f\x14\x92\x13\x149V\xc66\x08\xe0\x9a7\xbc\x8e\x1fx\xd9K\xf9\x8d\xdf\xfc\x18\xff\xf4\xe07q\x1c!\xca\n\x03\xec\x07_\xf2b\xdd\xff\x95/qtt\x8a\x8f\xdf\xfbaN=\xfc\x08\xecN\x92\xdeX*\x90\x0cQ\x14\xa2#B \x89\x91FF\x01\x86\\\x10\x86\x85\x88&\xc6\x1cXnH\xb0\x15,)\xc6\x08$\xa7\xdc\xc9\xdc\xa3\x19l)\xb6\x08^\xf8\xbc\x8by\xeb\xdb\xae\xe7\x13\xf7\xfc1_\xfd\xdb\x07\x91\x01\x06.\xb0\xef}\xef\xbf\xf4\xf0#\xff\xc9o\xdf\xf5\xcb\xec\x0eO\xe2\xbd\x01\x86\nL\x0e.T\'\xb1\xdc\xc0\xcf0\xaa\xb3q\x00\xb5\xb1\xc4D\x88!\'%ZM2;\xe00\x8e\xd0\x08\xa2\x15\x89q\x1c\x82!\x84Q\x12\xdb\x9cD\x0e\x1c\xb1\x1e\x05\x17_\xf4\x0cn\xbc\xf9\xad\xdc\xf7\xc9O\xf3\xc0\x03_\xc3\xad\x91$\xf6\x8do>\xa8\x8f~\xf8\xd7p\x84\xedv\x98\x198\xa8\x0c\xf3\t4\x9cs\xa9\x18(\x8e\xa9\xdeH\x19\x99\x03\xa9\xb0\x84YFI\x18E\x85\x81\x1c\xd5\x86\xe6 \xaaH\xc4>\xa0\xa2\xc8\x12VFD2\xc6\x8a9d\x889\x92\x17<\xffb\xae\xbe\xfe\x1a\xee\xbd\xfbw\xf9\xfaC\xff\x06\x06\xf6\xde\x9b\xae\xd6rp\x0e\xb4\x05kFI,t\xba9\xc9\x19\nhz\x12X2sRU\xb8\x0b\xe1\x98\x8a}\xc26&\xdd\x8b]\x88Q\xa2\x99\xb3\xcfD\xdbi\xa2\x1a\xdd\x93m\xc2\x0c\x98S \x03\xc1\xe9\xe33\x98\x8b\x02\x14bl\xc1\x8b_\xf4\\\xae\xbd\xee2>\xf0\xf3w\xf3\x9dG\xbe\x87\xfd\xdc\xbb\x7fRmw\x02k\x0b\x1d\x90\x19\x8b9g\x85V\x1a\x8e\xb7\x1dJ#$\xd0FQd\x19\xe6N&\x8c\xb9\x92%\x1c\xf0\x08T\xc6jBs#g\xd2\x80\x19\x1b\xc7ad\x00a`p\xbc\x1d\xd13\xd8\x9bCB\x8c +\xb8\xfc\xc7^\xcd\xf7\xbd\xf0Y|\xe0\xfd\xbf\x85\xdd~\xeb;\xd4\xac@\xe2\xd0w\xcc\xee43\x90\xf0n\xe4\x04L\xa4A/P\x19\xb3D\xc9\xa0@\xbea\xb1\xb2\x9f\x8d\xc6\xc2\xa83\xf4(d\xce\xbe\x84\x86\xe1$\x91\xa7\xd8O\x18a\xcc\t]\xc6\xccc,\x935\xa1\n\xa2\x82u\xac\x9c\xcc\x93\xbc\xe7\xce7\xf3\xa5\xcf\x7f\x05\xbb\xe3\xf6w\xe9\xb0\x02\xb9\xf0\xc5\xa9j\x98\x81\x9903\xaa\x84\xdc0\x19B4\x89\xc4\x88\x14Y\x06$.1\x22\xb1h\xa4\xce\xd0\xb2\xd8Jl*4\x1d*\xa1\x8e\x19\x91\x9c\t\x91\x05m\x18k\x1d\xd3b\xb2OG\x05Q\xc1\xc8I\x1f\x8d\xf3\x9f\xf2$\xde}\xcb[\xb0;n}\x97\x0e]\xa8\x1b\xa5di\rI\xb4\x06\x99E3G\xdeP\x16B\x98\x19B\x80\xb1\xdf\x12\xb2\xd1\x10\xe9\x06\x03\xdc\x079\x07Gs\xa50\xb6\x02F\xd2\xd83\x03\xb61\x19\x12Lg\x8d#Z\x04kA\x96\x93\x19\xcc\nZ6T\xc9\xd5W\xbe\x06\xbb\xed\xd6\x9bt\xb04@,\xcd\x90\x04f\x18Fo\xc2Sl4\xcc\x85J\x18Pe\x98\x17\x921\xa6(\x0c\tL@\xee\xd1\xd8\xb3I\xccY\x949#D\xcbb\xce\xc96\xf6l\x05;\x19c&\xdb\xb62\x94\xb8\x8c\x88\xa2J\xa4\x8a\x9c\x93\xa7\x9d<\xc4\xdew\xc7\xcd\xf2nP\xa2\xbb\xd1L\xa89 \x16\x89\xe1\x07\x98\x06\x1d1i\xb8\n\xca(\xc4Y\xb3D\x86Q\x12`\x90{\xa8d\x8c\x95\x941\x13\xa2\n\xc2\xc9XY\xe7\x9e\x11\xe0\x88\x1a\xce\x16\xc1:\xf74A$P\x90JbNz\x0c\xec\xfd\xef{\xb7\x0ca&0\xc3\x80]\x03Y\xc3T\xc8:\xe6\x89\xd2p;Aj\x12\x9a8E\x951\x11-a\xa4(\x0cjE\x19\x1c\x8d$Ux9#&\x16\x06\xb5\xb2\x8e=\xc7C`\x8e\x0f\xd82\xa9\xd8\x93)f\x01\tQA\x95\x18c\xc5~\xe9\x8ewi4XLXo\x90E\xef\x86SH\x86\xac\xe1\x0e\xaa\x8e\xf9\t2W\x82\r\x17\xa8\xc4\x86h2\xb2D\xa5\x9150\x8a3k\x92\x15,\xea\xcc\x0cb\x0b\x8c\x95u\xecY\xa7a*j8\xb3\n\xc5\x11\x95\xc5\x1a\x0e\x05Q\xc1\x88IDbw\xdez\x83\xda\xee\x10g\xc1M,\xdd\xb0\x04\xef\x02\x8c*\xc3wF\xd5\x02\xb9\x90\x04h\xa5l\xc1\xd4i\xb6r\xbc\x06\xdd\x1b\xbd\xe0\xb8\xc0\x11\xa7\xb7\x95\x19\xc1\x8e\xc1\xf1,\\\x0e\xb12\xe7\x9e\xc7V0\x8c\xfd\xd8\xb0l\x9c5\xe6 gB\xc1\x8cIT\x92\x95\xd8\xfbn\xbd^K;\x07y\xc3\x1at7\x9a\t\x13\x8fs\xe8\x8e\xd1P9\x81\xd3\xa2\x98l\x88\x05o\x9d\xca=U\x85\tz\x19\xfb\x14\x18\xac1\xc9\nj\x1c\x13id\x81\xc5\xca\x98{\x8e6\x91r\xe6\xb6Q\xe9\x98\x19c\x0er&\x14D\x05#&Ra\xbfp\xdb\xcd\xean\xcc&\xcc\x8cRrn?\x9f\xc11;_0\x19\xf4D\xd9\xb0:\x00/VM\x9c\xa4H<\x13\x01%\x11a\x84\x92^Id\xa0\xda\xd8f\xb2\x85 \x9c\xaa\xe2x\x0e\xc6\xb6\xb2\x03\xce\x0c\xd1\xca\x98\x95ls#gBAT0b\x80\n\xbb\xf3go\xd6\x89nT\x87)0\x92\x93\xcb\x93\x91\t\xc71\x0b$\xc7d\xc8\x9dR\x11\x12\x8bM\x82\xa0B,\r\xb6\x103\x8dR\xd2+\x88*\x14\xc7\xcc\x82u\x88,\x83(\x1e[7\xac6Z\xc2\xe9)z\x19\xa1b\x9b+1\'\xa4\x13\x95\x8c\xd8\x90\n\xbb\xfd\xbd7j\xd7\x17\x16k\xd0\x8dB4sZ\x13 \xa2\x8c\x8eH7\x16;\x97P\xd1\xad\x11\xda\xa3\x1c\xf4~\x0e\xe0\x1c\xe5\x11\xbb\x14\xc7)\\\x93\x8a\xa0r\xcf\x1a\' &\xfb\nz\xc06\x8a3\xe3\x0c\xa8\x18#\xe9j\x84\x8am\x0eb\xdb\x83\x1aQ\xc9\x16\x83\xac\xc2n{\xcf\r\xf2~H\xf7N\xb9\xb1,\xc2qd\x86\xab0\x13\x92\xd1\x9bcv\x02S!\x133\x92\xa5\x173\x1d\xe1\x94\x06\xad\xc4q\x8a\xa6 rP\xb11\xe6B*\xd9\x22iQ\xe4\x0c\x1e\xdbV\xa4\xa2F\x229(9\x1e\x1b1\x06\xa8\x11\x15\xccHf\x05v\xc7-?%\xbcA[(\xe0`\x019\x8f\x13\x07\xcdP\ts\xa3a\x14\xe0\x88,@\x07\xb4\xd6\xd9\xd71\x92\xe8%\x1c\xe38\xa0#\xb6x\x94J\'G\xa0\x82\x19\xa0Z\x89\xb9\xf2\xe8V,e\xcca\x14\x22\xf341\x8dm\x06\xa81b%\xaa\x93\xb5a\xb7\xder\x83\xbc\x1d`\xcd)5\xdc\xe1\xb0\x19V\x22\xdch&\xdc\x1c\xb3\xa2\xe4\xb8\x15%ci\x8d\xc4\xc9\n4Es\xc8\x82)\x81\x92mn\xc4\x0c\x98\xc1\x04j\x02\xb92s\xe5\xcc\x06\x14\xc4H\x12\xa3U\xb0m\x93}\x0c$\xa7*\x98QD\x06v\xd3;\xaf\xd1\xb9\x87\'pk\xe0;d\x8d\x05a2\xd2\xc5\xb2\x00e`\x02\x0cC\xb4nT\x827\x18\x13\xaaDwc&\x94\x06dq<\x92T\xa19\x08\x15L\x87\xb92j\xe5\xf4\x146\x04\xe5\xa4 G0b\xb0\xd5\xc6\xc8I\xaf\xc6\xac\xa0fa7\xfd\xf45:X\x1a\xc29\xdc\x9d\x04\x8a\xde\x0f\x10\x06\x96d\x89\xee\x1d3\xb1t#B,\xcd\xc8\x04\x9a \x8d\x94\x98@\xc3\x88\x98\xa0b\xcc=3\x8b\x19\x052b\x16\x8a\x8d\x11\x1bc\x16\x1a\x06\x18S\xc5\xd8V*ET0SX\x16\xa9$F`\xef|\xc75bY\x00\xe3\xdc\xe5\x10C\xe0\x0b2\xa7\xb5\trv\xde\x91\xc44#\x05\x8b\x8b(p7\\\x02\x8c\x1080#\xc8\x0c*\x8e\x18!BF\x0b\xb1V\xb1n\xc7\xf4H\xc6\x14\x99\xc6Y#\x83\xfd\xb6\x87\x04\x04\xb3\xc0\x22\x98\x19D\x06\xf6\xf6\x1b\xaf\x95\xb9\xd8-\xce\xce\x0e0k\x98\x8a\xd9;\x876\xb1e\xa1e\xa1\xe6\x94w\xa4\x02\x15\xe6\x1d\xc9i8\x95\x22\xb4G\x12D#\xab\x98\xf3\x080\x22\x80J\xb6\xb9\xb1\x85\xc8\x9ch\x13\x81PN\x22&\xdb\xdc@N\x96\x13Y\xb4\x1al!*\x85\xbd\xe3\xad\xd7J\x8b\xd1T\xb4\xe5$\x8b\x0b\x07\x869\'{\xe2\xde@@s0\xe7\t\x82p\xbc-T\x82c\xac:\xc3\x1a\x9d\x9d\xc4:\xf74%\xc2\x88\x01\xcad?\x93\x91I\xcd\x15\xa5Q\x145\xf7\xcc(f\xac\x98\x0e\x88*\xb6\x18\xb4Jf\x88\x92ao\xbf\xe1j\x99;r8hNa,\xad\x909\x87\x8b\xc8\x14aFs\xa3#*;\xa3u\x16\x0b\xba\x92\xccCd\x8d\x99\xc7\xb8\xc4\x9aB\x0804\x80\x84\xa3y\xcc\x96\x13\x93\xd0\x00$f\x15\xdb\xdc\x93SX\x15%\x98\xb1\xc7$\xd6\x04RP\x86\xbd\xfd\xba\xcbe}\x87\xcc8\xb9\x83\x94!\x83\xa20\x19nP\x1d\x04\xb4\x12\xcd\x16\xccN\xd0}\xa54(\x1d2etm\xb4\x82c\x81\x0bb\x16\xc8\x993Xs#s\xd2f1\xe5\x98`\xcd\xc1\xbamP@\xc1\xac\x80X1`?\x00\x81J\xd8\xdb\xde\xf4Z\r-\xf4\xdd\tv\x9e`\x0e\xad\xa1\x84\x93\xdd0`C\xb8\xc3A\x15\t\x847\xcc@\x02\xb2\x00\'\x00E!\x1c/P\x9cb\xd4\x8e\x9a\x1b!\x18\xb3\xa0\x1c\x03\xc6\\\xc9\x98\xec3)\x01%f\x04\x91A\x13d\nd\x8c\x08\xec\xfak/Q\xb2`m\xe1\xc0&\xadw\x9a\xef0KP\xa7uc\xc8p+v\xda\x88jD\xeb(\x1dk\xc2\x04nF\x86\xa0\x0cK\x10b\x1f\x1b)\xc7\xe61c\x16\xc21A\x94\xd8\xc6\x11H\xac\x91\x94\n\nf\x04Y\xc9.a\x14Hb\x9b\x13\xbb\xee\xaaW\xa9\xf7\x13\xa4:\xbd\x0f\x16%j;\xac\x81t\x12kNV\xd1p0\x91%\x9a9rp`V2\x15\xecj\xa1\xe1d\x9db\x8db+\xd1\xe4\xe4V\x80c@\x95\xd8\xb6\xc9\xd4DU(\x8f\x99\x82\x08#UT\n+\x81\x8c\x11\x81\x9bc\xd7]\xf5ju\xeb@g\xb6d\xe7\x85\x9b\xe3fX;\x07\x95a\r\x94\xc2\x9bQ\x02KA3(X5\xd9b\xb2\xd0\x100s0U,2v\xd3X\x05\x06T\x15\xfbm\x00F\xe4\x04\x89\x99\x83L\x08\x05\xaaB\x01HH0f\xb0[:v\xd5\xeb/\x11\xdd\x90\xc1\t3\x0c\x81\x19\xcb\x22F\x8a]wJ0\xb3h\xcd\x90\xa0J4wR\x89\xb3\xa3\xd38\x9d\x03WQr\x10\xc4L:\x86p\xb2&\xb1\xad\x84 \x13\x10\xa4\x8aR1c\x92\x99\x90\x80D\x22\xe6\x0cT\xc5+^\xfe\x12\xec\xaa\xd7\xff\xa8\x84\xc0\xc1\x81\x8e\xa0\x81\x993)\xca\x1cG\x80\x10\x8e\x01\x0bEb$\xe2\x04\x8d&\xd8*\xa1`\xa6#\x04\x14\x08\xa2\x92\x9c\x93\xac \x13\x90\x13*J\xc9\x88Ie\x91)\x9a\x8c(Q$\xb9Mv\x87\x0b?\xf3\x96+\xb1\xcb/\xbdDA\x22+\x0el\x87a\xa0\x023\xd4\x85\xa5\xa3\x06Fa\xee,\x14\x078k\x15\xb3\x9c\xac\xa2\xc9I\x8a\x06\xa4\x1c\x01\xd2i$\xa8\x01QE\x96@FJ\x94\x8a\x19Ae\xa2\x84\x148\xb0\xcf\x80\x0c\x96\x98\xbc\xe1\xba+\xf8\xeb\x07\xfe\x1e{\xf5+\x7fD\x87\xbbF5\xb1\xd0\xe9@\xf9\x82\xe3T%\xcd\x1bg\xa5%\x8b7Z\x89\x94(\x9c\xffo\x12\x12\r\x91r\xb6X\xc9\x10\x91\x89\xc9@\xa2Td%Q\x85\n\x14\x02\xc1\x900A\xcd\x15e\xf2\xd2\x17\xbd\x80\x0b\x9f}1\x9f\xfd\xec\x17\xb0W\xbe\xe2\xe52\x13\x07K\xa3$\xcc\xa0\xb7\x13\xb85\xce*\x15n\x8e\x01\x02\x0cC\x08p\xcc\x0c)y\xc2\x1e!j\x83\x12DAI\xccL\xdc\x04\x9a\xcc\x00Q(\xa1\x12$Q\xc0\xc8\x82\n|l<\xf3\xa2\x0b\xb8\xec\xda\xab\xb8\xe7\xeeO0bb\xf7\x7f\xf1>}\xf0C\x1faVr`\r\xb9aUX\xeb\x1cH`\xc6*0\x13.\xc3\x11nF\x98!5\xaa\x12sP%U \x05Bx%%\'\x08\x14\xc2d$\xa2BT\x01\x82\xd4\xa0\x04\x9aIE\xf0\xd4\xa7\x9c\xc7\x157\\\xc9\xef\xdfs\x1f\xfb\xa3=\x86a_\xf8\xb3Oi\xcd\xe4\xa3\x1f\xfd8s\x9b\x1cx\xc3\xdc(\x8a%\x1dw\x98\xc6\xe3\n\x95\xd3\x1cJ\x90@\xf18\x15\x94s\x96\x01\x83=Q\x8d\xae$\xb2Q\x1a\x18\xd0\n\xa2\xa0\n\x10 \x985\x88L|\x06\xcfx\xfa\xf9\xbc\xfe\x8dW\xf0\xc9\x8f\xff\tg\x1e;\x83\xc98\xcb\x9e\xf3\x8cg\xea\xde\xdf\xbb\x8b\xc3\xc3\x93\xdc\xfb;\xf7\xf0\xf0w\x1e\xa1\xf5\x03\xbc5\xa0\x10I\xe1`\x06\xd6\xb0\x02\x97S\x88\x92a\x14g\x89\x04D\xca\x99\x19,*\x84\x98%\xfeOB\x950\x8c\xac\x22\xab\x181\xc8,^\xf6\xfc\xe7\xf0\xba\xcb^\xc3=\xf7\xdc\xc7c\x8f\x9d\xc6)\x8c\'\xd8\xf7_x\x81NGr\xdb\xed7\xf2\xdaK/\xe5\xbe?\xfc\x03\xbe\xf6\x0f\xdf&3\xb0\xd6pkl\x04P\x08\xb1\xb0\xd0p\np\x9c\xa20@\xda\xc8jd%\x92\xf0*JP\x02$\x90qVT\x11\x99D\x06\xe6p\xe9%?\xc43/\xbc\x80O\xfd\xd1\x9f\xb3?\x1a\x98\x04\x04P\x9ceo\xfc\xe1\x8b\xf4\xd0w\x82\xff>=x\xdes\x9f\xcd\xaf\xdcu\'\x8f>|\x8a\xbf\xf8\xdc\x03|\xf3[\xdf\xa6\xb2H\x15xQ\x82f\r)1k\xa4\x92\xa6\x02\xc4\x94(\x19\xa4@ \x81\xf18\x03\x04*\x911\xa8\x98xk\\t\xf1\x85\\v\xf9\xab\xf8\xe7\x07\xff\x95\xcf\xdf\xffURIS\xe7\t\x05$\xa5\xc2\xfe\xf4\xae\x17\xeb\xa1\x876\xbe\xfa\x8f\x83\xbf\xfb\xf7c\xbcw\xder\xc3\x95\\q\xd5\x15|\xe3_\xbe\xceW\xbe\xfc7<\xf4\xado\x91Y45\x84(+\xcc\x8c\xb3\x9cB\x05Y \t\x93\x81\x01\x12)@\x80`\xe6\xc4*\xb8\xe0\xbcs\xf9\xf1\x9f\xb8\x84\xb63>\xf3\x99/\xf2\xf0\xc3\x8fR\x14\r\xc78K\x18\x02\x12\xe5\xe4\x7f\x01\xc3>\xa1\xd9\xc5\x13\x0e\xa3\x00\x00\x00\x00IEND\xaeB`\x82",
+        true,
+    ),
+    (
+        "TARGET",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x01\'\x00\x00\x002\x08\x02\x00\x00\x00\x91T\x8a\x8a\x00\x00 \x00IDATx\x01\xbc\xc1y\xb0\xa6\xd7]\xd8\xf9\xef\xef\x9c\xf3,\xef\xfb\xde}\xe9\xdb\xb7o\xafj\xb5Z\xdd\xda\xf7\xcd\x16\x96d\xb3\x9a\x18\x0c\x0c\xc3\x10\x88+\x99J&\xa9T1\x84\xd4\x0cTef2\xc5\xd4$a\x8aLR\x84\xad\xa8\xb0\x18\x1b\x83M\xc0K0x_dY\xabeYKK\xbdH\xbd\xef}\xf7w{\x9e\xe7\x9c\xdfo\xeem\xb7\x11 \xbb`\xe6\x8f\xf9|\xe4\xf1_\xf9\xc9\xba\x8a\x96\x87\xd5\xf5\x14\x1c\x13#\xa5\x9a\xf5c\x16%\x848\x10\xa3\x98j7U|\xea\xd9\xb3_:\xbc~e c\xae}Wy\xc3\xb8\x1b\x7f`\xf6\xfe\x9b\xfe\xe1#\xf1\xe5\xde\xd2\x0bGO\xac\x1c\xfaR\xf7\xb9\x17\x06\'\xce\xd7\xab7\xcc\x84_\xfa\xb5\xfb\x97\x8f\x9f\xf3[v\xb5\xe7oUK\xce\x92\xf3C\xd1z\xe5\xf8\xcb\x87>}\xa9Xp\xab\xefZ~al\xcd\xa4\x99X\xe9\x1f\xf8w\xfe\xce\xbf\xb7s\xcbMw\x9b+\x93\xb4\xa0\xe5euu\xf9\xfc\xeakgF\xc6\xfc\xd7\xfe\xec\xcc\xce\xa7\xde79w\xdb\xc8\x96\xad\x9d\xfd\xe3\xf5\xfd\x1d?\x13J5.$\xfd\xc40=\xd5\xad\xbb\x17\xfbz\xa4\xc7\xa9\x8b\xbc\xf1\xcf\xf3g\xc5{\x01\xc4\xc49\x01\x873a\xe7\x0c\xef}\xa8\xf0\xc3\xda\xd5\x03\xa0\xbdkN\xca\x19ABV\xf8\xcc\x9bzi\x86\x0c\xd7\xbc\xcb\xf2\xf6\x18AHU\xac\xba\x8dF\x08)\x1f\x15W\x88\xcf\\k:\xb4\xa6\x9c\xcf}1I\x98\xa8\xcdK\xb3\x9a9\xef\xf31\x93LD\xc0\xcc\xd4\xd2PcOS\xa3\xb1/\x82\x93\xcc\xf9L$\x80\x98\x13\x91\x80s \x0e\xe7$4\x92\x9b\x0b\x81\xe4\x0c\x11q\xa8\x9a\x89\x01\xc6\x06\xd5\x90\x1a\xb1\xe4L\x11QM\xe2\x83\x8a\x03T|\xf4\x99\x8a\x13\xe7\x80\x84\x8f.\x04K\x86E\t\xde\x92G\xc1\x101\x04\x0336\t\xd7\x18\x08\x1bDA\x106\x18&\x08\x1b\x0c\xc1\x14\xa9\xf1\x01\xf5\x161\x13\xcc\x9b\x22.s\xe6\xe3\xb0\x11o\xbeT\x033s\x01\'\x86\xab\xd59\xd3\xa0\xb5\x88\x80\x18&l0\xcc\xd4\x92Y\x82$\x98YB\x1b@LMk\x8bU\xb2(8\xf1-\xe7\x0b\xf1\xa5\x84\xd2\xc4c&\x98\xa4\xba\xaeWksE\xd6\xf2VYZO\xfd\x0b\xe2\x936\xeb6X\x16\'\x8d\xcfI\r!\xf7cs!\x1fu\x12b\xb5ZU\xc3$>\x97F,\x81\xa5f\xa8\xda8\x9af\xf5\xd2\xf0\xd2j\xaa\x93d\x0c\x1a>\xf7jx\xed\x827555\xc3T\r1\xb3\xa4\xa9\xfe\xb1\x9f\xe7\x86\xeb\xd97\xef\xb6\x94S#\xbcMx\x0c\xb6@\x17^\x84\x8f\xd5\x9c\x1aR\x9d\x1f\xa6W\xce?1\xf3\xb3\xad\xd1\xce\xf2\xc9\xe5\xe9\x87\xdf\x9b\xf2\t?2[0\xe9q5\xeb\xa7\xbe\xfe\xd5\xb3\xaf\xae\xac\xfewM\x1f)\x91\xfb\xd8r\xea#\x87\xf5\x8d\xd3\xf2\xe9_\xf9\xa7\xd2*\x9bT\xd7\x95\xb9N\xbbU\x8e\x98\xear?D\xf1\x1d_\x8b)\xb9OfO?\x7f\xe1\x8b\xaf\xafaa\xd4un-\xf6l\xcb\xe7o\x98\xd8\x7f\xfd\x8f\xde\x1b\x0f\xf7W\x0f\x9d<\xbd~\xf4\xa9\xee\x8b\xafUg/5\xeb\xd7M\xf9\x9f\xff\xc5\xdbWN\\,\x17\x16\xb2\xc9\xeb\xd0\xb6\xb1\xee|B\xd3\xd2\xa9#\xa7\x9f\xbc\xd8\x9a\x93\xd5\xb7\xaf\xbc6\xd6\x137\x18]Y\xbc\xf1\x97\xec\x86wL\xcf\xdf\xf1 \xe2\x1ak%?+~\xb5\xdf[Y;r\xaa5\x9a}\xfd\xb3\x97\xe7_\xfc\xf1\xb1-\x07\xda\x13\xd3\x9d=\xe3\xcd\x1d\xb9LP\x82\\F?\x17\xd3\x0b\xfd\xa6we\xa0o\x0c8\xb3\xcc\xd9\x7f\x93\xbd\xd2\xf3*\x88`8\'\xe0\xc4\x19\xccM\xd8\xf7\xdd\xd3vU\xed\xaa>Bg\xfb\xb4\x94\xd3\xe0Bp\x99\x88\xa5\x86\xa6.<\xc1\xb74\r\x9cU\x16\xfbfJh\xb9<4\xaeLVF\x0b!\xe4\xbe3c\xc5\x84\xcf&\xc9\'\xa2\xefH\xec\xfa\xd4\x17\x9f;\xdf\x11\xf1\x86b\x86E\xd3h\xa9\xb14\xd0T\x89\xf7\xcee\x22\x19\x88\x89\xc39\x11\x01\x11\xbcwY-\xb99\x97\x9b\nf\xe0E\xd1$jj&\x9a$5!\x0e\x83F1\x15LI&N}n\xde\xab\xcf\xd4\x17\xb8\xcc\x9c\x8f&\xd1\\\xed\xb2\xcc\x22X#\xc1[\x0c(`\x80\x81!\xfc%\x01\xe3*\x03\xc5\xc0\xd8 \x02\xc65\x22`\xe2k\xb2`MF\xf4b\x1e\xb0\x14\x9c8\x1a\x1d\x0e$\xcb$\xb4\xa2\xa2\x88!\xc9\\\x12\xd7\x90\x89iA23@06\x19fj\t\x0c\x12\x18\xa6\xd0\x08\xa0\x86\xd6\xa61\xa9\x22\xe2C\x07\x9f;\x97#\x81\xabL\xa3\xa5\x81\t\xb5ke\x1a\xbd\xf6\xa4Y\xd2\xe1%\x17\xb0\xe12\xae.;\xa3\xbel#\x19\xa1\x85\xf3\xd1\xb5p\x85\x0f\xe50\xa5\xba\xea\xbb\xe1\x92\xb3\x88w\x96\x1a\xd5\xe4h\x9a\xd5\xc5\xc1\x95u\x8b*\x9e~\xc3\x93o\xf8\xe3W\x9c}\x0bfj\x18\x16\xf1\xe9\xdd\xff\x84\x1d\x0b\xeci\xc9\x04\x13p\x0f<\x08\x930\x84\xc3\xf0\x198\x03\xc3u\xaa\xc3\xd5\'G~\xce\xaazxee\xf4\xde\xefK\xc5\xdc\x96\xfc\x9eIf\x81!z\xa2z~\xed\x8d\xf5\xa5\x03M\x22\x8c\xa0w\xd39\xfc\xca\x0b\'\xbe\xfa\xb4\xfc\xea\xcf\xfdt]NV8\x13I\xa3\xb3\xad\xf6\xa8\x08\x83\xc6\x12\x92yC\xadQ\xab\x93={f=*\xb9\xe4m)\xaf\xcb\xe7\xf7\xb5\xf7N\x8fl\xd9\xfa\xf0\xf6t\x92\xee\xf1\xf3\x97zg^\x1e\x1e;^]ZN\xfdmc\xee\xa7\xff\xf1\xbe\xb5\xd3K\x9d];\\9\x8d\x95H%\x12\xcd\xac{\xf1\xf8\xe2\xe3\x87\xc6\xb6s\xf9\x81\xe6\xe8\xc8`\xd4W\xa3\xdd\xc5m\xffv\xfd\xba\x07;\xdb\xef\xbc]\xdch\x83O~\xbe\x0e\x95\x0e\x97\xbbG\xdfp\xed\xb1\x17\x9f\xab\xc7\x8f\xfd\xe0DgGkb:\xdb\xd1\x92\x1b\x91qDa\x05}\x06=\x9c\x9aj\xa5\xd6s\rWz\\\xb9\xc2\xda\xc7\xb2K}I\x22 \x22\x88\x08\x82\xcdt\xf4\xc0<\xa3\xb9\xa2Hp\xd9h\xdb\xb7\xc7\xc5g\x81X\xe8\xa0\xe5M\xeau\xab\xfbb\xc9)N\xb0\xd8\xd7j\xa5\xc9F\x8b\x91i\xf1y\xf4\xed\x8a\xc2R\x8a\xd9\xa8v\xe6}9&Y[\xa4\x003\xadAE\x02\x92!\xe2p`\x98b\xc90\xd3\x1ap8\\\x00A\x04D\xc4\x818\xf1(\xe6\x9c\x88\x13\xc4b\xa3\x1a\x05sqP\xc6\x81b.U\xa1\xe9\x85\xa6\x1fR\xe5\x04A\x93\x88\x89OE+fm\xcd[\xde\xfb\xe4\x8b\xe4\xca>e\x92\x80 \x18\x88\x81\x80\x08\x98\x1a\x98\xaa\x19\x86\x01\xc6\xa6d\xc65\x16c\x14\'\xde{\xe7\x1d\x92\xc0\xcc\xd8`\xe2E<&\x98\t\x94\x0c=*4\xc1U)UK\x83\xc6y\xc9]\xd5\xb8\xb6\x12L\x1c\xb4\x87\x1aR\x93R\xd3\x8cd\x9d \x12B\xe6\xc4\x8b\x13\xc30\x14u\x08(f\xa0\x900\x84\x84%M\xc9\x14\xbcw.\x88\xe4\x88\x07\x0c\x13\xf1hm\x96\xc4\x07\x91\xa0\x9a|\xea\x16\xcd\x954\xb8diP\xa6\x95fl\xcb\xe8\xe4\xecDn\xb5J\xd4\xe0B\xabi\xcf%?jZ\xa96f`\xa4\xa6\xb2\xa6\xaf\xa91\x8dhL\xfd\xf5\xa6;\xd0F\xf1\\\xe9\xca\x89%\xb9\xb4\xeel\x03\x98\x19`j\x8a\xc4\x85=\xec\xbd\x89\xf91\xb6 \x81\x118\x08\xb7\xc0\x18xx\x03\xbe\x0c\xe7\xa1\x82\xfa$\xbf\xed\xfe\xf5`y\xd1\xa7\xaa8\xf0\xddU93\xc9\xdb\xb6\x11\x80\x01\\\xc1\x866X\x91\x98\xc8;\xd8\xf5\x94\x87.~\xee\xf5\xcf}R\x1e\xfeo\xfe\xc9\x03\x8f\xbds\xd7\xc2<\x10\xf2\xc29\xcf_a\xa61F\xc0\x89\x03<\xbe\xe5\xb2\t\xd7\x99-g\xcb\xac\xd3\xd9\xe5t\x85z9u\xeb\xd5Kqq)v\x07\xda\x8c\x95r\xc7\xbd\xd3\xf5\xea0\x9f\x1c7\xdf\x11\xf3&QD\xb1T\xad/\xad\x9f\xed\xe5cY\x7f^\x17\xb3x&\x1e\xeb\xae\x7ff\xc7\x7f\xb8\xb2\xf7\x9el\xeb\xedw;7V\xf9\x22\x85\xf9\xe8S\x15}\xf7\xe2Z\xd1\x9aY\xbfRl\x1d\xdc\xdc\xaa[\xa1]\xca\x0c2\x0b%\x9b\xfa\xd8\x19\xec\n\x1a1\xeb\'\xd6\x13u\xa4V\xa2\xb0\xc1\xc0\x0c\x04\x03\xc3\xd4\xd8\xa0|\x93w\x84\x00V\xf5\x87\xbd\x8b\x8b\xf5\x89\xd3\x9c>\xe5\xce\x9f\xf6\xcbW\xdc\xca2\xbd57\xec{\x8d\xcd\xfc\x82LM\xc7\xb2\x9dn\xbdc\xebco73B\xb0,36\x19\x1bL\xcc\xd0\x88\x19\xdf\x22 \xbc\xc90\x10\xc3\x00AD\x10@@\x040a\x83\t`\x02\xce\xbb\xc1\xeaj\xff\xf2\xa5x\xf1\x9c\xbbp\xd2\x9d9\x1a\xd6/f\x83\xa5\x9cA\xf0U\xeaHlw\x86\x93\xd3\xcd\xe4\x96j|V\'\xe6\xdd\x96]\xd9\x96]\xa3\x933\x85\x17I]\xc9K\t\x01A\x04\x04\x04\x10\x04\x04\x13\x10\xcc\t\x82\x89\x99\x80\x80`\x02\x82\x08\x08 \x22|\x93I.\x22\xe8\xbaJ\xdb$\xb34H\xa6\xbdj\xf5\xfc\xe2g\xea\xfa\xe8\xda\x00s\x92\x15\xe5\xc8\xd8#\xdb\'nS3\'\x05\x12\x9a\xd85\x82w\x9d\x18\xab\xa81!\x08\xd1$A\x82\xc6$\x81\x22\x06I\x8d\r\x06\xc67\x99\x02\xb2\x01\xc3\x0cL\x001\xc9|\xa1\xd8\xa0\xea\x17Y+\x84\xacj\x86)\xa6\x8b\xfd\xb5\xcf\xbf\xf1\xe4\xc0\xbbN92Z\xb4\x1e\xdc{\xdf\xbe\xd9\x05T\x83\xcf\x9d\x0blr \x18\x06IA\x04q  \x88\xf07\x08\x7f\x8d\x80\x87\x8co\x12(`\x0b\xccA\t\tV\xe0,\xf4\xe0%\xf8\xcaY~U\xff\xcf\xe1\xf2\x15\xd7\xf2\xe5\xde\x87\xfan\xf7\x04\xb7\xef\xe6;:\xc4\xf27\xfe\xe4?\xc9\x0f\xfd\xb3_\xfc\x99\x7f\xf6S\x0f\xdf\xb4+%\x8b1&\xddd\x18Wi\xd2\xa4I\x10\x8f\x80\x00A\\\xe9\x8a\xe0<\x1eJ0\x18\x822\xd4j\xa8ucZx\x19\x1bmA\x03%8\xde\xa4\xd0\x87\x12\x02W\xfd\x19/>s\xe5?_\xff[\x17v\x1e\xd4mw\xdcg\xf9T\xe3;\x8d\xdfR\xfb\x22\xd9h\xd1\xb8\xe9\xd1\x99Q-\xa7d\x9c>d0\xc6_\x13a\x08\n\xc6_a`\xbc\xc90\xe3\x1a\x03cCp\xe4\x013\xed7\x97.\xf7V\x8e_\\?|*\xbd\xf4Zq\xeaDq\xf1\x82_[\r\xeb\xebE]U\xf3[mzzmrF\x7f\xe0\xd1\xbb\xff\xe9\xf7\xb2\xa1Q\x9a\x88&68\x87\x80\x191b\xc6_c\xfc]x\xe1\x9b\x04\xccpB\x96\r\xbb\x83\x8b\x97\x97V\xcf\x9e\xee\x9ex\xad9\xf6\\\xb6t\xbc\xe8\x9d\xcb\xe3Z\xf0\xfd\xa6\x9d\xeb\xe4D\x9a\x9e\xae\xb6\xec\xa8f\xf7\x96\xdbn\x1f\x9b\xd935\xbd\xb0\xb55*$X\x83\x16d\xfcM\xc2\xff\x17-PX\x85\x11\xe8pU\xc5\xfa3\xab\xbf\xe6\xd33\xbdae>#LN\x8f\xbc\xef\xae\xe2a6%h`\x15\x02\x8cA\r\x91o1P$A\x82\x04\x8a(\x9b\x8ck\x8c7\x19\x9b\x8ckZ\xb4#i\x95\xf5QF\xda\x14@\x80\x97\x19\xfcO\x87>vIz\x9d\x91\xd1\x99\xf6\xf4\xbf\x9c~\xecm|{\x06\x89\xbfI\xf8\xdb\x19\x9b\x04\x04\x1c\xdf\xde\xe7\xe1\xff:\xc7\xbfM\xbf\xac\xeb\x97\xa5S\x86\x9d\x0f\xf6d\xfb\x14\x07\xf7\xf1\x1d\x1d\xa2\xf7\xdc\x7f\xfd-\xf9\xd9\xff\xf9w\x7f\xe2}\xdfw\xeb\xee\xa9\xdeZWl\x93&5\x8ckDU\xb9\xca\x19\x82+}\x1e\xf0\xc1\x85 \x05\xb3\x10a\rM\xd5j\xea\xf6\xb4j4\x95\x19\xf3\xdbF\xa1\x82\x11(\xc1\xc0\xd8\xa4\xb0\x16{\x1a:%\xe4\xd0\xfa\x14/>s\xe9\x03\x0b\xbfsq\xe7\x81f\xd7\xedw\xf5\xc2\x96Ptj7W\xb9\xe9\xb9\x91\xad{\xfc\xa8\x10S\xdd\xf7+c\x88\'\x876d\xbci\x00]H``\\el26\x19\x9b\x94k\x0c\x94o\x12\x1069!s8\xbfxf\xe5\xf8g^\xca\x9fyy\xec\xc8\x89XW\xf9\xeaz\xbb\xbbV\xcdN\xa5\x99\xa9+\x0b\xdb\xec\xfb\xee\xbf\xeb}\xf7\xd1\x8f\xf4\x064\x91\x181%\xf38\x01c\x93\xf1\x97\xcc0\xe3/\t\x9b\xcc\xb8\xc6\xd8 \x80!\xe0@\xc0\x813\x9cP\x14\x88`\x10\xb2s\'\x8e]:\xf4\x85\xb0\xf2\x8dV}$ou\xf3\xd6\xea`d\xc2\xc6\xc6B\x91-\xb7oKc\x0f\\7\xff\xd08\xe3\x90\xa0\x86\x08\n\x8e7\t\xdf\x91\xf1\xb7\xcb\xc1A\x02\x0f\x9eM\xedH\xff\xc5\xe67\x0by\xbe[\xa9\xf7\x017\xd6\xca\x7f\xf2&\xde\x0e=\xe8C\x05\xca&\x07\x0e\x1c\x9b\x84k\x0cP\x88H\x83T8\x05\x03\x03\x01c\x93\x82\x81a\xca&\x03\x83\x8c\\\x90\x84y\x82\xc7\x83\xb4(\x8eQ\xfd\xfc\xe2\xe7.\x84\xfeHgb.l\xf9\x17\xdc\xfa\x10t\xd9d\x90x\x93\x81\xf2&\x03\xe1M\xc65\x8e\xbfI\xc0\xc0\xb3)@\x87o\xe3\xf3\xf0\xcbg\xf9\xdf\xd2\xaf\xe8`%\x1f\xc9d\xfe\x81\xae\x9b\x9f\xe5\x86\x1b\xf9\x8e^a\xf8\xcc_\xfc\xae\xfc\xe7\xff\xfd\xe9G\xff\xfe]\xbb\xb7\xfb\xc1\xf2 \xc6\xa8IM\xd50\x03\x8f\xcb\\\xf0\x12\x92\xa5\xa81Y\x14C \x93PJ\xe1\xc8\xd8\xedY\x83\x15m\xb4\xbf\x9c\xd6\xd6\xd3\xb0\xb2\xd8\xce\xdc\xee\xeb\xc6!\xc2\x08\xe4lR6)t\xab\xb5:\xeb\x94\xf8Rh}\x96W\x9f8\xff\xe1m\xbf{f\xfb\xfef\xc7\x1dw\xd5\xc5\x16\x9f\x8f\xf7\xddBQ.\xecj\x8f\xcf\xe0\xa0\x17\xbb\xfd\xb04\x89oQ\xc2\x08\x14\xbc)B\x0f*P0\xfeN\x8cM\nQ\xc1pJ\xeeh\xf9f]_?\xb4\xdc\xfb\xd2\xebc\xcf\x9f\x91n\xafX^\x1d]\xeb\xd5\xd3Sif\xf4\xf2\xc2\x14\xef\xd8{\xebO\xece`T5\xc3\x9a\xa6\xc6\x12\x85G@ x\x84\xbf\xc6\x0c\xe3*C\x00\xc1\x94\rb\xa0`\x88\x82\xe2\xc0\x83\x13<H\x82H\xf0d\x01_B\xbb\xa2{v\xf5\xf4\xfa\xf2\xf3y\xef\xebcv\xca\xb3>\xf4c\xae]H\xd9^\xc9\xeek\x8f=\xb07\xec\x04\x07\x15T` \x10\xc0\xb3\xc9\x81\x80p\x8dq\x8d\x81\x81\xb1\xc9x\x93\xb0I@\xb8\xc6\xc0A\x0e\xc2&\x07\xadD\xf5\xb2\xfda[^\xea\xd5jR\xd6L\x8d\x86\xef=\xc0-\x22\xab0\x80\x1a\x94k\n\xf0l\x12\xded \xe0 \x18!\xe1#N\xb1\x86\x940E\x15S6\x19oRLp9\x85!\xe0\x05\xdf\x86\xa3\xf0\xaf\xea\x97\xaf\x04k[gV&~\xc6M\xddk\xd6\x87$\xa2\x90\xd8d \xfcM\x06\n\xc65\x89M\x02\x8eM\xc2&\x01\xc75\x1e<\x14P\xf2m|\x1e\xfe\xe3I~\xce~\x93\xc1b>5\x1efo\xe9\xb9\xeds\\\xb7\xdfLD\xf8v^\xa3\xff\xd5O\xbe_>\xf3k\x8b\xb7\xfe\xf0\xd4\x96\xad\xd0\x83\n\x1aP0\x0c\xdb \x1b\xbc\x98aj\xd8&\xa7X2\x17<\xb3B\t\'\x18\xd4\xab\xc9\xd2JZ\xebjU\x11\xdb\x85\xdf\xb7o\xc24\x8a\x1b\x83\x0c\x04\x0c\x0c4\xe9z\xd3MY\xa7\xc4\xe7B\xfb\xb3z\xf8\x0b\xc7?:\xffGol\xdb]-\xdcq\x8f\xeb\xccJk\xae\xcf\x8e\x1dS;\xb7\xfb\xbc\xa0\x07\xbd\xe1r\xbf\xbc2C6B\xcb1\x06-\xfe\x9a\x06\x1aP0\xfev\x06\xca\xa6\x085\x9b\x04<\xe4 \\\x1ep\xfc\x89\xb5\xe2\xc5\xc5li\xbd\xbd6\x98\xe8\xa7\xc1T\'N\x8c\xacL\xb6\xdd-\x137=\xd2bC\x03C%EL\t\x82\x13\x042\x8f\x03\x11\x106\x88a\x06\x02\x86\x00\n\x0e1H\x90 \xe1\x22\xa28\xc3\x83\x136\x19$\xa8\xc1 \x876\x94 ]\xe2+\xcb/\xda\xda\xf33\xe9x\xe6\xd6\xfbn\xb45RT\xd9X\x95\xdd\xbd\xbd\xbcm\n\x075\xd4\xa0\xe0\xc0A\x01\x01\x04\x1c\x08\x08\xd7\x08\x08\xd7(\x18\x18\x18o\x126\t\x9b\x04\x04\x22\x9bJp\xe0\xb9\xaa\xc2\xbe1|\xbc\xedNv+S\x8an\x1a\x9f\x0e7\xdc\x92o\xcf\xf3\x08\x15\xd4\xa0``P\x80\x07\x01\x07\x02\xc25\x02\x0e<d |\x07\x06\x06\x06\x86E\x92!\x1e/ `\xe0\xe0u\xf8O\x83\xd5%\x17\x8a&\x9f\x92\xf4\xdf\x8a\xeeS-\xf3<\xe4y\x03\xca5\xc6&a\x93\x81\x81\x81\x82\x82\x81\x82r\x8d\x03\x01\x01\x01\x0f\x0e\x1c\x088\x08\xe0\xf8\xf6\xbe\x08\xff\xf1u\xfeq\xf3\x9b\xae{\xc1MMt\xb6\xdd5(\xf6\xcc\xa5\xb9\x03\x22\xde{\xbe\x9dCT\xcf}\xf1\x03\xf2\xf5\x17l\xf7ML\x04\xbe=c\x93\x82\xb1\xc9@A!\x87\x1a=o\xc3\xe1Z\x226Vw\xad\xdf\xa3\x89\x9eV\xee\xf7\xee\x1d\xd7\x94\xbc\x1f\x81\x9cM\x06\x02)iWk\te\xa1xG\xfbS\xf1\xf0g_\xff\xe3m\x1fyc\xeb\xb6\xee\x8e\xdboct\xben\xed\x8ea\xf7\xdb\xa6wt\xb2\x0c\x96\xb1~\x7f\xb1W^\x9eu\xc5(\xad@\x07mA\x86\xb0I\xc0@\x8cM\xc6\xdf\xca\x0cK\x88 \x06\xca5\x02\x01<\x1b\x9e9Ju\xa6;\xde\xef\x8f\xd5\xd5\x84\xf3\xeb\xad\xd6\xb0\xd5\xea\x85\x90\xcd\xfa\x9b\xae\x17\xbe\xa9\x01\x83d\xe6\xc4\x040@\x9c\xe0\x11@\xd8d\\#l0\xe3*C\x14T00H\x8a9<\x9b\x12($0\xf0\x90C\x06\x1exb\xf1p\\\x7fuL\xcf\x8f\x855\xcb\nk\x8f\xaf\xfb-Eq\xe0`\xd8#RC\x05\r\x18\x9b\x1cx\xc3\x19\x08\x198A@\x0c\x84\r\xc65\x06\x06\x06\xc65\x02\x02\xc2&\xe1*C\xc1@\x84\x1c\x84\xab\x16\xa9\x9f9\xf3tG.\x0c\xacc~DC\xb9\xad\x98\xbf\xdeMf\x99\xe4\xb9\x88D\xae2Lq \x86:\x82\xc3s\x95\xf1M\x02\x0e\xbc!\xfc\x15\xc6\xb7a``\x18\x88\x03a\x93\xc1\x89\xc4\'.\\\xa9%\x1f\x95\xd4\xf6\xfaP\xe6f\xbd\xcf\xbc\xcf\xae\x12\x11\xfe\x0e\x0c\x94k\x1c\x08\xff\xaf=\x0e\xff\xf7\xeb\xfc\x83\xde\xaf\xd9\xe5\xe3an\xaa\\x`\xd0\xban\x9b\xcd\xde\xe2}\x9e\xe7|;\x87\x88\xcf>\xf1!\xf9\xfc\x7fX\xde\xffC\x13\xdbv\xb1\xa9\x81\x1a\x12f\xa8al\x12a\x83%4\x19jNM\x1b\xd5\x98bl4\xc6\xa8u\xb2\x94\\3\x94\xa6\xf2$G\xab\xcc\x16\x16F\xcc\xf0\xbe\r\x1e\x04L\xd8\xa0Q+\x8b\xf8<3\x82#\xfb\xf8\xea\xf3\x9f>\xfc\xfe\xed\x7fraf\xdbp\xe1\xa6\x9b\xfc\xf8\x02\x13\xd7\xd3\xda\xfb\xd8\xcc\x8e<\xcfE\x96\xb0AoqP.\xce\xb8|d=\xca\x92\xea\xe9\xa6\xe9\x9a\x9aG\xccPe\x93\xf1-\xc6[\x19\x08N\x10\'\x22!\xc8\xc2X6\xd3\t\x93\x85\xf7\xc2\x06\x11D\xc0\xb1\xe1\xf1S\xb1{\xa9;\xad\xc3q\x8bS\xb9\x1fd\xad:o]B:\x93\xe1\xb6Y\xc7_QGU\xb3#\x8b\xc33+\x95\xa9\x8a*\x96\x10\xc7\x06S\xded\xfc%QPP\x9c\x8a\x17\x1351\xcb\xcaNY\xec\x1c\xc9fZ\xd9\x98\x0f\x86\x81\t\x08\x19\x04\xe0S\xe7\xcfV\xab\'\'X\x9ajU\xa1\xd5\xa2\x18]uS\xa3\xed\xed\x07\xdc\x98\x88@\x03\x06\xcaU\x8bV]\xe8VgW\xabd\x86\x19\xa6\xa8\xb2A\xf8\x163\x8cM\xc65\xc2&a\x93p\x8d\x08\x86\x08\x08\x1b\xccL\r4\xc6j\xb0v\xb6t\xc3\xc6\xb7p\xb9\x16\x13{\xa6w\\\xef\\\x08\xa1\xddn{\xef!)6 \xbd\xb4\xdc[\xea7\x98\x9aF,\tb\xa6`l\x12\x103\x0f\x0e\x04\xc4\xd8 `|\x8b\x81\xb1\xc90\xc0\x04\x10\x10\xdb\xa0\xa6\xc80\xa5\xa5\xf5e\x95\x90g\xc1I\xba\xad\x9d\xdf01\xe1E\xb2,k\xb7\xdb\xce9\xfe\x7f\xf1q\xf8\xa5\'/\xff\xa3\xf5_\xb3\xcbgG\xf6,\x14\xf3\xb7\xeb\xe4\x8d\x0ba\xe1\xd6\x10\x8a\xa2\x10\x11\xde\xe2%\xf4\xa9\'\xffX~\xffg\xbf\xb8\xf7\x07n\xd8q\xfd\x14B\x8a1j\xaabe|\x8b\x99l\x08\xde\x10SCu$\xf3\xe3\x9e\xd4\xc4\x94R\x13\x1bMII\x92\xbb\xdaktn\xb1N\t\x9d\x9d.\xc49\x17\n\x11\xcf5\x06\x16\xab*U\x8d\xcb\x83\xb5}\x94\xf8\xf9\x93\xcf}\xf5k\xbf7\xf1\xa9\xee\xe4\x964\xb5k\xc7\x96\xbd\xfb\xa6\xae\xbb=\x1b\xdf\xf7\xe8\xcc\xee\x10B\x96u-\xf6\x86\xdd\xbaX\x9aj|~\xe2\xca\xf0\xf0\x85\xeekWz\xddfX\x14\x885\xa6\x11L\xf8\x163\xc0@\xf8&3\x10\xccDp^]H!\xb8\xb2\xbcnK\xe7\x86\xad#7\xce\x8d\xe4\xce\x99\x19W\x85,d\x99\xfb\xe4\xa1\xe5\x0b\'\xcfO\xc7\xe1\xacg\xae\xcc\xad=Fg\xe4\\\x18\xe9L\x85\xbb\xe6\x1c\xdf\x12\x9b\xd8\xadj\xb0?;t\xe5\x953\x8b\x12\xa3o\x06Y3\x10A\x10\x13\xc4\x14C\x040\xc3\x84\rf\x92D\x0cI&f!\xa8\xc3\xc4\x9a\xf6d\xab3\xb6\x7fv\xf4\xe0\x96\x89\xeb\xa7F\xcc4\xa5\xa4\xa9\xf1Y\x91\x87\xd2\x13\xfe\xf0\xf0\x1b+\x97\x8f\xcd\x87\x95\x99\x11+FG\xb2v\xa7\x1b\xa6&Fv\x1e\xf0c\xce\t4` \xa0C\xd2K\x97\x97_=\xb7\xf8\xfa\xa5\x15oUF$V\xa6\x8a\x99\xa0\x80\t\x98\x81\x99\x98\xb0\xc1\x0c\x13\x1c\x88!\x82\x031\x10\x04\x1c\x88\xe1\x00g\x8d\xd3:E\xcdDK\x17m\xd8\x15KC\xcb\x9b\xa8at\xf6\xee\xdb\xef\xbdyd\xa4U\x96\xedv\xdb{j\xaa*i\xaf\xd1?>t\xf6\xec\xf2j&\xea\x87\xeb\xc4\xbew\x8e\rf\x80\xb1\xc1\x1b\x1e\x9c!\xe0\x8cM\xc6\x0630\xcc\x0c\x03\x110\x141\x11\x03\xd1h)\xc6\x94\x10\xf1A\xe2\xb0[\'\x89\x92Uu\xb5ov\xf2\xb1\x9boj\xb5\xdbE\xab\x95\xb5\xdb\x8ds\xc6&\x03c\x93g\x93\x81\x83i\x98bS\x04\x05cS\x06\x8eM\x97a\t\x1c\x18\x18\x08\x08\x9b\x0c\x0c\x124\xd0\xb0\xc9\xe0\x93\xc7V\x7f\xef\x13\x9f\xff\x91\xc1\x7f\xc9c?\xdf\xba0w\xfd-\xd3\x07\xef\xdf\xd9\xde}k\x96\x85\x10\xb2,\xe3-^D\xbf\xf2\xa5\x8f\xc8?\x7f\xe4g\xc2\xf6\xed\xf9\xe4\xa4\xe6Y*\xb2\xda3\x8c\x95\n \xce0\x8dfd\x9d\x11i\xb7\xa5\xdd\x0e\x22\x07\x17\xa6\xdf{\xe7\xb6z0l\xaa:5\x8dZ\x92\xe0CY&\xcfJ\xd5|\xf8\x1bgL\xd7:\xe50\x949\xae\x14\x0b&\x98\xd5A$i3\xb8t\xa1YZ\xf3\xa3\xb2\xbau\xd0w\xbdjp\xa1^>6\xf6\xb1^g,N\xde\xb8o\xdb\xfe\xdb\xb6\xec\xbf-\x1f\xdb\xfd\xd8\xdc\xbe<\xcf\xb3\xac\xd2f\xb9\xa9\xd5.\xb5bh}\xf5\xe8\xea\x93/]\xd6\x95\xae\xd4\xbd\x82(.\x19*\x9a\x04\x03\x0c\x84M\x86\x01\x82\x19\x9b\x045\xe7\xd49\x9c\xa4<\x8b\x9d\x8e\x9f\x18\xdf\xb7g\xf6\x07n\x9d\xcbDLUSr\xce\xf9<\x14\xed\xe2\xe3O\x9f>y\xe8x\xb9ry:\xd6;\xda\xadb|6\x1b\x9f\\,[\xad\x85\xe9;\x0f\x8e\nBL\xa48\xac\x86\xbd\xa6\xc9\xcb\xf2O\x9e?\xf5\xea\xb13Y\x7f9\xeb\xaf\x85\xe1\x9a\xa4$N,\xe4b\x91\x14\x85M\xe6\x10K\x98\xe2\xb1\xe0!\x893\xf3\xc1\xbc\x93\xccWc\xf3qd6\x1f\x19\xbb\xef\xba\xf9w\xec\x99UM)EK\xc9gy\xc8\nO\xf1\x81o\xbct\xe9\xdckc\xf5\x85\xf9\xb1865\xde\x9a\x1c\x1f\x14sSc{\x0e\xe6[\x9dkD\x22\x18\xa0X/\xe9G_=\xf9\xc6\xc5\xf3\xb6v1\xc4\xf5\x9cZSBUH\x02\x86 \x08\x06\xc9H`\x90\x10\xc5\x1c\xe2\xc0\x81\x13\x9c\xe1\xc0\tb\x188@,a\xb56\x16$e.\xa5\xa6\x22\xc5A*\x87)\x0fc[\x1f\xbc\xe7\x91[\xc6\xa7\xda\x9b\n\xef\xb5\xa2i\x12\xbdh\x1fz\xf1\xf5KW.\x14i\xd5\r\x97%\xf6\x05\x11\x0c\x10\x13\xc3\x9b\x04,\x18^\xc5\x9b\xf9\x04\t\x0c3P3\xc5\x0c\x0c\x8cM\tT\xa41\x1a\x8d5Z7\xb1\x87$/U5\xa8cR#6:2\xbdu\xfe\x8e\xbb\x8b\xbc\xed|N\xa2Z\\\xd1\xa48\xe7D\xc0p.\xcbZ\xa1h\xf9\xa2\xed\xb3\xfc\x8e\xb9\xe2\'J\x0cj\x88` \xd0\x82\x00\x15|\xb0\xc7\xcb\x17\x06\xa9\xae\x9af\x98b\xe3\x92\xa9)f\x18*T\xc80\xd6\xfd\x94R\xa3M\xdd\xac]^\xbe\xf4\xc2\x8b?\x9d\x7f\xd29dz\xd7\xfc\xfe\x9bfo}dWg\xe1\xf6\xb2\xcc\xf3<\xcb2\xde\xe29\xd2\x97?\xf3a\xb9\xfb\xfa\xfb\xdc\xd8\x84/\xdb\x84\x9cv\'\x05\x1fUM\x106\x08\x82\x88\x0be\xdb\x8d\x8e\x87\x89\xe9lt\xe2\xee\xfd\xbb\xff\xc7\xc7\x0e\xf4\xba\xddj0l\xea\xda\x0c\x1f|\xd1.\x8b\xb2\\\xae\xf5\xdf\x7f\xeay\xe9\x9d\x9b\xdb\x12\x8b\x911i\x06>\x05M\r\x0c\xc5\x87T\xaf-\x1d:.\xc3\xa1\xdf\x1eNn_R\xed\x05[w\xf5\xda\xc8\xd7b\xf6z\xdaz\xd7\x9e\x99[\x1f\x9b\xbb\xfe`9\xb6\xe3\x9d\xf3\xfb[\xad2\x04\x85\x95zXw/J\x08\x9d\xcf\xbep\xf9\xc9\'\xcf\xb4W\x87\xf9j\xb7X\xebJj\\\x8c\xa8\n\x9bL\x14L0\x03!!\n\x98\x98\xd0\x98\x13\x0b\x98\'u\xf2z\xb4S\xcd\xce\xee\xb9\xe5\xba\x1f\x7f\xe4\x06\x9f\xd4b\xd4\x98\x9c\xf7\xbe\xc8\xb2N\xeb\x13_z\xed\xe8\xd3/\xa7K\xa7FW\x16w6\x83\xe9\x99\x9d\xad\xe9\xe9\xe5\xd1\x91\xf6\xcd\xfb\xee~\xf4fW\r\xa8+R3H\xdaW\ry\xfe\xb1\xc7_>\xfa\xe2\xa1r\xe9\\\xd6[r\x83U?\xe8\x81\xa9\xf7\x10\xa5V\x1c\x88\xe1T\x0c#\xe1\x94\xa0\xe6\x15\x17\xc9\xc42\'\xe3e=\xb5\xa3\x99\xdcQ\x8f/\xdcw\xcb]\xef\xbey\x7f]\xad\xa7\xd4\x98\xa9\xcf\xf2\x10Z\xce\xb5?\xf0\xfc\x97O\xbd\xf1dX9\xb6\xb5}elf||\xeb,#;g\xa6\x1f8\xd8\xbe\xd9\xfb\xbes\x11\x22\x84\x1a\xdf\xab\xfc\x07_x\xee\xfc\xe5\xa3\xad\xeaBV\x9d\x0b\xcd\xa2O\xb58\xc3LD\r56\x98XD\x14\xf1\x06b\xd1\xc4#\x01\x14D\x10C\x00\x01\x03\x0c\x01LAM\x05K\xa6\xd1\xa3\xcei/\x8du\xeb\xf10\xb6\xfb\x81\xfb\xde}\xeb\xf8\xc1V+\xb5\xdb!\x04\xa9\xc4\xc5\x14z\xd1>\xf4\xf5g\xaf\\9\\V\xe7\xb2\xb8,M\x97T\xa9)\xb6A\x0cQ\xc9#y\x22O\x84h\xa1&(\x9b\x8co\x12\x03E@\x15\x12$\xa36k\xd4\x224\xcai\xf5\x03\xe8i\xaa\x924x\x1d\xa601U\xdczS\x08Ef\xb9\x1b\x9a\\\\1\x13\x82\xc79\xf1\x01\xf1Yh\x85\xa2\x93\x8fL\x95\xed\xc9\x87\xb6o\xfd\x85q\x12\xf4\xa0\x06\x03\x07#\xd0\x82\x1a~\xf1\n\xcf\x9e:_W\xbd\xaa\xea\xc7z\xa0u\x1dcc\x9a\x04\'.SC5jJ:\xd4\xd8\x1f\xd4\x97\x96\xcbO\xfd\xfe\xfb\xee\xeee\xdb\xb67\xd9\xec\xcc\xde\xeb\xb7\xdc\xf2\xd8\xce\xce\xb6\xdb\xdb\xedV\xab\x15B\xe0-\x9e\xc0>\xfd\xb1\x0f\xca\xfc\x96\x9d\xaeh\xe1\x1c\xe2\\\xd9&\x045\xe3[$\xcf}^\x8as\xd9\xc8D>\xbb\xb5\xbdm\xcfCw\xde\xf2\x0b\xdf\x7f\xe7\xfa\xeaj\xaf\xdb\xab\xab\xca\xcc\xbc\xf7e\xbb=6>\xb6\xae\xfe\xdf}\xe4\xb3sc\x17\x8av\'h\xed\x9a~\xa8k\x89\x03H\xe6\xa4w\xf1\x8d\x95c\x17S\x1d\xdd\x9d\xee\xf4\xf4\xaai\x1f\x1b\x90\xea\xb1s\xae\xf5\x15?\xb9w|\xe6m?6\xbf\xef\xe6\x91\xa9]\x8f\xce\xed\xcb\xf3\xbc\xd5\xf2PU\xd5j=$\r\xdb\x9f{\xea\xcc3\x9f9T\xacT\xc5r\xb7\xb8x\xc9u\xbb4\x15N@\xc1\xc0@\xc1@!\x89\x98\xa1\xa0H\x8d%<\xa9U\xc6\x91\x22NMT\xf3\xdbw\xdd{\xebO\xbe\xe7\x1eW\xd7iXk\x8a.d\xa1\xc8\xf2\x91\x91\x8f\x7f\xf6\x85cO|\xbdY<\x1f\xce\x9d\x9cZ:\xb7uj~tn[oz\xcb\xd8\xbd\xb7\xdd\xff\xee\xfb]\xbfG5DS\x9d\x17\xfd\x94R\x8c\x1f\xfb\xd4\xd3\'\x9fy\xb6X<\xe3\xd7\x97\\\x7f=tWi\x86\x8a\x99\xc3\x14\xe7\xc4\x04\xf1`\x22$$\xe1\xd5\xbc\xe1\x05\x87\x15\x81\xd1\x918>\x1d\xa7\xb6W\xdbn\xbc\xfb\xe1w\xbd\xe7\x81;\xab\xf5\xc5X\x0f\x0c\xf5Y\x1e\x8a\xb6/\xda\x1fx\xfc3\xa7\x8e<\xee\x17\x8ft\xec\xbc\x1f\x99\x98\xda\xb1\xb5\xbde\xe7\xf6\xdd\x8f\x1d\x18\xbf%\x84~\x9e\x1b\xc4Dh\xb4\xacS\xf8\xfd\xa7?\x7f\xe1\xc27\xca\xb8X\x0cN\xe4\xc3\xb3\xaeY6\x03\x116\x08`\x06\x98Bc>G\x9cXe\x04sAH\x80\x13\x8cM\x86\x08\x86\x19\x08\x06\x82sh\xb2\x18MDq\xf4t\xa6\x17\xa7\xc2\xc4\xde\x07\x1e\xf8\xe1;\'nm\xb5b\xbb\x1d\x08D\xf2dy\xbf\xb1\x0f<\xf7\xb9\xa5\x0b_+\xfa\xaf\xfb\xb4j\xf5\x9a\r\xd7\x1aU\x90\xa4\x16U\x1a\xf1\x03B\x8d\xab\t5\xbe&$\x04P6\xa9\x91@\xcd\x80d\x96PM\xd6Wj\xa3AjdE\x8b&\x92\x10\x08J\xa0A&\xc7\xc3\xc1\xed\x92$\xb8\x96\x1f\n\xe7\xd6\xcd\x84,\xe0\x9d\xf3E\x08-\x17\x8aP\x8e\x96\xa3s#\x13\xbb\x1f\xb9\xfe\xc0\xbf\x9e)#ta\x08\t\x1c\x8c\xc08(\xfc\xcb3\xfd\xa7\x8e\x1e\xab\x87\xeb\xc3\xe1\xfa\xb0\xb7\x9a\xeaAS\x0f5E\xe7r\x91\xdc\x101\xa7\xb5\xea\xc0\xd2\xda\xba\x7f\xf2\xf1\x9f\x9a~q\xeb\xad;ll\xcf@\xb3\xe9\xeb\xae\x9f\xbc\xf1\xde=\xe3\xd7\xdd\xden\xe7y\xdej\xb5x\x8b\xcf\x12\xff\xe2\x8f\xfe@n\xdb1\x8f\xf7\xc9$\x99\x99\xf3\xd1\xa8\x92\x19\x08\x08H\xf0.\xcb}k4\x8cL\x84\xa9\xd9\x91\x1d\xfb\xee\xbf\xf7\xde\x7f\xf5\xde\xb7\xf7{\xdd~\xafWUCM\t\x91\xb2\xd5\x9e\x9c\x1c_O\xee\x13\xcf}ae\xd0\r\xa6a\xb8\xe8\xab\xaek\x06\x96\xa2\x13\x9f\x9a\xd5\xf3/\xbcX\xf5Sk*.\x1e\\^)\x9aD\x8a\xa6.i\xab\xb6-\x9f\xea\x98\xa5\xbd\xef\xf9\x81mw\xbe\xab5y\xddc[o\xc8\xf3\xbc,\xc5\x88\x8d\x0ec\x1d\x13\xd9\x9f?\xf1\xea\x93\x7f\xf6D\xfb\xd4\xd1\xfc\xca\x19\xbf\xbeL\xbfku\x8d\x98\x81b\xbc\xc90\x03\x03E\x0c3P\xbc\xd3\xb2\x93&g\x9b\xd9\xedi~\xcf\xde{\xee\xfb\x07?\xf4\xb0TU\xaa\x86\xa9\xae}\x96\x85\xbc\xc8GF?\xfa\xd9g\x8e|\xf5\x89t\xeeX\xbax2[\xbb\xbcmzz|\xdb\xce\xe1\xc2\r\xb3w\xde\xf7\xe8c\xf7\xb9AO\x87\x03\xc5bV\x0eqx\xff\x91?\xfb\xd2\xf1g\x1e\xcf/\x1e\xf3k\x97|oY\x86]\x8b\xb5\t&\x98\x13\x1c\x08\x9b\x1c\x88\x82\x99\x83\x80x\x91\xcc[\xd9\xa63\x9eF\xa7\xe3\xc4\xf6f\xee\xc0=w?\xf4\x83w\xdf\x9c\x9a&\xc6\xda4\x86,\xcf\x8a\x96/:\xbf\xf7\x85O\x1f\x7f\xe5q\xb9\xf2Z\xe8\x9d\x97\x22l\xdd1W\xcc\xdf\xb8\xe7\xc6w\xde\xbd\xeb\xce\x10\x06\x99\xa4\x98Rc\xb9J\x91\xc8\x7f\xfb\xcb\x9f\xbat\xfc\x89\xce\xfa\xab\xbe\x7f\xd1U\xcbV\xf7\xcc\x14\x11\xc5L@\xb8\xca6 \x9e\r\xa6j\x82\x88\x81\x19\xdfd\xbc\xc9\xc0Lp\x22bII\x8a\xe2\xd4\x18\xfa\xd9\xdaM\xe5S\xd7}\xd7;~\xf8\xed;\xef\xc8ZMV\x063\xa00)\xfa\x91\xdf\xfa\xf2g\xce\x9d\xf8\xaa_zE\x87+:X\xab\xfb\xddh\x16\xd5%#\x99$\x9c\nfl0\xa8\x9c3P0$! \xc6&g\xa8a\xa0P\xa9$\\4\xd4\xb9a\xf2IQ\x13\xc19s\x98\x0bc\xad\xce\xaeIK\x0e2\xad\xac\xbe\xd47\x15\x9c\x88w\xe2C\x96\xe7\xa1\x1c\xf7\xad\x89l|ndn\xff\x83\xb7\xdc\xfd\xbf\xee\xdf%\xd0\x87!D\x14\xe8\xe0F!\xc1\xcf?\x7f\xf4\xf9C\xdf\x88\xdd\xd5j\xf5r\xbdv%\x0e\x87VW\xa6\x8ax\\\x8e\x04M\xc9\xaa\xb8\xb5Y\xbc\xce_\xde\xaa\xe7G\x16\xb6d\xd3\xfbk\t\xfd\x18\xa6\xf7\xec\x99<x\xd7\xde\x89\x1bno\xb7\x8b\xa2(\xcb\x92\xb7\xf8$\xd5\x17?\xf6\xc7r\xe2\xa5\xbfo\x828\xba\xbd\xb4\xbaV\x9f\xbf0<\xf2\xfaj\x13\xd5y)2\xef\xb3 >h\xd6I\xd9X,\'\xea\x91];v\xdf\xf9\x8f\xeez\xb0\xa6\xdf\xd4\xfd\x14+3\x15\xc4\xbb<\x0b\xed\xd5$\x1f~\xee\xa9\xa5\xcb\x17C\xb5\xe2\x07\xcb\xaeZu\xcdP\xcc\x9bU\xcb\xaf\xbf\xb0zr9&\xf2\xe9t\xe2\xce\xc5\x9eS\x84\xc6(\x12\n\xfb>;\xdet\xd3\xae\x87n\xdf\xfb\xee\x9fjM\xdf\xf8\xcem\xfb[\xad\x96\xf7$\x06\xd1RJ\xa6j\x7f\xfe\x8dW\x9f~\xe9\xabc\xbd\xd7\xdb\xf1\xa2O=\xab\xbb\xa2\xd1\x10SK\t\xc40\xc5\x143\x8co\x11UC\xcd\x10\r\xed\xd8\x9a\xaa[[\xe3\xe8\xce\xdd\xd7\xdf\xfd\xd3o\x7f\xd0i\x9d\x9a*5\xb5\xf3!\x84,/G?\xfa\xdc\xd7\x8e\xbc\xfc\xd5t\xe9\xb5\xe6\xca\xe9\xb8~e~ajjng3\xb5\x7fn\xf7\xbd\xdf}\xe7}Rw\x9bj\x98T#\xa1J.\xe4\xad\x0f}\xf6\xcbG^\xf8\x8a\xbbt\xcc\xad^\x90\xde\x92V}\x8djj)\xa9)\x1b\xd40e\x83\x999\x0c\xc3\x143I\xe64\x94\xb4\xc6lt\x92\xe9\xed\xb6p\xe0\xe1\xb7?\xfcco\xbb\xbd\xea\xf7\x9aj\xa81\x86\xa2(Z\x9d\xa23\xf2+\xff\xe5/\x0e=\xfd\xe5t\xf6\xb0.\x9dw\xce\xcdm\x99k\xef:x\xcbC\x8f\xfe\xc0\xdb\xee\xd1z\x18c\x13c\x22\xe4\xe4\x85\xf9\xfcW\xff\xf4\xd3\xa7\x9e\xfdR~\xf2\xeb\xb6~\x85\xc1:u_\xcc\xc4a\x90\x00A\xb8\xcapA\x9c\xe0\x1c\xe2\x9c9o\x88x\xc1\x89\x93M\x08\x0es\xc1;\xc4\x04\x13sb\x22\x88`\xe2M\xe8\x8flM\xad\xc9\xf6\xdc\xce\x87\x1e\xfb{\x0f\xdct\xde>~/\x00\x00 \x00IDATk)1\xcf=\x02\xbeP\x9f\xf7\x22\xef\xff\xdc\xe7\x97O~\xad\xb5r\xd4U+R\xafI\xdd\x131\x84\x84\x98\t\x22\x888\x87 \xc8\x06\x87`b\x22\x82\x08\x88\x19`\x80\xb0\xc10\xcc\x07\x11\x01\x9c3\xe7\xcc\x8c\xa6Q\xd3d\x88\x89\x9b\x99*\x0e\xee\x9f0\xb4iXYO\xaf\x9f\xecW\xb59\'E\x99{\xef\\\x9ei>\xa6\xad\x89\xd4\x99nfn\xb8\xfe\xc0\xdb~b\xc7A\xae\x89\xa0\xe0\xc1s\xd5\x87N}\xfd\xd4\xb1gC\xf7\x82\xeb\x9e\xf3\xbd\xcb\x0c\xd75F5\x110\xc4\x0c\x89\xfd\xd8\xeb\x0f\x16{Z[1;\x15\xc6v%\xd7\x8e\x84\x81\xe5S\xd7\xed\x9d\xb9\xe5\xbe=\xe3\xd7\xdf92\xd2j\xb5B\x08\xbc\xc5\x87\xe9\x7f\xfdS\x7f*\xaf\xfd\xf9\xfd86\xf4\x86\xacv\x9b\xf3\x97\x9b\xa3\xa7\x86Q\x11\xf0^\x9c\x03\x9f\x91uR1f\xed\xa9z|\xcf\xce}\x0f\xfe\x0fo\xff\xdeZ\xfb\xd5p-V}3H.k\xb5Z#\x13k\x9a\xfd\xe1SO-_8\x9e\xf7.\xbb\xe1e\x1b\xac\xfa8\x14c\xfd\xfc\xcb\x17_8mj\xe2$\xee\xe5\xc4\r\xab\x11\xab!S\x11\xa8L\xae?2\x96\x9f\x0c3\xbb\xf7\xed\x7f\xcf\xf7N\xde\xf8\xdd\x8fn\xdd\xdfj\xb5\xbc\xd7Dl,\xaa\xa2\xe2?\xf3\xda\xb1\xc7\x9f{<\x9c\x7f\xad\xac\xcfg\xd6\xcb\xe9\x96\xde\xf22w\x1e\x01\xa7\xc6\x06SL\x05\x13\xc3\x1c\x08\xa6\x88Y\x8c.f\xa31\x1fo\xf2\x89~g\xe7\xf6]\xb7\xfd\xc4\x03\x0f8kRS\xc5\xa6\x16\x17\xbc\xcf\xf3\xd6\xe8\x9f>\xfb\xb5\xa3\xaf<\xa1W\x8eT\x8b\xa7\xe3\xfa\xd2\xdc\xd6\xc9\x99\xf9\xednz\xf7\xf4\xee\xfb\xdeu\xfb\xc3.u\x9bz\x18\x93\xd5\xd1\x86*e9\xf6\xa1\xc7\x9f|\xfd\xd5\xa7\xdd\xea)\xd7\xbb\xec{W:R\x07\x12\xe0\x9dK\xbd\xa8\x8d\x91\xccL\xd9`\x86\x22&8L\r\xc3$KYK;\x93\xf5\xd8\xb6j\xf6\x86\xfb\x1e~\xd7\x8f>tGw\xf1R=\x1c\xa8\xaa\xcf\x8b\xa2=\x92wF\x7f\xfdO?y\xeck_\xb2\xb3\x87\xd3\xf2\x05\x15\x99\x9b\xdb\xd2\xdeq\xf0\xc0\xfd\x8f=r\xf7\x1d>\xd5X\x8a)\xa9\xcb\xc9K\xf3\xd9o~\xfc3\xa7\xbf\xfexq\xfa\xeb\xd2]\xf4\xc3\xd5B\xea2\xb3\xcc#N\x9c \xe0\x04a\x83!^\xbcH\xe6\t\x99\xfa`\xc1\x8bw\xe2\xbd\x08f\x82\x13\xe7\xc49\x87\xf7Br\xce\xf0N\xd4\xc4Y\xc8\xf0b\xcb\xed\xdd:\xba5\x1b\xdfz\xe0\xe6Gn\xdd\xbe/\x0bU\x99\x05\x83D\xa6.\xeb\'\xfe\xe0\x89\xaf,\x9f}\xa5X?%\xc3\x95\x90zc\xa1\xe7E\xc4I\x08\xce\x89\xe1\xc4;\x87\x08\xe2L\xc4\xc0R\xd2\xa4Z7\xdaD1K\xa6$\x0c06\x98\x89\xf9\x80%\xcc\x9cC\x88B\xf2V;K\xa21x\xd7.\xdd\xe4T^G\x1bV\xb6\xba\xde\x1c?3\xac\x1a\x13\x11\xe7%\x04\x8f\xf7V\x8eZ{\xd2\xc6\xb6\xc4\xb9\x1b\xf7\xdd\xfe\xae\xf7\x1d|\x80k\xba\xe0 @\xceU\xef?\xfc\xd4\xa9C_\xf2\xcb\xc7\xfd\xday[\xbf,UW\x9b\xc6|f\xaa\x16\x9bX\xd7\xc3\xd5Z\xd5\xb5f\xa6\xf2\xf1m\x92\x8d\x99\x89J\x19\xc5\xd5.\x9f;\xb0o\xee\xb6\x87\xb6\xb5\xaf\xbb\xa3\xb5)\x84\xc0[\xbc\x9f\x95\xd7>\xf3_\xe5\x95/\xff\x88 \x02\xfd\x9a\xe5\xf3\x97\xd6\x9b\xfc\xe4\xeb\x97\xd4\x9cbb\xc2\x06\xe7\xd5\xb7Sk2\xb6\xa7\x9b\x89\xebv^\x7f\xdf?|\xf0\x91:u\xabA\xb7\xa9\xfa\x122\tE\x08EQ\x8c\xac\xaa\xfb\xc3\xa7\x9eY9\x7f$\xac\x9f\xf7\xd5e\x1f\xfb-\xaa\xba{\xf1\xdc\x93/\xd9\xa0\t\x85\xef\xa7x\xf9\xdd\xfd\xd5<6\xd8\xd0,\x13\xbc\x93\\\xfc|\xbf\x98\xf9\xfcD\xb1\xed\x86\xe9\x03w.<\xfc\xae\xef\xd9s\x7f\x9eg!\xa4\x846\xa6)b\xde\x7f\xe1\xc4\xe5\xaf\x1c}#\xef\x9e\x91\xe5#,\xbe\xd1\n\xda\xca\xb5\xcc\x08A\xf2\xcc\xfb\xbc\x14q\x22\x02\x02\x08\x98\x80\x18\x18\x22\x14\x9d\x98\x8dUV\xd4\xaeS\x15[\xe6\xe7\xf6\xbe\xf7\xc0\x01O\x8c\xa9\x8eM4\x13\xe7\xb32\x1f\xfd\xe8+/\x1f{\xe3\x05]<\xdc\xac\x9cc\xb0\xb6uazf\xfb.\xc6wLn\xb9\xf9\xed\xdbn\xf7~hTuLU\x93\xaa$Y\xd6\xfa\xe8KG\x0e\x9f<\xe2\xfb\x97d\xed\x8c\xeb^\x98\xe8\xb8V\xee$5\xc1\x9a\xa0&H\xaa\x1a\x8d\x1a\xbbu\x1a\xa6\xd4 *\x96,6\xaa\x8a\x89\xb3\xach\xc6\xb66c[\xab\xf6\x96{\xef\xb9\xff=\xf7\xdc\xd4]^\x8cu\xa5Hh\xb5\x8b\xf6h\xd6\xea\xfc\xea\x87\xff\xe4\x8dg>\xafg\x0f\xc7\xf5e\xf3\xf9\x96\xb9\x99r\xe1\xc0M\x0f>\xf6\xc8=w\xb8\xa6\x124&U\x17$+-d\xbf\xf1\xa7\x9f<\xf7\x8d\'\xc2\xf9#\xd2\xbd\x1c\x06\xcbc~\xd0\xce\xad\x08\x929\t\x01\'`\x08\x0e/\x1b,\xcf\xad=n>\xc39\xbc\xa4P\xa6r\xc4\xc7\x01\xce\xa5\xd0q\x99\x0b\x12C\xec\'_\x90\xe7\xb9\xd4h\xe3b\xdd\xf6\xfd\xcc\xa5\xd5\xf6.\x1b\xdf\xde\x8c\xee\xda\xb3\xef\xc1\xdb\xb6\xef\xf7\xd2\x0bN\xd4,Z\xc0\x17}\xe5\x0f\x9e}\xf1\xf2\xf9c\xd9\xdai\xd7\xbb\x90\xa7\xb5\xf1\xb6\x15\xb9\xf8 E\xe6\x82\x17\x9c\x17\xe75\xa96\xb1\x1eV\xa9\x89\x88`F2\x97e\xbe\xdd\x92\x10D\xbco\xb7E\x9c\xba\xe0}(|\x1a6\xa6\xaa\x99\r\xb0\x144\xb6\xdd\xc0\x9b\x82y\xc83\x8a\x9caU\x0fk\xba\x03\xbbp\xb9_\xf5\x87\x1aU|\xc0y\tA\xf3\x91\xd4\x9eJ\xa3sqz\xef\x9e\x03o\xfb\xf1\xed7rM\x03\n\x029W\xfd\xde\xa1gN\xbf\xfa\x84[:\xe1\xd6\xceK\x7f1O=bc\xb1\x11\'Z\xd7R\xb6\xb3\xb1-\xa1\x9c\xc2\xb5\x90\xcc\xc09\xc1\xd1x\x97\xf2\xd1\xa9\xbd{\xdb{\x0f\x8e\x17\x0b\x0f\x15\xb3y\x9e\x87\x10x\x8b\xdf\xe1\xc2\xb3/\x7fB\x8e\x1f\xfe?\xc0\xc0\xea\xc6\xc5hU\x15\xfb\xfda\x8c\x1ac\xaa\xebF\r\x13\xaf\xbe5\x0c\xed*L\xf6[\xdbf\xb6\xee\xff\x91\xeb\x0e*\xc3F\x87)51\x9a\xaa\xf9\x90\x15Eg-\xc9\x87\x9e}a\xe5\xdc\xaba\xedt\xa8\xd6Z\xd6o\xd5\'\xae|\xed\x10\xbdft2\x1bv\x9b\x8b\xef]]\x99h\xd6\xa0g\x86\x99\xf7\xe2s:Y\xd6\xce\xdc\xc2\xe5\x91\xf1\xaf\xde+\xdb\x0eN\xdep\xd7\x8f\xbe\xfd{\xbc\xcbB\xd0D]%MIL\xfc\x17O]y\xe2\xf89WwC\xb5,\xfd\xcb\xa3-7:\x92\x95eV\x16Y\x08N\x04\x11q\xe2\xc0#\x0eDHF\xe2*5m\xc8*i\x0f\xddH-\xc5\xc2\xd8\xcc\x0f\xee\\\xf0\xa4D\x9dRL*\x22\xbe\x08#\x1f?r\xe4\xf5\xe3/\xd9\xfa\xe9\xb8zA\x06+[\x17\xe6\xa6\xb7\xed\xd4\xd1\xf9\xf1\xa9\xeb\x1f\x9c\xde\x97e\t\xeaD\x1a\xd4q\xd0\xa4,+>~\xf8\xcc\xabgNK\xdd\x95\xfe\x92\x0c\xd7&\xc7\xb2\xd1N\xd6*}\xe6\x06\x19\x03G\x8cU\x95\x86\x8d\x0e+\x8d\x96\x86Q\x13i\x90\xfa\x97\xab\xc5\x0b\xd5\xc5A\x18\x86\xb6M\xce\x15\x933#\xe3\x13o\xbb\xe3\xa6\xef\xbe\xf5\xbaao\xbd\xae\x1b\x15\x97\x95-\xc9\xcb\xa1\xcb\x7f\xf9\xb7\xff\xf0\xe8\x13\x9f\xeb\x9f>\xd2_[-Z\xad\x99\x99\xd9b\xe7\xc1w<\xf6\xd8\x8f>v_\xaa\x86\x9aRL\xc9\x85\xcc\xe5\x85\xe4\xe5\xbfy\xff\xc7^\x7f\xe6+\xf1\xdc\x1b\xa1{\x81\xb5E\xeb\xf7|\x8aA$x\xc6K\xd7\x8f\xd6\xaf\xad\x93\xbbv\xe6\xc9JZ\x9d\xac=\xa6y\x8b\xa2\x0cE\x91\x8ft\xd6\xb4\xb4Aw\xa4\x15b\xd6\tA\xbcE\x1btC\xab\x15\xb2\xcc\xc7Ao\xb5/u\xbf\x18,9\xad\xd3\xf4\xce0\xb9-l\xdfw\xff;\xde\xf1\x8e\xdb\x0e4\x83^l\xaa:\x9a\x84\xe0\xf2\xbc\xaf\xf2\xbb_z\xf1\xe4\xa9SY\xbd*\xeb\x17tm\xa9#M\x11\xa43\x1a\xc6\xa6\xb2\xcex\xe6\xbcW\xc4m\xf0\xb8\x8cb\xb4\xf4eI\xd6\x91P\x22\x0e\x02\x92\x81\x03\xc5\x94\xd0\x12\'\x8eZ\rH\x995\x1e2b\x87\x9eW\xc5$Pe\xd2\xcf\xf2\xa0u?\x9a\xd4\xb5\r\x87MT\x9fR\xd6\xd4\x8d\x1a8\x9f\xb2NS\x8cW\xed\xd9\xe1\xf8\xce\xf9\xddw|O9\xc5w\xf0\xc13\xa7N\x1f{\xde]~\xdd\xad_\xce\x9a\xd5\x89\xd2\nO\x96\xf9,\xf3\xb2\xc1\x15\xe0\x00\xc3\x838!+\x82\x0b\xa6EGF\xe6l\xac8\xbf<\x98\x9e\x7f\xf0\xbbg\xf68\xe7B\x08\xbc\xc5\xefp\xe1\xe3\xaf\xbd_\x8e\x1f\xfe_\xb8\xaan,\xa9\xa4\xa4M\xd3\xa8JJ\xb1i\xc40\x13\x1f]Q\xf9\xd1\x9e\x9f\\\x0b\xb3S\xb37\xbeg\xc7~\xa8k\xfa\xb1ib\xd4\x944\x84\xbc(;\xeb\xea\xfe\xe0\xf9CK\xe7^-W\x8e\xe9\xfa\x92]y\x85\xa3GC\x93\xb2\xe0\x82\xc5\x17\xff\xfb%\x1dM\x04z\xc9L\xa5\x14\xa7\xce\x82\x93\xe0\x83d\xd2je\x0b\x17\xef\x9f~\xf5\x9e\xd6\xc2\xad\xefy\xfb#\xad\xdc;\x17\x12i\x18\xab\xa6N\xf8\xec\xf1SW\x9e>y6\xf9RSb\xb8>V\xc4\xb1\xb6\xe5y\xc8\x8bP\xe4\xc19\x9c\x08\x9b\x1coJ`f\x8a\x19X\x8f\xd1\x9e\x9b\x10\x91\xddc\xa3\xdf\xbfe\xda\x91\x121j\xa3I\xc4\xe5\x85o\x7f\xec\xc8\xe17N\x1c\xb2\xb5\x93i\xed\xbc\xf5\x17\xb7.\xccL\xcc-\xd4\xe5\xcc\xd4\xec\xc1\x87\xb7\xde\x92e\r\xd4\x91T\xd5q\xd8X\x96\xb7>q\xe4\xcc\xcb\xe7/(A\x9aJbo\xaaU\x8f\xb6\xf3V\xab\xe5}\xf0\xc1\x07\xd7\xa05:\xc0\xa2iLu\xd2\xaa\xb2X\xa5A\xd2*\xf5\xfb\xferwd\xb1j\x87\xf6\xe8]\x0b\xe3\xb7\xec^\xb8n\xdbL\x8a\xd1\xcc\x005\x96\x1aN\xac\xa7?\xfe\xc8\x87O?\xf5\x85x\xf9L\\\xbd\xa4\xbe\x98\xda\xb6ct\xf7\xcd\x0f<\xfa\xd8\x8f>z\xaf6uJ)\xc6\xc6\xf9\xcc\xe5\x85+\xca\x7f\xfb\xfe\x8f\xbd\xf6\xf4W\xd2\xb9\xd7\xeb\xe5\x0b\xac-N\xa5A\xcb\xa5 \xe2\x1dN\xf0N\xccP\xc4!6\xbeE\xda\xe3\xce\xe1\xc6\xa6mr\x0b#\x93\x84\xdc\x0b\xce\xe1\xc1\t\x88\xe0D\x04\x07\xce9\x11|\xdd-\xaa\x95r\xb8\xe4R]\x8f\xcfg\xad\x11\x99\xdbs\xeb\xfd\xefx\xe4\xb6\x1b\xe3\xb0W\r\xfa\xcd\x06\x15\xc9\xb2!\xfe\x03\x8f?\x7fvi%\xe5\xa3\xae^w\x83\xd5b\xb0<R05W\x94m\x9f\xb7\x8b\xd0\xcaC\x91\x85<sy&>\xc7\xb5M2\x93 \xcea\x8a\x19(\x18\x18\x96\x1c\xb1\xb1l@\xbb\xe5\x86\xb9\x0c\x81\xdc\xaa\x92~\x87\xaeX\x92\x18;\xd2/\xa4\xf6\x12\x9b:\x82D\x0bM4M\xaa\x9a\x9a\xbaQ\xc0\xf9\x18F\xeb|b\xd8\x9aYkm\x9f\xdbq\xc7;\xb3i\xbe\x83\x0f^\xbcx\xfc\xe8\xd7\xf2\x0b/\xe9\xca\xc5\xbc\xee\xce\x8d\xbbV\xee\x8a\x22\xcb\xb2<\xcf\xbc\x0f.xg&\xd1\xc4\xe1\x9c\x13\x17\x04\x8f\x94\x13\x8d\xd6\xe7\xdf8u\xb1\xd9\xb1\xfb\xbb~\xe8{g\xf6\xb8\xabx\x8b\xdf\xe0\xfc\'^\xf9\r9q\xf8\xe7\xd8dM2U\xd3\r\x86mr\x9aP0q\xd1\x95\x03\x99\xe8\xfb\xa9e7;>u\xe3\xf7o\xdd\x0f)\xd1o\xd2\xb0\xaecL\x9a\x85\xbc(;k\xea\xfe\xe8\x99g\x97\xcf\x1f\xf6KG\xd7\x8e<\xdf\xbc~r\xbe\x13\xb3@wep\xfe_\xac\x0fG\xe307\xe7)L\x9c\x92L\x06\xa2\xe2(|\xe6\x83\x0be\x96\xf9;\xf6,\xdf\xbd\xfd\xc2\x83\xdf\x7f\xef\xc391Y%E\xdeD\xeaF\x9d\xf7\x97\x06\xcd\xd9\xf5\x81\xcf;\xe0Rl\xf2L\xf2\\\x82w>\xb8\xab\x10\xc1\x0c\xe1/\x19\x18\x18\x18\xd7\x04\x93 \xd6\x8c\x07\xd9*9D\xa5\x89\xa61\x22\x92\x17a\xe4\xe3\x87_}\xfd\xe4\x8b\xac\x1eOk\xe7\x19\xac\xcc-L\x8f\xcf-\xf4\xf3\x85-[\x0e|\xd7\xfc\xcdYVC\x15-U\xb56\xc9B\xc8\xce\r\xd2\xe5~D\x9c\xaa\xa2\xb1\xc8-\x0f\x84\xe0\xc4y\xe7\x82\xf39.\x03\x8f)$,bI,\x99\xa9\xa5\xa81i4\xcc\xe7\xc2\x14u;\xcbs\x17R\x13\xcde.+\xa2q\xf4\xfc\xd2\xb3G\xde8\xf6\xb5/\x0e\x8f\xbfLwQ\xbbW\xd4\x17\xb3\xdb\xb6\x17;o:p\xdf\xa3\xef\xbc\xef\x1e\x17\x87X\x8a)\xa9d\x16rB\xf1\xeb\x7f\xf2\x89s/~5\xbf\xf4\x86\xae_\xcc\x06K\xf3E=^X\x99I;\x972\x972\x17\x035C\\\xd5\x9a\xad[S\xb13\x9e\xed\x7ft\xd7-\x0fK^\x82\x08F\xecc\xe2\x8a6\xcecIb\x0f_\x10J\xbc\x13K\xd5`i\xe5\xc4\x97\x8a\xfe\xc9u\nWt\xaa\xd6\xf6\x1d\xbb\xee\xbfu\xfez\xa1\xab\xf5\xb0n\x9aac\x887qo,\xafw\x93wY;\xc5\x86\xd4\xe4\xae\xc9[Y\x9eg\xae(\xbc\xcf\x9c\x03\x1c.\x07\x8f8,a\x11\x8b`l2P0\xae\x12k@\x94\x0c\xf1\x22\x085\xc6\xa0\xee\r\xd6\xbf\x91\xa7+\xaeZ.\xd3Jn\xc9\xa1\xa9I`1YR\xc5\x0c!\xaa\x19\xe0|\xcc\xc6\x87\xd9\xf4\xa0\x9c]\xcb\xb7\xcf,\xdc\xf90\xd3|\x07\x1f<s\xec\xe4\x91\xa7\xfd\xe2q]\xb9\x947\xeb;f\xc3H\xab\xc8\xf3\xa2,\xf3<\xf7YN\xf0b\x86\xaa\t\xe2\x9c\xf3\xce\'\xb4\xbfx\xf2\xec\xd7O5\x9d\x85\xfe\xcc\r\x93\x0f\xfe\xf0\xa3s\x07\xc6\x9c\xe3\xdb\xf9\xf7\\\xf8\xdc\xcb\xbf.\xa7\x8f\xfd\x02\x1b\x84\rMT5S3a\x83\x17\x91\xff\x8738\x8f\xd2\xf3<\xeb\x04\xfd\xbb\xefgy\x97o\xabU%\xa9$Y\x96-\xaf\xf2\x22\xcb[\x22[\x89w;\xb1\xe3\xb0\x9d\x19\x86\x9c$@H\x1f M\x87f\x084\x87@\x9f\x81\x9e\xeef\xe9,\x0c\x9d\x84\x00s\xba\x03\xc3N\x13\x07\'\xb1\xe38\xdew\xd9\x96\xbck)YUR\xa9T\xa5Z\xbf\xe5]\x9f\xfb\x1eU\x12\x8eC\xcf\xf4\x1f3\xd7\x05\x22!\xae)\x1aP\xbbO#\xcb\xb4\xa1\xd9>\xff\x96\xd1\x1d@\x00\xf2B\xf3\xb2\xac\xebZ\xac\xb1Q\xdc\xe8\n\xff\xc5\xd3O\xcc\x1fy&\x7f}\x7f>s\x22\x96\xee\x98\xe6\xf9 \x9b\xfb_{4\xa1\xc6ii\xd4\x83\x22B\x01\x145r\x12\xb1\xdap\x91\xb36\xb7d\xe4\xdd\x1dw\xd1y\xd55\x1fn\xdd\xe5\xb5,\xb2~U\x17\xeam\x805>f6g9\x13\x11,\x00\x0b\x87\xff?\x14(\x81\x1a\x10\xa0\x12\x84\xa0ZUJ\xe4#\xd7\xfc\xda\xa17\x8f\x1e\x7fU\xbb3am\x96\x06g6n\x19\x1f\xde\xb8%o\x9e3:r\xe1\r\xa3\x178W\x02E)uQ\xd4u\x10k\xads\x911\x9e\xc0\x04\x06``\x01\x02\x14`\x80\xb1\x8e\xf0?\xa4@\r\x04\xa0\x04z(k\t\xa1.\xea\xbc2}\xf1\xab\x83\xe2\xf5\xe3\'\x0e\xbe\xfa\xea\xda\xb1\x832wT\xfbK\xe8\xafT\xbe96yN\xba\xe5\x82\x8b\xaf\xbb\xe9\xd6\xeb\xaf\xe6*\x83J\x1dD\x8c\x83\x8d\xd4\xf8/\xfc\xdd\xd7f\x0f>e\x17\xa6d\xe5\xb4\xed/N&\xe5x\x83Z\tyK\xde\x91e\x10\xab\x02J\\$\xe3E\xba\xa1h\x8c\x8f\xed\xfd\xd0\xcd{\xdf\x8fw\xf4\xb0\xae\x89u\x01X\x01R \xc1w\x15(\x9e}\xe1\xaf\xfc\xca\xc1U\xf1\xe2\x9bU\xb2\xf1\xdc\xed\xd7^6\xb1\x03\xb2&e^Vu^\x8a(\x8c\xb5 \xe3\\d}L\xc4\xc6\x18F\x040\xc0\xf8\xffL\x81\x00(\xd69\xfc\x93\x0c\xf2\xea\xf2#Q5m\xf3\x85$\xac:-!\x02\x91\xba\x16\x11U\x15\x10\xd6\x11\x88\tl+\xd3\xce\xedh?\x1a[\x8b&\x87F\xae\xb8\x1e\x1d\xfc\x0f\xfc\xf9\xc9\xa33\x87\x9f\xe73S\xba\xb2\x10\x87\xfe\x8e\xcdQ3\xf6Q\x9c\xa6\r\x1fE\xd6\x18\x05\x14\x00AE\x04RI\x7fv\xe9\xd0\xe1\x95\xc3+\x9a\x0c\r\xda\x93\xcb\xe9\x8e\xeeGo\xba\x9dn\xbd\x1c\xff/\x16\x81/\xe1\xf8\xa3\x07\xff\x88\x8e\xbe\xf4Q\xfd\x1e\x82\xd4\xaaP5\x0c\x05\x81\x88\x19\xa4B\xb6\xa6(\xe3\xe1>\x8f-\xdbM\xcd\xceE\xb7O\\\x0e\xe4\x8aA)e\x91\xd7U-\xd6\xda8n\xacU\xf8\x93\xaf?|\xea\xf5\xe7u\xfae\xed\xce\xd3\xdaR:\xb6v\xfa\'\xfa\xb2A\xe3\x06;/\xb1%\x0f*\xa1\x19\xb4\x10\xb2\ne\xf8(*\r\x89!W\xdf\xd3v[7\x98K~&\xbd+\xcdCU\x0c\x8a</\xab\xbaR\xf5\x89\xb7ql\\\x84u\x04E#n3\x18\xc8\x01\x05\x1c\x90\x005\x90\xe1\xfb\x08H\x01\x06z\x80\x07\x0c0\x00j\x80\xb0N\x80ZQ\x07\x91 T\xd7D\xe4\xe3\xa8\xf5\xd5\xd7^;\xfc\xf6\x81\xbawZV\xa6M\x7fvbrtt\xe3\xa4\xb6\';\xa3\xbbn\xd8\xb4\xdb\xb9B\x90\x87 \x83A^U\xc19\xcf\xc6\x00D\xcc\x003\x9b\xd47\x00\x052\x80\xb1\x8e\x81\x06\xc0@\x1f\x08\x80\x05R@\x80\x020\x00\x01% @\r\x04@\x81\xa0U5\xd7\x97\xe3\xcb\xd5[\xd3s\xc7\xde>1?3\xad\xa7\x0e\xf3\xfc\x14w\x17\xc2`\xadll\x18;\xe7\xbc\xd6\xa6s.\xbdn\xdfm\xef\xba\x86\xaa\x0c\x22A!\xec\xc8E\x81\xec\x17\xfe\xeek\'\x0e<\xe5\xe6\x8f\x86\x95\xd3\xb6\xbf\xb89.\xc6\x13\x1eJ\xd92\x9c%g\xa1\x80\x12\x94\xa8h\x8cW\xe9x\xd1\xd8\xb0\xe1=\x1f\xba\xe5\x86\xbb\x81>B\t(\xa0\x00\x81\x08D\x08\x05\xb2U\xf8\x14Q\x13` \x1dh\xf9\xec3\x7fe\x97\x0e\x9e\x91N\x9e\x8es\xdc\xb9\xf0\xdc+\xae\xdc\xbc\x13a\xb5.\xb3\xb2,\xf3RD\xc0\xd6Z\xef\xd8\x18b\x16\x85\xf7Id\xda@\x05\x04@\x81\x08p@\x0f(\xb1\x8e\x00\x0f4\x80\x02\x18\x00\x04\x10@@\x03\x10 \x03\x180\x00\x01\x04X\xc0\x00\xe8\xa3:p\xf2\x1bQ~\xd4\xe4\x0bq\xb5\xe8$\'\x15HPU\x01\xab\x82\x98@\x00\x01D0\xb6\xb6\xed\xcc\x8e\x0c\x92\x8dk\xf1\xd6\xe1\r{\xde\x85\t\xbc#\x00\nX|\xd7WN\x1e{\xfb\xd03\xf6\xd4\x81\xb04\x17Uk\xe7n\xe4Vl\x1a\x8d4\x8e\xad\xf7\xc6:@\x15*a\xb0\x12\xf2b\xf5\xc8\x89\xee\x91\x15e\x22\xb2\x85ku\xfd\x86\x83\xbf\xb9#\xa2\xf7\xdeE?\xb4\x0f\x06\xff\x0f\'\x81?\xc1\x1b\x8f\xbf\xfc\xa7\xf4\xb5_\xda^\x17\x08!\x00L\xa0\x10\xd4X\x024\x08\x0c\xc3;\n\x81\xb9\xd9\x1a$#k~C>z\xde\xc8\xf6\xeb\x7f\xe2\xb6;\xeb|\xb5\x18\xf4\x8a\x22\xabj\xa9\x85\x9c\xf7I\xb3\xbdV\xd3\xff\xf9\xf5o\xce\x1f~a\xa9y0\xd7\xb9l\xe4Lc[\x8f\xda!i\xb0k\x90ZM<\x14\x08\x8a\xb3\x82P\xc2D\xa0\xd2ri\xc8\x1a\x1ba_\xc4\x17\x8e\xf3\x95?\x9d\xde\xe1P\x96\xe8iUKW\xaa\xae0\xb1u\x8e\x8dU\x85q\xce\xf9\x98 \x90@\x1a\x08\xb0\xd6X\xe7\xa1\x12\xeaB\x15\x8au\xd6\xc5\x80J])\xc8XK\xc4\xa1\xae\xa4.\x00\xa8\x84P\xd5!\x04U\x15b!\xc76N\xdb\x9d\xbf~\xfc\x85\xd7^z\xb2:s\xac:3\x1dVN\r\x0fwF6l\xe6\xf1\xed[/\xdb{\xcf\r\xfb\xb4\x18Ty&\xd0^oP\x05\x8d\xa2\xd8E\x1e \x22\x18\x1b\x19\xe7\r\x84\x99\x98YCI$D\xc4\xc6\x01$u\xa9*D\xc46\x226\x00UE\x06\r\xaa\x81IE\xa4\xcc\x85\x99|B\x1c\xf1|Ao\xafTo\xbc}r\xe6\xe4\xcc\xea\x89\xa9\xf2\xf8\xd1\xde\xb1\xb7\x8b\xf9S\x92\xf5\xa41\xbca\xeb\xb9\xcds.\xb8r\xef\xcd\xef\xbf\xe1\xeaP\x0c\x00\tA\xd4zv\x1e6\xfa\xec_\xde7\xbd\xffI:=\x15\x96Nsoq\x9c\x06#^;\x9ebO\x96\xd9\x10\xd6\x19b\xcbUk\xbch\x8c\x87\xce\x86\xad\xb7\x7f\xf8\xfd\xb7\x7f\x00\xa1\x8b\xaa\x84\x02P\x10\x00\x95PK]\x862c\xeb\xad\xf3d\x1d\xe2V\xb7\x08\x0f=\xf65\x99}1\x17H:\xa2\xcd\xb1K/\xba\xf6\xaa\xf3vj\xb1V\x96Y]W\x95@\x89\xe9,0\xc0\xa1\x14\x1b%\xd6{&\x81\x04\xd2\x9a\t\xc6zcL\xa8K\x95\x80uDl\x8c\xf5RU\x12*|\x1f\xb1\xf3\xa4\x1a\xea\x8a\xacc\xe3\xa0\n\x05y\x0br@rf\xb0\xfa\xe0?\xfe\x970\xf3\x12\xaf\xcc\xb8\xee\x9c\xad\xfbT\xd7Y\xaf\x8a#\x161\xb5@E\xc9\x90\xb1\x0c\x22\xb2V\xd2N\xdd\x1e\xab\x86\xb7\x94\x9b.\xdcr\xc5\xcdw]p5\xd6\x05 \x13\x84J(\xe2\x18\xf0\x00\xbe\xf0\xc4\xe3G_x\x98N\xbe^,\x9c\xe4\xee\xfc&7\xf0\x12\x9a\xa9sL\xb1gc\x08\n\x10\xa0\x12\x82\x10\xab%\x9e\xbdm\xb0\xb4)\x9c\xde\xec\xd66\x8c\x86\xf8\xe2!\xbc\xf7j\xdcs7\xb6O\xe0\x9f\xc9\x81G\x81\xfb\x07\xdfz\xe5\xc5\xaf\xd0\xe7?9\x82\xb3\x04 @\xb1\x8e\xe1bCP\xc3lJ\xad\xd5p\xd2*\xe3\x91\xa2\xb1\xb1\x18:\x7fr\xe7\r\x1f\xda\xfb\xbe:\xac\xe5\x83n\x91eU\x90:\x90s>\x1ak\xad\x80>\xbb\xff\xabSsO\x97\xbd\xc3\xe5\xdai\xe7\x96\x87\xb6\x0e\xa2!\xf1\r\n\x89\x92\x871P\x85e\xb2 @\x05p\xa0\xca\x98\xca\x90\xb3\xce\xea\x1ekvM\xd0\xd5\x1fk\xdciP\xe4\xe8\xd5(\xeb:\xd4\x85\x1ac\xa2`mpV\x9c\xf7\x89\x8f\xd2\xb2\xe8K\xa8\x9c\xb5\xcc \x02\x14P\x80p\x96*\xbe\x87\x08\xaa\x08!\x00\x88\x92\x96\x84\xaa\xcc\x07*u\xa8\xd7I]\x8b\x02\xc4`k\\\xd2h\x0f}\xe5\xd1\xe7^z\xfe\xd1|\xf6\x8d\xe2\xcc\xb4\x0c\x96\x87F\xdb#\x9b\xb7\x9a\xd1s\xb6\x9e\xbf\xf7C\xb7\xbcW\xea<\xcf\x06U-UY\xd6\n\xe7\xbd1\xdey\x0f\xc0\xba\xc8\xfb\xb8\xcc\xfb\xd6\xd98I\xcb<\x93P\x11@D\x00D\xbf\x8f\x99]\x14\xf9(\xcd\x07\xdd\x22\xcf\x99%\x04\r\xa2\x040K]\x06\xd5\xb0&\xbc,<\xd7\xed\x1d\x9f\x9fY8\xf1Zqz*?yluj\xa6\xb7\xb8\x16Lk|\xcb\xf6\xd6\x8e]{\xf6\xde\xf2\xfe\xeb\xae\xaa\x8b\x8cH\xeb\x10`\x1c[O.\xfe\xcc\xdf\xdcw\xec\xa5\'1?U\xaf\x9c\xb6\x83\xc5Q\xd7k{I\x1c5\x1c{\xcb\xceA\x02\x88\xc9E\xa6j\x8c\xe7\xf1x\xd9\x18\xdfq\xd3G~\xf8\xa6{\x81\x1c\xa8\xa1\xaa\xa1\x16\xa9\x89\xa8*\x07uU\x00\x81@\xc4\xc6\xd8\xc8\'\xed\x95J\xff\xe1\x99o\x95\xf3/\xa3\xea\x22J\xb5\xb9\xf9\x8aK\xf6^\xb7\xe5B\t+A\xaa\xb3\x06\xfd\xaa\xc8E\xd4\x10[6\x96\x8dM\xd2&\x13\x85j\xa0\nk\x98\t @\x01\xc5?!|\x8f\xaa\x02 @q\x16\x11\x8c\xa1 Jl\x88\x98\t\xc6Z\x851L\xc6$\xd3++\x7f\xf6\xd7_\x96\xf9\x03\xd1\xcaqZ;Ee\x9f\xa5\x8e+\xa4\xb5\xa9\x83\n\x93I\x8cOl\x08\x00\x93\xb2E\xa3#\xcd\xd1\xba\xb3\xa5\x18\xbe`\xeb\x9e[?x\xc5uXW\x07\xf4+\x95\xa0\x14sl\x10\x03\xf8\xcc\x13\x0f\x1d\xde\xff\x90\x9cz\xb3^>I\x83\xf9M\xc9\xa0\xe9\xb5\x91\x18k\xc9Z\xb2\x86\xceR\xd53\x97\x16\x8b\xdb\xeb\xf9\xf1z9\t\xa2\x08D`o\xe36\xc7\x17&n\xef\x0e\xdc\xf9Cx\xd7\x95\xf8g\x96\x80\xbf\x85>9\xff\xe7\xd3\xaf\xfc\x05\xfd\xc8\\\x0c\xa0R\xcd\xa0\x00D\x11\xa0\xa3\xcc\t\xb8R\xce\x82\xb1d\x15-\xc2\x08h\xf3\x90\xd9\xb1+\xdd\xf7/\x9bw\xf7\xb0\x94\xa1[\xa2\xa8*\x95\x8a\x9c\xf5\r\xdf\xe9\xc1|n\xff\xb7\x8f\x9f~\xaaZ;\x90\xaf\x9crv\xa5\xbd\xa9\xefF\xc4\xb44\xc4\xea<\xb5\x22c\x14\xaaP\xa0&-\xa11\xd8YW\x10\xacu\x8e\xf68\xde5\xceW\xffdz\x87E\x99cP\x22\xaf\xea\xba\xca\xc5:o\xd9\x18c\x9b\xdcq\x88\x00\x06\x84@\x06\x06\x00\xe1\xfb\x04P\x10\xbeK\xf1=*\x10\x810X\xa1\x01u\x0fk%\x8a\x1a! \x00\n\x85\t.\xe6\xa4\xc5C_\x9ez\xf6\xd1\x99G\xd7\xba\xaf\xe7\xab3\xa1XlL\xb6[\x93[\xa3\xe6\xf6+\x9b{\x7f\xae}s\xe9\xf3\x0cY@\xd0Z\xb5&2\xec\x8c\x8b9I\x90\x1aX\x80\x00\x01\xc0`\x06\x03 \xacS\xacS(\xa0\n\x10\x88\xc1\x02\x11\x84\x1a\x15\x83\x18\xeb2\x0c\x06\xe8+\x82G\xe4\x90\x12\x92\xfd\x0b\xd3\x0f\x1f}b\xf6\xccK\x83\xee!\x19\xccVu\x8fl{\xdb\xc5\xe7\xb6F/\xbbb\xfc\xa6{\xd2=\xc1\xe5b\xea\n\xb5\xc2\xb1z\xd2\xf8?=\xf7\xb5\xb7O>%\xf9\x91\xb27\xc7\xb24\xb2-KFC\xa3\xc3\x14\xa3\x8c\x94,\x1aD\x00zJ\x95\x8e71\xe1u\xe2\x82\xe6G~\xbcy/\xfeI\x85^\x8d\\\xa1\x05\x065\x8a\x80J\xa1\x00Y\xc4\t:k\xa0/\xcf}\xeb\xed\xe2\xc5\xac^\x22\x8e\x1dm\xbcm|\xdf\xbd|QE]\xf1u\xc5U\x95\x8b\nY\xe7\x99Ml\x92\x04-\x02c\x9d\x12\xd8\x82\tD\x80b\x9d\xe2\xbfGX\xa7\x00a\x9d@\tT\xa3\x10\xd4\nM\xd1\x01\x1c\xbek\x06\xcb\x7f~\xf2\x8f$\x7f9\xea\xcf \xccV\xb6wE\xdc\xdc9\xb84\x1a4\xa2\xa117v)G\x9b\x94\x14P\x10\x83MI\x94\x93\xefr|&\xab[\x9d\x8b\xaf\xc2&\xac+3\xf4\xd7 \x02\x8a\x11\xb7\x90\n\xf0\x9b\x0bO\xbe0\xf5P>\xffl\xbd|\x22\x84\x05?\xb1\xd6\x1c\n\xf1\x10\xd5\xb1\x96N\xad\xa7&\x08@\xa6\n\x80\x00\x05\x08 \x22\xc3\xae\x8c[&\xbe0r{\xda\xb8\xf1#\xf8\xc0\x8d\xf8g\xe6\x80\xbf\xc0\x89\x17\xa6\xbfz\xf2\xf0?\xd2\x9d\xb3\x11\x08\x04\x18\x80\x00\x052\xd1\x16\xb3\x03W\xc2AYa\r5\x81Q\xa6M\x1d\xdaqYs\xdf/4\xef\x19`%C\xb7@VT\x22\x15;\xe3\x9bQ\xa7\xab\xf4\xd9\xfd_\x9f\x9e{&\xf4\xde\xaaVN\x11/G\x9b\xbbnH\xd2\x11\xe2\x18\xf0\xf0\x96 d\x1954\x88*\x83\x80\x86\x8d\xf8,\xeb\x14\xbb\r\xef\xda\xc4\xd7\xfcdz\x87A\x91\xa3W\xa2\xaa\xeb\xa0\x95\xb1\xd6\x1f^\xeb\x1f\\^b\x22\xa8\x8ah09\xb82\xca\x00\x02\x83\x18D\x08\xa0Z\t\xdf\xa5\x00\x14J*\xa4\x00YJ\x98\r\x00\x02mM\x1b\xd7\x0e\x8d\x04TR\xd7D\xc4jc\x8e[<\xf4GG\x9f{t\xe6\x91\x95\xee\xeb\xd9\xdat\xc8\x97\xdb[\xda\xcdM\xe7D\x8d\x0b\xf6\xb4\xdf\xfd\xb3\x9dw\x17>\xcf\x91\x05\x04\xa9\xa15)\xf3++\xfdc\xdd\x1e u\x10\xa8\x8a\xc9\x85j(\x88\x89\x0c3\xe3{\x02\xd6\x11\xa0Xg\xb0\x8ea\x9d\x89\xadR\xcb\xfa\x9d\xed\xa1Mq\xd2 Sh\x16\xc3%\xd4t\xf0S\xf9\xda\xd3\xb3\xc7\x9f\x9dzpe\xe1\x05\xc9gsZ3Qg\xdb\xf9\x93C\x9d]\x97\x8f\xbd\xf7\xde\xd65\x95\x1d\xd4TU\xa8\x15\x8e\xd5\x93\xc6\xbf\xf3\xec}\xc7f\x9f4\x83\xa9\xbc\x7f\x9a\xe4L{\xeb\xc0\x8f\x86V\xc7\x98\x18U\x04\xef\xe0\x81\x12(AV7x\x8c\x02i\xc7\\\xb7!\xbd*\x08\xd7\xe2DcS\x1b\xaa\x9d\xd5\xe4\xca\x91\xf6\xd6\xc8\x07\x14\n\x01\xc8\x22N\xd0^\x05\xfd\xe7\xa9\x07\xdf\xea\xed/\xfa\x0b\t{\xef\'n\x9e|\xcf\xdd\xee\x02\xb1\x03N\x11\x8c\xd4%H\xad\x92}~aq\xa1\xc8T5\x88\n\x82\xda\x0c\n\x03\xb6\x0c0\x02A\x01\x0b0\xa0@\r\x10@X\xa7X\xa7\x00\x03\x8au\x86\x9c\x22\x88\x0c\x08N9\xb2L!\xe4\xddl\xe1\xf8\xea\x93\xb689\x91\xf5S9s^#\xbd`\xe4j\x13\xda\x9c-\xc6\x91\xba\xceFN&\xe0\x12\xe2\x88\xd8\x81M\x01\x0cDzj\x97\xcbfg\xfc\xfa\xdd\x18\xc5\xf7\xa9b\xad\x07\x01\xa2\x04i\x00~\xfd\xd4C/\x1f{\xb8\\<P.\xcdHX0\xe3k\xe9HpC@\x04\xf2\xb0\x1e\x06\xc4@\x065\x00\xe1\x1d\xc4\xa6\x8e\x12\x13\x9dk\xe3]\t\xde\xf5S\xf8\xf8-\xf8g\x8e\x03\x9f\xe9>t\xf4\xd8\x03\x8b\'\x1e\xa7{NE\n\x94\xaa\x0cX\x22\x00\xa2p`\x03\n\xcaA\x99`\x89\x9a\x84Q\xa2M\x1d\x9cwEs\xdf\xbfl\xdd=\xc0\xea\x00\xbd\x1c\xfd\xbaR\xad\xc8\x1a\xdf\x8c:]\xa5\xcf?\xff\xd5#\xa7\x9e.\x07G\xaa\xd5\xd3\xc6-\x0fo\xed7\xdapm\xd4\xb1&\x9e2#\x16d\x08\x01 \xc0\x83\x07\x08\x91u\xcc\x86\xac\rz\x9d\xe3\x8b6\x9b\xab\x7f&\xbd\xd3\xa2\xc8\xd0+Q\x06!\xae\x9da\xff\xe0\xc9S\xf7\xbf}X\xc2\x22B\xa6!\x97\xb0\xa6\x92s\x00)UP\x22\x18\xa0T*\x84\tP@\xa1\n\x08+\x00\x22\xc7v\x88m\xc2\xe4\xd8\xb4\xaf\xda\xb8\xfd\xe3\x97\\\x1cBVKM\x04C&\xb2q\x93\x86\xbe<\xf5\xdc\xa33\x8f\xaet\xdf\xea\xad\x1e\xaf\x8b3\x8d\xc9\xce\xc8\xa6s\xd2\xc6\xa5{\xda\xd7\xfd\x8b\xceu\x85\xcfsd5Dk\xaaJ\xb5\xc6\xfd\xe5\xd1\xb7\x9f\x9d\x9f\xae\xc3JU\x0f\x102\xa9{\x08\x15\x14J8\x8b\t\xeb\x14\x02(HA\x040\xc0\x80\x02jS\xe7:\x8e\xa2\xe1x\xe8\x8a\r\xdb\xde\xbd\xe9\x9c\xcb\x86\xc6W\xab%G\x14\xdb\x06\x81z\xe0\xb9\x9c\xfe\xec\xc0_O\xcf<\\\xf5g\\\xb3\x17\xb7GF6\x8f7\xe3\x9d\xbb\x87o\xfa`\xeb\x9a\xca\xf6+*+\x08\xe0X#\xd2\xe8w\x9f\xbd\xef\xc8\xec\x93<8Z\xf4O\xab\x9cio\x1d4G\xb5\xd5a\x1f\x03\x11\x82C\x80\x96@\x00;\x8c\x00-R\xab\xdaa\x0cQAy\xd1\xcc\xaa\x91|a\xb4.F\x8c\x1f\xbd\xf7\xfc\x0b\xf6m\x1e\x05\xca \x81\x84\xac\x89\x9a\xae\xb3,\xfa\xb9\x17\x1f8\xb4\xf0\xc2`y\x8e\xf2\xe0\x9bCw\\u\xc7\x8fu\xae\xd0\xb8\xe4\x14b\x10*b\xf8B\xcd\x7f}\xe3\xcdc+\'\xb4^\xa9B&!c\xe9R-* @\t\x81@\x02\x8bw\x08\xd6\t4\x00\x0c\x18\x10\xbe\x87\x889\x15\xa6\x10\xfa\x08\xa5P\xf0L\x08\xfdP\xaf\xf1\xea\xfc\x85\x88\xc6\xed\x0e\x19,\x8f\x0e\x99Kv\x0e\xa7\xbe\x11Gi\x84.\xf5\xe6\x11Efx#\xc7\x13\xc2\xc2\xa6Q\xa9\xede\x83\xb5\xae\xacuv\x0em\xbc\xf9J\xb4\xf1\xcf\x94\x80\x02Q\x01\xfc\xfa\x89\xaf\xbfr\xf4[\xd9\xea[\xc5\xf2\t\x96\x05?\xd9\xd5Ve[\x88\x132\x9e\x82S\x05\x1cP(\x18DP%X\xac+U\xe1\\\xec\xc7\xcb\xe6\xce&\xbf\xfbc\xf8\xf4\xed0\xf8\xae\x1a\xb0\xc0a\xe0W\x8f\xfd\xe1\xd2\xb1\x07\x8a\xc5W\xe8\x8eY_)j(\x03\x11\x91b\x9d%6\n\x80U,\xc1Zj\x90\x8e\x96\xd8\x98\xd2\x05\xbb[7\xfeR\xeb\xee.\x16st\x0b\x94R\xa9Td\x8doF\x9d>\xcc\x1f\xec\x7f\xe8\xf0\xa9\'\xcb\xee\xc1ru.\x98\xa5\xd6\xa6\xfe\xf0(l\x07\xa5\x97\xc8s\x14\x11\x01\x16\xa4@\x80\xaa\x02\x84\xdaXoM`\xa6\xfa}\xa9\xdb\xb6\xc9\\\xf6S\xe9\x1d\x06e\x86~\x85*(\xa8\xb6\xce$\xdf\x9c\x99\xbd\xff\xd8\xebU1S\x163R\xcc\x92\x06\x16!Q\x9c\xa5\x80\x80@\x03P\xa6lT5@\r@*\x04\x01\x98,\xf30\xb9\x96\xf7\x1d\xb6C\xbb7_\xf2\xaf\xae\xbc:hQ\xd6\xb9h\xed\x8d\x8fl\xd2@\xfbKS\xcf=6\xf3Dop\xb2\xbfz\xb4\x1aL\'[ZC\x1b\xb6D\xf1\xb6\xab\x86o\xfc\xc4\xf0\xcd\x85\xcf3\xe4\x82@\xe2\xa5&K\xf6\xbf\xbe\xf5\xe6\x93\xa7\xde(\xaa\xd3u9[T\x8b\\\x07\xa3\n\x85\x00\xaa \x81\x10\xc0j\x84\x84H\x15$`@\r\xce\x12r@\x9c\xc6\x1b\xdb\xc9\x96\xa1\xe6\xb6;v\\t\xf7\x96\xad\xab\xd5\x12Ke\x8c\xf5\xb6\xe1\x91*\x92\xdfy\xf9\xaf\x0e\xcd<TW\x87]\xd2\x8b\x1a\x93\x8d\xd1\x898\x9d\xbcn\xe4\xbd?\xdc\xb8\xba4\xdd\x8a\xaa\x00%xR\x0f\xf5\xbf\xff\xec}\x87g\x9f,\xfbG\xca\xc1i\x95\xc5\xce\xd6A:*\xb6\x8dF\x82fD\xb9\x83\x80\x1c\x180\x15\x8c &\x1d\x81\xf8\x18.\x14&\xcf\xdbU1\xbax\xb2S\x15\xa36\x19\xff\xe0\xc5\xd7\xde\xb2mR%\x0b!@a\xd85}gE\xf0\xd9\xfd\x0f\xbe5\xff\xfc`i\x11\x83*j4\xee\xbc\xe6\xce\x1f\x1b\xbe\x02Q\xa5.\xa8#\xc3\x8e\x10\x15b\xff\xf8\xb5W\x8f.\x1eA9\x83\xf2\x94\x94\xcb\xa4\x12\x82V*\x0c\x90\xb0\x8a\x12\x11\x13\xce\x92\xa08\x8b\x89\t\x01*P\x16\x82 \x18X\x22\x86\x8a\x96\xb5\xb2\xa2i\xb4\x1b\x909\x80T$\x94\x17\xe7+CU\x03u3\xd4\xd5\xf8ht\xe5e\xedF<\x91$\xed$q\xd60\xb4\x82\x94\xb5\xac\xce\x84\xd7\xdbn\xa4\x11]S\xbam=;\xb4\xe46\xb7\x93\xcbwc\x08\xef\x08@\x05x\x80k\xe0\xd3\xa7\x9fx\xe9\xe8\xb7\xb2\xc5\x17\xca\xa5\x19\x91\x05\xb3a\xcd\x0e\xd7i\x07\x88)8%\x07K\x14T\x0b\xa8\x05\x05UG\x14\x131(S)\x99`\x12\xdf>\xaf\xe5\xf7\xfd$\xfe\xb7\xf7\xa3\x89\xef\x12\x80\x81c\xc0/\x1e\xfe\xfd3G\xff\xb1:s\x80n>\xe9=\x93\xaa\x82\xa0\x8a\n\xcaJ\x11\xb1\x01CY\x95\t6\xa2\x060\xd2\xd7\x8d1]pUk\xdf\xa7Zw\xf7\xb1<@\xb7D^W\x82\x8a\x9d\xf5M\xdf\xe9)\x7fn\xff7\x8e\xce=]\xf4\xde(WN\t/\xb57\xf6G\xc6\xd8u$$\xf0\x11\x19\x8bR\xd4\x11\x81P\x05\xf5\x86\x00\x90s\xc6\x98\x81\x82D\xeb\xd2J\x00\x00 \x00IDAT\xf5\xa6\x86\xdd\xb9\xd9\\\xf9\x93\xe9\x1d\x06e\x81~\x8dZ\x14V<qr\xdf\xb1#\xdf8\xfa\\\x95OW\xc5\x8c\x16\'\x10\nB0\x80\xa8B \x01L\\0eJ\x22\xa8J\x18\x076\x10\xa8B\x05V\xd12~4nl\x88\xcc\xf8\xee\xcdW\xfc\xeb=\xfb\x84\xca2\xe4\xb5T\x8e\xac\xb7q\x13\x9d?\x9az\xee\xb1\x99\xc7{\xfd\xa9\xc1\xea\xf1A~\xb2=\xd9i\x8fo\xf6~\xcb\xee\x91\x1b\x7fa\xf4\xb6\xc2\x1792\x81X\xc4R\xb1g\xff\xa5\xd7\x9e\x7fb\xe6\xf9\xb2\x9c\xad\xaa\xd9\xaa\x9cg\xa9\t\n \x88\x06Q\xd4`C\x15\x8b\x01[\x02)i\x05\x10\xd4\x82@\x0e\x89\xa2\x11%\x9b\x1b\x8dMQ\xb4\xf9\xae\x9d\xd7\xfd/\xe7^\xbc&\xcb\xa1.\x0c!vi\x84\xd4\xa1\xf1;\xaf\xdd\xf7\xfa\x89o\x07:b\xdcB#\x1aK\x86&\xe2\xc6\xf6kGo\xfe@\xb2\'\xe7^I\xa5B\x19\x9e\xd5\xab\xba\xdfy\xee\xeb\x87O>!\xdd\xd7\xaal^t\xa5\xb5u\xe0G\x95\xdah%hD\xc4\x0e%\x10\x81\rx\x00u\x88,\xd2Jm\x1dLUp\xc8[\x92\x0f\xafM\x0f\xf7\x8b\rQk\xcb\x07/\xbe\xf1\xb6\xed\xe7\x06\xc9k\xa9\x18\xb0\xe4b\xdbZ\n\xfa\xf9\x03\x0f\x1e]\xd8?X\x99\x0fk\xb5\x8d\x9b\xef\xbb\xfa\xce\x1f\x1d\xbe\\\xa3\x12\\\xdb\xc88\x9f\x82\xe2\xbc\xe6/\x1e|zj\xe1U\xca\xa7\xear\xae\xaeV\x10\n\x82\x1a%QU\x81\x062\x06\xc4\x10\xa0\xac\xd5\x08\x9c#\x10\x04\xaa\x00\x05\n\xb5\x96N-\x93\x03j-+\x01\x87\xa63%!C\x00DT\xeb\xcbB\xd9\xae\x9a!k\x89\xc8\xc4\xb8\xbf\xe2\xf2\xe1F\xb2)M;i\x1a[\xe7 UU\xcd\x1e\xab\xf7\x7f\xa5>9\x0f\xfa\xd9\xe6\xe5[\xe3\xbb\x07~r\xd5\x9f\xd3\xf2\xbb\xaeD\x1b\xef\x08@\rx\x80*\xe0\xd7\xe7\xbes\xe0\xd8\xb7\xf3\xc5\x97\xfb\x8b\xd3R\xcf76\xf4h\xb8\xb2C01y\x8f(\xa2Z\x91\xab\x14\xaa\nDD\x0e\x14\x13\x05E\x06\xc1:\xb6\xed\x1dqc\xdf\x87\xf1\xbf\xdf\x8ba\xfc\x80\xe3\xc0/\x1c\xfa\xdd\xe5\xc3\xdf(\xce\xbcBw\xcc\xfa\x88\xd8\x025P\xa8\x08@\x04\xab\xc6\xa8\x81\xb2*\xd8\xb0\xd3&c,\xe8F`\xe7\xee\xf6\xbe_i\xdd=\xc0j\x86n\x86~U)U\xec\x8doD\x9d\xae\xd2g\x9f\xfb\x87\xc3\xa7\x9f\xe9\xf7\x8e`\xedtd\x97\x87\xb7\x0e\xccP\xd0&L\xa2\xde\x93\xb1$\xaa\x8e\x08@\x00\x12\xe2\x81H0\xa6\xe5]0&\xc8\xee\xc4\\>\xc9\xd7\xfcTz\x07\xa3\xc80\x08\x08\x00\x1b\xf1L\xd1\xdf\x1fy\xf5\xfe#\xdf6\xc5l\x9e\x9f(\xcaYS\xf5\xbd\x9654/\xd5\n*C5\x91\x80\x15\x08\x8aZ`\x18D8+@\x03\x19p\xea\xfc\x84K6\xfbh\xf3\xd5\x9b\xaf\xfd\xd4\x9e[\x02\x97\xa5\xe4AjC\xc6\x9b\xa8\x89\xce\x97\xa6\x9e{t\xfa\x91n\xef\xb5\xc1\xda\xc9<[hLvF6o\x8d\xd3sv\xb7\xf7~b\xe8\x96\xc2\x17\x05\xf2\x1a\xc1#Ae<\xf9\xcf\x1f|\xf4\xe9\x99\xc7\xcbj\xb6(g\xb5\x98\xafB!\x12@\x90\x1a\x08B\x0e\x08\xa8D\xada6P\x01\x03\x04\x94\x80\'\x8am\xcb\x99\x11o\xc6\xbd\x1f\xb7\x8d\x8dw^t\xfb\xc7\xce\xbfz\r\xcbY\x9d346>\xa6\xd4 \xf9\xfd\xd7\xbfy\xf8\xe4\xe3A\x0eq2\xd7H\x9bis,N.\xb8z\xf4\xd6{\x92=9\xf7\n*\x05\x81\x111\x22U\xff\x1f\x9f\xfb\xe6\xdb\'\x1e\x0f\xbd\x03\x83\xfei\xd2\xb5\x91my<*i\x07\xcd\x18\x12\x91:\x94\x00\x83\x1dP\x83\x03\xd0 @l\xa1.\x1f\x00y\xc2\xd9\xc8\xdcTg\xad\x98lt\xce\xff\xd1]\xb7\xde\xb1\xe3\xfcZ\x8b\x22\x14$\xe2\xd8E\xb69_\x85/\xbe\xfa\xad\x99\xc5\x97V\x96\xe7\x8bn\xe9m\xfb\x96K\xdf\xfbCc\xbb}\x1bQ\x04\xeb\x8d11!\x1eT\xf2\x85\x97\xbe\xfd\xf6\xc2\xfe:?,\xe5r\xa8V\xa5Z#\xa8\x11\xa0\x02\x11\xc8\xe2,\x05j@\x14\x11\xd6\t\xe1{X!@\r\x80p\x96BH\xc0d\r\x84\xa0R\xabBU%\x96z\xa7\xc6#a\xbc\xccu|\xbcq\xe5\xe5c\xcdtS\x9c\xb4\xe2D\x8d\x91J\x96\x0fe\xcf\xfdi\x98}\x9b\x82\xb2\x1d\xe2\xa1\xeb\xdd9\xd76\xdfm\xe3\x9b;\xf1U\xbb1\x8awT@\rx\xc0\x14\xc0\xaf\x9d\xb8\xff\xe0\xb1\x87\xcb\xe57\x06\xcb\'\x8c,\xa4\x9b\xbb\xbeSs\x0b.&\xf6\x10\x87B\x05\x80b]D\x04@\x01\x06*\xc0\x83\x0cqho\xa7\xe4=\x1f\xc1\xbf\xfb \x86\xf1\x03\x8e\x03\x9f8\xf4\xbb\xab\xc7\x9f\xaaV\x0f\xd3M\'}D\x88\x88s\x95\x02\n\xa0I\xccbU\r\x00U0\xdb\xa66\x99GK\xdd\xa8\xd8\xb9\xaby\xe3\xaf\xb4\xef\x1e`9\xc3j\x89\xb2\xaaDJv\xd6\xb7\xa2NW\xf9\x0f\xf6?\xf4\xd6\xdcS\xd9\xda\x01\xb3v\x9a\xcdr\xbc\xb9\x97\x8e\xa8\xef \xc4j,b\xcf\x02\x10\x14J\x96\xa9V-E\x8cs\x86\x8dw\xd6\xd0\xd5\x96vm\xa0\xab?\xd6\xb8\xd3\xa2*\x91\x95(\x15l\xc4[\x8a\xff\xee\xf0+\xf7\x1f~\xd0\xe4SYv<+Nq\xdd\xe7P\x90h]\xa3\x08\x941\xd5J\x06d\xa1\nR\x80@P\x9c%\xa4\x81\x99]\xd3\xb81\x8d7\xc5\x8d\xf3\xae\xdbr\xc3\xaf]{[Me)y\x15J\xcb66q\x8a\xd6\x97\xa7\x9e{t\xfa\x91\xa5\xee\xabkk3\x9a/\xa5\x9b\xdb\xa3\x93\xdb\x92\xd6\xf6\xdd\xcd\xbd?\xdf\xb9\xb9\xf0E\x8e\xbcF\xf0H\xa82\x8e\xdc\x1f\xbc\xfc\xc8\xe3\xd3\x8fV\xe5T]\xce\x15\xe5B]\xf5\xeb\xba\x16\x85\r`\x00\x06\x1c\xa0\nf\x94@P\x10\x81\x81\xa0\x88\x18\xa9iZ\xd7&\x1a5\xd1\xe6\xa8\xbd\xf3\xdeK\xee\xfc\xd8\x85\xd7\xaea\xa9\xa8s\x11\x89\xacO9\xf1H?\xfb\xda\x83\xafL?\x96go5\x1b\xa7\xd1\x1c\x8d\x86&\xda\xcdm\xd7\x8e\xde\xf4\xc1dO\xc9\xfd\x92\xca\x1a\xc1 bD\xaa\xfe\xf7\x9e{`j\xf6\xe9\xb2\xfff\xd6\x9d&93\xbc-o\x8fj\xdaA\x12\xa3\x8e\xa8\xe7 \x80\x07\x11PC+\xa5&\x98\x01\xab$\x99fY\x9c\xf7\x9b\xf3S\x9b\x07\xc5\xf6Fg\xe7\x8f]v\xfb]\xe7\x9d\xaf\xc8\xf3:\x17Qk\xac7\xcd3U\xf8\xe2+\x0f\xce\xac\xbc\xb1\xbc\xda-V\x97c\x93\xdet\xe1\x8d\x1f\x1c\xdf\xed;\xe4\x12v\x9e#\x930\xe2A\xa5\x7f\xf8\xc2\x833\xf3O\xcb\xe0`\xa8V\xcbr%\xab\xbb\x1a\xd4\x08|Mg\x89\xd1\xa0JJ\x0cb\x80\x80\x80u\x8cu\x02\x04\xa8\x01\xd5\xd0J\xd5\x13\x0c(@\x15\xf0 \x22\xac\x13\x95Z\xa8F\\\xc8V$\x17n\xde\xbe\xfb\xf2\x89Fc\x93\x89\xfa\xa7\xcd+\xcf`nE\xe5M\t\x8b\x10a\x22\xb6\x86;\x89\xdd0\xe6\xb7\xb7\xfd\xa5Wtn\xfb\x18\xf6\xe2\xfb\xfa@\xb1\x02r\x88\x22\xa4\x02|\xfa\xd4\xa3/N};[|\xa1X>\x11d!\x9eXk\x0c\x85\xa8\x03\x89!\x1ej\xb5\x82z\x10\x08P\x10\x91\x03\x02P\xa9&\xc4\x154\x22c[[$\xbd\xfcN\xfc\xf6\xcf\xe0B\xfc\x80c\xc0\xbfx\xf1\x97\x96N>\x19\xf2\x93\xf4\x9eY\x07\xc0\x10\x04\x04%RDj,\xd8+;@\x80\x9cLBM\xcfc\x95n\x04v\xeej\xbe\xe7\x97[\xef\xefb)G\xb7B\x1e*\xd5\x8a\x9c\xf5M\xdfYS\xfe\xec\xfeo\x1c9\xfdL\xd9}\xadZ\x99\x03-w6\xf5\x86\xc6$\x1e&\x8d\xb4\xb0\xcc\x96b\x16\x0f%B\x05\xea\x0bDa\x9dU2\xa9\xb7F\xf7\x10_\xb6\x91\xaf\xfex\xe3N\x8b\xb2\xc4\xa0D\r\x18\xab\x91A\xf4\xb7\x87\x0f\xde\x7f\xe8\x81\xd0\x7f\xa5\xcc\x8fW\xe5\x92\xd4=\x95\x92+VE\xa1\x08\x04UR\xd5\n\xaa\x8a\x98\xd8\x81*\x85@+\xa8\xb2\xf5\xb6\xe9\xfd\x98D\x9b|c\xc7\x9esn\xfc\xf5\xeb\xdeW\xa3(BVke\xc9z\x135\xd1\xfe\xf2\xd4s\x8fL?r\xa6\xfbZou\xba\xce\x96\x1a[\xda\xa3\x93\xdb\xd2\xc6\x85W\xb5\xdf\xf5\xf3\x9d\x1bK_\xe4\xc8k\x04\x87\x98*\xeb\xe0>\xf7\xf2w\x9e\x98~$\xcf\x8fh9W\x96g$\xf4B\xa84\x80\xb0N\x01\xc2:#P \x00\x81\xa0\n\xab`Ed\xe3\xd4u\x8c\x1bC\xba5n\\t\xef%w\xfd\xd4\x85\xd7\xae\xeaRQ\xe7\xaaj\xd9&6\x89\xd1\xf8O\xaf>x\xf0\xd8\xe3\xf9\xca\xa1(\x99\xf5C\xed\xc6\x86\x8d\xad\xf6\xb9\xd7\x8f\xddro|U\xce\xfd\x8a\xab\x1a\xc1\xc0\x93zU\xff\xb9\xe7\x1f<z\xea\xb9\xb2\x7f(\xeb\xce:]\x1c\xdf:\x88GT:\x14E\xa0\x88\xc4!W\x18 \x22V\x04Q\x10\\\x0bjUz\x99v3Wf\xed\x85\xb7\'Pl\xf3\xad\x0b~d\xd7mw\x9ew\xbeh^\x84\xb2V\xb1dc\xdbX\xaa\xe5?\xbf\xf2\xc0\xcc\xcakk++\xd5j\xcf\xbb\xd6M\x17\xdcp\xef\xd8\x95\xbeC\x9c0{NLb4\xca*\xfd?^xpf\xeei\xca\xdf\x08\xf5\xaaT\xabU\xe8\xe5u@P\x03\x02`\x89\xa0*\n\x0b\x8a\tg\x95\n\x01*\xa0Re\xc0\x131 \x80\x02\x06\n\xa0\x02*\x95\x94\xcf\x02A\x89\xb4.B^I\x16\xc4\x816\x8c\x9b\xc9\x8bm\x94\xd8,\x96i_-\x19\x11\x822bb&\x02[6\x1d6\x1b\x8c\xdf\xea\xdc%{\x92[\x7f\x05\xfb\x00\x08\xca>\x06\x19B\x00%\x88[H\x03\xf0\x1b\xa7\x1f\xd9\x7f\xec\xe1\xfe\xc2\xfe|i&\xd4\xf3\xd1x\xd7\x0f\xd7n\x08\x9a\x80=\xbc#&!EPf\x90a\x01T@\xa2$\x80B\x89\x0c\'\xa3Ig\xe7\xd5\xf8\xe5{q\xd7\xc5\x00\x03\x010\xc0#\xc0o=\xf6\xe1\xe5\xe9\'Q\xcf\xd3M\'\xbd\x02\x04\x22e\x03\xc2Y\n\x07j\x10\'D\x95\xa2\x0bfj\x10\x8dYl\x22\xbd\xe0\xd2\xe6\xbe_m\xdf\xdd\xc3J\x1f\xdd\x1c\x03\x94\x8a\x8a\xbc\xf5\xcd\xa8\xb3&\xf8\xbd\xe7\xff\xdb\xdb\xa7\x9f\xab\xfa\x87\x8b\xd5\xd3dW\x86\xb6\xf4[C\xa1\xd9\x86\x8d\xa1\x9e`!@\xad\xe4H=i\xa9\x94\x010V\xd8\xb6\x1cGt\rh\xd78]\xf3\xf1\xc6\x1d\x06E\x8e~\x80\x10\xacAD\xea\xff\xee\xd0\x81o\x1e\xfaF\xde{)\xcbf\xaa\xa2\xcbQO|\x15\x0c\' kH,\x94\xb4\x06*\xa8\x07%\xccF\x11\x14%\xb4\x84\n\x0cq\xd3\x9b\xb1\xc8m\x16\xb7y\xd7\xf8\xbe_\xbb\xec\xde\ny\x81,hm\xc48\x8eZ\xd4\xf9\xe3\xa3\xcf}g\xfa\xd1\xc5\xee\x1b\xbd\xb5\xe3Uv\xa6\xb1\xa532yN\x92\xec\xba\xa6s\xdd\xcf\r]_\xfa\x22G^#XD(MD\xd1\xe7^\xfa\xce\x133\x8f\xe4\xd9\xe1:\x9f\x0b\xd5\x22%}\xf2\x15\x19( \x0c\xf6\x04\xc2Y.@\x08g1`\x15\x0egQ`\xef\xb8e\xed\x06\xeb\xb6F\xd1\xc5\xef\xdf~\xc7G\xb6^\xbb\x82\xa5R\x0b\r\x81a\xbd\x8dc\xb4\xfe\xfd\xc1\x07\x0e\x1e\x7f\x9c\xbb\x87\x8d\x9cdoGvL4;\x97\\;v\xf3=\xf1U\xa5\xcd\xc4\x85\x1a5\xc42\x22\xa8\xfb\xec\xfe\x87\x0f\xcd>\xaf\xd9\x91\xaa\xbf\xc8XM7\xf7hX}\x8b\x1a\x11\xaa\x88\x12\x0b\x22\x10\x08j\x88J@\x03\xe2\x8e\x8aE\xbd\x92\xd1Z\x19\x15u\xbb\xec\xb5\xbdn5v\xe7\xfb\xb6\xddt\xcb\xc6\x1d\x15\xf2\x02\x95\x04\xf5jc\xdbX\xaa\xe4\x8b\xaf<8\xb3\xfc\xd2\xea\xf2|\xd5-\xbd\x1d\xba\xf9\xa2}\xf7\x8c]\xe9;\xa0\x84\xe19\xe2\xd4\xa8\x1bT\xfa\xa5\x17\xbf=3\xffb\x95\x1d+\xcbeS\xaf6\xd3\xaeX)\x19\xa4\x80%\xef`\x15\x04\x10`\x88\xa0\x00\xe1\xacL\x11\xa0\t\xc8\x10\xde\xa1\x02\x82\x02\xa2J\x8aA\xad\xb1Uo\x05R\x8b\xd4A\xab\xd2I\x96 \xebhi(X \x02\x1bD\xcc-fG %a[s\xa7\xb6\xe3\xd6o\xb5\xf6\xe2+\x93\xdb~\x057\x02\x18\xa0\xea\xa3\x17 \x00%\x88\x9bH\x05\xf8\xb5\xd9\xaf\xef\x9f\xfav\xbe\xf4F\xbe|\x22\xc8|\xb4\xa9K\x9d\xca7\x89\x13\xb0go\xc9\xb0X\x88\x82\x08g\x11\x14\x00\xd5J\x05\t\xa0 6\xd1p\xd2<\xef\x1c\xf7\x81\x8bq\xebF\\I\xa0\x0c\xcb\x05\xd6\x8e\xe0\x95\'\xbe\xf3\xc7\xdd\xb7_\xd6\xec4\xdd1\x133\xa0 \x80\xf0]\xa2\xb0D\x9e(\x02\x1b`Y\xb9\xa4\xd4\xd3X\x8b\'\x19;/l\xec\xfb7\xad\xbb{X\xeea\xad@^\x97\x8a\x8a\x9c\xf5\xad\xa8\xd3U\xfe\xcc\x0b\x0f\x1e9\xf5T\xd5}5_\x9b\x83Y\x89\xb7\x0c\x92\x8e4\x87\x94\x22\xae,\xc5^\x03P+\x19h\xc4\x22\x8aBA\xce*Y\xefl\xa2\xb7;\xb3}\x82/\xffD\xf3v\x8b*\xc3 \xa0V\x90\x91\xc8R\xfc7o\x1d\xfc\xc7\xb7\xbe9\xe8\xbd>\xc8\xa7\xab\xfe\xa2mvM\xab\x0c\x9e\x1b\x11\xa5\x11[Cb\xb4R\x05`\x89\x18\xe4\x80R\xd1\x17\x01\xe0\xd9Yj(\x8dy\xb3Y\xcd\xb9\x17u\xf6\xfe\xf2\xce;\x05U\x89\xbcBI\x81=GM\xea\xfc\xc9\x91g\x1f=\xf1\xe4Z\x7f\xba\xb7:U\x0ef\x1a\x93\x9d\xce\xc6I\x9f\x9c\xbbg\xf8\x86O\x0c\xdfT\xfa<G.\x08AX\x0bj\xda\xd6\x1f\xbc\xf4\xc8\xe33\x8f\x96\xfd\xc3R\xccU\xe5\x19\xd3\xee\x9bF\xcd1\xc8\x82\x1d\xc8\x12\x08B @\x01\x06\x1c\xa0\n\x15\x18@\x10\x11\xb7b;\xee\xcd6k/\xba}\xf3\x9d\x1f\x9d\xb8v\x15K\x05\xf2 \x81\xc5z\x1b\xc7h\xfd\xbbW\x1e\xd8\xff\xf6c\xe8\xbeU\x0cf\x8d\xb5\xe3;7\xb4F.\xbdn\xec\x96\x1fMwW.\x17\x1fj\x04\n\x96\xd4\x8a\xf0\xe7\x0f\xbc\xf0\xda\xdc\xab\x9a\x1d\x0f\xd9\x19\x91\x95x\x93\x9av\xed\x9b\xc1G*\x9e\xad\xd5\x00\xad\x95,\xb1A(\x15\nn\x13U\x22Y\xe5B\xcdV\x22\r\xa3\x22\x93\x919\xf7\x9eMW\xdf4\xbaEQU\xa8Ta\x82Ils\xb9\x92/\xbc\xf2\xe0\xf1\xe5\x97\xd6\x96\x96C\xb7\xf66\xb9\xe9\xa2\x1b?0\xbe\xdbv@\t\xb3g\x8b\x88\xd4\rj|\xe1\xc0\xb3S\x0b\xaf\xe6\xd9t\x9e-@V\xa2\xc6\x9aI\x8d\x8d\xacs\x00\x83,\x88@\x84u\n\x02\x0c\x11\x01\x01\xa8U\x05H@J\xc8U=Q-\x08\x80!xH_\xa4\x12mr\xed\xb8\xac(W*c\xaa\x95K\xf1u\x1d\xd3@I\x18\xec`-\x1b\xc0\xa8\x96\xd0\x16\x9b\xd8\xd8\x82:\x85\x197n\x8bq\x17_\x95\xdc\xf1)\xdc\x00 C9@?@\x14\x14#n\x22\x15\xe07N=\xba\xff\xd0\xb7\xb2\xe5\x97\xf2\xe5\x19\tg\xe2\xcd]\xdb\xa9L\x1b.fD\x14\x0c\x98@P\x02\x0c`\x84@\x00H\x95j\nBJ`\xe3\x9b\xdc\x18\x8b\x93\x0b:\xb8\xb8\x85\xdd5\xaa\x1c\'\xb3jeu\xfa\xc4\xa9\x17_)\xd7f\xa5\\\xa3\xbb\xa7S\x81*\x91\xe0\x1d\x06$\x80\x02\x0e\x14\xc8\x94H\r\x8d5h\xd2\xe0\x82\xcb\x9a\xef\xf9T\xeb}=,\x0f\xd0-P\x94\xa5Pe\x92(MmsY\xc2g\xf6?pt\xee\x85\xb2\xfb\xd6`\xf5\x84\xd2\x99db\x10\x8d\xa9\x1b\x82z\x16O\xce\xab*\x81\x94\xa0\xb5*\xceR\x88s\x91\xb1\xcc\x9c\xe0\xe6\xc4\x9d\xb7\x8dv\x7f\xb2u\x17!\x1f\xa0[\xa3\x02\x8c\x15g)\xf9\xab7\x0f\xfc\xc3\x9b\x0f\xe4\xd9[\xf9`:\xef\x9e6\xad\x9e\x1b\xaa\\\x93L\x93\xe2\x065\x89\x03\xb4\x862\x88\t\xa2p\xa0\x81j\x06% &\x9bR\x830\x16h3\xe8\xfc\xcb;\xfb\xfe\xf5\xb6[\x03\xca\x12Y\x85\x92\x83\xf1\x14\x0f\xf1\xd8\x97\x8e>\xf5\xd8\xccc\xdd\xee\xb1\xc1\xda\xd1\x22;92\xb9!\xdd0i\xfd\xc4\x15\xa3{\x7f~\xec6\xf5\xa1@Q\xa1\xac\x82ha\x86\xa2\x91\xcf\xbd\xf8\xf0S\xc7\x1f)\xfaG\xc2`\xae,O\xdb\xd1\xbei\x05\x97\x22j\x92\x8dq\x96\x02\x01\x00A\t\x0c@\xa18\x8b\x0cP\xa8SJ\x133\x11\xf39\x86/\xbe}\xe2}?=r\xfd2\xe6\x07\xc8D\x85\x82\x89L\x92P\xfb\xb7_}\xe0\xa5\xe9\xc7\x06+o\x0ez\xb3\x064r\xfe\xc4\xf0\x86]7N\xdc\xfe\xe3\xe9\x9e\xdc\xf5\xd5\x87\x80 5H\x8c\xaa\xfd\xdb\xa9\xc3/\xceM-\xaf\x9d\xac\xb2%E\xcf\x8f\x89kV\x9c\x06\xe3\xc1\x8e\xd4\xa0P\x014\x22\x04E\x01\x10Q\x03\xc8\x95j\xb568_9\xc1\x907\x1b\'\xe2-\xb7\x8c\x9f\x7fU:\x06\x84\x80\n\x00W\xb6\xe9:+\xb5\xfe\xc1\xc1\x07\xa6\x96^X]Y\x0ckul\xd2\x9b.\xbe\xe9G\xc6\xf7\xd8N\xd0\x84\xc8\x93QG\xea\xfa5\xbe\xf8\xcaK\x87\x17\xdf\xae\xcb\xc5<[\x08\xb2\xcc\x8d\xc26L\x9a\x18\x17\x83\x0c\x94@\x84\x1fTc\x9d\x01\x08\x10\xc0\x01\x05P)\x98`\xc0\x02\x88\x82\xa8\xacQ\xc1\xc0\x86\\\xc3\xa0v]5=\x87~\x82\x92\xac\x94^\x94\x18\x00\x81\xd8\x90\x88\x96\x90L\xb5\xc5f\xd8z2\xad\x82\xc6\xd9oqn\xd7\xee\xe4\x8eOa/\x80\x0cE\x86A\x80(\xb8\x81V\n[\x02\xff\xf6\xd4w^\x9ez$?\xf3J\xb6t,T\xa7\xa3\r=?\\\xf9a\xe2\x98$BiI\x95\x88\xc4\x12\xac\xc2\x8a!\x82\x92BH)\x08\x14J\xe4\xa2`\x13I\xdb.\x9d\x88q\x89\xa0(VO\xe6g\xf2\xc1\xccZ\xf7\xd5\xd30=\xa5\x92n\x99N\x15\xaa8\x8b\xf0O\x18P `]J\x06\x94\x16\x18\xb3\x98\x8c\xe8\xa2\xdd\xcd}\xff\xa6\xfd\xbe\x1eV3t\xfb\xc8B\xa9\\\xbbN:\xec\x11\x9d\xae\xfb\xbf\xf7\xfc?\x1c[8P\xf7O\xe7\xab\xc7\xc0\x0b\xd1\x96^<\xa4\xae\x05\x13\x93\xf1\x04\x8b\\\x98I\x01\xc9\x14\x16\x10\xc08\x97X\xeb,\xc7tE\xcaWn\xe3w\xffb\xe3\x1e\xc1\xea\x1aVj\xd4\x80\xb1\xe2\x1d%\x7f\xf5\xe6\x81\xaf\xbe\xf5\xc0\xa0\xf7\xc6\xa0w\xbc\x1c\xcc\xf9\xb1<\x1d\xadm\nM\xc9\xa4h8\x08\x10\x14L0\xa0J5\x80J\xa8\x00\x0ep0\x8e\x13\xabc\x056\xb3^\xb8\xa7\xbd\xef\x97&o\xaf\x91\xe7\x18\xd4\xa8XlD\xe98m\xfa\xc3\xa9\'\x1e\x9byhi\xe5`o\xed8\xb2\xc5\x91\xc9\xcdC\x9b\xb6\xdb\xe6\x86\xcb:\xd7~|\xf8f\xeb}\x81\xbc\x8f\x9e\x08\xb4\xb0C\xd1\xe8\xe7^\xfc\xf63o?\x9c\x0f\xa6\xb2\xdeL\x9e\xcfE\x93\xb9\xed\x04\x93\x92o\xc2FJ\x86\xa0\x10\x00\x04\xc2:Q\x10`\x88\x08:\x80\x15D)op\xd8\xee\xf8\xb2\xbb\xc6\xef\xfd\x99\xce\xbb\x161;@_ T\x9b\xc8\xa4)u~\xeb\xd5\x07^\x9e~\xac\xbb\xfaz\xd1\x9b\x05t\xe8\xdc\x8dC#\x97\xde\xb4\xe9\xae\x0f\xb7\xaf\x1f\xf85uU\x8dPU\x01\x81\xadM^=3\xf7\xf2\xfc\xfc\xcb\xf3\x0be\xd1\x87f\xb65\xb0\r1\xa9\xc2\x22\xb0\xb2\x81\x00\x96`\x80\n\xa8\x14\n8@\xa0A\x9946\x9a\x906&\xe2\xce\xd5#\x1b\xceOG6Q\x93\xa15*\x00\xb6\xf2\x1d7\xba\xa2\xf4\x99\x97\xffqj\xf1\x99\x95\xe5\xd9\xaa\x17b3z\xfb\xc5\xb7\xffO\xe3\xd7\xa0SH\x1c\xd4\x13\x8b\x85pV\xe3O\xdf|\xe3\xe8\xca\xa2\x06)\xf3\x85 ]\xdb\xa6(\x05E`\x076`\x02\x08D8K\x01Q\xd4\x8a\xb3<!\x22\x22\xa0\x02J\xd1\x00\x18\xc0\x92\x11\xa0\x06\x14=\xa1\x82\xa0\xd0<`\xad\xe2\xd3\x81\xe6\x85\x16=\t\x102S+\x83\x08g)\xc0\x80(j\xc0\x12\xb5\xd9\x1a\xdb\xae\xcd(\xf9s\x22\x7f\xc5u\xd1\xed\xbf\x8a\xbd\x002T\x03\xf4k\x04\x86\x1dB\xc7\x01\x03\xe0\xd3\'\xee;p\xec\xc9bm\xa6\x7f\xe6P\x1df\xd3\x89\x9e\xed\xd4\xb6\x05\x13\x93\x8d\xa1\x86KE\xc2\xca\xa4P\xa8\xb0\x90*`\x95\x18R\x011H4\t\xba\x810\x1e\xfb\xed\xad\xe4\xb2\xb8\x18\xaaW\x91\r\x16\x16\x17\x0e\x9d~\xfd\xf9\x80y\xa5\x15\xbak&u\x04\x01J\x01\x13\x1c\xa1V\x08\x90\x10\x18\xc8\x15\x1dk\x14i\xa9c\xaa\x93\x86.\xba\xac\xb9\xefS\xad\xf7\xf5\xb0Z\xa0;@\x16J\xd5\x8a\xbc\x8f\x86\xddX\x1f\xfe7\x9f\xfe\xca\xdbsO\xc9\xe0X\xd5=\xa3f\xc5l\xeaaH]\x0b&!vD\x96\x0c\xc1\x00\x85j\x806\x89\x15\x88\xac%kJc\x1cni\x9a\xf3\xb6\x9a\xcb>\xdex\x8fC\xa8\x90\t\x14`\x16k)\xf9\x9b\xb7\x0e\xdc\xf7\xd6\x03E\xef\x8d\xb2?]\xf6N\xb7\'\xb2\xf6\xc6ZS$\r\xb2)\x82\x051\x08 @\xb1\xaePR\x80\x80\x98\xc8\x80K\xc4AF*la\xb9\xe0\xca\xc6\xbe_\x98\xb8= /\x91\tD\x94P\x9b\xd4\xb5\xfe\xcb\xe1\xfd\xcf\xcc>uf\xf5`\xbf7\x87|\x10M\xe8\xa6\xad\xdb\xa3d\xd7\x95C\xd7}\xb4s\xad\xf5\xae@>@\xdf\x22\x0e%1\xf8\x0f_z\xf4\xb9\x99\'\xb2\xee\xd4`0S\xe6\xa7\xe3\xad\xb9\x1d\x0f&\x85k\xaa\x8f!\x06\x00\x05\x05\x88,)\xa0\xaa\x84\xb3\x08\x04\xd4b\x89\xa2\x18;\x18;X\xb7\xdf>v\xebGZW\x0f\xd0-Q(D\x03G\x9c\xa6\xd4\xf9\xedW\xbf\xb9\x7f\xfa\xb1\xbc\xfbF\xd5;\x19T[\xdb&\x86\xc6.\xbbe\xe3]\x1f\xee\xbc\xab\x88\x06\xb5-\n\x14U\x19H\x98\xc9\xae\x95\xf5J\x19V\n\x84P\xab\xd6\xe4\x85l G`\x05a\x82\xb8I\xd6\x90ap\x85zQ\xabE\x88%e@\x94@\x86\xc8\xa8\xd6\x89\xb3c6j\xc2$`E\x00\x88@N\x92&w\x16E~w\xff\x9f\x1d_\xd9\xdf_-u\x10{\x9b\xee\xdb\xb9\xe7\x9e\xd1+\xa2\x16\x9b\x06k\x04\xaa\x19b\x02xjm\xb0V\xd5$\x02\x01\xa0&&\xe34\x18\x05\x83\x18DP@A\x84u\x82u\x06 \xacc\x90@\x15P\x80\x81\xa0\x01d#J\x04!h U\x85\xcc\x95\xf3\x8f\xf4\xee[\xc5[\x95\xce\x07Z\r\xe8+\xd55\x01\x8c\xa0J\xa0\x98\xc9(A\x00\x22bcl\x93\xed8\xdc9\xb1\xbf\xe2]\xfe\xf6_\xc7\r\x00\xfa(\xfb\xe8\x07\x08@1\x92a$\x00>y\xe2\xfe\x17\x0f\x7f3\xef\x1e\x19\xac\xce\x96\xd5|\xbc\xa1\xe7:\xb5m\x91\x8d\xa9\x19\x91\xb5\x18@G\x98k\xa0\x80\x9eU)\x18\xf0DA)\x00\x1b\x88\x83\x1f\x81\xdd\xdep\xd7\xedh\xed=\x17W\xb41\xa20%z\xaf.?\xf7\xc4\x83_\xec\xaf\xbc\x1e\xcaY\xfa\xd9\xd94b\x14\x8a\xbe\xc0\x11RB\xa6\x10 \x22(P\x08\xda\xc6\x10\xa5A\xc6\x84&\xc1\x17mO\xde\xf3\xaf\x9a\xef\x1f`\xadB7GVU\xa2\x15[g;n\xac\x07\xff\xef\x9f\xfd\xca\xc9\xb9\xa7\xea\xfe\xb1\xaa\xb7\x10\xb0\x1c\xc6\x07\xf5\x88\xd00!\x22x\x82\xd5\x18\x1c\x13\xa9j\xcd\x9a\x80S&\xb5\xb6b\xee3Q\xb8\xb9i\xcf;\xcf\\\xf6\xf3\xad\x9b#\x84\x1a\x85B\x00&u\x86\xe2\xbf\x7f\xf3\xa5o\xbeu\xbf\xe9\x1f\xa9\x073uo\xae5\x91\xc7\x1b\xebx\x88\xda\r\xa2\x84\n\xab1\xc3\x13JU\x80\x08\xf0D\xf8\x1eE\x01\xee\xab\x13\x19\r:\x19\xe4\xc2\x0b\x93\xf7~|\xec\x0eEV\x22\x0b\x90\x00\x82\xb8\x84\xd3?;\xb2\xff\xd9\x93O.\xad\x1e\\\xeb\x9e\xca\xf2\x81\xdf\x14&\xb6ni\xa4\xbb\xafl_\xff\xd1\xceu\xd6\xfb\x12y\x86\x9eCC\x03k\xc0\xe7_|\xe8\xa9\xe9G\xb2\xde\xdb\xe5`\xb6.\xe6\xe3\xadE2QEC\xeaR\xe2Ha\t\x04\x05ja\xcbbH\x19 @\x81JI\xd4:\xf6N\xcf\x83\x9c\x07\xec\xb8m\xe8\xa6\x0f5\xae\x1d\xa0[\xa1\x12\xa8\n\xc5\x9c6\xd0\xf9\xadW\xbf\xf6\xe2\xcc#\xf9\xda\xa1\xb2\x7fR\x15\xcdm\xe3\xc3\xe3\x17^;~\xc7\x8f\x0e\xdd\x98F\x22\xb6,\x91WU@\rb\xb6\xc63;\x0bc\xc0L\xdc$\x8e\xc1\x16\x060\x80\xc1:\x05\x04\x10\x80\x00\x83u\x02\x08 \x82\x90Ar\x145\x82\x02\x82JP\t\x04`\x86\xf7H\x15\xf1\xf1\xac\xff\xc7\x07\xfel\xb6{\xa0X\r:\x88\x9cm\xdcp\xc1\xb5w\xb4w\xb9T\xd3\xe1\x98c\xd2\xc0\x12\x08\xcc\xce\xc4\x96-\xa9:\xf2\x86-\x03\x04U(\x00\xc5:\x05\x14\x84\xefR\x80\x00\x03(\xa0XG\x80\xe2\xfb\x04\n\x90\x85\xb5p\x0c#P\x0b\xf7\x16\xe6\x7f\x7f\xf9K\xf3\xe1\xa52\x9c\xccq*\xa0\x0b\r\x11H\x15\x01`\xc0\x11\xe1,\xc5:6j\x9a\xc6n\x88\xdcv\xe7/\xbf\xd6\xdd\xf1i\xec\x050@\xddC? \x10\xd8#\x1aFB\xc0\'O\xde\xff\xc2\xa1\xfb\x8b\xb5\xa9|\xe5dQ/\x98\xd1\x9e\x1d\xa9\xa3!r\x115#J,\x0f )\xb8\x840\x11\x01\x0c\x18\x22U\xf4U\x13\xe2\x04\xe4\xd3\r\x9a\\\xb0#\xfe\xe8\x8f\xe0\xc7\xae\xc4;\x1eC\xefs\xdf\xfa\xf9\xd5\xd9\xe7L6C\x9f\x9cK\xa1(\x14%\xd0\x00\x98\xa0\x80\x02P\x94@\x054\xd9\x04JI\xc6\x94&\x95.\xda\x91\xde\xf4\xc9\xe6=9\xba9z\x05\xfaUP\n&\xf5-\x0b\xbf \x83\xcf?\xff\xdf\xa6O\xbf\x9c\x0f\xe6te\xca\xda3\xad-\x037\xa4u\x0b\x99\xa7`y`\xd5\x83\xda\xcc\xa2\xda\'I\xc1\x9ei\x85\xd9[\xe7-W\xe1\xd2\x06]y\xa9\xbb\xe1\x93\xcd\x0f\x18\xf4s\xac\x95\xa8\x15l\x10\x19\xc4\x7f\xf9\xc63\xdf~\xf3\xef\xc2\xe0\x84\x0cfCo.\xd9\x98G\x1b\xeb\xb116\tJ\x0fk1l\t\x8a\x01\x84A\x16\x08 \x05,\xc0@\x01\xea\xa9W\x19\x15\x9d\x0cr\xe1E\xc9\xcd\x1f\x1f\xbbS\x91\x97\x18T\xa8\x02\xacG2\x8c\xf1/O=\xf1\xf4\xf1ou\xbb\x07\xfb\xdd\xe9\xe5\xfeBc\xdbPsrc\xb3\xb9\xf3\xb2\xe6\xde\x9f\xee\xdc\xec\xbc\xafP\xf4\xb0\x16\xa1\xc1\xc1{\x93\xfc\xc7g\xfe\xfe\xd9\xe3\xdf,\xba\'\x8b\xe2tY\x9eI\xb7f\xd1\x86:\x1a\x82O\xa0\x11\xc42\x08\x04\xd4\x02\x06\x88`X\t\xa8\x84\x98\xd4\x90!D\xc0\x04a\x87\r\xbb\xee\x1a\xba\xf7c\x8d\xbd\x8b8\x9d#\x13\x08\xa9\x89\xa9\xd1@\xfb\xd3\x07\xff\xfa\xc0\x89\x87\xb3\xdet\xd1\x9b\x85\xa0\xb1\xad\xd3\x9e\x98\xbcd\xe4\xf6[G\xde\xb33j\xc7VsduU\xab\xa0I\xd4r\xa9!\x0eUa\xb9a\x8dw`\xacc\xac#|_\x05\xad\x10\x14L`\x00\x0c\x10\xc0Xg\x00\x02\x04\xa8\x81A\x89|\r\xa1\x043\xa2\x04\xed\x93ey`\xe9\xd4\x83\xc7\xee\x9b_{-[\xeb\xcbZ\x15\xf9\xf1\x9b/y\xff\x0f\x8f]\x8bt\xe0\x9a\x0c\x0f\t\x08\x026\x1c\xbb\xd4\xc11\x88@\x00\x08\xcaP\x85\x02P\x80\xb0NA\x00\x02\xa0\x80\x01\x08Dx\x87@\x01\x08\xc8\xc2\x05\xd4\x03t\x13\xb4<b\x80\x124\xa6\xb0\xfa\xfb\xfd/\x9d\xaa_\xec\xe5ogzJ\xb0\x06\xad\x8d\xa8a\x22\x10\xa0\xa2\xb0D\x84\xefbS\x99&\xdb\xf1\xc4mw\xee\xf2\xeb\xfd\x9d\xbf\x81\xbd\x00J\xa0\x8fA@ \x98&\xd2\x08\x18\x00\xbf:\xfb\xd5\x17\x0f=R\xac\x9e\xe8/\x1f\x129\xcd\x1b\xbbv(p\x13&\xa1v\xc4m\xe6\x0c\x02\xc5Y)\xb3!\xb2D\x04\x14\xa2\x01\n\x90Bm2\x91\xb6/\xbd\xd5\xfe\xc6Ob\x0f~\xc0+\xc0\xbf}\xf4gz3\xaf\xf8\xf24\xfd\xc2\xa9T\x01\x06\xa0P\x02\x01\x04\xa8\x02\x84\xef\x11\x98\x01\xd2\x84\xc6@\x9br\x9a<?\xb9\xf5W\x9a\x1f*\xd1\x1b\xa0_\xa3\xa8\xa1\x14\xd8\x998As\x19\xf5\x7fx\xf6+GO=E\xfdcU\xef\xb45+\xad-Y2\xa4\xbe\x03x\xca\xadYa\x8d\x88<S\xae\xdaWi0\x07\xd55\xe6\xd4\xda\xd8\xd8\x88.\x1f\xf7\xe7\x9fo\xf6\xfet\xfc?;T9z\nU\x18\x85!\xf8\xff\xeb\x8d\'\xbf\xf5\xc6_\x17\xd9\t\x1e\x9c\x92\xde\xdc\xd0\xc6\xbc\xb19\xe808V\x17S\xcb1\xb3\nT\x01\x0f\x8a\x89N\x05\x01\xa8\xc5\xe4\x81\n4P_\xc9\xa8\xe8F\xc1\xa6\xf3\xa3\xdb?1\xf6\xe3@U#/Q\x01\xce#N\xd0\xfc\xc3\xc3\x0f\xbc0}\x7f\xd6?\x96uOf\x83\x85d\xdb\x90\x9d\x98\x88\xd2\xf3wun\xf9\xc5\xe1{\xc4W\x15\x8a\n\x15`B@D\xd1\x7fx\xf6\x1f\x9e\x9b\xfeF\xe8\x9f\xecg\xf3u\xb9\x90l\xcd\xfcx\x88\x86\xe1\x12hL\x15\x11\x00k\x14\x80\n\x11\x81\xa0\x02\x88\x92aU\x18\x95\xd8\xf1\x06C\x9b\x8d\x9es{\xe7\'~.\xbdu\xf5\xff\xae\x0b\xdeb,\xcd\xce\xb3\x00\xbf\xef\xb7\xd6\xfaO{\xef\xdaU\xddU]}\x98\x9e\x99\x9e\xce\xf4\x8c\xed\xb1\x07EqB\x0c\x08c\x89\x18\xc5\x8e\xb0#q\x81rglp\x14\x1c\x22P\x04\x02\x85\x0b\x12!EB(R\x04\xc2\xbe\xe2$\x90\x22_\x90\x04K\x88S\x88B\x88ql\xc7\x89=\x1e\xb7\xdd\xed\x99\xee\x9e\xae>TU\xd7\xae}\xf8Ok\xad\xef\xa3\xba\x93 G\x88\xe7\xc1b\xc4h0\x03K\xd45\xa6\x7f\xef\xf7\xff\xddW\xee\xfe\xd7qs7\xb6\x07P\x9d<?m.n_\xdd\xfa\xe1\x0fm\x7f\xe8G\xa6\xef=\xef\xa7@\x1fM\x93*H\x82\x00\xcc\x0c\xa0\x88\x0b\xa4\xe0\xff\x12\xc0\x01#\xe2\x10;\x05@\x11_\x96f\xd9r$\x01\x03\x0b\x81#@\x80\x80\x01\x04\x0c\x08\x80\x07\xaa\xaf-\xee\xfe\xf7\x83\xaf~\xf5\xf0\xbf\x9d\xb6o\xb7k\xc9\xab\xa1*\xa7\x7f\xe1\x95\x8f\xfd\x95\xbd\x0f\x86\xad\xcc\xca\xac0\x03L)\xcey\t\x0eB\x80\xa0\x01\x84\tLa\x00\x02\x82\x83O\x18\x15j\x80\xe2\x8c\x0b\x08\x8a\xa4H\xc4\x19\x02\x08(\r:`t(\x00dd\x8188\x825\xa6oa\xf9O\x16\x9f\xbd\x9f~o\xd5\xbd\xad\xfap\x90S0F5/\x14\x81\x1a\x12l&\x22 \xce8\'nF\xd9Cq\xb5,\xaf\xfd\xa0\xff\xc8\xcf\xe3\xc3xf\x002\x14\x90\n\x10<\xf53\xf7\xbf\xf0\xa5\x9b_\xe8\x96\xb7\xe2\xe9\xc1\x98\x0f\xc3\xc5\xb5\xcc\xb3\xdb\x82\x94\x9c\x95\xd28n\xcc\x00\x10\xd8\xa6\x04!\x9e\x89v\x06O\x19&\x93\xfd\xf9\xce\xfb>\xca_\xfa(\x9e\xc7\xf7x\x0b\xf8\xbb\xbf\xf3\xe9\xf5\xdb\xdfv\xe3\t?q\xd0\x10\xa8\t\x02=\xe0\x80`(\x04\xc4\x1f18e3b\'q\x17\xdc\xbf^\xfd\xa5\x9f\x9d~R\x91\x06\xb4\x03ZA\x10\x04\x81\xab1y\x8c\xe1\x17\xbe\xf8\xb9\xfb\x0f\xfe\xc7\xd8\xbe5\xacNDN\xeb\xfda\xba\xab\xd56\xab\x9a\x16p\xec\xd0\x19T\xa1\x02\x18+2+V\x8e\xc1\xb9\xbd\xc2\xed\xf9\xf7\xed\x94/l\xeb\x0f}\xb2\xfad\rF\x0c\x00\r\x04$\x01\xff\xfa\xcd\xdf\xf8\xcfo\xfc[\xd7\xdf\xef\xdb\x83\xb8yr~\x7f\xd8\xb9\x94\xdd\x0es\x93\x8b\x9a\xe2)\x02\x03h \xe1\xc0\xdet\x97\xce\x83\n\xcb\x90\xc1\xca\x85\xce\r\xbb\x8a\xddk\xe1G\xff\xd6\xde\xa7<,#fd\xc2\t\n\x0f\xf7\xcf\xbe\xfd\xeb\xdf\xb8\xfb\xab\xfd\xe6\xfeby\x7f\xe8\x8e.\xbc\xb0\x13\xf6\xf6\x9f\xf8+\xaf\xed~\xe4\x1f\xec\xfe\xb8\x15\xc8\x88\x00\x15\xd6#\x06\xf3\xbf\xf0\xc5_\xf9_o\xff\xc7a8\x88\xfdQl\x8f\'\xcf\xf7\xd5~\xf2sJ\x85\\\xc0<H8\x22\x90\x01\x040\xc0\x22,\x00\xa3\xc2LJ\x17\x02.8^t\xbc\xf4\xa1\xd9\'>\xd3\xfc\xe8\x80\x98\x91\x14f\xb0\x80\xc2#\xfc\xec\xef\xff\xab\xdf}\xeb?\r\x9b{\xaf\xb5\'W\xacx\xe5\xc5\x0bW\xcf\xdf\xe0\xce\xbbe\xfa\xca\xa5\xd9\x8d\xf3\xf5N\x01\x17\xa0\x04\x04T$@\r \x003\xa1\t@\xfc\xa1\x00\x14\xc0\x08(`\x80\x02\x02\x04\x1d\xc6\x1c\xa3*\x0cO\x85@\x12g(\x8e\x22F\xf4\x8a6\xdbjL_\xbf\xf3\xed\x87\xc7\xdf\xb2\xf5\xcd<<8J\xdd\xaa\xdd\xe4\xc2_{\xe5c\x1f\xd9\xfd\xd0d^\xb9\x8a,4J2\xb8\x92\x95B\x01\x03`\x00\x01\x01\x08(\x0c@\x89\xe0\x11F\x0c\x19\x19\xcf\x10\xce\xa1T\xa4\x84\x08\x80\x80\x81\x05J\x83\r\x18\x02J\x03\x22F\x0f\xef\xe1\t\x96hn\xe1\xf8\x9f\x1e~\xf6A\xfc\xea\xb2\xbb3\xd8\x83Q\x96\x90$4:\x8a\xc3`\xd6\xabN\x9d\x13<#.\xb8Y\xf0\xe7]\xb8\\\x96\x97\xdf\xef?\xfe\x8f\xf0\x97\xf1\x8c\x02\x82?\xe1\xa7\xdf\xf9\xb5/\xdf\xfc\x0f\xdd\xf2;\xdd\xe9a\x8e\xc7no\x1d\xce)\xe7\xe6\x1a\x96\xa5\xb8@\x00\x0e\x14`\xa6\xf4\xc6\x822B[\xd5\xb9\x83\x18\x84\xa8&\xfb\xd3\xf9\xeb?\x8e\x7f\xf1A\xec\xe3{\xdc\x01\xfe\xfeW~\xf2\xf4\xf6m\x1b6\xfck\x07M\x00\x1c\xa1\xc0`P`\x02T\x02\x02\x04\x0c\xc8p\x91\x8d\xb1Q\xcc\x9c=wm\xf2\xb1\xbf=\xf9\x14\x9eY\xe1\xa8\xc4V\x81\x02\xcf<@\xfb\x8f\x7f\xfb\x97\x1e>\xf8\xadny?\x0f\x0b+\xd6\xcd\x95a\xb2e\xd5\x04\xe5\x94R\xc1\x1c\xeee\xb4\x8a\x92((\x02&\xb5\xceQD\xae\x96~\xd7\xbfkR\xbd8\xd3\x0f\xfc\xf5\xf2\xd3%\x88\xef\x11a\x9f{\xe3\x0b\xff\xe5\x8d\xcfiw\xcf\xfac\xb4\xfd\xf6\xa5\xa1\xda\x8f\xf5\x8e\x84\t\xac6\x15\x94\x8e%\\\x86u\xc8\x0e\xccf\r\xb9\x05I\xb0\xd6\x98Q\xb5V\x05\xce\xcd\x9e\xbb\xea~\xec3\x17>\xed \xf8\x93~\xf9;\xbf\xf6\xe5;\x9f\xefW\xef\xa4\xc5\xc1\xb09\xdc\xbf\xbe\xbf}\xf5\xd2\xe3\xea\xfcK[\x1f\xf9\xb9s?\xe1\xbd\xc7\x1f\xeb\xa1\x19\xe3\xcf\xfd\xf6\xbf\xf9\x9fo\x7f>\x0e\x0f\xb4;\x8d\xfdiq\xb5\xaf/\xe6b\x8b\xa8\x81\x12\x0cp\x04\x7f\x92g\xf1\x00\x00\x10hIDAT\x01G\xc2P\x90\x19h\xa1b\xc8\x06\x01\x84\xa4]t\xb8\xe0y\xe9/n}\xeag\xa6?\x86\xff\xc7\xbf\xfc\xbd_yr\xfbwB\x7f4\xac\x96\x96\xe2\x95\xe7\xb6\xe6\xfb\x97\'\xbbWdr)\xf1r]_\xde\x9d\xec_.\xa6\x138<\x15\x15\x91 \x9e2\x82\x80\xc3S\x04\x088\xc0\x00\x03\x0cO\x19 \x80\x01\nd\xc0\x90M\x15\xa60\xc0\x85\x82\xe2\x01\xde]\x9c\xde\xbe\xff\xf0\xce\xdd[\x8f\xee\xde\x1c\x1e\xdd\x94\xfe\xc0\xc9\xb2\x1b\xa0\x93jza\xf7\xfa\xeb\x1f{\xffs?\xb8=\x9f\xd7u\x1dB\xd8\xc8\x13E\xd8\xc2|\xc4\x98\x10\x01\x18\x0c\xa0\x03\x88\xa7\x0c\x10\x10\xa0B\xf1\x0c\x01\xc3\x191\x18`x\xc6@\xe2\x0c\x15V\xa1\x89\x88K<\x99a\xbbA\x89g\xde\xc4\x93_|\xe7\x9f?\x1c\xbf\xb2\xee\xee\x8cz`~Mo\xb9\xd0\xc2\x93\xc4\x1a:\xa86\x8e\x1e\x02\xc0\xc4\x89\x9b\x86\xb0%nV\xb8k\x1f\xa8\x7f\xe2\xe7\xf1q\xfc\x7f\xfc\xcd\xbb\xff\xfe+o\xfej{\xf2V\xb7:4.\xfc\xe5\xae\x9c\x9b6ZL%\x94d\x80\x01\x04\x1c\xe82\x0bCM1`4-\x81ZP\x0b|\xb3?\x9b\xbf\xfea|\xf6\xc3\xb8\x80\xef\xf1\x0e\xf0\x0f\xdf\xfe\xc9\x93\x9b_c\xbb\xe4\xdf8h\xa6\x82l\xe8\x81\xdepf\x02\x08A\xa0 \x0c\xe8\xcde\xd4\x95\x14\xc2\n\xbc\xd8\x94?\xf0\\\xf1g5Y\x1a%\xf6.m\xf2\xb8\x89\xc1\xa9\x13\xebs\xf7\xcd\x83\xdf\\\x1d}C\xdb\xc7c?\xb0\x8e;/\x8e\xf5\x04E\x830\xa5\x14\xe8=Z\xe0$!\x01sq%\xa9\x8aC\xb1m\'\xdb^\xe6\xc5\xbb\xce\xd5{\x92\x9f;/?\xe4b\x15G\x0c=\xe2\x1a\xa9E;\xb6\xf7\x8e\xdexr\xfc\xbf\xc3x,\xfdI\xdf\xae\xab\xcb\xb1\xbeh\x93\x1d\xa9&L\x959\x8fF\\\x00GX\x846\x10\x81\t\xa0@4SHF\xd5\x9b\x07k\xc3\xfe$\xbf\xefR\xfd\x81\xb2\xa8-2\x0e\xd6\xaf,uF\xd1{\xa7\x7f\xb0X}clO\xd3\xe2`\xb39\xde}y~\xe5\x85\xdd0\xdf\xeb\xfd\xbb\xf6\x9a\xefG\xf6\x88\xd0Q\xba\xa5\xae\x9elb\x1c\xbes\xf4\x95\xa3\xf5\x1f\xe4q\xa1\xddJ\xc7\xb5\x7f>\x96\x17\xd4\xcd\xe9j\x14\rJ\x8fLD\x85\x12f\xf0 \x88A\x8d\x86B@\x22\x1bi\xfb\x05\xcf\x95\xd2\xfc\x88\xff\xe0{g\x1f\x08\xb1\xca]\xdd\x8fX,\xc6q\xc8\xce\xe1\xc9\xe1\xb7\xba\xc3\x9b\xec\x9e\xc4\xcdI\xc0p\xf5\xf9\xd9\xd6\xf9\xed\xc9\xce\xae+\xaaM[\x0c\xb8\xd0\r\xe7\xd6\xed\x84\xa1\x12\x07\'F!\xc5\x19`\xa0\xf3\x22N\xc4\x898/\xceS\x883:\x02jj4\x10\x06\xc0\xd4\xd42\x00\x02\x9a\xd4\x92\xe6.\xc5\x11\xed\xe98\xaeV\xabM\xb79=\xd5\xe5\xfday\x84\xfeI\x91\x8e\x1c6\x9b^TB\xe9\xeb\x17~\xe0\xfd{/\xbf\xbb\xd8\x9a\xaa\x9fh1\x8d\xa6\x19\xceW\xa5\xb17\xe6\xce\x86\xb5\xe5\x8eFo \x8c\xc8\x84\x08\t\x10\xb03\x80#\x00\x1a$\x02j\n\xc3\x19#@&\x98\x01\x82@B\xad\x17\x96\x19\x96\xf2\x9a\xc8\xa7\xf1\xf4\xd6\xe6\x8b\xedx\x7f\xe8\x0e\xc7\xf4\x00\xd5\xc6\xcf5W&\x05 \xc8b\xd1\xac\xa4\x04J\x86e\xc2\xfb\x99\xb8Z|U\xb8\xab7\x9a\x0f\xbd\x1f\x1fV0\xc2\x14DFj\x93I\xf6\x8eY\xf2o~\xfb\x0bo\xdf\xfe\xad\xe1\xf4\xbe\xb5\xad\xba>\xbc0\x943\x95\x06nB)\x00\x8f\x0cS\x18\x812\xbb\x1a\x0c\xa4\x9a\x11\x08@C4\x0e\xa1\xd9\x9f\xcd_\x7f?~\xf1\xa3\xb8Q\x03\x0e\x7f\xe4\x1b\xc0/o>q\xf4\xe6\x97|\xbb\xe0\'\x0f\x9a9Q\x10k\x83\x1a\xce\x90h\rf\x98\n&\xc4)\x9c\xa1\xac\xe1k\t\x94]+\x9eSwQ\x07\xb4\x9b\xb0Yn\xe5\x93M\xbbX9I\xa57\'y\xb5~0\xac\xdeI\xe3\xe1z\x01\x94\xba\x7f#o\x9dC\x98!{V\x05\xe8a\xc0\x83\x8cCE\t\xd4\x94@\xae`\xe7\xbc\xecy\xb7U\xbe4m\xb6\xcd&\x9a\xe7\xc32\xc4\x8dk{\xed\x17\xda\x9f`\xdd\xadsw\xec\xc6#\x9f6q\\\x0f\xedrzE\'\x97Xn\xb3\x98\xb0\xa8\xa5\xf2T\x22B\xa3\x9a\x17\xcc\xe0\x03\x0c\xc0\x00\x1da\x1e.X\xa9t\x19\xc5`\xdbj{&\xfb\xdeU\xda\x8bn\xf8\xe4Q\x1e\x96\xb9\x08Y\xf2\t\xf2b=\x0c\xed\xe6p\x1c\x96\xcd\x8b\xf5\xf7]\xdd\x9a\x9f\xdb\xbb\xa3[k\x99OB\xb0\xb5\xb4\'h\x8f\xd2\x93\xc7\x9ba\xe8\x92\x1e&;\xc9\xdd\xc6bG\x19\xca\xe7\xd4\xefZ\xd8bh\xe0jx\x07\x12\xd10\x02 \xbc\xc1\x91j\x80\xd9G\xac\xf9~\x9e\xdfv\xe7\xc3x\xa5\xc4\xcb,\xe7-\xdd\x98\xcb\xd3V\xbaN\xfb\xd1\x9e<\x19\xfbAE\xc4\xc7\xd5p\xfa(\xae\x0e\xe3\xe2\xa1t\'\x97\xf6\xab\xad\xa6\xa8\xc7PMf\xb8\xbc\xd7c\xfbT\xe7\xf7W\x93>y\xefEh\x0c%\xc2\x14 @\xb5\xa4\x10S\xc3\x19\x12g\xcc\x80\x010\x00\x04`g\x00U\xd8S\xaap\xa64\x03i\xa6\x92\x07\xd7\x9f\xe8\x98\xd3r\xdd?<\x1cN7\xab\xc7k]\x1e[\xees\xa8\xa5\x08\xbb;\xf5\xab\x7f\xeaE_o\x1d\xad\x18\x8bIk3\x94e\x0eBSq,&\xa4\x13?\x9f\x9a\xd0\xa0\xd1\xc5(\xc3c]\x1fY\xce\x80\x01\x86\xa7\x1c\xce0\x1b{\x83\xc2\x04gH \xc3\x12\xcc\x00\xa1\x9c12\xe6\x0c\x0c\xb4\xe5\x98\xc6ql-\x1ejl\xdbn3v\x8f\xddN\xb7\xf5\xaa\xf4eJ%B\r_\xd0\x14$\x0cJ\x88\xd0\x93%%\x84\xd0\x84r\xffr\xfd\xde=\\\xef\x81\x1eD;\xc5*v\'+\xf1\x89\xce(\xfa\xce\x83o\x1f\xdf\xfff\xec\x0f\xd2\x93,\x92\xe5\xa5\xb1<\xafn&\xbe\xa4\xf7\x94\x80\x0cs \x0c\x85\x12\x06!J2\x18`\xb6\xed\xe0\x05\xac\xf7\xfd\xfc\xf5W\xf1W\xdf\x8d\x1f\xae1\xf7\xa8\x152\x81{\x0b7\x7f\x03\x9fyr\xff[a\xbd\xe1O\x1d45!\xc4h8\x93\x81\x04(0\x02\x04\x02\x01cA\x11\x04\xc7Zd\x1ae;\xd9\\\x07\x8c\x9d\xf4K\x8e\x8f\xb4=\x8a\xe6G\xf1F\xc2\xe2\xa0\xddQ\xea\x0e7\x83\xf9\x1a\x17\xafk\xb3E?\x815L\x1e.\xa0!\x1ef<P8\xa0\x82\x14\x22#\xf0B\xe1\xf6\x9d\x98+\xb3\xbb`61H\xbb\xf2\xd2\x86n\xad\xed#l\x0e-\xd9\xe0\xf3R\xe2QT\xb38\x8c\x9bu\xf5\x02\x8b+\xf43\x16\r.7\xbe\xf4\x1c\xa8#4\x1b\xce8`\x00j\xb2\x87\x11\x98A\n\xb8\x01\x85G}j\x051+\xb0\x93\xb2\xeb7\x88k[<\xb0~\x91K\x9f\xbc>I\xf9t\x1d\xc7\xa1\x1b\xebl\xcdK\xe5\xa5\xcb\xb5;\xb7w\x97e\x1b\x04Y\xd8R\x17\xd2?\xd4\xd5\xe3\xde,\xc2\x8e\x92-\xf2hi\x18\x89\\]\x83\xdfC9\xa3k\xa0%\xca\x00\x12\xd9\xf0z\x0e\x7f\x9e\xdb\xcf\xf9\xfd\xedxn\x9e\xae\x89\x9f\xa7|\xda\xaf\xee\x83\xb0\xa2J~\xd6r[\xa5a\x98.7X\xaf\xe3\xeat\xf3\xe0\xe1\xa6m\xa3\xd3(\xcb\x87\xe3\xe1\xa3\xb8hu\xb5\xa9\xb6y\xfd\xdal\xe7\xea\xde\xb4\t\xf5\xf6v\x1bv\xba\\lz\xde{8\xae{\xa58)f\x08\xa5\x15\xdb\x92[\xa6M\xce\tg\xc8\xac\x06\x05\x88\xa7LA\xc0\x00\x18\xf1\x87\x8c@\x962K\xed\xe2\xca\xe5\x019[\x8a\x02\x13\xc2,\xd3\x94\x1a%m\xd8.\xd2\xe9\xe9\xea\xde\xe9\xb0\xce\xcb\x13V\xd3\xed\xe7\xf6\xb6B\x19z_L/4]\xc4\xc9\x1a\xd1\xe0\x84\x84\x18\x99G\x85\x989\xfaR|\xe9\xc3Vp\x93\x1a\x22(,7J\xa2\xb5\xd5;\\\x1f1\x1b\xb8\xce\x12\x8d\x02\x13\x22\xc3\xd4`\x00h\nU\x81\x01j&0\x98&\x1dsns\x7f\xa4\xa9\xcfqHmt{6{\r,\xd5M\xc8\xca\xe0A\xc0@\x18\n\xd2\x893\x113O?e\x98\xcd\xa6\x97=\xf7c\xc2\xd8\x1a\xd6>=\xcc\xfd\xe1h\x12\xe9\xb2s\x18\xdaU\xbbx0.\x1fh\x9f\x19\xb4z)\xfbmH\x037\xa1\x14\x80G\x86\x15\x10\x02[\x99\xd9\x90\x00\x07\x94\x80\x11/z\x0c\x064;~~\xedy\xfc\xe9\xcb\xb8A\\&\xa0h\x89\xa6\xc7\xc3\xaf\xe7\xcf/\xde|#\x9c\x1c\xf3\xa7\x0e\x1aOx\x22\x19\x06`\x04\xd4P\x12\n(\x10\x00GfCA\'\x0c\x03C\xb4JRc\x83Y\'\xa9\xe5\xe9\xdd\xbc~\x10\xc5%\rj\x02U\xb5\xb1O\xdd&\xaaM\xb6\xb8{\xcd\xc2\x8cU\x83<\xa1\x14\x08\x01%\xf18\xe3(#\x02\x01$\x08\xca\xab\xa5\xdb\x13d\xca\x88zD\xa0a\xd8\xf8\xd8\xf9\xe1\xd4\xd6\xf7\xb1xG]\xb0ZF\xb3\xa1O\x83\x8c\xe30\xc4\xe6\x1a\'W%4\xac\x1al7N\xbd\t\xe0I\x85%\xd8\xa0\xa6D 2P\x19\'\x94\r\xd0C\x04!B\x9c\x95N\'\xe8eh\xd1\xafls\xcf\x96G\xb1\x0c)8D\xb3\xd5x\xdaw1d\x9c\xbf\x11\xae\\)\xdc\xbcy\xa7\x90\xc7\x9e\xe8&\xae-\xaa\x8d;\xbes\xbay\xd8\x89\xcb\xe6:\xb3>E\xd3\x98-\xe5\xe6U\xd6\x17\x11j~\xbc\x9c\xfc\xb9\xe6\xdaE\xff\x92\x8b.\xe8^Nc\xbf~\x00\x01D\xe0\x05\x02\x11/.\xe0\x8csY\xfc\x98\x90\xcd\xe7\x91\xa7\'\x9b\xe5I{\xf8\xf6\xc9\xad\x9b\xeb\xb6\xcbU%\x85O\xa1\x84/$iv\xcc\xdf\xf7\xd2\xf4\xdcv3i\x8ar6i1\x8b\x12\x86\x1c\xee=\xca\xc7O\xda~\x84\x08\xc5{:GDjTU\xd2\x9c\xf3)\x9b\x9a\xc2H\x00\x84A\xa1\x0e\xcc\xc4\x19\xc2@de0\x06\xb1\x919\xe1\x8c)@E\x89~\xedr+\x04S/\xb13\xb5\x98R\x8aZ\x067\xab\xc2\xc5\xb9\x1f\x0f\xba\'\'h[\x9c\xb6|<T\xd2X\x99{)\xbd\x04\xe7\x0053\xb5\xaeUUs@JV4R\xce\x02\x83P\xe8+W_\x98\xbb\xa6B@*\xe2\xc0\xf1\xc4N\xef\xa0\xbb\xc3\x08\xc0\xf0\xd4`I\xe9\x9dL\x92\xb5\x96G\xa8\t\x22\x115I\xca]J+\xeb-\x9c\xe7\xe45\xf8Z\xa5&+\xd0\xa3\x10\x19\xcd\x00\xa8\xc1\x84^\xc4S\x94\xa5\xb9\xd2\xbbI\xb4\x89\xf4\xc2At\x08\xab\xb7\xc7\xe1^tn\x10\xafA\x98r\x8ec\x1bWK\x1d\x93\x9fbv\x1di\xaa\xaef1\xa3/\xe9<\x0c\xa0A\x88*3\x1a\x06\x03\x01\x0f\x14\xc4+\x01\x9d!L&akk\x0b\xbbS\xece\\\x17\x8c\x05\x1eo \t\xee\xfe\x9b\xc7\xab\xdb\xdf\x8cO\x8e\xf8\xd3\x0f\x1a\x1a\x8c\xc8\xc0\xc6 \x80\x03\x94 P\x03\xce\x90\x81\x91\x0cD\x22\x13H\x06I\x85u\xa6\x03S\x8f\xc7\xb7t}7\x85\x90 6\x12\x19&j\x165\x0fZ\xcel\xe7e\x99\xec\xb2\x99\x02%k\x0f:\x88\xe0a\xc6\x91B\x8d\n\xcb\x86\x00\xb9Q\xc8\xb6\x87P\x1c\xa4\xa7\x16\x90\xf5F\xba\xd6i\x87\xe3\xef\xea\xe2\xb6\x15\xc1D`@\xd4^b\xb2\xd1\xce]\x97\xd9\xf3\xe2\'\x0c\r\xad\x86y\xdb\xa2x`0\x15\x92@\x01\xb4\xa6JlA\x08\x9e\xc2\x08F\xc0\x04\xa2>\xe7\x82=\xadc\x1c\xec\xf0;\xba8H\xc1\xc5*\xcc\x92\x94m{<\xc4\x81\xc6\x17^\x0b\xfbWC\xd1\xf0V\xd0\xc7N\\;\xb7\xae\xe2R\x16\xb7\x8f\xfb\xfb+T\xd9\xc4\x00\xecg\xf7\x9e\\oc\xf2\xda\xb5\x9d\xeb\xe7_\xd8\xda\xd9\xf78E\xf7\xc8U\x8e\xde\x81\x01!\xd0y\x90t\x1e\x14\xc2h\x84\xc6<\xacr\x8a\xe3r=\x9clRk\xddw\xdb\x96\x856\xa1s\xf2\xdd#[\xf5\xa8&%\x12\x99\x12\xf28Z.+\xb9\xf1\xf2\xde\xf9\xdd\xe9t{\xaf\x9c\xed\x0cV\xa9-s\xd2\x07\x0f\xd7\x0f\x0f\x96\x8f\x0e;\xe7\xbd\x0f\xe2%\x9b)`8C\x05\xc4`8c\x04\rF\x03T\xd5T\x9d\x90\xc4\x19\x82\xd9L\x95ND\x003\x83E\xd0\xe70wq\xedrk9#\rTM\xc9\xc6!\x19xa\xee.]\xac\x9f\xbb\xd8\xf8\xc2/O\x87\xfe\xa4=Y\xa4\xaf\xbdi\xcb\xe3\x8e\xcb\xe5\x18Y\x16\xcey\xba@\t\xf0\xb5\xd7\xa4:\xe4\xd8\xe61\x1a\x8c\x00\xcd`\x80\x89\x95\x8d+\xbc\x98 \xcc}s\xa1\x96\xb2\xb2J\x8a\x9d\x1a\x9ek=\xfe2\x1e\xdf\x12!\xe6\x19K\xb56g-L\xa1\x80\xdb\x89\xb9\x1b\xc6\x13\x8cV\xefr\xfe\x9ah\xad\xd2@j\x06O\x03\x12\x10\xcd\xd4@\xb2\x14\xf1\x82HR\x9cH\x91\xb5\xca\x1d92\x0f\xdc\xdcN\xe9-\xf5>\xc2)\x085\x98\x99\xf6\xa3\xb6\xc9M8{\x05\xb6m2\x83\x96\x08\x81.\x80\x86Z$\x9b\x15\x89\nt\x06\x03\x1abB\xec;$\xc5VU\xd6M\x85\xb2\x0c2S\xec\r\xb0\x02\x9b5\xac;\xd2\xa3\xdf]\xb4\xf7\x8fm\xdd\xf1\xef<l20\x18\x0c0 \x00\x0e\x18\x01\x05\x04\x08\x00\x81\x04(9\x1aD\xe8M,3\r6\x0ep=\x1e\x7fK\x8f\xbf\xab\xde\x81^\xb3CJ\xa0\xa1P\xd3h~\x8es\xef\x91\xfa\x1c\xcb\x06\x93\x92\xceC<\xce\xdcK8T\x94\x10\x00\x19p\xc0\x15\xaf\xa5G\x01\xce\xe0\x85\xf4\xe0\xa6\xb3\xae\xb3\xa1\xb5\xc5-[\xde2/\xb6\x86f\x22\xa8\x99*\x15\x17o\xb8\xf3\xd7\x04\x13\xb2\xb4Ma\x85\xe3D(\xb0lF\xb2\x02\x03\xe0\x81\xd6\x0c\x84\x80\x06\xf1\xc0\x08\x80\x1cT\xa3\xd2\r\xb4\x91}g\xc7\xdf\xd4\x93\xbb\x99\xce .)\xa0\x99\xd9\x08\xbb\xfa\xba\xaf\xafH\x98\xb8{\xa5-\x1c\\\'\xb1\xe5\xb8\xc4\xeb7\xdd\xec\xa0\xd9\xf6;>M\xa1\xcci\x1c\xd7\'\xae\xe0\xbb^\xdd\xb9t\xb9\xa9\'e9\teA\xef\x03\x85\x22\x01$Laf\xaa\x80\xa5v\xb9\xb9\xfb\xa8\x7f\xb4\xcam\xc6\x90\xcbY\x90\xba(v\xa7R\x84\xd1\x8a\x0en\xbd\xc9o\xdc\\\x1e\x9f\x8cN\x08xx\x07\xe7\xb3\xa1\xa9\x8b\xf7\xbcg\xff\xdc\xeet2qEU\xc41\xc2RJy\xb1\x18\x8e\x8f\xbb\xc3\xc3\xae\x1fm\xcc\xa6)\x99)A\x11\x90f\x14\x98\x19\x8d\xa0\x19\x08\x18L\xd5\x00\x88\x80 \x01X2U\x05HO@\x00\x18\x94g\x1c\xa0\xc8\xa69C\x8d4@\x83G]\xba\xbdm9?w\xe7\xb7\n\xef\xd8\xf5\xcc\x86\xe5:\x7f\xe3\xe6\xe2d1x\x9a\xa6\x94\x96\xe3x4.\x8f\x86q\xd0\x108\x99{W\xfaz\xb7\x88\xab\xd8\x9f$<cj1f\xcb 0&3\x80\x04\x08fVS\x11/\xe5\xdco]?\xe7\x9aZ=S3\x8c\xae{4.\xee\xf8\xfe\xaeK1\xc9\x90\xb2ZBB\xd8\xc3\xf4\xbd\xc2\xdaX\xc1\xd5p\x81\x863\xec\xd5\x0cV\x8b\x04a\x82)\xe0\x9c\x90A\xb3K\x83\xa5\xc1\xac\xc3x\xcb\xc6[F1\xf1\x9a\xbci\x22\x15HY\xc7\xec\xb6X\xbf\x0b\xe5.\xad!\x82\xc1\x83\x1e\x8e,\xc9\xd1l\x9a\x19\x8c\x03@\xa0\x01j\xc19\xc2\x11%i\xe4@\xb8\xa2,g\xd3M\x8c\\o\xe2\xca\xd6\x07<\xbeUX\xa7\xae\xdb\xf03\x0f\x1a\x00#\x90\r5!\x00\xf1T\x07\x8c@\x05\x04`\x042P\x00\nd\xa3e\xc4\x88\x14\xad\xe8prK\x0f\xef\x98\x1a\xbc7\t\x88#\xa0(\t\x01\xaa\x1dN_\x11\xce\xe0K\xce*\x04\x818$\xe2^\xc4\xc2PS<\x99\x0c\xc9\xf4\xb2\xb3Z\xd4\x915\xa4\xa4#`=\xbb^\xbbN\x17wuu\xdb<\xb01k\xcd\xb6I3(p\xe1e\xd9y^lbZ0\x8a\x9d\xf3\x92a\x91\xe6\x8d\x04F\x9a\x03.AV\xb0%\xcc\x81%\xc4\x03\x1eL\xb0\xb5fM,\xcc\x8d\x83\xae6\xba\xf8\x8em\xde1!6f0\xab\x9d+H\x85\xee\xbf*\xba\xcf?SN\xdf=\xd9\xddn\xa6vX\xc8j\xb7O\xe1\x8d\xaf\xdf\xbd{\xf3\xb1\x041\'\xf4b\xe2(\xf4An\xbcr~\xff\xe2VS\x17E\x90\x10\x9cw4\xcd\xa6\xa3\x0e]\xde\xac\xd2\xaa[|\xedI\xdcd\n\\\xc1\xc9\xe5\xa6\xbe\xb4\xe5\xca\xc2\x04\xa4@\xc4\x92\xf5\x91\xfdh\x9bAo~ws\xbc&|I\x06\x88\x90\x02\xc3d\x12^}e\xf7\xdcy_\xd7\xf0\x9e\x06h\xb2a\x88}\x1f\xd7\xeba\xb9\x8c\'\xa7q\xbd\x1a\xbb!i\x06\t\xe7H* \x80\x02\x02\xc0L\t5\x18!\xf8c\x06\xa5%\xc3\x19\x05\x1c \x02\x12\x82\xa7H\x113\xe4\xa8\x00DrU\xd8d\xe2\xb7\xb7\xc2\xf6\xccO+\xd4%=]\xd7\xa5\x14\xb1n\xe3\xb7n\xad\x16\xa7\x03H38\xa2\x08\x1c\x87<v}\xffh\xb3:\xe852\x8e&\x82\xad\x0b\xc1\x15t\xa5\x88P\xa3\xc6U\xd2lC\xa7\xe3\xa0\xaa\x96\x13\x1c\x18\xa39\xcf\x14-\x04\x9a\x19\x1c\xab\x89\x94[n\x8cz\xe9\xf5\x9dj\xb7\x80\x9f\xf5~\xb3t\xcb/\xe9\xe2\xf6\xceX\xbf\x9b\xa1\x01KJ\r\x08Taf\xc9p\xa6\xa2\x90HP\x18(\x04\x04J\xcb\xb0\x08\xb4\x18o\xa3{K\xcd\xcc9\xe4`:\x1a3!\xc8\xaa\x98\xa3z\x85\xd56}-\xce\xc39\x9aG\x86\x8df\x00v\xb2\x14 \x00\x01\x02\xe0\x04/8x<\xd5\x03\xa3\xc1{\xc7\xa2hS\x1e\x87q\xba\xb1\xd5\x1d<\xfc\xba/\x0b\x047\xfe\x1f\xfb\xef\x22i\x8f\x9f\x00\xf6\x00\x00\x00\x00IEND\xaeB`\x82",
+        true,
+    ),
+    (
+        "QTE",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00%\x00\x00\x00\r\x08\x00\x00\x00\x00\x0b\n\xf8\x84\x00\x00\x01\xb0IDAT\x18\x19\x1d\xc1\xddKSq\x1c\x07\xe0\xcf\xe7w^\xb7SsT\x0eK\x8b\xa0\xa6\xad\x97Y\xa0\xe5\xad\x88y\x11\x04\xd1E\xd8]P\x10\xfd\x07\x92\xd7\xa2B\xd9\x8d\x17vS]\xf5\x06%\xeb&\x22j\x08\xb9\x11\xe1E\x0cDz!\x90\x1cE0\xda:k\xe7\xfc\xce\xce7\xd7\xf3\xf0I\xd5k\xf5\x96\xbbGi\x1b\x90?\x1a\x80\x88\x80R\xaf\xf6\xec\x16\x80T\xd3\x13E\xbe\x89\xc2\x9do_/*\x07\xdbX\xd7\x10\x81\x00\x8ccG@(X\xcfv\xbc\xe2{g\xf3\xf1p\xef\x11\x87\xe8\x88k\x02\x08\x84 A\x00\x8a*\\\xfa\xcb\xcaSg<\xb92f)\x88\x08X\x0f\x05\xff\x11\x80\xb2\x8cH`\xde\xbc\xc8\xf2\xcf\xbb\xd7\xc3\xac\xe7?\xec\xdb\x95O\xc6l6\x05\x02\x10\x84x.\x80@\x9b\x0f&\xb8\xfc}\xdf\xbd\xaei\x8b\xfe\xadj\xff\xd9\xe3\xa2\x7fC\xb0\x8d\x90\x94-\x80\xb0\x85Gd\xa1\x11g\xe7\xe7\x0c\xc0\\\xb9\xdf\x7f\xe1P\xbbF@@H\xc2\x03 \x10\x04\xc5Y\x96\xe0\'\x16fL*\xa8\xb5\xf9+c\xba\x0eA\x87J\x13\x14\x08\x10lMr5lf\xa6\x16M)\x7f\xd4\xb2:8\x15\xf8\x80\x00\x10\xcf5\x10\xc2`\x0c\xfd\xae\xc4\x97\x9f\xf4\xe8\xa5\x82\r\x85x\xfdy\xe6Z+\xa0\x8el\x00)\xbb\xb4\xfe\xc3\xd9\x9f\xcb\x1a\xfa\xc50\x176S\xf9\xa5\xdb.\xd1!\xca\xc7\xd7/=a\x1e\xaa\xabR\xf8\x10\r\xe5\xbc\x91t\xb8v\x92\xe72}Fc\xd2M\x10\x1d\xba\xbdQI\xee\xad\x1dK;\xe6\x9d\x8d\xabg\x18C\xd8,\x9e\xe0\xc8\xd1\\\xb5q\xe0|\xdbr)Q[\x95>\x1f\xfe\xb5\xe7\xdb\xc1\xd3vtC\x9f\x1a\x18w\x00l]n\xfd\x03\xe2\xea\xb7\xd9\xb9\x0e\xe3u\x00\x00\x00\x00IEND\xaeB`\x82",
+        false,
+    ),
+    (
+        "COIN_FIVE",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x0f\x00\x00\x00\x0f\x08\x02\x00\x00\x00\xb4\xb4\x02\x1d\x00\x00\x01\xf6IDAT(\x15U\xc1KH\x14\x01\x18\xc0\xf1\xff73;3\xbb\xeb\xa4\xd8\x8a\xaf\rt\xdf\n\xa1]\n/I\x97\xbaE\x1bt\x90\x82\x82\x02;\x84\x10\xd8\xa9\x9b\x87\x02\x0f]<D\x05\x11Q\x87\n\xe9\xe4\xa5\xb0C\xa4\x1d\x82@:t1BzJ\x89/\xdcu\xdd\x9d\xf9\x9a\xdd\x1e\xe4\xef\'=\xa9\x04\xc2.J\x9d\xb0\x8b\xa2 =\xe9\x04\x08\xc2\x1f\xaa\xd4\t\x82\x80\xd2\xa0\x80\xa2Ho:\x01\x82P\x1c\xdc>y\xa0\x02J\x9d\x00\xcb\x1b\xc6\xf8\xb4GH\x01U\x90\xdeL\x1b\x080q|\xf3\xcc\xc12\xffYZ1\x86\xae\xefq\xa36\xaa\x84T$\x95m\xa7No\x8e\xac\x1f\xed\xaf\x9c\xbe\x1d[\xf8d\xd2P\xf3u\xb5d4y.\x22\x80*\x92\xca\xb5\x13R\x9d\x1e]\x1dLV\x0bW\xbdr\xe0\x98\x96AH\x15\x10\x11D\x00U$\x95\xef@\x01\x9d\xbf\xf2\xa3\xdd\x0b\xae\xcd8\xc3\x05\xf5U\xe6>\xd8\xf7^\xc7\xfc\x80:\x11@\x15I\xe7:hX\x9c\xf8\x0elWy\xf7\xc5\xda\xdf\xed\xbb\x11}\xb5\x189\xff0\xe1\xfbJ\x83*\x92\xcew\x00\xa6!\xe7\x86\xb6\x0e\xed+\x8d?r?\xaf\x9a\xd9N\x9d\x19\xdbl\x89\xe9\xd9\xbb\xde\xdcR\x13(\xa1\x00\xc9\xe4;\xf9Mdg\xbbZ\xab\xf9\xaek\x1b\xa6L\x16\xd7\x8a\x83\xe5\xa9Y\xe7\xc6\x8b\x16+b\x00\x1a \x99B\x17\xd0\x1a\x0f\x0eg*\xa5\x1dy\xf6\xde\xa5a\xb2\xb8V\x1c(M\xcd:\x93\xcf=\xd7\xb5\t)\x92-t\x83\xb6y\xfe\xcb\xcb\xcb\xa6\xc1\xa9;\xad\x0b_\xa3={\xabO.\xfclv\x83\x91[\xf1\xb9\x8fQ7j\x83h\x80d\xfb\x92\xa0(\x97\x867\xc6\x8el\xd6\x02\xbe\xad\x9b]\xcd\xbei\xf0\xf4\xad}\xf1~,\xde\xe4Z\x11\x13\x11\r\x90\\\x7f\x92\xbf\x8e\xe57N\x0c\x94\xd3m\xfe\xca\x96\xf1\xf8\x8d\xfd`\xde\x11\xcb\x8a\xc5\x1d\x1aT\x91\\_\x12\xe1\x9f\xeaN\xadR\xa9i\x10\x88!\xb6m\xd9N\x84\x90\x12\n\x94_\x22\x06\xbc\xac\t\xff\xd6\x1c\x00\x00\x00\x00IEND\xaeB`\x82",
+        true,
+    ),
+    (
+        "COIN_ONE",
+        b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x00\x00\x00\x0f\x00\x00\x00\x0f\x08\x02\x00\x00\x00\xb4\xb4\x02\x1d\x00\x00\x01\x8bIDAT(\x15U\xc1\xbbn\xd3\x00\x18\x86\xe1\xf7WS\x13{)CP\x03dhsj\xcb\x00\\A\x11\x88\xb6\x12\xbd\x04\x98X\x1d\x1b\xc9v\x10N2\xa3n\\\x00\x8bege@L\x0c0\x96{\x22\xc6\x1fv\x13\x0ey\x1e;\xe8w0\xb6\x88\x86\xb1E\x08\xec`\xd0\x01\xc3\xd8\x90h\x18\xc6?\x02\x84\xb0\xc3A\x07\x0ccC\xba8\xbf|\xf5\xf2\xf5\xb7\xef_\xb3\xfc#k\x02$\xb0\xc3\xe1\x1d0j\xc6\xc5\xd9\x8b\xab\xf7\x1f\xf6\xf6n\x03\xc52K\x17\xb1\xe3\xb4\xa8I\xd4\x84\xf5G\xfb4\x04\x9c\x9f]\x86\x93\xf8\xe7j\xf5\xe8\xe1\xe3\xbc\xc8\xd2Y\xd4vw\xa9\x99\x01\xaa\xb0\xfex\x9f\x9a\x84\xc0(\xcb*\xf0\xe3$~\x97\x17Y:\x8b\xda\xee.53@\xc2\xfaG]\x04\x88\r{\x13\xc4\xe1$\xc9\x8bl6\x8f\xda\xaeC\xcd\x0c\x90\xb0\xc1\xb8\xcb\xb6p\x12\x87A\x92\x17\xd9l\x91\xb4]\x07\x89\x1b\x1268\xea\xd20\x8c\x86\x14\xfaq\x18$y\x91\xcd\x16I\xdbs\x10 j\x156<\xba\xcb\x9a\x19\x02\x14\xf8q\x18\xc4E\x91\xa5\x8b\xa9\xeb94\x04\xa8\xc2\x86\xc7\xf7\xd8\x16\xf8Q8\x89\x8be\x96\xce\xa7\xae\xe7\xf0\x97\xb0\xd1\xf1}\x10kF-\xf0\xe3\xc0\x8f\x8ae\x96\xce\xa7\x9e\xe7`\xdc0U\xd8\xe8\xa4\x07B4\x8cZ\xe0G\x81\x1f\x15\xcb,\x9d\xbf\xf5<\x07\xa3a\xa6\n\x1b?\xe8\xb1\xed\xe9\x93\xe7\xa7\xa7\xcf~\\_\x7f\xfe\xf2\xc9\xf5n\xf1\x87\x84\x8dOz\x18\xff\x93(W\xbf\xca\xb2\xdci\xed8N\x8b\x9a\xa8U\xe27\xba$\x92\xaan##\xcb\x00\x00\x00\x00IEND\xaeB`\x82",
+        true,
+    ),
+];
+
 #[cfg(test)]
 mod tests {
     use crate::dxgi_capture::show_image;
-    use crate::template_img::TemplateImg;
+    use crate::template_img::{TARGET, TemplateImg};
+
+    #[test]
+    fn test_all_templates_decode() {
+        TemplateImg::check_all().unwrap();
+        assert!(TemplateImg::embedded("MISSING").is_err());
+        assert_eq!(
+            TemplateImg::embedded_or_placeholder("MISSING").img.width(),
+            1
+        );
+        // 颜色检测用的原图保留颜色
+        assert_eq!(TARGET.img.channels(), 1);
+        assert_eq!(TARGET.color.channels(), 3);
+        assert_eq!(
+            (TARGET.color.width(), TARGET.color.height()),
+            (TARGET.img.width(), TARGET.img.height())
+        );
+    }
 
     #[test]
     fn test_decode_gray() {
-        let temp_img = TemplateImg::TARGET;
-        show_image(&temp_img.img);
+        show_image(&TARGET.img);
     }
 }
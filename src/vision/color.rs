@@ -0,0 +1,249 @@
+use crate::vision::{Backend, Image, ImageOps, Result, VisionError};
+use serde::Deserialize;
+
+/// HSV 取值范围 (OpenCV 约定, H: 0..180)
+/// * lower.0 > upper.0 时色相跨过 0, 例如红色 (170, ..) ~ (10, ..)
+/// * 配置文件中写作 { lower = [10, 128, 128], upper = [30, 255, 255] }
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HsvRange {
+    pub lower: (u8, u8, u8),
+    pub upper: (u8, u8, u8),
+}
+
+impl HsvRange {
+    /// 配置文件中的范围由 serde 构造, 这里用于在代码中给出常量
+    #[allow(dead_code)]
+    pub const fn new(lower: (u8, u8, u8), upper: (u8, u8, u8)) -> Self {
+        Self { lower, upper }
+    }
+
+    pub fn contains(&self, h: u8, s: u8, v: u8) -> bool {
+        let hue_ok = if self.lower.0 <= self.upper.0 {
+            (self.lower.0..=self.upper.0).contains(&h)
+        } else {
+            h >= self.lower.0 || h <= self.upper.0
+        };
+        hue_ok
+            && (self.lower.1..=self.upper.1).contains(&s)
+            && (self.lower.2..=self.upper.2).contains(&v)
+    }
+}
+
+/// HSV 图中落在 range 内的像素比例
+pub fn in_range_ratio(hsv: &Image, range: &HsvRange) -> f64 {
+    let mut count = 0usize;
+    for y in 0..hsv.height() {
+        count += hsv
+            .row(y)
+            .chunks_exact(3)
+            .filter(|px| range.contains(px[0], px[1], px[2]))
+            .count();
+    }
+    count as f64 / (hsv.width() * hsv.height()) as f64
+}
+
+/// H-S 二维直方图 (不含亮度, 对明暗/泛光变化不敏感), 已归一化为总和 1
+pub fn hs_histogram(hsv: &Image, h_bins: usize, s_bins: usize) -> Vec<f64> {
+    let mut hist = vec![0f64; h_bins * s_bins];
+    for y in 0..hsv.height() {
+        for px in hsv.row(y).chunks_exact(3) {
+            let h = (px[0] as usize * h_bins / 180).min(h_bins - 1);
+            let s = px[1] as usize * s_bins / 256;
+            hist[h * s_bins + s] += 1.0;
+        }
+    }
+    let total = (hsv.width() * hsv.height()) as f64;
+    hist.iter_mut().for_each(|v| *v /= total);
+    hist
+}
+
+/// 直方图交集 (HISTCMP_INTERSECT), 两个归一化直方图完全一致时为 1
+pub fn histogram_intersection(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a.min(*b)).sum()
+}
+
+/// 颜色判定方式, 配置文件中写作 { in_range = { .. } } 或 { histogram = { .. } }
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ColorRule {
+    /// 落在 range 内的像素比例 >= min_ratio
+    InRange { range: HsvRange, min_ratio: f64 },
+    /// 与模板的 H-S 直方图交集 >= min_similarity, 模板需为彩色图
+    Histogram {
+        h_bins: usize,
+        s_bins: usize,
+        min_similarity: f64,
+    },
+}
+
+impl ColorRule {
+    /// 颜色得分, shot 为未经二值化的彩色截图
+    pub fn score(&self, shot: &Image, template: &Image) -> Result<f64> {
+        let hsv = Backend::to_hsv(shot)?;
+        match self {
+            ColorRule::InRange { range, .. } => Ok(in_range_ratio(&hsv, range)),
+            ColorRule::Histogram { h_bins, s_bins, .. } => {
+                if template.channels() == 1 {
+                    return Err(VisionError::new(
+                        "Histogram color rule needs a color template",
+                    ));
+                }
+                let templ_hsv = Backend::to_hsv(template)?;
+                Ok(histogram_intersection(
+                    &hs_histogram(&hsv, *h_bins, *s_bins),
+                    &hs_histogram(&templ_hsv, *h_bins, *s_bins),
+                ))
+            }
+        }
+    }
+
+    pub fn threshold(&self) -> f64 {
+        match self {
+            ColorRule::InRange { min_ratio, .. } => *min_ratio,
+            ColorRule::Histogram { min_similarity, .. } => *min_similarity,
+        }
+    }
+}
+
+impl std::fmt::Display for ColorRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorRule::InRange { range, min_ratio } => write!(
+                f,
+                "in_range({:?}~{:?}, >= {})",
+                range.lower, range.upper, min_ratio
+            ),
+            ColorRule::Histogram {
+                h_bins,
+                s_bins,
+                min_similarity,
+            } => write!(
+                f,
+                "hs_histogram({}x{}, >= {})",
+                h_bins, s_bins, min_similarity
+            ),
+        }
+    }
+}
+
+/// SSIM (或模板匹配分数) 与颜色得分的组合方式, 配置文件中写作 "all", "any" 或 { weighted = { .. } }
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Combine {
+    /// 两者都达到各自阈值
+    All,
+    /// 任意一个达到阈值
+    Any,
+    /// ssim_weight * score + (1 - ssim_weight) * color > threshold, score 需越大越好
+    Weighted { ssim_weight: f64, threshold: f64 },
+}
+
+impl Combine {
+    /// score/passed 为 SSIM 或模板匹配的分数和单独判定的结果
+    pub fn decide(&self, score: f64, passed: bool, color: f64, color_threshold: f64) -> bool {
+        match self {
+            Combine::All => passed && color >= color_threshold,
+            Combine::Any => passed || color >= color_threshold,
+            Combine::Weighted {
+                ssim_weight,
+                threshold,
+            } => ssim_weight * score + (1.0 - ssim_weight) * color > *threshold,
+        }
+    }
+}
+
+impl std::fmt::Display for Combine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combine::All => write!(f, "all"),
+            Combine::Any => write!(f, "any"),
+            Combine::Weighted {
+                ssim_weight,
+                threshold,
+            } => write!(f, "weighted({}, > {})", ssim_weight, threshold),
+        }
+    }
+}
+
+/// 某个区域的颜色检测配置
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ColorCheck {
+    pub rule: ColorRule,
+    pub combine: Combine,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture_settings::CapturePos;
+    use crate::template_img;
+    use crate::vision::ReadMode;
+
+    /// QTE 提示的橙色
+    const QTE_ORANGE: HsvRange = HsvRange::new((10, 128, 128), (30, 255, 255));
+
+    fn qte_shot(path: &str) -> Image {
+        let img = Image::load(path, ReadMode::Unchanged).unwrap();
+        img.roi(CapturePos::qte(0, 0).rect).unwrap()
+    }
+
+    #[test]
+    fn test_hue_range_wraps_around_zero() {
+        let red = HsvRange::new((170, 100, 100), (10, 255, 255));
+        assert!(red.contains(175, 200, 200));
+        assert!(red.contains(5, 200, 200));
+        assert!(!red.contains(60, 200, 200));
+        assert!(!red.contains(5, 50, 200));
+    }
+
+    #[test]
+    fn test_in_range_separates_qte_screenshot() {
+        let rule = ColorRule::InRange {
+            range: QTE_ORANGE,
+            min_ratio: 0.05,
+        };
+        let templ = &template_img::QTE.img;
+        let qte = rule.score(&qte_shot("test_data/qte.jpg"), templ).unwrap();
+        let target = rule
+            .score(&qte_shot("test_data/target.jpg"), templ)
+            .unwrap();
+        assert!(qte >= rule.threshold(), "qte: {}", qte);
+        assert!(target < rule.threshold(), "target: {}", target);
+    }
+
+    #[test]
+    fn test_histogram_ignores_brightness() {
+        let shot = qte_shot("test_data/qte.jpg");
+        // 整体变暗 30%, 色相/饱和度基本不变
+        let darker = shot
+            .to_vec()
+            .iter()
+            .map(|v| (*v as f64 * 0.7) as u8)
+            .collect();
+        let darker = Image::from_vec(shot.width(), shot.height(), 3, darker).unwrap();
+        let rule = ColorRule::Histogram {
+            h_bins: 18,
+            s_bins: 8,
+            min_similarity: 0.8,
+        };
+        let sim = rule.score(&darker, &shot).unwrap();
+        assert!(sim >= rule.threshold(), "sim: {}", sim);
+        let gray = Backend::to_gray(&shot).unwrap();
+        assert!(rule.score(&shot, &gray).is_err());
+    }
+
+    #[test]
+    fn test_combine() {
+        assert!(Combine::All.decide(0.95, true, 0.2, 0.1));
+        assert!(!Combine::All.decide(0.95, true, 0.05, 0.1));
+        assert!(Combine::Any.decide(0.5, false, 0.2, 0.1));
+        let weighted = Combine::Weighted {
+            ssim_weight: 0.5,
+            threshold: 0.6,
+        };
+        assert!(weighted.decide(0.8, false, 0.5, 0.1));
+        assert!(!weighted.decide(0.6, false, 0.5, 0.1));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::vision::{Image, Result};
+
+/// BGR 颜色
+pub type Color = [u8; 3];
+
+pub const GREEN: Color = [0, 200, 0];
+pub const RED: Color = [0, 0, 230];
+pub const WHITE: Color = [255, 255, 255];
+pub const BLACK: Color = [0, 0, 0];
+
+/// 字符宽高 (像素, 缩放前), 字符之间留 1 像素
+pub const GLYPH_WIDTH: i32 = 5;
+pub const GLYPH_HEIGHT: i32 = 7;
+
+///
+/// 5x7 点阵字体, 每行低 5 位从左到右为像素
+///
+/// * 只有数字, 大写字母和常用符号, 小写字母按大写绘制, 其余字符画成 '?'
+///
+fn glyph(c: char) -> [u8; 7] {
+    match c.to_ascii_uppercase() {
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'A' => [0x0E, 0x11, 0x11, 0x11, 0x1F, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        ' ' => [0; 7],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F],
+        '+' => [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00],
+        '=' => [0x00, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x00],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        '[' => [0x0E, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0E],
+        ']' => [0x0E, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0E],
+        '<' => [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02],
+        '>' => [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08],
+        '|' => [0x04; 7],
+        '%' => [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+    }
+}
+
+/// 单行文字的宽高
+pub fn text_size(text: &str, scale: i32) -> (i32, i32) {
+    let scale = scale.max(1);
+    let chars = text.chars().count() as i32;
+    (
+        (chars * (GLYPH_WIDTH + 1) - 1).max(0) * scale,
+        GLYPH_HEIGHT * scale,
+    )
+}
+
+///
+/// 可修改的 BGR 画布, 用于在截图上标注检测区域
+///
+/// * 超出画布的部分直接裁掉, 不报错
+///
+pub struct Canvas {
+    width: i32,
+    height: i32,
+    data: Vec<u8>,
+}
+
+impl Canvas {
+    /// 拷贝 img 的像素, 灰度和 BGRA 转为 BGR
+    pub fn from_image(img: &Image) -> Self {
+        let channels = img.channels() as usize;
+        let mut data = Vec::with_capacity((img.width() * img.height() * 3) as usize);
+        for y in 0..img.height() {
+            for px in img.row(y).chunks_exact(channels) {
+                match channels {
+                    1 => data.extend_from_slice(&[px[0]; 3]),
+                    _ => data.extend_from_slice(&px[..3]),
+                }
+            }
+        }
+        Self {
+            width: img.width(),
+            height: img.height(),
+            data,
+        }
+    }
+
+    fn put(&mut self, x: i32, y: i32, color: Color) {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return;
+        }
+        let i = ((y * self.width + x) * 3) as usize;
+        self.data[i..i + 3].copy_from_slice(&color);
+    }
+
+    pub fn fill_rect(&mut self, rect: (i32, i32, i32, i32), color: Color) {
+        let (left, top, width, height) = rect;
+        for y in top.max(0)..(top + height).min(self.height) {
+            for x in left.max(0)..(left + width).min(self.width) {
+                self.put(x, y, color);
+            }
+        }
+    }
+
+    /// 矩形边框画在 rect 外侧, 不遮挡区域内的像素
+    pub fn draw_rect(&mut self, rect: (i32, i32, i32, i32), color: Color, thickness: i32) {
+        let (left, top, width, height) = rect;
+        let t = thickness.max(1);
+        self.fill_rect((left - t, top - t, width + 2 * t, t), color);
+        self.fill_rect((left - t, top + height, width + 2 * t, t), color);
+        self.fill_rect((left - t, top, t, height), color);
+        self.fill_rect((left + width, top, t, height), color);
+    }
+
+    /// 单行文字, (x, y) 为左上角, 每个点放大为 scale x scale
+    pub fn draw_text(&mut self, x: i32, y: i32, text: &str, color: Color, scale: i32) {
+        let scale = scale.max(1);
+        for (i, c) in text.chars().enumerate() {
+            let left = x + i as i32 * (GLYPH_WIDTH + 1) * scale;
+            for (row, bits) in glyph(c).iter().enumerate() {
+                for col in 0..GLYPH_WIDTH {
+                    if bits >> (GLYPH_WIDTH - 1 - col) & 1 == 1 {
+                        self.fill_rect(
+                            (left + col * scale, y + row as i32 * scale, scale, scale),
+                            color,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// 带背景的文字, 背景比文字四周各大 scale 像素
+    pub fn draw_label(
+        &mut self,
+        x: i32,
+        y: i32,
+        text: &str,
+        color: Color,
+        background: Color,
+        scale: i32,
+    ) {
+        let scale = scale.max(1);
+        let (width, height) = text_size(text, scale);
+        self.fill_rect((x, y, width + 2 * scale, height + 2 * scale), background);
+        self.draw_text(x + scale, y + scale, text, color, scale);
+    }
+
+    pub fn into_image(self) -> Result<Image> {
+        Image::from_vec(self.width, self.height, 3, self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_is_drawn_outside_and_clipped() {
+        let gray = Image::from_vec(6, 6, 1, vec![9; 36]).unwrap();
+        let mut canvas = Canvas::from_image(&gray);
+        canvas.draw_rect((2, 2, 2, 2), RED, 1);
+        // 超出画布
+        canvas.draw_rect((5, 0, 3, 1), GREEN, 1);
+        let img = canvas.into_image().unwrap();
+        let px = |x, y| [img.at(x, y, 0), img.at(x, y, 1), img.at(x, y, 2)];
+        assert_eq!(px(1, 1), RED);
+        assert_eq!(px(4, 3), RED);
+        assert_eq!(px(2, 2), [9, 9, 9]);
+        assert_eq!(px(0, 0), [9, 9, 9]);
+        assert_eq!(px(4, 0), GREEN);
+    }
+
+    #[test]
+    fn test_text_glyphs() {
+        assert_eq!(text_size("AB", 2), (22, 14));
+        assert_eq!(text_size("", 1), (0, 7));
+        let gray = Image::from_vec(20, 10, 1, vec![0; 200]).unwrap();
+        let mut canvas = Canvas::from_image(&gray);
+        // '1' 第一行只有中间一个点, 最后一行是 3 个点
+        canvas.draw_text(1, 1, "1", WHITE, 1);
+        // 小写按大写绘制, 超出画布的部分被裁掉
+        canvas.draw_label(8, 1, "l-", RED, BLACK, 1);
+        canvas.draw_text(18, 5, "W", GREEN, 2);
+        let img = canvas.into_image().unwrap();
+        let px = |x, y| [img.at(x, y, 0), img.at(x, y, 1), img.at(x, y, 2)];
+        assert_eq!(px(3, 1), WHITE);
+        assert_eq!(px(1, 1), BLACK);
+        assert_eq!(px(2, 7), WHITE);
+        assert_eq!(px(4, 7), WHITE);
+        assert_eq!(px(5, 7), BLACK);
+        // 'L' 的竖线在背景内偏移 1 像素
+        assert_eq!(px(9, 2), RED);
+        assert_eq!(px(8, 1), BLACK);
+        assert_eq!(px(18, 5), GREEN);
+    }
+}
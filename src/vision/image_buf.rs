@@ -0,0 +1,286 @@
+use crate::vision::{Result, VisionError};
+use image::{DynamicImage, ImageFormat};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 读取图片时的通道处理方式, 对应 opencv 的 IMREAD_UNCHANGED / IMREAD_GRAYSCALE
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReadMode {
+    Unchanged,
+    Grayscale,
+}
+
+///
+/// 后端无关的 8 位图像, 通道顺序与 opencv 一致 (1: gray, 3: BGR, 4: BGRA)
+///
+/// * 像素数据通过 Arc 共享, `roi` 返回的子图不拷贝像素
+/// * step 为每行字节数, offset 为左上角像素在 data 中的位置
+///
+#[derive(Clone)]
+pub struct Image {
+    data: Arc<Vec<u8>>,
+    width: i32,
+    height: i32,
+    channels: i32,
+    step: usize,
+    offset: usize,
+}
+
+impl Image {
+    pub fn from_vec(width: i32, height: i32, channels: i32, data: Vec<u8>) -> Result<Self> {
+        if width <= 0 || height <= 0 || !matches!(channels, 1 | 3 | 4) {
+            return Err(VisionError::new(format!(
+                "Invalid image shape: {}x{}x{}",
+                width, height, channels
+            )));
+        }
+        let step = (width * channels) as usize;
+        if data.len() != step * height as usize {
+            return Err(VisionError::new(format!(
+                "Image data length {} does not match {}x{}x{}",
+                data.len(),
+                width,
+                height,
+                channels
+            )));
+        }
+        Ok(Self {
+            data: Arc::new(data),
+            width,
+            height,
+            channels,
+            step,
+            offset: 0,
+        })
+    }
+
+    /// 1x1 全 0 的单通道图片, 用作无法加载的图片的占位
+    pub fn placeholder() -> Self {
+        Self {
+            data: Arc::new(vec![0]),
+            width: 1,
+            height: 1,
+            channels: 1,
+            step: 1,
+            offset: 0,
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn channels(&self) -> i32 {
+        self.channels
+    }
+
+    pub fn is_continuous(&self) -> bool {
+        self.step == (self.width * self.channels) as usize
+    }
+
+    /// 每行字节数, 子图 (roi) 沿用原图的行宽
+    #[cfg_attr(not(feature = "opencv-backend"), allow(dead_code))]
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// 从左上角像素到右下角像素的字节, 行与行之间按 step 排列, 用于把像素借给后端而不拷贝
+    #[cfg_attr(not(feature = "opencv-backend"), allow(dead_code))]
+    pub fn pixels(&self) -> &[u8] {
+        let len = (self.height as usize - 1) * self.step + (self.width * self.channels) as usize;
+        &self.data[self.offset..self.offset + len]
+    }
+
+    /// 第 y 行的像素字节
+    pub fn row(&self, y: i32) -> &[u8] {
+        let start = self.offset + y as usize * self.step;
+        &self.data[start..start + (self.width * self.channels) as usize]
+    }
+
+    /// (x, y) 处第 c 个通道的值
+    pub fn at(&self, x: i32, y: i32, c: i32) -> u8 {
+        self.row(y)[(x * self.channels + c) as usize]
+    }
+
+    /// 按 (left, top, width, height) 截取子图, 与原图共享像素数据
+    pub fn roi(&self, rect: (i32, i32, i32, i32)) -> Result<Self> {
+        let (left, top, width, height) = rect;
+        if left < 0
+            || top < 0
+            || width <= 0
+            || height <= 0
+            || left + width > self.width
+            || top + height > self.height
+        {
+            return Err(VisionError::new(format!(
+                "Roi {:?} out of image bounds {}x{}",
+                rect, self.width, self.height
+            )));
+        }
+        Ok(Self {
+            data: self.data.clone(),
+            width,
+            height,
+            channels: self.channels,
+            step: self.step,
+            offset: self.offset + top as usize * self.step + (left * self.channels) as usize,
+        })
+    }
+
+    /// 拷贝出连续的像素数据 (行与行之间没有间隔)
+    pub fn to_vec(&self) -> Vec<u8> {
+        if self.is_continuous() && self.offset == 0 && self.data.len() == self.step * self.height as usize {
+            return self.data.as_ref().clone();
+        }
+        let mut out = Vec::with_capacity((self.width * self.height * self.channels) as usize);
+        for y in 0..self.height {
+            out.extend_from_slice(self.row(y));
+        }
+        out
+    }
+
+    /// 只含本图像素的图片, 子图 (roi) 拷贝一份, 不再引用原图的数据
+    /// * 需要长时间保留子图 (如排队写盘) 时使用, 避免整帧无法回收
+    pub fn compact(&self) -> Self {
+        if self.offset == 0 && self.is_continuous() && self.data.len() == self.step * self.height as usize {
+            return self.clone();
+        }
+        Self {
+            data: Arc::new(self.to_vec()),
+            width: self.width,
+            height: self.height,
+            channels: self.channels,
+            step: (self.width * self.channels) as usize,
+            offset: 0,
+        }
+    }
+
+    /// 取回像素缓冲区以便复用, 仍有子图 (roi) 共享数据或本身是子图时返回 None
+    pub fn try_into_vec(self) -> Option<Vec<u8>> {
+        if self.offset != 0 || !self.is_continuous() {
+            return None;
+        }
+        Arc::try_unwrap(self.data).ok()
+    }
+
+    /// 从 dxgi 抓取的 BGRA 数据去掉 alpha 通道
+    pub fn bgra_to_bgr(&self) -> Result<Self> {
+        if self.channels != 4 {
+            return Err(VisionError::new(format!(
+                "Expect 4 channels image, got {}",
+                self.channels
+            )));
+        }
+        let mut out = Vec::with_capacity((self.width * self.height * 3) as usize);
+        for y in 0..self.height {
+            for px in self.row(y).chunks_exact(4) {
+                out.extend_from_slice(&px[..3]);
+            }
+        }
+        Self::from_vec(self.width, self.height, 3, out)
+    }
+
+    /// 解码 png/jpg 等图片数据
+    pub fn decode(bytes: &[u8], mode: ReadMode) -> Result<Self> {
+        let img = image::load_from_memory(bytes)?;
+        Self::from_dynamic(img, mode)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P, mode: ReadMode) -> Result<Self> {
+        let img = image::open(path)?;
+        Self::from_dynamic(img, mode)
+    }
+
+    fn from_dynamic(img: DynamicImage, mode: ReadMode) -> Result<Self> {
+        let (width, height) = (img.width() as i32, img.height() as i32);
+        if mode == ReadMode::Grayscale {
+            return Self::from_vec(width, height, 1, img.into_luma8().into_raw());
+        }
+        match img {
+            DynamicImage::ImageLuma8(gray) => Self::from_vec(width, height, 1, gray.into_raw()),
+            img if img.color().has_alpha() => {
+                let mut data = img.into_rgba8().into_raw();
+                data.chunks_exact_mut(4).for_each(|px| px.swap(0, 2));
+                Self::from_vec(width, height, 4, data)
+            }
+            img => {
+                let mut data = img.into_rgb8().into_raw();
+                data.chunks_exact_mut(3).for_each(|px| px.swap(0, 2));
+                Self::from_vec(width, height, 3, data)
+            }
+        }
+    }
+
+    /// 转成 image crate 的图片 (BGR -> RGB)
+    pub fn to_dynamic(&self) -> Result<DynamicImage> {
+        let (width, height) = (self.width as u32, self.height as u32);
+        let mut data = self.to_vec();
+        let img = match self.channels {
+            1 => image::GrayImage::from_raw(width, height, data).map(DynamicImage::ImageLuma8),
+            3 => {
+                data.chunks_exact_mut(3).for_each(|px| px.swap(0, 2));
+                image::RgbImage::from_raw(width, height, data).map(DynamicImage::ImageRgb8)
+            }
+            _ => {
+                data.chunks_exact_mut(4).for_each(|px| px.swap(0, 2));
+                image::RgbaImage::from_raw(width, height, data).map(DynamicImage::ImageRgba8)
+            }
+        };
+        img.ok_or_else(|| VisionError::new("Failed to convert image buffer"))
+    }
+
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.to_dynamic()?
+            .save_with_format(path, ImageFormat::Png)?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Image({}x{}x{})",
+            self.width, self.height, self.channels
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roi_shares_pixels() {
+        let data: Vec<u8> = (0..4 * 3).map(|v| v as u8).collect();
+        let img = Image::from_vec(4, 3, 1, data).unwrap();
+        let roi = img.roi((1, 1, 2, 2)).unwrap();
+        assert_eq!(roi.to_vec(), vec![5, 6, 9, 10]);
+        assert_eq!((roi.step(), roi.pixels()), (4, &[5, 6, 7, 8, 9, 10][..]));
+        assert!(img.roi((3, 0, 2, 1)).is_err());
+        assert!(roi.clone().try_into_vec().is_none());
+        // 子图还在时不能取回缓冲区
+        assert!(img.clone().try_into_vec().is_none());
+        // 拷贝后的子图不再占用原图
+        let compact = roi.compact();
+        drop(roi);
+        assert_eq!(compact.try_into_vec(), Some(vec![5, 6, 9, 10]));
+        assert_eq!(img.try_into_vec().map(|v| v.len()), Some(12));
+    }
+
+    #[test]
+    fn test_decode_keeps_bgr_order() {
+        let mut rgb = image::RgbImage::new(1, 1);
+        rgb.put_pixel(0, 0, image::Rgb([10, 20, 30]));
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(rgb)
+            .write_to(&mut bytes, ImageFormat::Png)
+            .unwrap();
+        let img = Image::decode(bytes.get_ref(), ReadMode::Unchanged).unwrap();
+        assert_eq!(img.to_vec(), vec![30, 20, 10]);
+    }
+}
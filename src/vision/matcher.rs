@@ -0,0 +1,152 @@
+use crate::vision::{Backend, Image, ImageOps, MatchMethod, Result};
+use log::debug;
+
+/// 多尺度匹配结果
+/// * location: 匹配位置左上角, 已换算回原图 (未缩放的截图) 坐标
+/// * scale: 最佳匹配时截图的缩放比例
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleMatch {
+    pub score: f64,
+    pub location: (i32, i32),
+    pub scale: f64,
+}
+
+///
+/// 在一组缩放比例上对截图做模板匹配, 取所有比例中最好的结果
+///
+/// 模板是在某一分辨率下截取的, 截图按 scales 依次缩放后与模板比较,
+/// 因此游戏窗口/UI 缩放改变时只需调整 scales
+///
+#[derive(Debug, Clone)]
+pub struct MultiScaleMatcher {
+    pub scales: Vec<f64>,
+    pub method: MatchMethod,
+    pub threshold: f64,
+}
+
+impl MultiScaleMatcher {
+    pub fn new(scales: Vec<f64>, method: MatchMethod, threshold: f64) -> Self {
+        Self {
+            scales,
+            method,
+            threshold,
+        }
+    }
+
+    /// 以 center 为中心, 每层相差 step, 上下各 levels 层的缩放比例
+    pub fn pyramid(center: f64, step: f64, levels: u32) -> Vec<f64> {
+        let mut scales = vec![center];
+        for i in 1..=levels {
+            let delta = step * i as f64;
+            scales.push(center + delta);
+            if center - delta > 0.0 {
+                scales.push(center - delta);
+            }
+        }
+        scales
+    }
+
+    ///
+    /// 返回所有缩放比例中最好的匹配
+    ///
+    /// * 缩放后比模板小的比例会被跳过, 全部被跳过时返回 None
+    ///
+    pub fn find(&self, img: &Image, templ: &Image) -> Result<Option<ScaleMatch>> {
+        let mut best: Option<ScaleMatch> = None;
+        for &scale in &self.scales {
+            let scaled = if (scale - 1.0).abs() < f64::EPSILON {
+                img.clone()
+            } else {
+                Backend::resize(img, scale, scale)?
+            };
+            if scaled.width() < templ.width() || scaled.height() < templ.height() {
+                continue;
+            }
+            let res = Backend::match_template(&scaled, templ, self.method)?;
+            debug!(
+                "Match scale: {:.3}, {}: {:.6}, location: {:?}",
+                scale, self.method, res.score, res.location
+            );
+            if best.is_none_or(|b| self.method.is_better(res.score, b.score)) {
+                best = Some(ScaleMatch {
+                    score: res.score,
+                    location: (
+                        (res.location.0 as f64 / scale).round() as i32,
+                        (res.location.1 as f64 / scale).round() as i32,
+                    ),
+                    scale,
+                });
+            }
+        }
+        Ok(best)
+    }
+
+    /// 匹配结果是否达到阈值
+    pub fn is_match(&self, m: &ScaleMatch) -> bool {
+        self.method.passes(m.score, self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capture_settings::CapturePos;
+    use crate::template_img;
+    use crate::vision::ReadMode;
+
+    fn qte_matcher(method: MatchMethod, threshold: f64) -> MultiScaleMatcher {
+        MultiScaleMatcher::new(MultiScaleMatcher::pyramid(0.5, 0.05, 3), method, threshold)
+    }
+
+    #[test]
+    fn test_pyramid_skips_non_positive_scales() {
+        let scales = MultiScaleMatcher::pyramid(0.1, 0.05, 3);
+        assert!(scales.iter().all(|s| *s > 0.0));
+        assert_eq!(scales[0], 0.1);
+        assert_eq!(scales.len(), 5);
+    }
+
+    #[test]
+    fn test_find_qte_in_test_data() {
+        let img = Image::load("test_data/qte.jpg", ReadMode::Grayscale).unwrap();
+        let shot = img.roi(CapturePos::qte(0, 0).rect).unwrap();
+        for (method, threshold) in [
+            (MatchMethod::CcorrNormed, 0.99),
+            (MatchMethod::CcoeffNormed, 0.8),
+            (MatchMethod::SqdiffNormed, 0.05),
+        ] {
+            let matcher = qte_matcher(method, threshold);
+            let m = matcher
+                .find(&shot, &template_img::QTE.img)
+                .unwrap()
+                .unwrap();
+            assert!(matcher.is_match(&m), "{}: {:?}", method, m);
+            assert!((m.scale - 0.5).abs() < 0.06, "{}: {:?}", method, m);
+        }
+    }
+
+    #[test]
+    fn test_no_qte_in_target_screenshot() {
+        let img = Image::load("test_data/target.jpg", ReadMode::Grayscale).unwrap();
+        let shot = img.roi(CapturePos::qte(0, 0).rect).unwrap();
+        let matcher = qte_matcher(MatchMethod::CcoeffNormed, 0.8);
+        let m = matcher
+            .find(&shot, &template_img::QTE.img)
+            .unwrap()
+            .unwrap();
+        assert!(!matcher.is_match(&m), "{:?}", m);
+    }
+
+    #[test]
+    fn test_too_small_scales_are_skipped() {
+        let img = Image::load("test_data/qte.jpg", ReadMode::Grayscale).unwrap();
+        let shot = img.roi(CapturePos::qte(0, 0).rect).unwrap();
+        let matcher = MultiScaleMatcher::new(vec![0.1], MatchMethod::CcorrNormed, 0.99);
+        assert!(
+            matcher
+                .find(&shot, &template_img::QTE.img)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
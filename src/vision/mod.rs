@@ -0,0 +1,188 @@
+//!
+//! 图像处理后端
+//!
+//! * `opencv-backend` (默认): 通过 opencv crate 调用本机 OpenCV
+//! * `pure-rust`: 基于 image crate 的纯 Rust 实现, 开启后优先使用, 不再依赖 OpenCV
+//!
+//! 业务代码只使用 [`Image`] 与 [`Backend`], 不直接接触具体后端
+//!
+pub mod color;
+pub mod draw;
+mod image_buf;
+mod matcher;
+#[cfg(feature = "opencv-backend")]
+pub mod opencv_ops;
+#[cfg(any(feature = "pure-rust", test))]
+pub mod pure_ops;
+
+pub use color::ColorCheck;
+pub use image_buf::{Image, ReadMode};
+pub use matcher::MultiScaleMatcher;
+use serde::Deserialize;
+
+#[cfg(not(any(feature = "opencv-backend", feature = "pure-rust")))]
+compile_error!("Either feature `opencv-backend` or `pure-rust` must be enabled");
+
+#[cfg(feature = "pure-rust")]
+pub type Backend = pure_ops::PureOps;
+#[cfg(all(feature = "opencv-backend", not(feature = "pure-rust")))]
+pub type Backend = opencv_ops::OpenCvOps;
+
+pub type Result<T> = std::result::Result<T, VisionError>;
+
+#[derive(Debug, Clone)]
+pub struct VisionError {
+    pub message: String,
+}
+
+impl VisionError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for VisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for VisionError {}
+
+impl From<image::ImageError> for VisionError {
+    fn from(e: image::ImageError) -> Self {
+        Self::new(format!("image error: {}", e))
+    }
+}
+
+#[cfg(feature = "opencv-backend")]
+impl From<opencv::Error> for VisionError {
+    fn from(e: opencv::Error) -> Self {
+        Self::new(format!("opencv error: {}", e))
+    }
+}
+
+/// 二值化方式, 自适应阈值的 block_size 需为 >= 3 的奇数
+/// * 配置文件中写作 "none", "otsu", { fixed = 190 }, { adaptive_mean = { block_size = 11, c = 2.0 } }
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThresholdMode {
+    /// 不做二值化, 只转灰度
+    None,
+    /// 固定阈值 (THRESH_BINARY)
+    Fixed(u8),
+    /// Otsu 自动阈值
+    Otsu,
+    /// 邻域均值 - c
+    AdaptiveMean { block_size: i32, c: f64 },
+    /// 邻域高斯加权均值 - c
+    AdaptiveGaussian { block_size: i32, c: f64 },
+}
+
+impl std::fmt::Display for ThresholdMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThresholdMode::None => write!(f, "none"),
+            ThresholdMode::Fixed(thresh) => write!(f, "fixed({})", thresh),
+            ThresholdMode::Otsu => write!(f, "otsu"),
+            ThresholdMode::AdaptiveMean { block_size, c } => {
+                write!(f, "adaptive_mean({}, {})", block_size, c)
+            }
+            ThresholdMode::AdaptiveGaussian { block_size, c } => {
+                write!(f, "adaptive_gaussian({}, {})", block_size, c)
+            }
+        }
+    }
+}
+
+/// 模板匹配方法, 对应 opencv 的 TM_*_NORMED
+/// * 配置文件中写作 ccorr_normed, ccoeff_normed, sqdiff_normed
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMethod {
+    CcorrNormed,
+    CcoeffNormed,
+    /// 越小越相似
+    SqdiffNormed,
+}
+
+impl MatchMethod {
+    /// a 是否比 b 更匹配
+    pub fn is_better(&self, a: f64, b: f64) -> bool {
+        match self {
+            MatchMethod::SqdiffNormed => a < b,
+            _ => a > b,
+        }
+    }
+
+    /// score 是否达到阈值 (SqdiffNormed 需小于阈值)
+    pub fn passes(&self, score: f64, threshold: f64) -> bool {
+        self.is_better(score, threshold)
+    }
+
+    /// 比任何结果都差的初始值
+    pub fn worst(&self) -> f64 {
+        match self {
+            MatchMethod::SqdiffNormed => f64::MAX,
+            _ => f64::MIN,
+        }
+    }
+}
+
+impl std::fmt::Display for MatchMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MatchMethod::CcorrNormed => "TM_CCORR_NORMED",
+            MatchMethod::CcoeffNormed => "TM_CCOEFF_NORMED",
+            MatchMethod::SqdiffNormed => "TM_SQDIFF_NORMED",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 模板匹配结果, location 为匹配位置左上角 (相对于被搜索的图片)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchResult {
+    pub score: f64,
+    pub location: (i32, i32),
+}
+
+///
+/// 识别流程中用到的图像操作, 每个后端实现一份
+///
+/// * 输入输出均为 8 位图像, 多通道图片按 BGR(A) 顺序
+/// * 各后端的结果需在一定误差内一致 (见 pure_ops 中的对比测试)
+///
+pub trait ImageOps {
+    const NAME: &'static str;
+
+    /// BGR/BGRA 转灰度, 灰度图原样返回
+    fn to_gray(img: &Image) -> Result<Image>;
+
+    /// BGR/BGRA 转 HSV (COLOR_BGR2HSV), H: 0..180, S/V: 0..255, 不支持灰度图
+    fn to_hsv(img: &Image) -> Result<Image>;
+
+    /// 二值化 (THRESH_BINARY), otsu 为 true 时忽略 thresh 自动计算阈值
+    fn threshold(img: &Image, thresh: u8, otsu: bool) -> Result<Image>;
+
+    /// 自适应二值化 (ADAPTIVE_THRESH_MEAN_C / ADAPTIVE_THRESH_GAUSSIAN_C + THRESH_BINARY)
+    /// 像素值 > 邻域均值 - c 时为 255
+    fn adaptive_threshold(img: &Image, block_size: i32, c: f64, gaussian: bool) -> Result<Image>;
+
+    /// ksize x ksize 均值滤波, 边界按 BORDER_REFLECT_101 处理
+    /// * 检测流程暂未使用, 由后端对比测试覆盖
+    #[allow(dead_code)]
+    fn blur(img: &Image, ksize: i32) -> Result<Image>;
+
+    /// 双线性缩放 (INTER_LINEAR)
+    fn resize(img: &Image, fx: f64, fy: f64) -> Result<Image>;
+
+    /// 模板匹配, 返回最佳值 (SqdiffNormed 为最小值, 其他为最大值) 及其位置
+    fn match_template(img: &Image, templ: &Image, method: MatchMethod) -> Result<MatchResult>;
+
+    /// 两张同尺寸图片的 SSIM, 多通道时取各通道均值
+    fn ssim(img1: &Image, img2: &Image) -> Result<f64>;
+}
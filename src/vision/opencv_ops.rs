@@ -0,0 +1,344 @@
+use crate::vision::{Image, ImageOps, MatchMethod, MatchResult, Result, VisionError};
+use opencv::core::AlgorithmHint::ALGO_HINT_DEFAULT;
+use opencv::core::{
+    add_weighted, divide2, mean, min_max_loc, multiply, no_array, subtract, Mat, MatTraitConst,
+    Point, Size, BORDER_DEFAULT, CV_32F, CV_8UC1, CV_8UC3, CV_8UC4,
+};
+use opencv::imgproc;
+use opencv::imgproc::cvt_color;
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::ops::Deref;
+use log::debug;
+
+/// OpenCV 后端, 借用 [`Image`] 的像素构造 Mat 后调用 opencv, 只有结果拷贝回 Image
+pub struct OpenCvOps;
+
+/// Image -> Mat (深拷贝), 返回的 Mat 不再引用 img 的像素
+pub fn to_mat(img: &Image) -> Result<Mat> {
+    with_mat(img, |mat| Ok(mat.try_clone()?))
+}
+
+/// 借用 img 的像素构造 Mat 交给 f, 不拷贝像素, 子图 (roi) 按原图的 step 借用
+/// * Mat 只在 f 执行期间有效, 且只能作为 opencv 函数的输入
+pub fn with_mat<R>(img: &Image, f: impl FnOnce(&Mat) -> Result<R>) -> Result<R> {
+    let typ = match img.channels() {
+        1 => CV_8UC1,
+        3 => CV_8UC3,
+        _ => CV_8UC4,
+    };
+    let pixels = img.pixels();
+    // SAFETY: Mat 不拥有像素, 只在 f 中以 &Mat 使用, 期间 img 被借用, 像素不会被释放或修改;
+    // opencv 只读取输入 Mat, 不会通过这里转出的 *mut 写入
+    let mat = unsafe {
+        Mat::new_rows_cols_with_data_unsafe(
+            img.height(),
+            img.width(),
+            typ,
+            pixels.as_ptr() as *mut c_void,
+            img.step(),
+        )?
+    };
+    f(&mat)
+}
+
+/// Mat -> Image (拷贝一次), 只支持 8 位图像
+pub fn from_mat(mat: &Mat) -> Result<Image> {
+    if mat.depth() != opencv::core::CV_8U {
+        return Err(VisionError::new(format!(
+            "Unsupported mat depth: {}",
+            mat.depth()
+        )));
+    }
+    let data = if mat.is_continuous() {
+        mat.data_bytes()?.to_vec()
+    } else {
+        mat.try_clone()?.data_bytes()?.to_vec()
+    };
+    Image::from_vec(mat.cols(), mat.rows(), mat.channels(), data)
+}
+
+fn gray_mat(mat: &Mat) -> Result<Mat> {
+    let channels = mat.channels();
+    if channels == 3 || channels == 4 {
+        let cvt_code = if channels == 4 {
+            imgproc::COLOR_BGRA2GRAY
+        } else {
+            imgproc::COLOR_BGR2GRAY
+        };
+        let mut gray = Mat::default();
+        cvt_color(mat, &mut gray, cvt_code, 0, ALGO_HINT_DEFAULT)?;
+        Ok(gray)
+    } else {
+        // 深拷贝, mat 可能借用的是 Image 的像素
+        Ok(mat.try_clone()?)
+    }
+}
+
+/// 灰度图直接借用 img 的像素, 彩色图先转为灰度 Mat
+fn with_gray_mat<R>(img: &Image, f: impl FnOnce(&Mat) -> Result<R>) -> Result<R> {
+    if img.channels() == 1 {
+        return with_mat(img, f);
+    }
+    let gray = with_mat(img, gray_mat)?;
+    f(&gray)
+}
+
+impl ImageOps for OpenCvOps {
+    const NAME: &'static str = "opencv";
+
+    fn to_gray(img: &Image) -> Result<Image> {
+        if img.channels() == 1 {
+            return Ok(img.clone());
+        }
+        with_mat(img, |mat| from_mat(&gray_mat(mat)?))
+    }
+
+    fn to_hsv(img: &Image) -> Result<Image> {
+        if img.channels() != 3 && img.channels() != 4 {
+            return Err(VisionError::new("Can not convert gray image to HSV"));
+        }
+        with_mat(img, |mat| {
+            let mut converted = Mat::default();
+            let bgr = if img.channels() == 4 {
+                cvt_color(mat, &mut converted, imgproc::COLOR_BGRA2BGR, 0, ALGO_HINT_DEFAULT)?;
+                &converted
+            } else {
+                mat
+            };
+            let mut hsv = Mat::default();
+            cvt_color(bgr, &mut hsv, imgproc::COLOR_BGR2HSV, 0, ALGO_HINT_DEFAULT)?;
+            from_mat(&hsv)
+        })
+    }
+
+    fn threshold(img: &Image, thresh: u8, otsu: bool) -> Result<Image> {
+        let mut thresh_mat = Mat::default();
+        let typ = if otsu {
+            imgproc::THRESH_BINARY | imgproc::THRESH_OTSU
+        } else {
+            imgproc::THRESH_BINARY
+        };
+        with_gray_mat(img, |gray| {
+            Ok(imgproc::threshold(gray, &mut thresh_mat, thresh as f64, 255.0, typ)?)
+        })?;
+        from_mat(&thresh_mat)
+    }
+
+    fn adaptive_threshold(img: &Image, block_size: i32, c: f64, gaussian: bool) -> Result<Image> {
+        let mut thresh_mat = Mat::default();
+        let method = if gaussian {
+            imgproc::ADAPTIVE_THRESH_GAUSSIAN_C
+        } else {
+            imgproc::ADAPTIVE_THRESH_MEAN_C
+        };
+        with_gray_mat(img, |gray| {
+            Ok(imgproc::adaptive_threshold(
+                gray,
+                &mut thresh_mat,
+                255.0,
+                method,
+                imgproc::THRESH_BINARY,
+                block_size,
+                c,
+            )?)
+        })?;
+        from_mat(&thresh_mat)
+    }
+
+    fn blur(img: &Image, ksize: i32) -> Result<Image> {
+        let mut dst = Mat::default();
+        with_mat(img, |mat| {
+            Ok(imgproc::blur(
+                mat,
+                &mut dst,
+                Size::new(ksize, ksize),
+                Point::new(-1, -1),
+                BORDER_DEFAULT,
+            )?)
+        })?;
+        from_mat(&dst)
+    }
+
+    fn resize(img: &Image, fx: f64, fy: f64) -> Result<Image> {
+        let mut dst = Mat::default();
+        with_mat(img, |mat| {
+            Ok(imgproc::resize(
+                mat,
+                &mut dst,
+                Size::new(0, 0),
+                fx,
+                fy,
+                imgproc::INTER_LINEAR,
+            )?)
+        })?;
+        from_mat(&dst)
+    }
+
+    fn match_template(img: &Image, templ: &Image, method: MatchMethod) -> Result<MatchResult> {
+        let mut res_mat = Mat::default();
+        let cv_method = match method {
+            MatchMethod::CcorrNormed => imgproc::TM_CCORR_NORMED,
+            MatchMethod::CcoeffNormed => imgproc::TM_CCOEFF_NORMED,
+            MatchMethod::SqdiffNormed => imgproc::TM_SQDIFF_NORMED,
+        };
+        with_mat(img, |img| {
+            with_mat(templ, |templ| {
+                Ok(imgproc::match_template(
+                    img,
+                    templ,
+                    &mut res_mat,
+                    cv_method,
+                    &no_array(),
+                )?)
+            })
+        })?;
+        let (mut min_val, mut max_val) = (0f64, 0f64);
+        let (mut min_loc, mut max_loc) = (Point::default(), Point::default());
+        min_max_loc(
+            &res_mat,
+            Some(&mut min_val),
+            Some(&mut max_val),
+            Some(&mut min_loc),
+            Some(&mut max_loc),
+            &no_array(),
+        )?;
+        let (score, loc) = match method {
+            MatchMethod::SqdiffNormed => (min_val, min_loc),
+            _ => (max_val, max_loc),
+        };
+        Ok(MatchResult {
+            score,
+            location: (loc.x, loc.y),
+        })
+    }
+
+    fn ssim(img1: &Image, img2: &Image) -> Result<f64> {
+        with_mat(img1, |img1| with_mat(img2, |img2| mat_ssim(img1, img2)))
+    }
+}
+
+/// 计算两个 Mat 之间的 SSIM
+/// 返回 Scalar channel 1或者channel 1 2 3的均值，因为 SSIM 是分通道计算的（BGR 每个通道一个值）
+/// 仿C++版本, unsafe部分是为了绕过&var和&mut var无法同时使用的问题
+///
+/// ## Parameters
+///
+/// * img1: opencv mat.
+/// * img2: opencv mat.
+fn mat_ssim(img1: &Mat, img2: &Mat) -> Result<f64> {
+    let c1 = 6.5025; // (0.01 * 255)^2
+    let c2 = 58.5225; // (0.03 * 255)^2
+
+    let mut i1 = Mat::default();
+    let mut i2 = Mat::default();
+    // 转换为 32 位浮点数以保证计算精度
+    img1.convert_to(&mut i1, CV_32F, 1.0, 0.0)?;
+    img2.convert_to(&mut i2, CV_32F, 1.0, 0.0)?;
+
+    let mut i1_2 = Mat::default();
+    let mut i2_2 = Mat::default();
+    let mut i1_i2 = Mat::default();
+
+    multiply(&i1, &i1, &mut i1_2, 1.0, -1)?; // i1^2
+    multiply(&i2, &i2, &mut i2_2, 1.0, -1)?; // i2^2
+    multiply(&i1, &i2, &mut i1_i2, 1.0, -1)?; // i1 * i2
+
+    // 均值计算 (μ)
+    let mut mu1 = Mat::default();
+    let mut mu2 = Mat::default();
+    let ksize = Size::new(7, 7);
+    let anchor = Point { x: -1, y: -1 };
+    imgproc::blur(&i1, &mut mu1, ksize, anchor, BORDER_DEFAULT)?;
+    imgproc::blur(&i2, &mut mu2, ksize, anchor, BORDER_DEFAULT)?;
+
+    let mut mu1_2 = Mat::default();
+    let mut mu2_2 = Mat::default();
+    let mut mu1_mu2 = Mat::default();
+    multiply(&mu1, &mu1, &mut mu1_2, 1.0, -1)?; // μ1^2
+    multiply(&mu2, &mu2, &mut mu2_2, 1.0, -1)?; // μ2^2
+    multiply(&mu1, &mu2, &mut mu1_mu2, 1.0, -1)?; // μ1 * μ2
+
+    // 方差与协方差计算 (σ)
+    let sigma1_2 = RefCell::new(Mat::default());
+    let sigma2_2 = RefCell::new(Mat::default());
+    let sigma12 = RefCell::new(Mat::default());
+    let mut_sigma1_2 = unsafe { &mut *sigma1_2.as_ptr() };
+    let mut_sigma2_2 = unsafe { &mut *sigma2_2.as_ptr() };
+    let mut_sigma12 = unsafe { &mut *sigma12.as_ptr() };
+    imgproc::blur(&i1_2, mut_sigma1_2, ksize, anchor, BORDER_DEFAULT)?;
+    subtract(
+        sigma1_2.borrow().deref(),
+        &mu1_2,
+        mut_sigma1_2,
+        &no_array(),
+        -1,
+    )?; // σ1^2 = E[i1^2] - μ1^2
+
+    imgproc::blur(&i2_2, mut_sigma2_2, ksize, anchor, BORDER_DEFAULT)?;
+    subtract(
+        sigma2_2.borrow().deref(),
+        &mu2_2,
+        mut_sigma2_2,
+        &no_array(),
+        -1,
+    )?; // σ2^2 = E[i2^2] - μ2^2
+
+    imgproc::blur(&i1_i2, mut_sigma12, ksize, anchor, BORDER_DEFAULT)?;
+    subtract(
+        &sigma12.borrow().deref(),
+        &mu1_mu2,
+        mut_sigma12,
+        &no_array(),
+        -1,
+    )?; // σ12 = E[i1*i2] - μ1*μ2
+
+    // 公式: SSIM = ((2*mu1*mu2 + C1) * (2*sigma12 + C2)) / ((mu1^2 + mu2^2 + C1) * (sigma1^2 + sigma2^2 + C2))
+    let t1 = RefCell::new(Mat::default()); // 2*mu1*mu2 + C1
+    let t2 = RefCell::new(Mat::default()); // 2*sigma12 + C2
+    let t3 = RefCell::new(Mat::default()); // t1 * t2
+
+    let mut_t1 = unsafe { &mut *t1.as_ptr() };
+    let mut_t2 = unsafe { &mut *t2.as_ptr() };
+    let mut_t3 = unsafe { &mut *t3.as_ptr() };
+
+    // t1 = 2 * mu1_mu2 + C1;
+    let _ = &mu1_mu2.convert_to(mut_t1, CV_32F, 2.0, c1)?;
+    // t2 = 2 * sigma12 + C2;
+    sigma12.borrow().convert_to(mut_t2, CV_32F, 2.0, c2)?;
+    // t3 = t1*t2
+    multiply(t1.borrow().deref(), t2.borrow().deref(), mut_t3, 1.0, -1)?;
+    // t1 = mu1_2 + mu2_2 + C1;
+    add_weighted(&mu1_2, 1.0, &mu2_2, 1.0, c1, mut_t1, -1)?;
+    // t2 = sigma1_2 + sigma2_2 + C2;
+    add_weighted(
+        sigma1_2.borrow().deref(),
+        1.0,
+        sigma2_2.borrow().deref(),
+        1.0,
+        c2,
+        mut_t2,
+        -1,
+    )?;
+
+    multiply(t1.borrow().deref(), t2.borrow().deref(), mut_t1, 1.0, -1)?;
+
+    let mut ssim_map = Mat::default();
+    // ssim_map =  t3./t1
+    divide2(
+        t3.borrow().deref(),
+        t1.borrow().deref(),
+        &mut ssim_map,
+        1.0,
+        -1,
+    )?;
+
+    // 对全图取平均值
+    // mssim = average of ssim map
+    let mssim = mean(&ssim_map, &no_array())?;
+    if cfg!(debug_assertions) {
+        debug!("Sim: {:?}", mssim.0);
+    };
+    let channels = img1.channels().clamp(1, 4) as usize;
+    Ok(mssim.0[..channels].iter().sum::<f64>() / channels as f64)
+}
\ No newline at end of file
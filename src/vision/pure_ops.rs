@@ -0,0 +1,601 @@
+use crate::vision::{Image, ImageOps, MatchMethod, MatchResult, Result, VisionError};
+
+/// 基于 image crate 的纯 Rust 后端, 算法尽量与 OpenCV 对齐
+pub struct PureOps;
+
+/// BORDER_REFLECT_101: gfedcb|abcdefgh|gfedcba
+fn reflect_101(i: i32, n: i32) -> usize {
+    if n == 1 {
+        return 0;
+    }
+    let mut i = i;
+    while i < 0 || i >= n {
+        if i < 0 {
+            i = -i;
+        } else {
+            i = 2 * n - 2 - i;
+        }
+    }
+    i as usize
+}
+
+/// 取出第 c 个通道转为 f32
+fn channel_f32(img: &Image, c: i32) -> Vec<f32> {
+    let mut out = Vec::with_capacity((img.width() * img.height()) as usize);
+    for y in 0..img.height() {
+        let row = img.row(y);
+        out.extend(
+            row.iter()
+                .skip(c as usize)
+                .step_by(img.channels() as usize)
+                .map(|v| *v as f32),
+        );
+    }
+    out
+}
+
+/// BORDER_REPLICATE: aaaaaa|abcdefgh|hhhhhhh
+fn replicate(i: i32, n: i32) -> usize {
+    i.clamp(0, n - 1) as usize
+}
+
+/// 可分离滤波, 先横向再纵向, kernel 长度需为奇数
+fn separable_filter(
+    src: &[f32],
+    width: i32,
+    height: i32,
+    kernel: &[f32],
+    border: fn(i32, i32) -> usize,
+) -> Vec<f32> {
+    let half = (kernel.len() / 2) as i32;
+    let mut tmp = vec![0f32; src.len()];
+    for y in 0..height {
+        let row = &src[(y * width) as usize..((y + 1) * width) as usize];
+        for x in 0..width {
+            let mut sum = 0f32;
+            for (k, w) in (-half..=half).zip(kernel) {
+                sum += row[border(x + k, width)] * w;
+            }
+            tmp[(y * width + x) as usize] = sum;
+        }
+    }
+    let mut out = vec![0f32; src.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0f32;
+            for (k, w) in (-half..=half).zip(kernel) {
+                sum += tmp[border(y + k, height) * width as usize + x as usize] * w;
+            }
+            out[(y * width + x) as usize] = sum;
+        }
+    }
+    out
+}
+
+/// 均值滤波
+fn box_filter(src: &[f32], width: i32, height: i32, ksize: i32) -> Vec<f32> {
+    let kernel = vec![1.0 / ksize as f32; ksize as usize];
+    separable_filter(src, width, height, &kernel, reflect_101)
+}
+
+/// 与 OpenCV getGaussianKernel(ksize, 0) 相同的一维高斯核
+fn gaussian_kernel(ksize: i32) -> Vec<f32> {
+    match ksize {
+        1 => vec![1.0],
+        3 => vec![0.25, 0.5, 0.25],
+        5 => vec![0.0625, 0.25, 0.375, 0.25, 0.0625],
+        7 => vec![0.03125, 0.109375, 0.21875, 0.28125, 0.21875, 0.109375, 0.03125],
+        _ => {
+            let sigma = 0.3 * ((ksize - 1) as f64 * 0.5 - 1.0) + 0.8;
+            let half = (ksize / 2) as f64;
+            let kernel: Vec<f64> = (0..ksize)
+                .map(|i| (-(i as f64 - half).powi(2) / (2.0 * sigma * sigma)).exp())
+                .collect();
+            let sum: f64 = kernel.iter().sum();
+            kernel.iter().map(|v| (v / sum) as f32).collect()
+        }
+    }
+}
+
+/// 与 OpenCV getThreshVal_Otsu_8u 相同的类间方差最大化
+fn otsu_threshold(gray: &Image) -> u8 {
+    let mut hist = [0f64; 256];
+    for y in 0..gray.height() {
+        for v in gray.row(y) {
+            hist[*v as usize] += 1.0;
+        }
+    }
+    let total = (gray.width() * gray.height()) as f64;
+    let mu: f64 = hist
+        .iter()
+        .enumerate()
+        .map(|(i, h)| i as f64 * h / total)
+        .sum();
+
+    let (mut q1, mut mu1, mut max_sigma, mut max_val) = (0f64, 0f64, 0f64, 0u8);
+    let eps = f32::EPSILON as f64;
+    for (i, h) in hist.iter().enumerate() {
+        let p_i = h / total;
+        mu1 *= q1;
+        q1 += p_i;
+        let q2 = 1.0 - q1;
+        if q1.min(q2) < eps || q1.max(q2) > 1.0 - eps {
+            continue;
+        }
+        mu1 = (mu1 + i as f64 * p_i) / q1;
+        let mu2 = (mu - q1 * mu1) / q2;
+        let sigma = q1 * q2 * (mu1 - mu2) * (mu1 - mu2);
+        if sigma > max_sigma {
+            max_sigma = sigma;
+            max_val = i as u8;
+        }
+    }
+    max_val
+}
+
+impl ImageOps for PureOps {
+    const NAME: &'static str = "pure-rust";
+
+    fn to_gray(img: &Image) -> Result<Image> {
+        match img.channels() {
+            1 => Ok(img.clone()),
+            cn => {
+                let mut out = Vec::with_capacity((img.width() * img.height()) as usize);
+                for y in 0..img.height() {
+                    for px in img.row(y).chunks_exact(cn as usize) {
+                        // 与 OpenCV 相同的定点系数: 0.114 B + 0.587 G + 0.299 R
+                        let (b, g, r) = (px[0] as u32, px[1] as u32, px[2] as u32);
+                        out.push(((b * 1868 + g * 9617 + r * 4899 + (1 << 13)) >> 14) as u8);
+                    }
+                }
+                Image::from_vec(img.width(), img.height(), 1, out)
+            }
+        }
+    }
+
+    fn to_hsv(img: &Image) -> Result<Image> {
+        let cn = img.channels();
+        if cn == 1 {
+            return Err(VisionError::new("Can not convert gray image to HSV"));
+        }
+        let mut out = Vec::with_capacity((img.width() * img.height() * 3) as usize);
+        for y in 0..img.height() {
+            for px in img.row(y).chunks_exact(cn as usize) {
+                let (b, g, r) = (px[0] as i32, px[1] as i32, px[2] as i32);
+                let v = b.max(g).max(r);
+                let diff = v - b.min(g).min(r);
+                let s = if v == 0 {
+                    0
+                } else {
+                    (diff as f64 * 255.0 / v as f64).round() as i32
+                };
+                // 色相按 OpenCV 8 位图的约定除以 2, 范围 0..180
+                let h = if diff == 0 {
+                    0
+                } else {
+                    let h = if v == r {
+                        g - b
+                    } else if v == g {
+                        b - r + 2 * diff
+                    } else {
+                        r - g + 4 * diff
+                    };
+                    let h = (h as f64 * 30.0 / diff as f64).round() as i32;
+                    if h < 0 { h + 180 } else { h % 180 }
+                };
+                out.extend_from_slice(&[h as u8, s as u8, v as u8]);
+            }
+        }
+        Image::from_vec(img.width(), img.height(), 3, out)
+    }
+
+    fn threshold(img: &Image, thresh: u8, otsu: bool) -> Result<Image> {
+        let gray = Self::to_gray(img)?;
+        let thresh = if otsu { otsu_threshold(&gray) } else { thresh };
+        let mut out = Vec::with_capacity((gray.width() * gray.height()) as usize);
+        for y in 0..gray.height() {
+            out.extend(gray.row(y).iter().map(|v| if *v > thresh { 255 } else { 0 }));
+        }
+        Image::from_vec(gray.width(), gray.height(), 1, out)
+    }
+
+    fn adaptive_threshold(img: &Image, block_size: i32, c: f64, gaussian: bool) -> Result<Image> {
+        if block_size < 3 || block_size % 2 == 0 {
+            return Err(VisionError::new(format!(
+                "Adaptive threshold block size must be odd and >= 3, got {}",
+                block_size
+            )));
+        }
+        let gray = Self::to_gray(img)?;
+        let (width, height) = (gray.width(), gray.height());
+        let kernel = if gaussian {
+            gaussian_kernel(block_size)
+        } else {
+            vec![1.0 / block_size as f32; block_size as usize]
+        };
+        let src = channel_f32(&gray, 0);
+        let mean = separable_filter(&src, width, height, &kernel, replicate);
+        // 与 OpenCV 一致: 均值先取整到 u8, 再比较 src - mean > -ceil(c)
+        let delta = c.ceil() as i32;
+        let out = src
+            .iter()
+            .zip(mean)
+            .map(|(v, m)| {
+                let m = m.round().clamp(0.0, 255.0) as i32;
+                if *v as i32 - m > -delta { 255 } else { 0 }
+            })
+            .collect();
+        Image::from_vec(width, height, 1, out)
+    }
+
+    fn blur(img: &Image, ksize: i32) -> Result<Image> {
+        let (width, height, cn) = (img.width(), img.height(), img.channels());
+        let mut out = vec![0u8; (width * height * cn) as usize];
+        for c in 0..cn {
+            let blurred = box_filter(&channel_f32(img, c), width, height, ksize);
+            for (i, v) in blurred.iter().enumerate() {
+                out[i * cn as usize + c as usize] = v.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+        Image::from_vec(width, height, cn, out)
+    }
+
+    fn resize(img: &Image, fx: f64, fy: f64) -> Result<Image> {
+        let (src_w, src_h, cn) = (img.width(), img.height(), img.channels());
+        let dst_w = (src_w as f64 * fx).round() as i32;
+        let dst_h = (src_h as f64 * fy).round() as i32;
+        if dst_w <= 0 || dst_h <= 0 {
+            return Err(VisionError::new(format!(
+                "Invalid resize scale {}x{} for {:?}",
+                fx, fy, img
+            )));
+        }
+        // 像素中心对齐, 与 OpenCV INTER_LINEAR 的坐标映射一致
+        let map = |d: i32, scale: f64, src: i32| -> (usize, usize, f64) {
+            let s = (d as f64 + 0.5) / scale - 0.5;
+            let s0 = s.floor();
+            let (mut i0, mut t) = (s0 as i32, s - s0);
+            if i0 < 0 {
+                i0 = 0;
+                t = 0.0;
+            }
+            if i0 >= src - 1 {
+                i0 = src - 1;
+                t = 0.0;
+            }
+            (i0 as usize, (i0 + 1).min(src - 1) as usize, t)
+        };
+        let mut out = Vec::with_capacity((dst_w * dst_h * cn) as usize);
+        for dy in 0..dst_h {
+            let (y0, y1, ty) = map(dy, fy, src_h);
+            let (row0, row1) = (img.row(y0 as i32), img.row(y1 as i32));
+            for dx in 0..dst_w {
+                let (x0, x1, tx) = map(dx, fx, src_w);
+                for c in 0..cn as usize {
+                    let at = |row: &[u8], x: usize| row[x * cn as usize + c] as f64;
+                    let top = at(row0, x0) * (1.0 - tx) + at(row0, x1) * tx;
+                    let bottom = at(row1, x0) * (1.0 - tx) + at(row1, x1) * tx;
+                    out.push((top * (1.0 - ty) + bottom * ty).round().clamp(0.0, 255.0) as u8);
+                }
+            }
+        }
+        Image::from_vec(dst_w, dst_h, cn, out)
+    }
+
+    fn match_template(img: &Image, templ: &Image, method: MatchMethod) -> Result<MatchResult> {
+        if img.channels() != templ.channels()
+            || templ.width() > img.width()
+            || templ.height() > img.height()
+        {
+            return Err(VisionError::new(format!(
+                "Template {:?} does not fit image {:?}",
+                templ, img
+            )));
+        }
+        let row_len = (templ.width() * templ.channels()) as usize;
+        let templ_px: Vec<f64> = (0..templ.height())
+            .flat_map(|y| templ.row(y).iter())
+            .map(|v| *v as f64)
+            .collect();
+        let n = templ_px.len() as f64;
+        let templ_mean = templ_px.iter().sum::<f64>() / n;
+        let templ_sq: f64 = templ_px.iter().map(|v| v * v).sum();
+        let templ_var: f64 = templ_px.iter().map(|v| (v - templ_mean).powi(2)).sum();
+
+        let mut best = MatchResult {
+            score: method.worst(),
+            location: (0, 0),
+        };
+        for y in 0..=img.height() - templ.height() {
+            for x in 0..=img.width() - templ.width() {
+                let (mut cross, mut img_sum, mut img_sq) = (0f64, 0f64, 0f64);
+                for ty in 0..templ.height() {
+                    let start = (x * img.channels()) as usize;
+                    let img_row = &img.row(y + ty)[start..start + row_len];
+                    for (a, b) in img_row.iter().zip(templ.row(ty)) {
+                        let (a, b) = (*a as f64, *b as f64);
+                        cross += a * b;
+                        img_sum += a;
+                        img_sq += a * a;
+                    }
+                }
+                let score = match method {
+                    MatchMethod::CcorrNormed => {
+                        let denom = (img_sq * templ_sq).sqrt();
+                        if denom > f64::EPSILON { cross / denom } else { 0.0 }
+                    }
+                    MatchMethod::CcoeffNormed => {
+                        // sum((I - mean_I)(T - mean_T)) = sum(I*T) - sum(I) * mean_T
+                        let num = cross - img_sum * templ_mean;
+                        let img_var = img_sq - img_sum * img_sum / n;
+                        let denom = (img_var.max(0.0) * templ_var).sqrt();
+                        if denom > f64::EPSILON { num / denom } else { 0.0 }
+                    }
+                    MatchMethod::SqdiffNormed => {
+                        let num = img_sq - 2.0 * cross + templ_sq;
+                        let denom = (img_sq * templ_sq).sqrt();
+                        if denom > f64::EPSILON { num / denom } else { 1.0 }
+                    }
+                };
+                if method.is_better(score, best.score) {
+                    best = MatchResult {
+                        score,
+                        location: (x, y),
+                    };
+                }
+            }
+        }
+        Ok(best)
+    }
+
+    fn ssim(img1: &Image, img2: &Image) -> Result<f64> {
+        if img1.width() != img2.width()
+            || img1.height() != img2.height()
+            || img1.channels() != img2.channels()
+        {
+            return Err(VisionError::new(format!(
+                "Ssim needs images of the same shape: {:?} vs {:?}",
+                img1, img2
+            )));
+        }
+        let c1 = 6.5025f32; // (0.01 * 255)^2
+        let c2 = 58.5225f32; // (0.03 * 255)^2
+        let (width, height) = (img1.width(), img1.height());
+        let blur = |v: &[f32]| box_filter(v, width, height, 7);
+
+        let mut total = 0f64;
+        for c in 0..img1.channels() {
+            let i1 = channel_f32(img1, c);
+            let i2 = channel_f32(img2, c);
+            let mul = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).collect::<Vec<_>>();
+
+            let mu1 = blur(&i1);
+            let mu2 = blur(&i2);
+            let sigma1_2 = blur(&mul(&i1, &i1));
+            let sigma2_2 = blur(&mul(&i2, &i2));
+            let sigma12 = blur(&mul(&i1, &i2));
+
+            let mut sum = 0f64;
+            for i in 0..i1.len() {
+                let (m1, m2) = (mu1[i], mu2[i]);
+                let s1 = sigma1_2[i] - m1 * m1;
+                let s2 = sigma2_2[i] - m2 * m2;
+                let s12 = sigma12[i] - m1 * m2;
+                let t3 = (2.0 * m1 * m2 + c1) * (2.0 * s12 + c2);
+                let t1 = (m1 * m1 + m2 * m2 + c1) * (s1 + s2 + c2);
+                sum += (t3 / t1) as f64;
+            }
+            total += sum / i1.len() as f64;
+        }
+        Ok(total / img1.channels() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(width: i32, height: i32) -> Image {
+        let data = (0..width * height)
+            .map(|i| ((i % width) * 255 / width + (i / width) * 3) as u8)
+            .collect();
+        Image::from_vec(width, height, 1, data).unwrap()
+    }
+
+    #[test]
+    fn test_ssim_of_same_image_is_one() {
+        let img = gradient(21, 12);
+        let sim = PureOps::ssim(&img, &img).unwrap();
+        assert!((sim - 1.0).abs() < 1e-6, "sim: {}", sim);
+    }
+
+    /// 没有重复结构的图片, 线性渐变平移后 CCOEFF 仍为 1, 不能用来测位置
+    fn pattern(width: i32, height: i32) -> Image {
+        let mut state = 0x2545_f491u32;
+        let data = (0..width * height)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state >> 24) as u8
+            })
+            .collect();
+        Image::from_vec(width, height, 1, data).unwrap()
+    }
+
+    #[test]
+    fn test_match_template_finds_location() {
+        let img = pattern(40, 30);
+        let templ = img.roi((12, 7, 10, 8)).unwrap();
+        let res = PureOps::match_template(&img, &templ, MatchMethod::CcorrNormed).unwrap();
+        assert!(res.score > 0.999, "score: {}", res.score);
+        assert_eq!(res.location, (12, 7));
+        let res = PureOps::match_template(&img, &templ, MatchMethod::CcoeffNormed).unwrap();
+        assert!(res.score > 0.999, "score: {}", res.score);
+        assert_eq!(res.location, (12, 7));
+        let res = PureOps::match_template(&img, &templ, MatchMethod::SqdiffNormed).unwrap();
+        assert!(res.score < 1e-6, "score: {}", res.score);
+        assert_eq!(res.location, (12, 7));
+    }
+
+    #[test]
+    fn test_templates_match_test_data() {
+        use crate::capture_settings::CapturePos;
+        use crate::template_img;
+        use crate::vision::ReadMode;
+
+        let img = Image::load("test_data/target.jpg", ReadMode::Grayscale).unwrap();
+        let shot = img.roi(CapturePos::target(0, 0).rect).unwrap();
+        assert!(PureOps::ssim(&shot, &template_img::TARGET.img).unwrap() > 0.7);
+
+        let img = Image::load("test_data/qte.jpg", ReadMode::Grayscale).unwrap();
+        let shot = PureOps::resize(&img.roi(CapturePos::qte(0, 0).rect).unwrap(), 0.5, 0.5).unwrap();
+        let res = PureOps::match_template(&shot, &template_img::QTE.img, MatchMethod::CcorrNormed).unwrap();
+        assert!(res.score > 0.99, "{:?}", res);
+    }
+
+    #[test]
+    fn test_adaptive_threshold_follows_local_mean() {
+        // 左右两侧亮度不同, 固定阈值无法同时分开, 自适应阈值只把比邻域暗的点置 0
+        let data = (0..20 * 10)
+            .map(|i| {
+                let (x, y) = (i % 20, i / 20);
+                let base = if x < 10 { 60 } else { 200 };
+                if x % 10 == 5 && y == 5 { base - 40 } else { base }
+            })
+            .collect();
+        let img = Image::from_vec(20, 10, 1, data).unwrap();
+        for gaussian in [false, true] {
+            let threshed = PureOps::adaptive_threshold(&img, 5, 10.0, gaussian).unwrap();
+            assert_eq!(threshed.at(5, 5, 0), 0);
+            assert_eq!(threshed.at(15, 5, 0), 0);
+            assert_eq!(threshed.at(2, 2, 0), 255);
+            assert_eq!(threshed.at(17, 2, 0), 255);
+        }
+        assert!(PureOps::adaptive_threshold(&img, 4, 10.0, false).is_err());
+    }
+
+    #[test]
+    fn test_hsv_of_primary_colors() {
+        // BGR: 蓝, 绿, 红, 灰
+        let data = vec![255, 0, 0, 0, 255, 0, 0, 0, 255, 128, 128, 128];
+        let img = Image::from_vec(4, 1, 3, data).unwrap();
+        let hsv = PureOps::to_hsv(&img).unwrap();
+        assert_eq!(hsv.to_vec(), vec![120, 255, 255, 60, 255, 255, 0, 255, 255, 0, 0, 128]);
+        assert!(PureOps::to_hsv(&PureOps::to_gray(&img).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_otsu_splits_two_levels() {
+        let data = (0..100).map(|i| if i < 50 { 20 } else { 200 }).collect();
+        let img = Image::from_vec(10, 10, 1, data).unwrap();
+        let threshed = PureOps::threshold(&img, 190, true).unwrap();
+        assert_eq!(threshed.at(0, 0, 0), 0);
+        assert_eq!(threshed.at(9, 9, 0), 255);
+    }
+}
+
+/// 与 OpenCV 后端的对比测试, 需要同时编译两个后端
+#[cfg(all(test, feature = "opencv-backend"))]
+mod cross_backend_tests {
+    use super::*;
+    use crate::capture_settings::CapturePos;
+    use crate::template_img;
+    use crate::vision::opencv_ops::OpenCvOps;
+    use crate::vision::ReadMode;
+
+    const TOLERANCE: f64 = 0.02;
+
+    fn qte_shot() -> Image {
+        let img = Image::load("test_data/qte.jpg", ReadMode::Grayscale).unwrap();
+        img.roi(CapturePos::qte(0, 0).rect).unwrap()
+    }
+
+    #[test]
+    fn test_gray_and_threshold_agree() {
+        let img = Image::load("test_data/target.jpg", ReadMode::Unchanged).unwrap();
+        let shot = img.roi(CapturePos::target(0, 0).rect).unwrap();
+        let gray_cv = OpenCvOps::to_gray(&shot).unwrap();
+        let gray_rs = PureOps::to_gray(&shot).unwrap();
+        let max_diff = gray_cv
+            .to_vec()
+            .iter()
+            .zip(gray_rs.to_vec())
+            .map(|(a, b)| (*a as i32 - b as i32).abs())
+            .max()
+            .unwrap();
+        assert!(max_diff <= 1, "gray diff: {}", max_diff);
+
+        let th_cv = OpenCvOps::threshold(&shot, 190, true).unwrap();
+        let th_rs = PureOps::threshold(&shot, 190, true).unwrap();
+        let sim = PureOps::ssim(&th_cv, &th_rs).unwrap();
+        assert!(sim > 1.0 - TOLERANCE, "threshold sim: {}", sim);
+
+        for gaussian in [false, true] {
+            let th_cv = OpenCvOps::adaptive_threshold(&shot, 11, 2.0, gaussian).unwrap();
+            let th_rs = PureOps::adaptive_threshold(&shot, 11, 2.0, gaussian).unwrap();
+            let sim = PureOps::ssim(&th_cv, &th_rs).unwrap();
+            assert!(sim > 1.0 - TOLERANCE, "adaptive threshold sim: {}", sim);
+        }
+    }
+
+    #[test]
+    fn test_hsv_agree() {
+        let img = Image::load("test_data/target.jpg", ReadMode::Unchanged).unwrap();
+        let shot = img.roi(CapturePos::target(0, 0).rect).unwrap();
+        let hsv_cv = OpenCvOps::to_hsv(&shot).unwrap().to_vec();
+        let hsv_rs = PureOps::to_hsv(&shot).unwrap().to_vec();
+        for (cv, rs) in hsv_cv.chunks_exact(3).zip(hsv_rs.chunks_exact(3)) {
+            // 色相环首尾相接, 179 与 0 只差 1
+            let dh = (cv[0] as i32 - rs[0] as i32).abs();
+            assert!(dh.min(180 - dh) <= 1, "{:?} vs {:?}", cv, rs);
+            assert!((cv[1] as i32 - rs[1] as i32).abs() <= 1, "{:?} vs {:?}", cv, rs);
+            assert_eq!(cv[2], rs[2]);
+        }
+    }
+
+    #[test]
+    fn test_ssim_agree() {
+        let shot = Image::load("test_data/target.jpg", ReadMode::Grayscale)
+            .unwrap()
+            .roi(CapturePos::target(0, 0).rect)
+            .unwrap();
+        let templ = &template_img::TARGET.img;
+        let sim_cv = OpenCvOps::ssim(&shot, templ).unwrap();
+        let sim_rs = PureOps::ssim(&shot, templ).unwrap();
+        assert!((sim_cv - sim_rs).abs() < TOLERANCE, "{} vs {}", sim_cv, sim_rs);
+
+        let blur_cv = OpenCvOps::blur(&shot, 7).unwrap();
+        let blur_rs = PureOps::blur(&shot, 7).unwrap();
+        let sim = OpenCvOps::ssim(&blur_cv, &blur_rs).unwrap();
+        assert!(sim > 1.0 - TOLERANCE, "blur sim: {}", sim);
+    }
+
+    #[test]
+    fn test_resize_and_match_template_agree() {
+        let shot = qte_shot();
+        let resized_cv = OpenCvOps::resize(&shot, 0.5, 0.5).unwrap();
+        let resized_rs = PureOps::resize(&shot, 0.5, 0.5).unwrap();
+        assert_eq!(resized_cv.width(), resized_rs.width());
+        assert_eq!(resized_cv.height(), resized_rs.height());
+
+        let templ = &template_img::QTE.img;
+        for method in [
+            MatchMethod::CcorrNormed,
+            MatchMethod::CcoeffNormed,
+            MatchMethod::SqdiffNormed,
+        ] {
+            let res_cv = OpenCvOps::match_template(&resized_cv, templ, method).unwrap();
+            let res_rs = PureOps::match_template(&resized_rs, templ, method).unwrap();
+            assert!(
+                (res_cv.score - res_rs.score).abs() < TOLERANCE,
+                "{}: {:?} vs {:?}",
+                method,
+                res_cv,
+                res_rs
+            );
+            assert!((res_cv.location.0 - res_rs.location.0).abs() <= 1);
+            assert!((res_cv.location.1 - res_rs.location.1).abs() <= 1);
+        }
+    }
+}
@@ -3,7 +3,9 @@ use std::ffi::c_void;
 use windows::Win32::Foundation::{HWND, LPARAM, POINT};
 use windows::Win32::Graphics::Dwm::{DWMWA_EXTENDED_FRAME_BOUNDS, DwmGetWindowAttribute};
 use windows::Win32::Graphics::Gdi::ClientToScreen;
-use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, FindWindowW, GetWindowTextW};
+use windows::Win32::UI::WindowsAndMessaging::{
+    EnumWindows, FindWindowW, GetClientRect, GetWindowTextW,
+};
 use windows::core::{BOOL, HSTRING, PCWSTR};
 ///
 /// * 根据完整窗口名获取HWND
@@ -108,6 +110,30 @@ pub fn get_window_client_offset(hwnd: HWND) -> Option<(i32, i32)> {
     }
 }
 
+/// 客户区大小 (宽, 高)
+pub fn get_window_client_size(hwnd: HWND) -> Option<(i32, i32)> {
+    use windows::Win32::Foundation::RECT;
+    let mut rect = RECT::default();
+    unsafe {
+        match GetClientRect(hwnd, &mut rect) {
+            Ok(_) => Some((rect.right - rect.left, rect.bottom - rect.top)),
+            Err(e) => {
+                error!("GetClientRect error: {:?}", e);
+                None
+            }
+        }
+    }
+}
+
+/// 窗口子系统的程序没有控制台, 从命令行启动时附加到父进程的控制台以便输出
+/// * 输出被重定向到文件/管道时不需要, 附加失败也不影响
+pub fn attach_parent_console() {
+    use windows::Win32::System::Console::{ATTACH_PARENT_PROCESS, AttachConsole};
+    unsafe {
+        let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;